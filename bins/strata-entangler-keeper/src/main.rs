@@ -0,0 +1,109 @@
+//! Watches configured child entanglers and submits permissionless or keeper-owned transactions
+//! once their timestamps pass. Swap fees stay in storage until the authority withdraws them, so
+//! there is no separate fee collection transaction to crank. Run with the path to a JSON config:
+//!
+//! ```json
+//! {
+//!   "rpc_url": "https://api.mainnet-beta.solana.com",
+//!   "keypair": "/etc/keeper/id.json",
+//!   "poll_interval_seconds": 60,
+//!   "child_entanglers": ["..."]
+//! }
+//! ```
+
+mod task;
+
+use anyhow::{anyhow, Context as _, Result};
+use fungible_entangler_sdk::{rpc, state::FungibleChildEntanglerV1};
+use serde::Deserialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+  account::from_account,
+  clock::Clock,
+  commitment_config::CommitmentConfig,
+  pubkey::Pubkey,
+  signature::{read_keypair_file, Keypair, Signer},
+  sysvar,
+  transaction::Transaction,
+};
+use std::{str::FromStr, time::Duration};
+
+#[derive(Deserialize)]
+struct Config {
+  rpc_url: String,
+  keypair: String,
+  #[serde(default = "default_poll_interval_seconds")]
+  poll_interval_seconds: u64,
+  child_entanglers: Vec<String>,
+}
+
+fn default_poll_interval_seconds() -> u64 {
+  60
+}
+
+pub struct Keeper {
+  pub client: RpcClient,
+  pub payer: Keypair,
+}
+
+impl Keeper {
+  fn unix_timestamp(&self) -> Result<i64> {
+    let account = self.client.get_account(&sysvar::clock::id())?;
+    let clock: Clock = from_account(&account).ok_or_else(|| anyhow!("Invalid clock sysvar"))?;
+    Ok(clock.unix_timestamp)
+  }
+
+  fn crank(&self, child_entangler: &Pubkey) -> Result<()> {
+    let child: FungibleChildEntanglerV1 = rpc::get_account(&self.client, child_entangler)?;
+    let now = self.unix_timestamp()?;
+
+    for task in task::due(self, child_entangler, &child, now)? {
+      let blockhash = self.client.get_latest_blockhash()?;
+      let transaction = Transaction::new_signed_with_payer(
+        &[task.instruction],
+        Some(&self.payer.pubkey()),
+        &[&self.payer],
+        blockhash,
+      );
+      match self.client.send_and_confirm_transaction(&transaction) {
+        Ok(signature) => log::info!("{} {}: {}", task.name, child_entangler, signature),
+        Err(err) => log::error!("{} {} failed: {}", task.name, child_entangler, err),
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn main() -> Result<()> {
+  env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+  let config_path = std::env::args()
+    .nth(1)
+    .ok_or_else(|| anyhow!("Usage: strata-entangler-keeper <config.json>"))?;
+  let config: Config = serde_json::from_str(
+    &std::fs::read_to_string(&config_path).with_context(|| format!("Reading {}", config_path))?,
+  )?;
+  let child_entanglers = config
+    .child_entanglers
+    .iter()
+    .map(|address| Pubkey::from_str(address).map_err(|_| anyhow!("Invalid address {}", address)))
+    .collect::<Result<Vec<_>>>()?;
+  let keypair = &config.keypair;
+  let keeper = Keeper {
+    payer: read_keypair_file(keypair)
+      .map_err(|err| anyhow!("Failed to read keypair {}: {}", keypair, err))?,
+    client: RpcClient::new_with_commitment(config.rpc_url, CommitmentConfig::confirmed()),
+  };
+
+  let interval = Duration::from_secs(config.poll_interval_seconds);
+  loop {
+    for child_entangler in &child_entanglers {
+      // One bad entangler shouldn't stop the rest from being cranked
+      if let Err(err) = keeper.crank(child_entangler) {
+        log::error!("Failed to crank {}: {}", child_entangler, err);
+      }
+    }
+    std::thread::sleep(interval);
+  }
+}
@@ -0,0 +1,87 @@
+use crate::Keeper;
+use anyhow::Result;
+use fungible_entangler_sdk::{
+  instruction, pda, rpc,
+  state::{FungibleChildEntanglerV1, FungibleParentEntanglerV1, TopOffRecordV0},
+};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signer};
+use spl_associated_token_account::get_associated_token_address;
+
+/// A transaction that has come due for a child entangler
+pub struct Task {
+  pub name: &'static str,
+  pub instruction: Instruction,
+}
+
+/// Commits the freeze once the child or its parent passes its freeze time
+fn enforce_freeze(
+  keeper: &Keeper,
+  child_entangler: &Pubkey,
+  child: &FungibleChildEntanglerV1,
+  now: i64,
+) -> Result<Option<Task>> {
+  if child.frozen {
+    return Ok(None);
+  }
+
+  let parent: FungibleParentEntanglerV1 =
+    rpc::get_account(&keeper.client, &child.parent_entangler)?;
+  let is_due = |freeze: Option<i64>| freeze.map_or(false, |freeze| now >= freeze);
+  if !is_due(child.freeze_swap_unix_time) && !is_due(parent.freeze_swap_unix_time) {
+    return Ok(None);
+  }
+
+  Ok(Some(Task {
+    name: "EnforceFreezeV0",
+    instruction: instruction::enforce_freeze_v0(&child.parent_entangler, child_entangler),
+  }))
+}
+
+/// Once a child expires, reclaims the keeper's own TopOff deposit into its associated token
+/// account
+fn reclaim_after_expiry(
+  keeper: &Keeper,
+  child_entangler: &Pubkey,
+  child: &FungibleChildEntanglerV1,
+  now: i64,
+) -> Result<Option<Task>> {
+  if !child.expiry_unix_time.map_or(false, |expiry| now >= expiry) {
+    return Ok(None);
+  }
+
+  let depositor = keeper.payer.pubkey();
+  let (top_off_record, _) = pda::top_off_record(child_entangler, &depositor);
+  let record = match rpc::get_account_if_exists::<TopOffRecordV0>(&keeper.client, &top_off_record)?
+  {
+    Some(record) => record,
+    None => return Ok(None),
+  };
+  if record.reclaimed || record.amount == 0 {
+    return Ok(None);
+  }
+
+  Ok(Some(Task {
+    name: "ReclaimAfterExpiryV0",
+    instruction: instruction::reclaim_after_expiry_v0(
+      child_entangler,
+      &depositor,
+      &get_associated_token_address(&depositor, &child.child_mint),
+      child.rewards_pool.as_ref(),
+    ),
+  }))
+}
+
+/// Every task due for child_entangler at now
+pub fn due(
+  keeper: &Keeper,
+  child_entangler: &Pubkey,
+  child: &FungibleChildEntanglerV1,
+  now: i64,
+) -> Result<Vec<Task>> {
+  Ok(
+    enforce_freeze(keeper, child_entangler, child, now)?
+      .into_iter()
+      .chain(reclaim_after_expiry(keeper, child_entangler, child, now)?)
+      .collect(),
+  )
+}
@@ -0,0 +1,512 @@
+use crate::{Cli, Command, Direction};
+use anyhow::{anyhow, bail, Result};
+use fungible_entangler_sdk::{
+  instruction::{self, EntanglerPair},
+  pda,
+  quote::SwapDirection,
+  rpc,
+  state::{
+    AuthorityConfigV0, ExchangeRateV0, FungibleChildEntanglerV1, FungibleParentEntanglerV1,
+    GlobalConfigV0, ProgramAccount,
+  },
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+  commitment_config::CommitmentConfig,
+  instruction::Instruction,
+  pubkey::Pubkey,
+  signature::{read_keypair_file, Keypair, Signer},
+  transaction::Transaction,
+};
+use spl_associated_token_account::get_associated_token_address;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+enum Entangler {
+  Parent(Box<FungibleParentEntanglerV1>),
+  Child(Box<FungibleChildEntanglerV1>),
+}
+
+struct Context {
+  client: RpcClient,
+  payer: Keypair,
+}
+
+impl Context {
+  fn pubkey(&self) -> Pubkey {
+    self.payer.pubkey()
+  }
+
+  fn send(&self, instructions: &[Instruction]) -> Result<()> {
+    let blockhash = self.client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+      instructions,
+      Some(&self.payer.pubkey()),
+      &[&self.payer],
+      blockhash,
+    );
+    let signature = self.client.send_and_confirm_transaction(&transaction)?;
+    println!("Signature: {}", signature);
+
+    Ok(())
+  }
+
+  fn entangler(&self, address: &Pubkey) -> Result<Entangler> {
+    let account = self.client.get_account(address)?;
+    if let Ok(child) = FungibleChildEntanglerV1::try_from_account_data(&account.data) {
+      return Ok(Entangler::Child(Box::new(child)));
+    }
+
+    FungibleParentEntanglerV1::try_from_account_data(&account.data)
+      .map(|parent| Entangler::Parent(Box::new(parent)))
+      .map_err(|_| anyhow!("{} is not a parent or child entangler", address))
+  }
+
+  fn parent(&self, address: &Pubkey) -> Result<FungibleParentEntanglerV1> {
+    Ok(rpc::get_account(&self.client, address)?)
+  }
+
+  fn child(&self, address: &Pubkey) -> Result<FungibleChildEntanglerV1> {
+    Ok(rpc::get_account(&self.client, address)?)
+  }
+
+  fn pair(&self, child_entangler: &Pubkey) -> Result<EntanglerPair> {
+    let child = self.child(child_entangler)?;
+    let parent = self.parent(&child.parent_entangler)?;
+
+    Ok(EntanglerPair {
+      parent_mint: parent.parent_mint,
+      parent_entangler: child.parent_entangler,
+      child_mint: child.child_mint,
+      child_entangler: *child_entangler,
+    })
+  }
+
+  fn fee_destination(&self) -> Result<Pubkey> {
+    let global_config: GlobalConfigV0 = rpc::get_account(&self.client, &pda::global_config().0)?;
+    Ok(global_config.fee_destination)
+  }
+
+  fn token_account(&self, account: Option<Pubkey>, mint: &Pubkey) -> Pubkey {
+    account.unwrap_or_else(|| get_associated_token_address(&self.pubkey(), mint))
+  }
+}
+
+pub fn run(cli: Cli) -> Result<()> {
+  let keypair_path = match cli.keypair {
+    Some(path) => path,
+    None => {
+      let home =
+        std::env::var("HOME").map_err(|_| anyhow!("No keypair given and HOME is unset"))?;
+      format!("{}/.config/solana/id.json", home)
+    }
+  };
+  let ctx = Context {
+    client: RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed()),
+    payer: read_keypair_file(&keypair_path)
+      .map_err(|err| anyhow!("Failed to read keypair {}: {}", keypair_path, err))?,
+  };
+
+  match cli.command {
+    Command::Init {
+      parent_mint,
+      seed,
+      authority,
+      go_live,
+      freeze_swap,
+      freeze_parent_to_child,
+      freeze_child_to_parent,
+      name,
+      uri,
+      reject_freeze_authority,
+      namespaced,
+    } => {
+      let (entangler, _) = if namespaced {
+        pda::namespaced_parent_entangler(&parent_mint, &ctx.pubkey(), seed.as_bytes())
+      } else {
+        pda::parent_entangler(&parent_mint, seed.as_bytes())
+      };
+      let fee_destination = ctx.fee_destination()?;
+      let args = instruction::InitializeFungibleParentEntanglerV0Args {
+        authority: Some(authority.unwrap_or_else(|| ctx.pubkey())),
+        dynamic_seed: seed.into_bytes(),
+        go_live_unix_time: go_live,
+        freeze_swap_unix_time: freeze_swap,
+        name,
+        uri,
+        rent_refund: None,
+        reject_freeze_authority,
+        default_rate: None,
+        freeze_parent_to_child_unix_time: freeze_parent_to_child,
+        freeze_child_to_parent_unix_time: freeze_child_to_parent,
+      };
+      let ix = if namespaced {
+        instruction::initialize_namespaced_parent_entangler_v0(
+          &ctx.pubkey(),
+          &ctx.pubkey(),
+          &parent_mint,
+          &fee_destination,
+          args,
+        )
+      } else {
+        instruction::initialize_fungible_parent_entangler_v0(
+          &ctx.pubkey(),
+          &parent_mint,
+          &fee_destination,
+          args,
+        )
+      };
+      ctx.send(&[ix])?;
+      println!("Parent entangler: {}", entangler);
+    }
+    Command::InitChild {
+      parent_entangler,
+      child_mint,
+      go_live,
+      freeze_swap,
+      freeze_parent_to_child,
+      freeze_child_to_parent,
+      rate_parent_units,
+      rate_child_units,
+      swap_fee_bps,
+      refund_window_seconds,
+      reverse_rebate_seconds,
+      expiry,
+      reject_freeze_authority,
+      max_child_to_parent,
+      commit_reveal_seconds,
+    } => {
+      let parent = ctx.parent(&parent_entangler)?;
+      let pair = EntanglerPair {
+        parent_mint: parent.parent_mint,
+        parent_entangler,
+        child_mint,
+        child_entangler: pda::child_entangler(&parent_entangler, &child_mint).0,
+      };
+      let ix = instruction::initialize_fungible_child_entangler_v0(
+        &ctx.pubkey(),
+        &ctx.pubkey(),
+        &pair,
+        &ctx.fee_destination()?,
+        instruction::InitializeFungibleChildEntanglerV0Args {
+          go_live_unix_time: go_live,
+          freeze_swap_unix_time: freeze_swap,
+          refund_window_seconds,
+          expiry_unix_time: expiry,
+          rate: Some(ExchangeRateV0 {
+            parent_units: rate_parent_units,
+            child_units: rate_child_units,
+          }),
+          swap_fee_bps,
+          reject_freeze_authority,
+          max_child_to_parent_amount: max_child_to_parent,
+          reverse_rebate_seconds,
+          freeze_parent_to_child_unix_time: freeze_parent_to_child,
+          freeze_child_to_parent_unix_time: freeze_child_to_parent,
+          commit_reveal_seconds,
+          ..Default::default()
+        },
+      );
+      ctx.send(&[ix])?;
+      println!("Child entangler: {}", pair.child_entangler);
+    }
+    Command::Swap {
+      child_entangler,
+      direction,
+      amount,
+      all,
+      source,
+      destination,
+      memo,
+      allow_partial,
+    } => {
+      let pair = ctx.pair(&child_entangler)?;
+      let args = instruction::SwapV0Args {
+        amount,
+        all: if all { Some(true) } else { None },
+        output_splits_bps: vec![],
+        memo,
+        allow_partial,
+      };
+      let ix = match direction {
+        Direction::ParentToChild => instruction::swap_parent_for_child_v0(
+          &ctx.pubkey(),
+          &pair,
+          &ctx.token_account(source, &pair.parent_mint),
+          &ctx.pubkey(),
+          &ctx.token_account(destination, &pair.child_mint),
+          &[],
+          args,
+        ),
+        Direction::ChildToParent => instruction::swap_child_for_parent_v0(
+          &ctx.pubkey(),
+          &pair,
+          &ctx.token_account(source, &pair.child_mint),
+          &ctx.pubkey(),
+          &ctx.token_account(destination, &pair.parent_mint),
+          &[],
+          args,
+        ),
+      };
+      if let Some(amount) = amount {
+        let direction = match direction {
+          Direction::ParentToChild => SwapDirection::ParentToChild,
+          Direction::ChildToParent => SwapDirection::ChildToParent,
+        };
+        let quote = rpc::get_live_quote(&ctx.client, &child_entangler, direction, amount)?;
+        if !quote.is_fillable() {
+          bail!(
+            "Storage holds {} but the swap pays out {}",
+            quote.output_storage_amount,
+            quote.quote.output_amount
+          );
+        }
+        println!(
+          "Receiving {} after a {} fee",
+          quote.quote.received_amount, quote.quote.fee_amount
+        );
+      }
+      ctx.send(&[ix])?;
+    }
+    Command::TopOff {
+      child_entangler,
+      amount,
+      source,
+    } => {
+      let child = ctx.child(&child_entangler)?;
+      let ix = instruction::top_off_child_storage_v0(
+        &ctx.pubkey(),
+        &child_entangler,
+        &ctx.token_account(source, &child.child_mint),
+        &ctx.pubkey(),
+        instruction::TopOffChildStorageV0Args { amount },
+      );
+      ctx.send(&[ix])?;
+    }
+    Command::Withdraw {
+      entangler,
+      amount,
+      destination,
+    } => {
+      let ix = match ctx.entangler(&entangler)? {
+        Entangler::Parent(parent) => instruction::transfer_parent_storage_v0(
+          &ctx.pubkey(),
+          &entangler,
+          &ctx.token_account(destination, &parent.parent_mint),
+          instruction::TransferParentStorageArgsV0 { amount },
+        ),
+        Entangler::Child(child) => instruction::transfer_child_storage_v0(
+          &ctx.pubkey(),
+          &child.parent_entangler,
+          &entangler,
+          &ctx.token_account(destination, &child.child_mint),
+          instruction::TransferChildStorageArgsV0 { amount },
+        ),
+      };
+      ctx.send(&[ix])?;
+    }
+    Command::Close { entangler } => {
+      let ix = match ctx.entangler(&entangler)? {
+        Entangler::Parent(parent) => instruction::close_fungible_parent_entangler_v0(
+          &parent.rent_refund,
+          &ctx.pubkey(),
+          &entangler,
+        ),
+        Entangler::Child(child) => {
+          let pair = ctx.pair(&entangler)?;
+          instruction::close_fungible_child_entangler_v0(&child.rent_refund, &ctx.pubkey(), &pair)
+        }
+      };
+      ctx.send(&[ix])?;
+    }
+    Command::Seal { parent_entangler } => {
+      let ix = instruction::seal_entangler_v0(&ctx.pubkey(), &parent_entangler);
+      ctx.send(&[ix])?;
+    }
+    Command::Show { entangler } => match ctx.entangler(&entangler)? {
+      Entangler::Parent(parent) => {
+        println!("Parent entangler {}", entangler);
+        print_parent(&ctx, &parent)?;
+        for (address, child) in rpc::get_children(&ctx.client, &entangler)? {
+          println!();
+          println!("Child entangler {}", address);
+          print_child(&ctx, &child)?;
+        }
+      }
+      Entangler::Child(child) => {
+        println!("Child entangler {}", entangler);
+        print_child(&ctx, &child)?;
+      }
+    },
+  }
+
+  Ok(())
+}
+
+fn print_optional<T: std::fmt::Display>(label: &str, value: Option<T>) {
+  match value {
+    Some(value) => println!("  {:<24} {}", label, value),
+    None => println!("  {:<24} -", label),
+  }
+}
+
+fn print_parent(ctx: &Context, parent: &FungibleParentEntanglerV1) -> Result<()> {
+  println!("  {:<24} {}", "version", parent.version);
+  println!("  {:<24} {}", "name", parent.name);
+  println!("  {:<24} {}", "uri", parent.uri);
+  println!("  {:<24} {}", "parent mint", parent.parent_mint);
+  println!(
+    "  {:<24} {} ({})",
+    "parent storage",
+    parent.parent_storage,
+    rpc::get_token_balance(&ctx.client, &parent.parent_storage)?
+  );
+  print_optional("authority", parent.authority);
+  print_optional("authority nft mint", parent.authority_nft_mint);
+  if let Some(authority) = parent.authority {
+    // Wallet authorities fail to deserialize, only multisig configs print
+    if let Ok(Some(config)) =
+      rpc::get_account_if_exists::<AuthorityConfigV0>(&ctx.client, &authority)
+    {
+      println!(
+        "  {:<24} {} of {}",
+        "authority threshold",
+        config.threshold,
+        config.signers.len()
+      );
+      for signer in &config.signers {
+        println!("  {:<24} {}", "authority signer", signer);
+      }
+    }
+  }
+  print_optional("namespace", parent.namespace);
+  print_optional("withdrawals frozen until", parent.withdrawals_frozen_until);
+  println!("  {:<24} {}", "go live", parent.go_live_unix_time);
+  print_optional("freeze swap", parent.freeze_swap_unix_time);
+  print_optional(
+    "freeze parent to child",
+    parent.freeze_parent_to_child_unix_time,
+  );
+  print_optional(
+    "freeze child to parent",
+    parent.freeze_child_to_parent_unix_time,
+  );
+  println!("  {:<24} {}", "children", parent.num_children);
+  println!("  {:<24} {}", "rent refund", parent.rent_refund);
+
+  Ok(())
+}
+
+fn print_child(ctx: &Context, child: &FungibleChildEntanglerV1) -> Result<()> {
+  println!("  {:<24} {}", "version", child.version);
+  println!("  {:<24} {}", "parent entangler", child.parent_entangler);
+  println!("  {:<24} {}", "child mint", child.child_mint);
+  println!(
+    "  {:<24} {} ({})",
+    "child storage",
+    child.child_storage,
+    rpc::get_token_balance(&ctx.client, &child.child_storage)?
+  );
+  println!(
+    "  {:<24} {} parent : {} child",
+    "rate", child.rate.parent_units, child.rate.child_units
+  );
+  println!("  {:<24} {}", "swap fee bps", child.swap_fee_bps);
+  for tier in &child.fee_tiers {
+    println!(
+      "  {:<24} {} bps from {}",
+      "fee tier", tier.bps, tier.min_amount
+    );
+  }
+  println!("  {:<24} {}", "go live", child.go_live_unix_time);
+  print_optional("freeze swap", child.freeze_swap_unix_time);
+  print_optional(
+    "freeze parent to child",
+    child.freeze_parent_to_child_unix_time,
+  );
+  print_optional(
+    "freeze child to parent",
+    child.freeze_child_to_parent_unix_time,
+  );
+  println!("  {:<24} {}", "frozen", child.frozen);
+  print_optional("expiry", child.expiry_unix_time);
+  print_optional("refund window seconds", child.refund_window_seconds);
+  print_optional("reverse rebate seconds", child.reverse_rebate_seconds);
+  print_optional("commit reveal seconds", child.commit_reveal_seconds);
+  if let Some(band) = child.rate_band {
+    println!(
+      "  {:<24} {} : {} to {} : {}",
+      "rate band",
+      band.min_rate.parent_units,
+      band.min_rate.child_units,
+      band.max_rate.parent_units,
+      band.max_rate.child_units
+    );
+  }
+  print_optional(
+    "strategy program",
+    child.yield_strategy.map(|strategy| strategy.program),
+  );
+  print_optional(
+    "strategy deposited",
+    child
+      .yield_strategy
+      .map(|strategy| strategy.deposited_amount),
+  );
+  println!(
+    "  {:<24} {} child : {} parent",
+    "accrued fees", child.accrued_child_fee_amount, child.accrued_parent_fee_amount
+  );
+  print_optional(
+    "distributing fees",
+    child
+      .fee_distribution
+      .map(|distribution| distribution.child_fee_amount),
+  );
+  if let Some(limit) = child.drawdown_limit {
+    println!(
+      "  {:<24} {} / {} child, {} / {} parent per {}s",
+      "drawdown",
+      limit.child_amount,
+      limit.max_child_amount,
+      limit.parent_amount,
+      limit.max_parent_amount,
+      limit.window_seconds
+    );
+  }
+  print_optional("max parent to child", child.max_parent_to_child_amount);
+  println!(
+    "  {:<24} {}",
+    "total parent to child", child.total_parent_to_child_amount
+  );
+  print_optional("max child to parent", child.max_child_to_parent_amount);
+  println!(
+    "  {:<24} {}",
+    "total child to parent", child.total_child_to_parent_amount
+  );
+  println!(
+    "  {:<24} {} parent : {} child",
+    "recorded swapped in",
+    child.recorded_parent_to_child_amount,
+    child.recorded_child_to_parent_amount
+  );
+  let now = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |duration| duration.as_secs() as i64);
+  for (label, volume) in [
+    ("parent to child", &child.parent_to_child_volume),
+    ("child to parent", &child.child_to_parent_volume),
+  ] {
+    println!(
+      "  {:<24} ema {}, {} in {} swaps since {}",
+      format!("{} volume", label),
+      volume.decayed_ema_amount(now),
+      volume.window_amount,
+      volume.window_swap_count,
+      volume.window_start_unix_time
+    );
+  }
+  println!("  {:<24} {}", "total top off", child.total_top_off_amount);
+  println!("  {:<24} {}", "rent refund", child.rent_refund);
+
+  Ok(())
+}
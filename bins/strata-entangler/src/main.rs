@@ -0,0 +1,169 @@
+//! Operator CLI for fungible entanglers. Amounts are in base units of the relevant mint and
+//! times are unix timestamps.
+
+mod command;
+
+use clap::{ArgEnum, Parser, Subcommand};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Parser)]
+#[clap(name = "strata-entangler", version, about)]
+pub struct Cli {
+  /// RPC endpoint
+  #[clap(
+    long,
+    short = 'u',
+    env = "SOLANA_URL",
+    default_value = "https://api.mainnet-beta.solana.com"
+  )]
+  pub url: String,
+  /// Keypair that pays for and signs transactions. Defaults to the solana cli keypair
+  #[clap(long, short = 'k', env = "SOLANA_KEYPAIR")]
+  pub keypair: Option<String>,
+  #[clap(subcommand)]
+  pub command: Command,
+}
+
+#[derive(Clone, Copy, ArgEnum)]
+pub enum Direction {
+  ParentToChild,
+  ChildToParent,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+  /// Create a parent entangler and its storage
+  Init {
+    #[clap(long)]
+    parent_mint: Pubkey,
+    /// Seed the entangler is derived from, together with the parent mint
+    #[clap(long)]
+    seed: String,
+    /// Defaults to the keypair
+    #[clap(long)]
+    authority: Option<Pubkey>,
+    #[clap(long, default_value_t = 0)]
+    go_live: i64,
+    #[clap(long)]
+    freeze_swap: Option<i64>,
+    /// Stop parent to child swaps from this time, leaving child to parent open
+    #[clap(long)]
+    freeze_parent_to_child: Option<i64>,
+    /// Stop child to parent swaps from this time, leaving parent to child open
+    #[clap(long)]
+    freeze_child_to_parent: Option<i64>,
+    #[clap(long, default_value = "")]
+    name: String,
+    #[clap(long, default_value = "")]
+    uri: String,
+    /// Fail if the parent mint has a freeze authority
+    #[clap(long)]
+    reject_freeze_authority: bool,
+    /// Claim the seed in the keypair's namespace, so no other deployer can take it
+    #[clap(long)]
+    namespaced: bool,
+  },
+  /// Create a child entangler under a parent. The keypair must be the parent's authority
+  InitChild {
+    #[clap(long)]
+    parent_entangler: Pubkey,
+    #[clap(long)]
+    child_mint: Pubkey,
+    #[clap(long, default_value_t = 0)]
+    go_live: i64,
+    #[clap(long)]
+    freeze_swap: Option<i64>,
+    /// Stop parent to child swaps from this time, leaving child to parent open
+    #[clap(long)]
+    freeze_parent_to_child: Option<i64>,
+    /// Stop child to parent swaps from this time, leaving parent to child open
+    #[clap(long)]
+    freeze_child_to_parent: Option<i64>,
+    /// Parent units per rate_child_units child units
+    #[clap(long, default_value_t = 1)]
+    rate_parent_units: u64,
+    #[clap(long, default_value_t = 1)]
+    rate_child_units: u64,
+    #[clap(long, default_value_t = 0)]
+    swap_fee_bps: u16,
+    #[clap(long)]
+    refund_window_seconds: Option<i64>,
+    /// Rebate the fee of a parent to child swap reversed within this many seconds
+    #[clap(long)]
+    reverse_rebate_seconds: Option<i64>,
+    #[clap(long)]
+    expiry: Option<i64>,
+    /// Fail if the child mint has a freeze authority
+    #[clap(long)]
+    reject_freeze_authority: bool,
+    /// Lifetime cap on child tokens converted into the parent
+    #[clap(long)]
+    max_child_to_parent: Option<u64>,
+    /// Only allow committed swaps for this many seconds after go live
+    #[clap(long)]
+    commit_reveal_seconds: Option<i64>,
+  },
+  /// Swap through a child entangler
+  Swap {
+    #[clap(long)]
+    child_entangler: Pubkey,
+    #[clap(long, arg_enum)]
+    direction: Direction,
+    #[clap(long, required_unless_present = "all")]
+    amount: Option<u64>,
+    /// Swap the whole source balance and close the source account
+    #[clap(long)]
+    all: bool,
+    /// Defaults to the keypair's associated token account of the input mint
+    #[clap(long)]
+    source: Option<Pubkey>,
+    /// Defaults to the keypair's associated token account of the output mint
+    #[clap(long)]
+    destination: Option<Pubkey>,
+    /// Logged with the swap through the SPL memo program
+    #[clap(long)]
+    memo: Option<String>,
+    /// Swap what storage can cover instead of failing when it holds too little
+    #[clap(long)]
+    allow_partial: bool,
+  },
+  /// Deposit child tokens into a child entangler's storage
+  TopOff {
+    #[clap(long)]
+    child_entangler: Pubkey,
+    #[clap(long)]
+    amount: u64,
+    /// Defaults to the keypair's associated token account of the child mint
+    #[clap(long)]
+    source: Option<Pubkey>,
+  },
+  /// Withdraw from a parent or child entangler's storage. The keypair must be the parent's
+  /// authority
+  Withdraw {
+    /// A parent or child entangler
+    #[clap(long)]
+    entangler: Pubkey,
+    #[clap(long)]
+    amount: u64,
+    /// Defaults to the keypair's associated token account of the storage mint
+    #[clap(long)]
+    destination: Option<Pubkey>,
+  },
+  /// Close a parent or child entangler, returning rent to its rent refund
+  Close {
+    /// A parent or child entangler
+    #[clap(long)]
+    entangler: Pubkey,
+  },
+  /// Permanently remove a parent entangler's authority. The keypair must be the authority
+  Seal {
+    #[clap(long)]
+    parent_entangler: Pubkey,
+  },
+  /// Print a parent entangler and its children, or a child entangler
+  Show { entangler: Pubkey },
+}
+
+fn main() -> anyhow::Result<()> {
+  command::run(Cli::parse())
+}
@@ -0,0 +1,159 @@
+//! Postgres writes. u64 amounts are bound as text and cast to NUMERIC since they overflow BIGINT.
+
+use fungible_entangler_sdk::state::{
+  FungibleChildEntanglerV1, FungibleParentEntanglerV1, SwapRecordV0,
+};
+use postgres::{Client, Error, NoTls};
+use solana_program::pubkey::Pubkey;
+
+const SCHEMA: &str = include_str!("schema.sql");
+
+pub struct Db {
+  client: Client,
+}
+
+impl Db {
+  pub fn connect(connection_str: &str) -> Result<Self, Error> {
+    let mut client = Client::connect(connection_str, NoTls)?;
+    client.batch_execute(SCHEMA)?;
+
+    Ok(Self { client })
+  }
+
+  pub fn upsert_parent(
+    &mut self,
+    address: &Pubkey,
+    parent: &FungibleParentEntanglerV1,
+    slot: u64,
+  ) -> Result<(), Error> {
+    self.client.execute(
+      "INSERT INTO parent_entanglers (address, parent_mint, parent_storage, authority, name, uri,
+         go_live_unix_time, freeze_swap_unix_time, num_children, slot)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+       ON CONFLICT (address) DO UPDATE SET authority = $4, name = $5, uri = $6,
+         go_live_unix_time = $7, freeze_swap_unix_time = $8, num_children = $9, slot = $10
+       WHERE parent_entanglers.slot <= $10",
+      &[
+        &address.to_string(),
+        &parent.parent_mint.to_string(),
+        &parent.parent_storage.to_string(),
+        &parent.authority.map(|authority| authority.to_string()),
+        &parent.name,
+        &parent.uri,
+        &parent.go_live_unix_time,
+        &parent.freeze_swap_unix_time,
+        &(parent.num_children as i64),
+        &(slot as i64),
+      ],
+    )?;
+
+    Ok(())
+  }
+
+  pub fn upsert_child(
+    &mut self,
+    address: &Pubkey,
+    child: &FungibleChildEntanglerV1,
+    slot: u64,
+  ) -> Result<(), Error> {
+    self.client.execute(
+      "INSERT INTO child_entanglers (address, parent_entangler, child_mint, child_storage,
+         rate_parent_units, rate_child_units, swap_fee_bps, go_live_unix_time,
+         freeze_swap_unix_time, expiry_unix_time, total_parent_to_child_amount, slot)
+       VALUES ($1, $2, $3, $4, $5::TEXT::NUMERIC, $6::TEXT::NUMERIC, $7, $8, $9, $10,
+         $11::TEXT::NUMERIC, $12)
+       ON CONFLICT (address) DO UPDATE SET rate_parent_units = $5::TEXT::NUMERIC,
+         rate_child_units = $6::TEXT::NUMERIC, swap_fee_bps = $7, go_live_unix_time = $8,
+         freeze_swap_unix_time = $9, expiry_unix_time = $10,
+         total_parent_to_child_amount = $11::TEXT::NUMERIC, slot = $12
+       WHERE child_entanglers.slot <= $12",
+      &[
+        &address.to_string(),
+        &child.parent_entangler.to_string(),
+        &child.child_mint.to_string(),
+        &child.child_storage.to_string(),
+        &child.rate.parent_units.to_string(),
+        &child.rate.child_units.to_string(),
+        &(child.swap_fee_bps as i32),
+        &child.go_live_unix_time,
+        &child.freeze_swap_unix_time,
+        &child.expiry_unix_time,
+        &child.total_parent_to_child_amount.to_string(),
+        &(slot as i64),
+      ],
+    )?;
+
+    Ok(())
+  }
+
+  /// Removes an entangler whose account was closed
+  pub fn delete_entangler(&mut self, address: &Pubkey) -> Result<(), Error> {
+    let address = address.to_string();
+    self.client.execute(
+      "DELETE FROM parent_entanglers WHERE address = $1",
+      &[&address],
+    )?;
+    self.client.execute(
+      "DELETE FROM child_entanglers WHERE address = $1",
+      &[&address],
+    )?;
+    self.client.execute(
+      "DELETE FROM storage_balances WHERE entangler = $1",
+      &[&address],
+    )?;
+
+    Ok(())
+  }
+
+  pub fn upsert_storage_balance(
+    &mut self,
+    address: &Pubkey,
+    entangler: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+    slot: u64,
+  ) -> Result<(), Error> {
+    self.client.execute(
+      "INSERT INTO storage_balances (address, entangler, mint, amount, slot)
+       VALUES ($1, $2, $3, $4::TEXT::NUMERIC, $5)
+       ON CONFLICT (address) DO UPDATE SET amount = $4::TEXT::NUMERIC, slot = $5
+       WHERE storage_balances.slot <= $5",
+      &[
+        &address.to_string(),
+        &entangler.to_string(),
+        &mint.to_string(),
+        &amount.to_string(),
+        &(slot as i64),
+      ],
+    )?;
+
+    Ok(())
+  }
+
+  pub fn insert_swap_record(
+    &mut self,
+    address: &Pubkey,
+    record: &SwapRecordV0,
+    slot: u64,
+    write_version: u64,
+  ) -> Result<(), Error> {
+    self.client.execute(
+      "INSERT INTO swap_history (swap_record, child_entangler, owner, parent_to_child_amount,
+         child_to_parent_amount, refundable_child_amount, slot, write_version)
+       VALUES ($1, $2, $3, $4::TEXT::NUMERIC, $5::TEXT::NUMERIC, $6::TEXT::NUMERIC, $7, $8)
+       ON CONFLICT DO NOTHING",
+      &[
+        &address.to_string(),
+        &record.child_entangler.to_string(),
+        &record.owner.to_string(),
+        &record.parent_to_child_amount.to_string(),
+        &record.child_to_parent_amount.to_string(),
+        &record.refundable_child_amount.to_string(),
+        &(slot as i64),
+        &(write_version as i64),
+      ],
+    )?;
+
+    Ok(())
+  }
+}
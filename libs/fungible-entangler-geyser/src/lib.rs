@@ -0,0 +1,196 @@
+//! Geyser plugin that indexes fungible entanglers into Postgres. Parent and child entanglers are
+//! upserted as they change, storage token balances are tracked for every entangler seen, and each
+//! swap record write is appended to `swap_history`.
+//!
+//! Load it with a validator config such as
+//!
+//! ```json
+//! {
+//!   "libpath": "target/release/libfungible_entangler_geyser.so",
+//!   "connection_str": "host=localhost user=postgres dbname=entangler"
+//! }
+//! ```
+
+mod db;
+
+use db::Db;
+use fungible_entangler_sdk::state::{
+  FungibleChildEntanglerV1, FungibleParentEntanglerV1, ProgramAccount, SwapRecordV0,
+};
+use serde::Deserialize;
+use solana_geyser_plugin_interface::geyser_plugin_interface::{
+  GeyserPlugin, GeyserPluginError, ReplicaAccountInfoVersions, Result, SlotStatus,
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use std::{
+  collections::HashMap,
+  fmt,
+  sync::{Mutex, PoisonError},
+};
+
+#[derive(Deserialize)]
+struct Config {
+  connection_str: String,
+}
+
+#[derive(Default)]
+pub struct EntanglerIndexer {
+  // Plugins must be Sync, which the postgres client is not
+  db: Option<Mutex<Db>>,
+  // Storage token account to the entangler that owns it. Storage writes seen before their
+  // entangler are skipped, the next write after it records the balance
+  storages: HashMap<Pubkey, Pubkey>,
+}
+
+impl fmt::Debug for EntanglerIndexer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("EntanglerIndexer")
+      .field("storages", &self.storages.len())
+      .finish()
+  }
+}
+
+fn update_error(err: postgres::Error) -> GeyserPluginError {
+  GeyserPluginError::AccountsUpdateError {
+    msg: err.to_string(),
+  }
+}
+
+impl EntanglerIndexer {
+  fn db(&mut self) -> Result<&mut Db> {
+    self
+      .db
+      .as_mut()
+      .map(|db| db.get_mut().unwrap_or_else(PoisonError::into_inner))
+      .ok_or_else(|| GeyserPluginError::AccountsUpdateError {
+        msg: "Plugin is not loaded".to_string(),
+      })
+  }
+
+  fn index_program_account(
+    &mut self,
+    address: Pubkey,
+    data: &[u8],
+    lamports: u64,
+    slot: u64,
+    write_version: u64,
+  ) -> Result<()> {
+    if lamports == 0 {
+      self.storages.retain(|_, entangler| *entangler != address);
+      return self.db()?.delete_entangler(&address).map_err(update_error);
+    }
+
+    if let Ok(parent) = FungibleParentEntanglerV1::try_from_account_data(data) {
+      self.storages.insert(parent.parent_storage, address);
+      self
+        .db()?
+        .upsert_parent(&address, &parent, slot)
+        .map_err(update_error)?;
+    } else if let Ok(child) = FungibleChildEntanglerV1::try_from_account_data(data) {
+      self.storages.insert(child.child_storage, address);
+      self
+        .db()?
+        .upsert_child(&address, &child, slot)
+        .map_err(update_error)?;
+    } else if let Ok(record) = SwapRecordV0::try_from_account_data(data) {
+      self
+        .db()?
+        .insert_swap_record(&address, &record, slot, write_version)
+        .map_err(update_error)?;
+    }
+
+    Ok(())
+  }
+
+  fn index_token_account(&mut self, address: Pubkey, data: &[u8], slot: u64) -> Result<()> {
+    let entangler = match self.storages.get(&address) {
+      Some(entangler) => *entangler,
+      None => return Ok(()),
+    };
+    let account = match spl_token::state::Account::unpack(data) {
+      Ok(account) => account,
+      Err(_) => return Ok(()),
+    };
+
+    self
+      .db()?
+      .upsert_storage_balance(&address, &entangler, &account.mint, account.amount, slot)
+      .map_err(update_error)
+  }
+}
+
+impl GeyserPlugin for EntanglerIndexer {
+  fn name(&self) -> &'static str {
+    "fungible-entangler-geyser"
+  }
+
+  fn on_load(&mut self, config_file: &str) -> Result<()> {
+    let config = std::fs::read_to_string(config_file)?;
+    let config: Config =
+      serde_json::from_str(&config).map_err(|err| GeyserPluginError::ConfigFileReadError {
+        msg: err.to_string(),
+      })?;
+    self.db = Some(Mutex::new(
+      Db::connect(&config.connection_str)
+        .map_err(|err| GeyserPluginError::Custom(Box::new(err)))?,
+    ));
+
+    Ok(())
+  }
+
+  fn on_unload(&mut self) {
+    self.db = None;
+  }
+
+  fn update_account(
+    &mut self,
+    account: ReplicaAccountInfoVersions,
+    slot: u64,
+    _is_startup: bool,
+  ) -> Result<()> {
+    let ReplicaAccountInfoVersions::V0_0_1(account) = account;
+    let owner = Pubkey::new(account.owner);
+    let address = Pubkey::new(account.pubkey);
+
+    if owner == fungible_entangler_sdk::id() {
+      self.index_program_account(
+        address,
+        account.data,
+        account.lamports,
+        slot,
+        account.write_version,
+      )
+    } else if owner == spl_token::id() {
+      self.index_token_account(address, account.data, slot)
+    } else {
+      Ok(())
+    }
+  }
+
+  fn update_slot_status(
+    &mut self,
+    _slot: u64,
+    _parent: Option<u64>,
+    _status: SlotStatus,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  fn notify_end_of_startup(&mut self) -> Result<()> {
+    log::info!(
+      "fungible-entangler-geyser tracking {} storage accounts",
+      self.storages.len()
+    );
+    Ok(())
+  }
+}
+
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+/// # Safety
+///
+/// Called by the validator's plugin manager, which takes ownership of the returned plugin
+pub unsafe extern "C" fn _create_plugin() -> *mut dyn GeyserPlugin {
+  let plugin: Box<dyn GeyserPlugin> = Box::new(EntanglerIndexer::default());
+  Box::into_raw(plugin)
+}
@@ -0,0 +1,51 @@
+CREATE TABLE IF NOT EXISTS parent_entanglers (
+  address TEXT PRIMARY KEY,
+  parent_mint TEXT NOT NULL,
+  parent_storage TEXT NOT NULL,
+  authority TEXT,
+  name TEXT NOT NULL,
+  uri TEXT NOT NULL,
+  go_live_unix_time BIGINT NOT NULL,
+  freeze_swap_unix_time BIGINT,
+  num_children BIGINT NOT NULL,
+  slot BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS child_entanglers (
+  address TEXT PRIMARY KEY,
+  parent_entangler TEXT NOT NULL,
+  child_mint TEXT NOT NULL,
+  child_storage TEXT NOT NULL,
+  rate_parent_units NUMERIC NOT NULL,
+  rate_child_units NUMERIC NOT NULL,
+  swap_fee_bps INTEGER NOT NULL,
+  go_live_unix_time BIGINT NOT NULL,
+  freeze_swap_unix_time BIGINT,
+  expiry_unix_time BIGINT,
+  total_parent_to_child_amount NUMERIC NOT NULL,
+  slot BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS child_entanglers_parent ON child_entanglers (parent_entangler);
+
+CREATE TABLE IF NOT EXISTS storage_balances (
+  address TEXT PRIMARY KEY,
+  entangler TEXT NOT NULL,
+  mint TEXT NOT NULL,
+  amount NUMERIC NOT NULL,
+  slot BIGINT NOT NULL
+);
+
+-- One row per swap record write. Amounts are cumulative, so consecutive rows for a record give
+-- the size of each swap or refund
+CREATE TABLE IF NOT EXISTS swap_history (
+  swap_record TEXT NOT NULL,
+  child_entangler TEXT NOT NULL,
+  owner TEXT NOT NULL,
+  parent_to_child_amount NUMERIC NOT NULL,
+  child_to_parent_amount NUMERIC NOT NULL,
+  refundable_child_amount NUMERIC NOT NULL,
+  slot BIGINT NOT NULL,
+  write_version BIGINT NOT NULL,
+  PRIMARY KEY (swap_record, slot, write_version)
+);
+CREATE INDEX IF NOT EXISTS swap_history_owner ON swap_history (owner);
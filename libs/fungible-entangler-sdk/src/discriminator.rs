@@ -0,0 +1,27 @@
+//! Anchor prefixes instruction data, account data and event data with the first 8 bytes of a
+//! sha256 of the instruction, account or event name.
+
+use solana_program::hash::hash;
+
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+fn sighash(preimage: &str) -> [u8; DISCRIMINATOR_LEN] {
+  let mut discriminator = [0u8; DISCRIMINATOR_LEN];
+  discriminator.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..DISCRIMINATOR_LEN]);
+  discriminator
+}
+
+/// Discriminator of a program instruction, given its snake case name
+pub fn instruction(name: &str) -> [u8; DISCRIMINATOR_LEN] {
+  sighash(&format!("global:{}", name))
+}
+
+/// Discriminator of an account, given its struct name
+pub fn account(name: &str) -> [u8; DISCRIMINATOR_LEN] {
+  sighash(&format!("account:{}", name))
+}
+
+/// Discriminator of an event, given its struct name
+pub fn event(name: &str) -> [u8; DISCRIMINATOR_LEN] {
+  sighash(&format!("event:{}", name))
+}
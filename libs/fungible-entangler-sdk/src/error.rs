@@ -0,0 +1,24 @@
+use solana_program::pubkey::Pubkey;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SdkError {
+  #[error("Account data is shorter than the discriminator")]
+  AccountTooSmall,
+
+  #[error("Account discriminator does not match {0}")]
+  InvalidDiscriminator(&'static str),
+
+  #[error("Failed to decode account: {0}")]
+  Decode(#[from] std::io::Error),
+
+  #[error("{0} is not a token account")]
+  InvalidTokenAccount(Pubkey),
+
+  #[error("Quote overflows or the exchange rate is invalid")]
+  QuoteOverflow,
+
+  #[cfg(feature = "rpc")]
+  #[error("Rpc request failed: {0}")]
+  Rpc(#[from] solana_client::client_error::ClientError),
+}
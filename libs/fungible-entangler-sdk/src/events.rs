@@ -0,0 +1,114 @@
+//! Mirrors of the events the program logs as spl-noop instruction data. Only decode noop
+//! instructions the entangler program invoked directly, as anyone can invoke spl-noop.
+
+use crate::{discriminator, SdkError};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct SwapEventV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+  pub source_authority: Pubkey,
+  pub parent_to_child: bool,
+  pub amount: u64,
+  pub output_amount: u64,
+  pub fee_amount: u64,
+  pub unix_time: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ParentEntanglerCreatedV0 {
+  pub parent_entangler: Pubkey,
+  pub parent_mint: Pubkey,
+  pub authority: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ChildEntanglerCreatedV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+  pub child_mint: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ChildEntanglerFrozenV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ChildEntanglerClosedV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ParentEntanglerClosedV0 {
+  pub parent_entangler: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntanglerEvent {
+  Swap(SwapEventV0),
+  ParentEntanglerCreated(ParentEntanglerCreatedV0),
+  ChildEntanglerCreated(ChildEntanglerCreatedV0),
+  ChildEntanglerFrozen(ChildEntanglerFrozenV0),
+  ChildEntanglerClosed(ChildEntanglerClosedV0),
+  ParentEntanglerClosed(ParentEntanglerClosedV0),
+}
+
+impl EntanglerEvent {
+  /// Decodes the data of a noop instruction. None when the discriminator matches no event
+  pub fn try_from_noop_data(data: &[u8]) -> Result<Option<Self>, SdkError> {
+    if data.len() < discriminator::DISCRIMINATOR_LEN {
+      return Ok(None);
+    }
+    let (prefix, mut rest) = data.split_at(discriminator::DISCRIMINATOR_LEN);
+    let is = |name: &str| prefix == discriminator::event(name);
+
+    Ok(Some(if is("SwapEventV0") {
+      Self::Swap(SwapEventV0::deserialize(&mut rest)?)
+    } else if is("ParentEntanglerCreatedV0") {
+      Self::ParentEntanglerCreated(ParentEntanglerCreatedV0::deserialize(&mut rest)?)
+    } else if is("ChildEntanglerCreatedV0") {
+      Self::ChildEntanglerCreated(ChildEntanglerCreatedV0::deserialize(&mut rest)?)
+    } else if is("ChildEntanglerFrozenV0") {
+      Self::ChildEntanglerFrozen(ChildEntanglerFrozenV0::deserialize(&mut rest)?)
+    } else if is("ChildEntanglerClosedV0") {
+      Self::ChildEntanglerClosed(ChildEntanglerClosedV0::deserialize(&mut rest)?)
+    } else if is("ParentEntanglerClosedV0") {
+      Self::ParentEntanglerClosed(ParentEntanglerClosedV0::deserialize(&mut rest)?)
+    } else {
+      return Ok(None);
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_decodes_swap_event() {
+    let event = SwapEventV0 {
+      parent_entangler: Pubkey::new_unique(),
+      child_entangler: Pubkey::new_unique(),
+      source_authority: Pubkey::new_unique(),
+      parent_to_child: true,
+      amount: 1000,
+      output_amount: 990,
+      fee_amount: 10,
+      unix_time: 1_000_000,
+    };
+    let mut data = discriminator::event("SwapEventV0").to_vec();
+    event.serialize(&mut data).unwrap();
+
+    assert_eq!(
+      EntanglerEvent::try_from_noop_data(&data).unwrap(),
+      Some(EntanglerEvent::Swap(event))
+    );
+    // Compression changelogs share spl-noop, and are not events
+    assert_eq!(EntanglerEvent::try_from_noop_data(&[0; 40]).unwrap(), None);
+  }
+}
@@ -0,0 +1,2060 @@
+//! Typed builders for the entangler's instructions. Account order and writability mirror the
+//! program's `Accounts` structs, and PDAs are derived from the keys passed in.
+
+use crate::{
+  account_compression, discriminator, legacy_entangler, light, noop, pda,
+  state::{
+    BurnConfigV0, CompressedSwapRecordV0, CpiPolicyV0, ExchangeRateV0, FeeTierV0, OfferV0,
+    QueuedSwapV0, RateBandV0, RateScheduleEntryV0, SettlementEntryV0, VestingConfigV0,
+  },
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+  instruction::{AccountMeta, Instruction},
+  pubkey::Pubkey,
+  system_program, sysvar,
+};
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InitializeFungibleParentEntanglerV0Args {
+  pub authority: Option<Pubkey>,
+  pub dynamic_seed: Vec<u8>,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub name: String,
+  pub uri: String,
+  pub rent_refund: Option<Pubkey>,
+  pub reject_freeze_authority: bool,
+  pub default_rate: Option<ExchangeRateV0>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InitializeFungibleChildEntanglerV0Args {
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub vesting: Option<VestingConfigV0>,
+  pub refund_window_seconds: Option<i64>,
+  pub expiry_unix_time: Option<i64>,
+  pub min_lock_seconds: Option<i64>,
+  pub burn: Option<BurnConfigV0>,
+  pub rent_refund: Option<Pubkey>,
+  pub rate: Option<ExchangeRateV0>,
+  pub swap_fee_bps: u16,
+  pub max_parent_to_child_amount: Option<u64>,
+  pub cpi_policy: CpiPolicyV0,
+  pub cpi_allowlist: Vec<Pubkey>,
+  pub reject_freeze_authority: bool,
+  pub max_child_to_parent_amount: Option<u64>,
+  pub stake_program: Option<Pubkey>,
+  pub liquidity_program: Option<Pubkey>,
+  pub fee_tiers: Vec<FeeTierV0>,
+  pub reverse_rebate_seconds: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+  pub commit_reveal_seconds: Option<i64>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InitializeEntanglerWithChildrenV0Args {
+  pub parent_args: InitializeFungibleParentEntanglerV0Args,
+  pub child_args: Vec<InitializeFungibleChildEntanglerV0Args>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SwapV0Args {
+  pub amount: Option<u64>,
+  pub all: Option<bool>,
+  pub output_splits_bps: Vec<u16>,
+  pub memo: Option<String>,
+  pub allow_partial: bool,
+}
+
+/// Return data of swaps with allow_partial set
+#[derive(BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SwapFillV0 {
+  pub amount: u64,
+  pub output_amount: u64,
+}
+
+/// Return data of validate_init_params_v0. Issues are the program's error codes
+#[derive(BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct InitValidationV0 {
+  pub parent_issues: Vec<u32>,
+  pub child_issues: Vec<Vec<u32>>,
+  pub parent_decimals: u8,
+  pub child_decimals: Vec<u8>,
+  pub creation_fee_lamports: u64,
+  pub rent_lamports: u64,
+}
+
+impl InitValidationV0 {
+  pub fn is_ok(&self) -> bool {
+    self.parent_issues.is_empty() && self.child_issues.iter().all(|issues| issues.is_empty())
+  }
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct CommitSwapV0Args {
+  pub commitment: [u8; 32],
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct RevealAndSwapV0Args {
+  pub amount: u64,
+  pub parent_to_child: bool,
+  pub salt: [u8; 32],
+  pub memo: Option<String>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetWithdrawalsFrozenUntilV0Args {
+  pub withdrawals_frozen_until: i64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetPendingSettlementV0Args {
+  pub settler: Pubkey,
+  pub timeout_seconds: i64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct DeferSwapV0Args {
+  pub amount: u64,
+  pub parent_to_child: bool,
+  pub reference: [u8; 32],
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct QueueSwapV0Args {
+  pub amount: u64,
+  pub parent_to_child: bool,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct DrawdownLimitArgsV0 {
+  pub window_seconds: i64,
+  pub max_child_amount: u64,
+  pub max_parent_amount: u64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetDrawdownLimitV0Args {
+  pub drawdown_limit: Option<DrawdownLimitArgsV0>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct RebalanceBetweenEntanglersV0Args {
+  pub parent_amount: u64,
+  pub child_amount: u64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetRateBandV0Args {
+  pub rate_band: Option<RateBandV0>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetRegistryWeightsV0Args {
+  pub weights_bps: Vec<u16>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SwapParentForBasketV0Args {
+  pub amount: u64,
+  pub min_output_amounts: Vec<u64>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetYieldStrategyV0Args {
+  pub keeper: Pubkey,
+  pub min_liquid_bps: u16,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct RebalanceStrategyV0Args {
+  pub deposit: bool,
+  pub amount: u64,
+  pub data: Vec<u8>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InvokeAsNftAuthorityV0Args {
+  pub data: Vec<u8>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetAuthorityConfigV0Args {
+  pub signers: Vec<Pubkey>,
+  pub threshold: u8,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InvokeAsAuthorityConfigV0Args {
+  pub signer_count: u8,
+  pub data: Vec<u8>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct ImportLegacyEntanglerV0Args {
+  pub dynamic_seed: Vec<u8>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetGlobalPauseV0Args {
+  pub paused: bool,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SwapAndStakeV0Args {
+  pub swap: SwapV0Args,
+  pub stake_data: Vec<u8>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SwapAndProvideLiquidityV0Args {
+  pub swap: SwapV0Args,
+  pub deposit_data: Vec<u8>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct RefundSwapV0Args {
+  pub amount: u64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InitializeSwapRecordTreeV0Args {
+  pub max_depth: u32,
+  pub max_buffer_size: u32,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct RefundCompressedSwapV0Args {
+  pub root: [u8; 32],
+  pub record: CompressedSwapRecordV0,
+  pub index: u32,
+}
+
+/// Light's validity proof of a compressed token swap's inputs
+#[derive(BorshSerialize, Clone, Copy, Debug)]
+pub struct CompressedProof {
+  pub a: [u8; 32],
+  pub b: [u8; 64],
+  pub c: [u8; 32],
+}
+
+#[derive(BorshSerialize, Clone, Copy, Debug, Default)]
+pub struct QueueIndex {
+  pub queue_id: u8,
+  pub index: u16,
+}
+
+/// Indices into the instruction's trailing tree accounts
+#[derive(BorshSerialize, Clone, Copy, Debug, Default)]
+pub struct PackedMerkleContext {
+  pub merkle_tree_pubkey_index: u8,
+  pub nullifier_queue_pubkey_index: u8,
+  pub leaf_index: u32,
+  pub queue_index: Option<QueueIndex>,
+}
+
+/// A compressed token account spent by a compressed token swap, packed as Light's indexer
+/// returns it
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct InputTokenDataWithContext {
+  pub amount: u64,
+  pub delegate_index: Option<u8>,
+  pub merkle_context: PackedMerkleContext,
+  pub root_index: u16,
+  pub lamports: Option<u64>,
+  pub tlv: Option<Vec<u8>>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SwapCompressedTokenV0Args {
+  pub amount: u64,
+  pub allow_partial: bool,
+  pub memo: Option<String>,
+  pub proof: Option<CompressedProof>,
+  pub input_token_accounts: Vec<InputTokenDataWithContext>,
+  pub output_merkle_tree_index: u8,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct TopOffChildStorageV0Args {
+  pub amount: u64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct CreateOfferV0Args {
+  pub nonce: u64,
+  pub taker: Pubkey,
+  pub offer_amount: u64,
+  pub ask_amount: u64,
+  pub expiry_unix_time: Option<i64>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetFreezeTimeV0Args {
+  pub freeze_swap_unix_time: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetParentFreezeTimeV0Args {
+  pub freeze_swap_unix_time: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetFeesV0Args {
+  pub swap_fee_bps: u16,
+  pub fee_tiers: Vec<FeeTierV0>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetRateV0Args {
+  pub rate: ExchangeRateV0,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetAuthorityV0Args {
+  pub new_authority: Pubkey,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct SetRateScheduleV0Args {
+  pub entries: Vec<RateScheduleEntryV0>,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct AppendRateScheduleV0Args {
+  pub entry: RateScheduleEntryV0,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct TransferChildStorageArgsV0 {
+  pub amount: u64,
+}
+
+#[derive(BorshSerialize, Clone, Debug, Default)]
+pub struct TransferParentStorageArgsV0 {
+  pub amount: u64,
+}
+
+/// The parent and child entangler a swap, refund or top off goes through
+#[derive(Clone, Copy, Debug)]
+pub struct EntanglerPair {
+  pub parent_mint: Pubkey,
+  pub parent_entangler: Pubkey,
+  pub child_mint: Pubkey,
+  pub child_entangler: Pubkey,
+}
+
+impl EntanglerPair {
+  pub fn new(parent_mint: &Pubkey, child_mint: &Pubkey, dynamic_seed: &[u8]) -> Self {
+    let (parent_entangler, _) = pda::parent_entangler(parent_mint, dynamic_seed);
+    Self {
+      parent_mint: *parent_mint,
+      parent_entangler,
+      child_mint: *child_mint,
+      child_entangler: pda::child_entangler(&parent_entangler, child_mint).0,
+    }
+  }
+
+  /// The pair under a parent created in namespace's namespace
+  pub fn namespaced(
+    parent_mint: &Pubkey,
+    child_mint: &Pubkey,
+    namespace: &Pubkey,
+    dynamic_seed: &[u8],
+  ) -> Self {
+    let (parent_entangler, _) =
+      pda::namespaced_parent_entangler(parent_mint, namespace, dynamic_seed);
+    Self {
+      parent_mint: *parent_mint,
+      parent_entangler,
+      child_mint: *child_mint,
+      child_entangler: pda::child_entangler(&parent_entangler, child_mint).0,
+    }
+  }
+
+  pub fn parent_storage(&self) -> Pubkey {
+    pda::storage(&self.parent_entangler).0
+  }
+
+  pub fn child_storage(&self) -> Pubkey {
+    pda::storage(&self.child_entangler).0
+  }
+}
+
+fn data<T: BorshSerialize>(name: &str, args: &T) -> Vec<u8> {
+  let mut data = discriminator::instruction(name).to_vec();
+  args.serialize(&mut data).unwrap();
+  data
+}
+
+fn instruction(accounts: Vec<AccountMeta>, data: Vec<u8>) -> Instruction {
+  Instruction {
+    program_id: crate::id(),
+    accounts,
+    data,
+  }
+}
+
+pub fn initialize_fungible_parent_entangler_v0(
+  payer: &Pubkey,
+  parent_mint: &Pubkey,
+  fee_destination: &Pubkey,
+  args: InitializeFungibleParentEntanglerV0Args,
+) -> Instruction {
+  let (entangler, _) = pda::parent_entangler(parent_mint, &args.dynamic_seed);
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new(entangler, false),
+      AccountMeta::new(pda::storage(&entangler).0, false),
+      AccountMeta::new_readonly(*parent_mint, false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new(*fee_destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    data("initialize_fungible_parent_entangler_v0", &args),
+  )
+}
+
+/// Creates a parent whose PDA is derived from namespace and the dynamic seed. namespace must sign
+pub fn initialize_namespaced_parent_entangler_v0(
+  payer: &Pubkey,
+  namespace: &Pubkey,
+  parent_mint: &Pubkey,
+  fee_destination: &Pubkey,
+  args: InitializeFungibleParentEntanglerV0Args,
+) -> Instruction {
+  let (entangler, _) = pda::namespaced_parent_entangler(parent_mint, namespace, &args.dynamic_seed);
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*namespace, true),
+      AccountMeta::new(entangler, false),
+      AccountMeta::new(pda::storage(&entangler).0, false),
+      AccountMeta::new_readonly(*parent_mint, false),
+      AccountMeta::new(
+        pda::namespace_lookup(namespace, &args.dynamic_seed).0,
+        false,
+      ),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new(*fee_destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    data("initialize_namespaced_parent_entangler_v0", &args),
+  )
+}
+
+pub fn initialize_fungible_child_entangler_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  fee_destination: &Pubkey,
+  args: InitializeFungibleChildEntanglerV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(pda::lookup(&pair.parent_mint, &pair.child_mint).0, false),
+      AccountMeta::new_readonly(pair.child_mint, false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new(*fee_destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    data("initialize_fungible_child_entangler_v0", &args),
+  )
+}
+
+/// Like initialize_fungible_child_entangler_v0, but succeeds if the child already exists with the
+/// same args
+pub fn ensure_child_entangler_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  fee_destination: &Pubkey,
+  args: InitializeFungibleChildEntanglerV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(pda::lookup(&pair.parent_mint, &pair.child_mint).0, false),
+      AccountMeta::new_readonly(pair.child_mint, false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new(*fee_destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    data("ensure_child_entangler_v0", &args),
+  )
+}
+
+/// Creates the parent and one child per `(child_mint, args)` entry, all or nothing. The program
+/// accepts at most 4 children per instruction.
+pub fn initialize_entangler_with_children_v0(
+  payer: &Pubkey,
+  parent_mint: &Pubkey,
+  fee_destination: &Pubkey,
+  parent_args: InitializeFungibleParentEntanglerV0Args,
+  children: Vec<(Pubkey, InitializeFungibleChildEntanglerV0Args)>,
+) -> Instruction {
+  let (entangler, _) = pda::parent_entangler(parent_mint, &parent_args.dynamic_seed);
+  let mut accounts = vec![
+    AccountMeta::new(*payer, true),
+    AccountMeta::new(entangler, false),
+    AccountMeta::new(pda::storage(&entangler).0, false),
+    AccountMeta::new_readonly(*parent_mint, false),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+    AccountMeta::new(*fee_destination, false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new_readonly(noop::id(), false),
+  ];
+  let mut child_args = Vec::with_capacity(children.len());
+  for (child_mint, args) in children {
+    let (child_entangler, _) = pda::child_entangler(&entangler, &child_mint);
+    accounts.extend_from_slice(&[
+      AccountMeta::new_readonly(child_mint, false),
+      AccountMeta::new(child_entangler, false),
+      AccountMeta::new(pda::storage(&child_entangler).0, false),
+      AccountMeta::new(pda::lookup(parent_mint, &child_mint).0, false),
+    ]);
+    child_args.push(args);
+  }
+
+  instruction(
+    accounts,
+    data(
+      "initialize_entangler_with_children_v0",
+      &InitializeEntanglerWithChildrenV0Args {
+        parent_args,
+        child_args,
+      },
+    ),
+  )
+}
+
+/// Checks the arguments of initialize_entangler_with_children_v0 without creating anything. Meant
+/// to be simulated, with an InitValidationV0 as return data
+pub fn validate_init_params_v0(
+  parent_mint: &Pubkey,
+  parent_args: InitializeFungibleParentEntanglerV0Args,
+  children: Vec<(Pubkey, InitializeFungibleChildEntanglerV0Args)>,
+) -> Instruction {
+  let (entangler, _) = pda::parent_entangler(parent_mint, &parent_args.dynamic_seed);
+  let mut accounts = vec![
+    AccountMeta::new_readonly(*parent_mint, false),
+    AccountMeta::new_readonly(entangler, false),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+  ];
+  let mut child_args = Vec::with_capacity(children.len());
+  for (child_mint, args) in children {
+    let (child_entangler, _) = pda::child_entangler(&entangler, &child_mint);
+    accounts.extend_from_slice(&[
+      AccountMeta::new_readonly(child_mint, false),
+      AccountMeta::new_readonly(child_entangler, false),
+      AccountMeta::new_readonly(pda::storage(&child_entangler).0, false),
+      AccountMeta::new_readonly(pda::lookup(parent_mint, &child_mint).0, false),
+    ]);
+    child_args.push(args);
+  }
+
+  instruction(
+    accounts,
+    data(
+      "validate_init_params_v0",
+      &InitializeEntanglerWithChildrenV0Args {
+        parent_args,
+        child_args,
+      },
+    ),
+  )
+}
+
+/// Creates owner's swap record, which swaps through a child that tracks swaps for refunds, reverse
+/// swap rebates or fee distribution require
+pub fn initialize_swap_record_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  owner: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new_readonly(*owner, false),
+      AccountMeta::new(pda::swap_record(&pair.child_entangler, owner).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    discriminator::instruction("initialize_swap_record_v0").to_vec(),
+  )
+}
+
+fn swap_accounts(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+) -> Vec<AccountMeta> {
+  let mut accounts = vec![
+    AccountMeta::new(*payer, true),
+    AccountMeta::new(pair.parent_entangler, false),
+    AccountMeta::new(pair.parent_storage(), false),
+    AccountMeta::new(pair.parent_mint, false),
+    AccountMeta::new(pair.child_entangler, false),
+    AccountMeta::new(pair.child_storage(), false),
+    AccountMeta::new(pair.child_mint, false),
+    AccountMeta::new(*source, false),
+    AccountMeta::new_readonly(*source_authority, true),
+    AccountMeta::new(*destination, false),
+    AccountMeta::new(
+      pda::swap_record(&pair.child_entangler, source_authority).0,
+      false,
+    ),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+    AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new_readonly(spl_memo::id(), false),
+    AccountMeta::new_readonly(pda::rate_schedule(&pair.child_entangler).0, false),
+    AccountMeta::new_readonly(noop::id(), false),
+  ];
+  accounts.extend(
+    split_accounts
+      .iter()
+      .map(|account| AccountMeta::new(*account, false)),
+  );
+
+  accounts
+}
+
+/// Commits owner to a swap before the child goes live. Build the commitment with
+/// `SwapCommitV0::commitment` and keep the salt secret until revealing
+pub fn commit_swap_v0(
+  payer: &Pubkey,
+  owner: &Pubkey,
+  child_entangler: &Pubkey,
+  args: CommitSwapV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*owner, true),
+      AccountMeta::new_readonly(*child_entangler, false),
+      AccountMeta::new(pda::swap_commit(child_entangler, owner).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("commit_swap_v0", &args),
+  )
+}
+
+/// Executes source_authority's committed swap. Source and destination must hold the input and
+/// output mints of the committed direction. commit_payer paid for the commitment and gets its rent
+/// back
+pub fn reveal_and_swap_v0(
+  payer: &Pubkey,
+  commit_payer: &Pubkey,
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  args: RevealAndSwapV0Args,
+) -> Instruction {
+  let mut accounts = swap_accounts(payer, pair, source, source_authority, destination, &[]);
+  accounts.push(AccountMeta::new(
+    pda::swap_commit(&pair.child_entangler, source_authority).0,
+    false,
+  ));
+  accounts.push(AccountMeta::new(*commit_payer, false));
+
+  instruction(accounts, data("reveal_and_swap_v0", &args))
+}
+
+/// An SPL Token Swap `Swap` instruction against the pair, handled by the program's token-swap
+/// adapter. Unlike the other swaps it takes a minimum output and is not recorded for refunds
+#[allow(clippy::too_many_arguments)]
+pub fn token_swap_adapter_swap(
+  pair: &EntanglerPair,
+  parent_to_child: bool,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  amount_in: u64,
+  minimum_amount_out: u64,
+) -> Instruction {
+  let (swap_source, swap_destination) = if parent_to_child {
+    (pair.parent_storage(), pair.child_storage())
+  } else {
+    (pair.child_storage(), pair.parent_storage())
+  };
+  // token-swap's SwapInstruction::Swap
+  let mut data = vec![1];
+  data.extend_from_slice(&amount_in.to_le_bytes());
+  data.extend_from_slice(&minimum_amount_out.to_le_bytes());
+
+  instruction(
+    vec![
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(*source_authority, true),
+      AccountMeta::new(*source, false),
+      AccountMeta::new(swap_source, false),
+      AccountMeta::new(swap_destination, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new(pair.parent_mint, false),
+      AccountMeta::new(pair.child_mint, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new_readonly(sysvar::instructions::id(), false),
+      AccountMeta::new_readonly(pda::rate_schedule(&pair.child_entangler).0, false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    data,
+  )
+}
+
+/// Swaps parent tokens from source for child tokens. Output split accounts receive their share of
+/// the output in the order of `args.output_splits_bps`
+pub fn swap_parent_for_child_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+  args: SwapV0Args,
+) -> Instruction {
+  instruction(
+    swap_accounts(
+      payer,
+      pair,
+      source,
+      source_authority,
+      destination,
+      split_accounts,
+    ),
+    data("swap_parent_for_child_v0", &args),
+  )
+}
+
+/// Swaps parent tokens from source for the weighted basket of a registry page. legs holds the
+/// child mint and destination of every weighted child, in registry order. Children that track
+/// swaps need source_authority's swap record, see initialize_swap_record_v0
+pub fn swap_parent_for_basket_v0(
+  parent_mint: &Pubkey,
+  parent_entangler: &Pubkey,
+  page: u16,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  legs: &[(Pubkey, Pubkey)],
+  args: SwapParentForBasketV0Args,
+) -> Instruction {
+  let parent_storage = pda::storage(parent_entangler).0;
+  let mut accounts = vec![
+    AccountMeta::new(*parent_entangler, false),
+    AccountMeta::new(parent_storage, false),
+    AccountMeta::new(*parent_mint, false),
+    AccountMeta::new(*source, false),
+    AccountMeta::new_readonly(*source_authority, true),
+    AccountMeta::new_readonly(pda::registry(parent_entangler, page).0, false),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+    AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(noop::id(), false),
+  ];
+  for (child_mint, destination) in legs {
+    let child_entangler = pda::child_entangler(parent_entangler, child_mint).0;
+    accounts.extend_from_slice(&[
+      AccountMeta::new(child_entangler, false),
+      AccountMeta::new(pda::storage(&child_entangler).0, false),
+      AccountMeta::new(*child_mint, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(pda::rate_schedule(&child_entangler).0, false),
+      AccountMeta::new(
+        pda::swap_record(&child_entangler, source_authority).0,
+        false,
+      ),
+    ]);
+  }
+
+  instruction(accounts, data("swap_parent_for_basket_v0", &args))
+}
+
+/// Swaps parent tokens from source for child tokens sent to destination, then invokes the child's
+/// stake program with stake_accounts and `args.stake_data` to deposit them
+#[allow(clippy::too_many_arguments)]
+pub fn swap_parent_for_child_and_stake_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  stake_program: &Pubkey,
+  stake_accounts: &[AccountMeta],
+  args: SwapAndStakeV0Args,
+) -> Instruction {
+  let mut accounts = swap_accounts(payer, pair, source, source_authority, destination, &[]);
+  accounts.push(AccountMeta::new_readonly(*stake_program, false));
+  accounts.extend_from_slice(stake_accounts);
+
+  instruction(accounts, data("swap_parent_for_child_and_stake_v0", &args))
+}
+
+/// Swaps child tokens from source for parent tokens. Output split accounts receive their share of
+/// the output in the order of `args.output_splits_bps`
+pub fn swap_child_for_parent_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+  args: SwapV0Args,
+) -> Instruction {
+  instruction(
+    swap_accounts(
+      payer,
+      pair,
+      source,
+      source_authority,
+      destination,
+      split_accounts,
+    ),
+    data("swap_child_for_parent_v0", &args),
+  )
+}
+
+/// Swaps child tokens from source for parent tokens sent to destination, then invokes the child's
+/// liquidity program with pool_accounts and `args.deposit_data` to deposit them into a pool
+#[allow(clippy::too_many_arguments)]
+pub fn swap_child_for_parent_and_provide_liquidity_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  liquidity_program: &Pubkey,
+  pool_accounts: &[AccountMeta],
+  args: SwapAndProvideLiquidityV0Args,
+) -> Instruction {
+  let mut accounts = swap_accounts(payer, pair, source, source_authority, destination, &[]);
+  accounts.push(AccountMeta::new_readonly(*liquidity_program, false));
+  accounts.extend_from_slice(pool_accounts);
+
+  instruction(
+    accounts,
+    data("swap_child_for_parent_and_provide_liquidity_v0", &args),
+  )
+}
+
+/// Returns child tokens from source within the refund window, paying parent tokens to destination
+pub fn refund_swap_v0(
+  pair: &EntanglerPair,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  args: RefundSwapV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new(pair.parent_storage(), false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(
+        pda::swap_record(&pair.child_entangler, source_authority).0,
+        false,
+      ),
+      AccountMeta::new(*source, false),
+      AccountMeta::new_readonly(*source_authority, true),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    data("refund_swap_v0", &args),
+  )
+}
+
+/// Gives the child a swap record tree. merkle_tree must already be allocated and owned by
+/// spl-account-compression
+pub fn initialize_swap_record_tree_v0(
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  merkle_tree: &Pubkey,
+  args: InitializeSwapRecordTreeV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(*merkle_tree, false),
+      AccountMeta::new_readonly(account_compression::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    data("initialize_swap_record_tree_v0", &args),
+  )
+}
+
+fn swap_compressed_accounts(
+  pair: &EntanglerPair,
+  merkle_tree: &Pubkey,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+) -> Vec<AccountMeta> {
+  let mut accounts = vec![
+    AccountMeta::new(pair.parent_entangler, false),
+    AccountMeta::new(pair.parent_storage(), false),
+    AccountMeta::new(pair.parent_mint, false),
+    AccountMeta::new(pair.child_entangler, false),
+    AccountMeta::new(pair.child_storage(), false),
+    AccountMeta::new(pair.child_mint, false),
+    AccountMeta::new(*source, false),
+    AccountMeta::new_readonly(*source_authority, true),
+    AccountMeta::new(*destination, false),
+    AccountMeta::new(*merkle_tree, false),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+    AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(spl_memo::id(), false),
+    AccountMeta::new_readonly(pda::rate_schedule(&pair.child_entangler).0, false),
+    AccountMeta::new_readonly(account_compression::id(), false),
+    AccountMeta::new_readonly(noop::id(), false),
+  ];
+  accounts.extend(
+    split_accounts
+      .iter()
+      .map(|account| AccountMeta::new(*account, false)),
+  );
+
+  accounts
+}
+
+/// swap_parent_for_child_v0 recording to the child's swap record tree instead of a swap record
+pub fn swap_parent_for_child_compressed_v0(
+  pair: &EntanglerPair,
+  merkle_tree: &Pubkey,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+  args: SwapV0Args,
+) -> Instruction {
+  instruction(
+    swap_compressed_accounts(
+      pair,
+      merkle_tree,
+      source,
+      source_authority,
+      destination,
+      split_accounts,
+    ),
+    data("swap_parent_for_child_compressed_v0", &args),
+  )
+}
+
+/// swap_child_for_parent_v0 recording to the child's swap record tree instead of a swap record
+pub fn swap_child_for_parent_compressed_v0(
+  pair: &EntanglerPair,
+  merkle_tree: &Pubkey,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+  args: SwapV0Args,
+) -> Instruction {
+  instruction(
+    swap_compressed_accounts(
+      pair,
+      merkle_tree,
+      source,
+      source_authority,
+      destination,
+      split_accounts,
+    ),
+    data("swap_child_for_parent_compressed_v0", &args),
+  )
+}
+
+/// Reverses a compressed parent to child swap. proof holds the leaf's proof nodes, from its
+/// sibling up to the root
+pub fn refund_compressed_swap_v0(
+  pair: &EntanglerPair,
+  merkle_tree: &Pubkey,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  proof: &[Pubkey],
+  args: RefundCompressedSwapV0Args,
+) -> Instruction {
+  let mut accounts = vec![
+    AccountMeta::new_readonly(pair.parent_entangler, false),
+    AccountMeta::new(pair.parent_storage(), false),
+    AccountMeta::new(pair.child_entangler, false),
+    AccountMeta::new(pair.child_storage(), false),
+    AccountMeta::new(*merkle_tree, false),
+    AccountMeta::new(*source, false),
+    AccountMeta::new_readonly(*source_authority, true),
+    AccountMeta::new(*destination, false),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(account_compression::id(), false),
+    AccountMeta::new_readonly(noop::id(), false),
+  ];
+  accounts.extend(
+    proof
+      .iter()
+      .map(|node| AccountMeta::new_readonly(*node, false)),
+  );
+
+  instruction(accounts, data("refund_compressed_swap_v0", &args))
+}
+
+fn swap_compressed_token_accounts(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  owner: &Pubkey,
+  recipient: &Pubkey,
+  trees: &[Pubkey],
+) -> Vec<AccountMeta> {
+  let mut accounts = vec![
+    AccountMeta::new(*payer, true),
+    AccountMeta::new(pair.parent_entangler, false),
+    AccountMeta::new(pair.parent_storage(), false),
+    AccountMeta::new(pair.parent_mint, false),
+    AccountMeta::new(pair.child_entangler, false),
+    AccountMeta::new(pair.child_storage(), false),
+    AccountMeta::new(pair.child_mint, false),
+    AccountMeta::new_readonly(*owner, true),
+    AccountMeta::new_readonly(*recipient, false),
+    AccountMeta::new(
+      pda::compressed_token_transit(&pair.child_entangler, &pair.parent_mint).0,
+      false,
+    ),
+    AccountMeta::new(
+      pda::compressed_token_transit(&pair.child_entangler, &pair.child_mint).0,
+      false,
+    ),
+    AccountMeta::new(pda::light_token_pool(&pair.parent_mint).0, false),
+    AccountMeta::new(pda::light_token_pool(&pair.child_mint).0, false),
+    AccountMeta::new_readonly(pda::global_config().0, false),
+    AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    AccountMeta::new_readonly(pda::light_cpi_authority().0, false),
+    AccountMeta::new_readonly(pda::light_registered_program().0, false),
+    AccountMeta::new_readonly(pda::light_account_compression_authority().0, false),
+    AccountMeta::new_readonly(light::compressed_token::id(), false),
+    AccountMeta::new_readonly(light::system::id(), false),
+    AccountMeta::new_readonly(light::account_compression::id(), false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new_readonly(spl_memo::id(), false),
+    AccountMeta::new_readonly(pda::rate_schedule(&pair.child_entangler).0, false),
+    AccountMeta::new_readonly(noop::id(), false),
+  ];
+  accounts.extend(trees.iter().map(|tree| AccountMeta::new(*tree, false)));
+
+  accounts
+}
+
+/// Swaps owner's compressed parent tokens for compressed child tokens owned by recipient. trees
+/// are the state trees and queues, in the order the packed inputs and output tree index refer to
+pub fn swap_parent_for_child_compressed_token_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  owner: &Pubkey,
+  recipient: &Pubkey,
+  trees: &[Pubkey],
+  args: SwapCompressedTokenV0Args,
+) -> Instruction {
+  instruction(
+    swap_compressed_token_accounts(payer, pair, owner, recipient, trees),
+    data("swap_parent_for_child_compressed_token_v0", &args),
+  )
+}
+
+/// Swaps owner's compressed child tokens for compressed parent tokens owned by recipient
+pub fn swap_child_for_parent_compressed_token_v0(
+  payer: &Pubkey,
+  pair: &EntanglerPair,
+  owner: &Pubkey,
+  recipient: &Pubkey,
+  trees: &[Pubkey],
+  args: SwapCompressedTokenV0Args,
+) -> Instruction {
+  instruction(
+    swap_compressed_token_accounts(payer, pair, owner, recipient, trees),
+    data("swap_child_for_parent_compressed_token_v0", &args),
+  )
+}
+
+pub fn top_off_child_storage_v0(
+  payer: &Pubkey,
+  child_entangler: &Pubkey,
+  source: &Pubkey,
+  depositor: &Pubkey,
+  args: TopOffChildStorageV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new(*child_entangler, false),
+      AccountMeta::new(pda::storage(child_entangler).0, false),
+      AccountMeta::new(pda::top_off_record(child_entangler, depositor).0, false),
+      AccountMeta::new(*source, false),
+      AccountMeta::new_readonly(*depositor, true),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("top_off_child_storage_v0", &args),
+  )
+}
+
+pub fn transfer_child_storage_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  child_entangler: &Pubkey,
+  destination: &Pubkey,
+  args: TransferChildStorageArgsV0,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+      AccountMeta::new_readonly(*child_entangler, false),
+      AccountMeta::new(pda::storage(child_entangler).0, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    data("transfer_child_storage_v0", &args),
+  )
+}
+
+pub fn transfer_parent_storage_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  destination: &Pubkey,
+  args: TransferParentStorageArgsV0,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+      AccountMeta::new(pda::storage(parent_entangler).0, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    data("transfer_parent_storage_v0", &args),
+  )
+}
+
+/// Closes a child entangler, and the mint pair lookup if the child holds it. `refund_to` must be
+/// the entangler's rent refund
+pub fn close_fungible_child_entangler_v0(
+  refund_to: &Pubkey,
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*refund_to, false),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(pda::lookup(&pair.parent_mint, &pair.child_mint).0, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    discriminator::instruction("close_fungible_child_entangler_v0").to_vec(),
+  )
+}
+
+/// Closes a parent entangler once all of its children are closed. `refund_to` must be the
+/// entangler's rent refund
+pub fn close_fungible_parent_entangler_v0(
+  refund_to: &Pubkey,
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*refund_to, false),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+      AccountMeta::new(pda::storage(parent_entangler).0, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    discriminator::instruction("close_fungible_parent_entangler_v0").to_vec(),
+  )
+}
+
+/// Pays depositor their share of child storage, at most what they topped off, once the child has
+/// expired. Pass the child's rewards pool, if it has one, so accrued rewards are settled
+pub fn reclaim_after_expiry_v0(
+  child_entangler: &Pubkey,
+  depositor: &Pubkey,
+  destination: &Pubkey,
+  rewards_pool: Option<&Pubkey>,
+) -> Instruction {
+  let mut accounts = vec![
+    AccountMeta::new(*child_entangler, false),
+    AccountMeta::new(pda::storage(child_entangler).0, false),
+    AccountMeta::new(pda::top_off_record(child_entangler, depositor).0, false),
+    AccountMeta::new_readonly(*depositor, true),
+    AccountMeta::new(*destination, false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+  ];
+  if let Some(rewards_pool) = rewards_pool {
+    accounts.push(AccountMeta::new(*rewards_pool, false));
+  }
+
+  instruction(
+    accounts,
+    discriminator::instruction("reclaim_after_expiry_v0").to_vec(),
+  )
+}
+
+/// Permissionlessly freezes a child once it or its parent passes its freeze time
+pub fn enforce_freeze_v0(parent_entangler: &Pubkey, child_entangler: &Pubkey) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*parent_entangler, false),
+      AccountMeta::new(*child_entangler, false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    discriminator::instruction("enforce_freeze_v0").to_vec(),
+  )
+}
+
+/// Halts or resumes every swap program wide. Only the global config admin may call this
+pub fn set_global_pause_v0(admin: &Pubkey, args: SetGlobalPauseV0Args) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*admin, true),
+      AccountMeta::new(pda::global_config().0, false),
+    ],
+    data("set_global_pause_v0", &args),
+  )
+}
+
+/// Sweeps a balance of storage, either of the pair's storages, that no swap at the child's rate
+/// can reach. sweeper is the parent's authority, or anyone sweeping to an account owned by the
+/// fee destination once the parent is sealed
+pub fn sweep_dust_v0(
+  sweeper: &Pubkey,
+  pair: &EntanglerPair,
+  storage: &Pubkey,
+  destination: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*sweeper, true),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new(*storage, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    discriminator::instruction("sweep_dust_v0").to_vec(),
+  )
+}
+
+/// Simulate and decode the return data as a VersionV0 to find the build of the deployed program
+pub fn get_version_v0() -> Instruction {
+  instruction(
+    vec![],
+    discriminator::instruction("get_version_v0").to_vec(),
+  )
+}
+
+/// Permanently removes the parent's authority, so it can never be updated, withdrawn from or
+/// closed again
+pub fn seal_entangler_v0(authority: &Pubkey, parent_entangler: &Pubkey) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+    ],
+    discriminator::instruction("seal_entangler_v0").to_vec(),
+  )
+}
+
+/// Creates the pair's rate schedule or replaces all of its entries
+pub fn set_rate_schedule_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  args: SetRateScheduleV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new(pda::rate_schedule(&pair.child_entangler).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("set_rate_schedule_v0", &args),
+  )
+}
+
+/// Adds an entry after the last one in the pair's rate schedule
+pub fn append_rate_schedule_v0(
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  args: AppendRateScheduleV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new(pda::rate_schedule(&pair.child_entangler).0, false),
+    ],
+    data("append_rate_schedule_v0", &args),
+  )
+}
+
+/// Permissionless, at most once per epoch
+pub fn snapshot_v0(payer: &Pubkey, pair: &EntanglerPair) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.parent_storage(), false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new_readonly(pair.child_storage(), false),
+      AccountMeta::new(pda::snapshot_history(&pair.child_entangler).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    discriminator::instruction("snapshot_v0").to_vec(),
+  )
+}
+
+fn child_settings_accounts(authority: &Pubkey, pair: &EntanglerPair) -> Vec<AccountMeta> {
+  vec![
+    AccountMeta::new_readonly(*authority, true),
+    AccountMeta::new_readonly(pair.parent_entangler, false),
+    AccountMeta::new(pair.child_entangler, false),
+  ]
+}
+
+/// Replaces the child's freeze times
+pub fn set_freeze_time_v0(
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  args: SetFreezeTimeV0Args,
+) -> Instruction {
+  instruction(
+    child_settings_accounts(authority, pair),
+    data("set_freeze_time_v0", &args),
+  )
+}
+
+/// Replaces the parent's freeze times, which apply to every child
+pub fn set_parent_freeze_time_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  args: SetParentFreezeTimeV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+    ],
+    data("set_parent_freeze_time_v0", &args),
+  )
+}
+
+/// Freezes storage withdrawals of the parent and its children until a later time than any
+/// freeze already in place
+pub fn set_withdrawals_frozen_until_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  args: SetWithdrawalsFrozenUntilV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+    ],
+    data("set_withdrawals_frozen_until_v0", &args),
+  )
+}
+
+pub fn set_fees_v0(authority: &Pubkey, pair: &EntanglerPair, args: SetFeesV0Args) -> Instruction {
+  instruction(
+    child_settings_accounts(authority, pair),
+    data("set_fees_v0", &args),
+  )
+}
+
+pub fn set_rate_v0(authority: &Pubkey, pair: &EntanglerPair, args: SetRateV0Args) -> Instruction {
+  instruction(
+    child_settings_accounts(authority, pair),
+    data("set_rate_v0", &args),
+  )
+}
+
+/// Bounds the rates the child's rate schedule swaps at, or removes the bounds when args.rate_band
+/// is None
+pub fn set_rate_band_v0(
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  args: SetRateBandV0Args,
+) -> Instruction {
+  instruction(
+    child_settings_accounts(authority, pair),
+    data("set_rate_band_v0", &args),
+  )
+}
+
+/// Caps what swaps through the child pay out of storage per window, or removes the cap when
+/// args.drawdown_limit is None
+pub fn set_drawdown_limit_v0(
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  args: SetDrawdownLimitV0Args,
+) -> Instruction {
+  instruction(
+    child_settings_accounts(authority, pair),
+    data("set_drawdown_limit_v0", &args),
+  )
+}
+
+/// Hands the parent and its children to args.new_authority
+pub fn set_authority_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  args: SetAuthorityV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+    ],
+    data("set_authority_v0", &args),
+  )
+}
+
+/// Hands the parent and its children to whoever holds nft_mint
+pub fn set_nft_authority_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  nft_mint: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+      AccountMeta::new_readonly(*nft_mint, false),
+      AccountMeta::new_readonly(pda::nft_authority(parent_entangler).0, false),
+    ],
+    discriminator::instruction("set_nft_authority_v0").to_vec(),
+  )
+}
+
+/// Wraps inner, an authority gated instruction built with `pda::nft_authority(parent_entangler)`
+/// as its authority, so that the holder of the parent's authority NFT can execute it
+pub fn invoke_as_nft_authority_v0(
+  holder: &Pubkey,
+  parent_entangler: &Pubkey,
+  holder_nft_account: &Pubkey,
+  inner: Instruction,
+) -> Instruction {
+  let nft_authority = pda::nft_authority(parent_entangler).0;
+  let mut accounts = vec![
+    AccountMeta::new_readonly(*holder, true),
+    AccountMeta::new_readonly(*parent_entangler, false),
+    AccountMeta::new_readonly(*holder_nft_account, false),
+    AccountMeta::new_readonly(nft_authority, false),
+    AccountMeta::new_readonly(crate::id(), false),
+  ];
+  // The program signs for the PDA, the transaction can't
+  accounts.extend(inner.accounts.into_iter().map(|account| AccountMeta {
+    is_signer: account.is_signer && account.pubkey != nft_authority,
+    ..account
+  }));
+
+  instruction(
+    accounts,
+    data(
+      "invoke_as_nft_authority_v0",
+      &InvokeAsNftAuthorityV0Args { data: inner.data },
+    ),
+  )
+}
+
+/// Hands the parent and its children to threshold of args.signers acting together
+pub fn set_authority_config_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  args: SetAuthorityConfigV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*parent_entangler, false),
+      AccountMeta::new(pda::authority_config(parent_entangler).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("set_authority_config_v0", &args),
+  )
+}
+
+/// Wraps inner, an authority gated instruction built with
+/// `pda::authority_config(parent_entangler)` as its authority, so that signers, at least the
+/// config's threshold of them, can execute it together
+pub fn invoke_as_authority_config_v0(
+  parent_entangler: &Pubkey,
+  signers: &[Pubkey],
+  inner: Instruction,
+) -> Instruction {
+  let authority_config = pda::authority_config(parent_entangler).0;
+  let mut accounts = vec![
+    AccountMeta::new_readonly(*parent_entangler, false),
+    AccountMeta::new_readonly(authority_config, false),
+    AccountMeta::new_readonly(crate::id(), false),
+  ];
+  accounts.extend(
+    signers
+      .iter()
+      .map(|signer| AccountMeta::new_readonly(*signer, true)),
+  );
+  // The program signs for the PDA, the transaction can't
+  accounts.extend(inner.accounts.into_iter().map(|account| AccountMeta {
+    is_signer: account.is_signer && account.pubkey != authority_config,
+    ..account
+  }));
+
+  instruction(
+    accounts,
+    data(
+      "invoke_as_authority_config_v0",
+      &InvokeAsAuthorityConfigV0Args {
+        signer_count: signers.len() as u8,
+        data: inner.data,
+      },
+    ),
+  )
+}
+
+/// Recreates a parent of the original Strata program, whose dynamic seed is args.dynamic_seed,
+/// and moves its storage over. authority is the legacy parent's authority
+pub fn import_legacy_entangler_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  legacy_parent_entangler: &Pubkey,
+  legacy_parent_storage: &Pubkey,
+  parent_mint: &Pubkey,
+  args: ImportLegacyEntanglerV0Args,
+) -> Instruction {
+  let (entangler, _) = pda::parent_entangler(parent_mint, &args.dynamic_seed);
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*legacy_parent_entangler, false),
+      AccountMeta::new(*legacy_parent_storage, false),
+      AccountMeta::new(entangler, false),
+      AccountMeta::new(pda::storage(&entangler).0, false),
+      AccountMeta::new_readonly(*parent_mint, false),
+      AccountMeta::new_readonly(legacy_entangler::id(), false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("import_legacy_entangler_v0", &args),
+  )
+}
+
+/// Recreates a child of the original Strata program under pair.parent_entangler, already imported
+/// with import_legacy_entangler_v0, and moves its storage over
+pub fn import_legacy_child_entangler_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  legacy_parent_entangler: &Pubkey,
+  legacy_child_entangler: &Pubkey,
+  legacy_child_storage: &Pubkey,
+  pair: &EntanglerPair,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*legacy_parent_entangler, false),
+      AccountMeta::new_readonly(*legacy_child_entangler, false),
+      AccountMeta::new(*legacy_child_storage, false),
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(pda::lookup(&pair.parent_mint, &pair.child_mint).0, false),
+      AccountMeta::new_readonly(pair.child_mint, false),
+      AccountMeta::new_readonly(legacy_entangler::id(), false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    discriminator::instruction("import_legacy_child_entangler_v0").to_vec(),
+  )
+}
+
+/// Escrows args.offer_amount of offer_mint from source for args.taker to claim with
+/// args.ask_amount of ask_mint. The mints are the pair's parent and child mints, in either order
+pub fn create_offer_v0(
+  payer: &Pubkey,
+  maker: &Pubkey,
+  pair: &EntanglerPair,
+  offer_mint: &Pubkey,
+  ask_mint: &Pubkey,
+  source: &Pubkey,
+  args: CreateOfferV0Args,
+) -> Instruction {
+  let offer = pda::offer(&pair.child_entangler, maker, args.nonce).0;
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*maker, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new(offer, false),
+      AccountMeta::new(pda::offer_escrow(&offer).0, false),
+      AccountMeta::new_readonly(*offer_mint, false),
+      AccountMeta::new_readonly(*ask_mint, false),
+      AccountMeta::new(*source, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("create_offer_v0", &args),
+  )
+}
+
+/// Pays the ask from source to the maker's maker_destination and releases the escrow to
+/// destination. Only the offer's taker may sign
+pub fn accept_offer_v0(
+  address: &Pubkey,
+  offer: &OfferV0,
+  source: &Pubkey,
+  maker_destination: &Pubkey,
+  destination: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(offer.taker, true),
+      AccountMeta::new(offer.maker, false),
+      AccountMeta::new(*address, false),
+      AccountMeta::new_readonly(offer.child_entangler, false),
+      AccountMeta::new(offer.escrow, false),
+      AccountMeta::new(*source, false),
+      AccountMeta::new(*maker_destination, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    discriminator::instruction("accept_offer_v0").to_vec(),
+  )
+}
+
+/// Returns the escrow to destination and closes the offer. Only the maker may sign
+pub fn cancel_offer_v0(address: &Pubkey, offer: &OfferV0, destination: &Pubkey) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(offer.maker, true),
+      AccountMeta::new(*address, false),
+      AccountMeta::new_readonly(offer.child_entangler, false),
+      AccountMeta::new(offer.escrow, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    discriminator::instruction("cancel_offer_v0").to_vec(),
+  )
+}
+
+/// Approves program for yield strategies. admin must be the global config's admin
+pub fn approve_strategy_program_v0(
+  payer: &Pubkey,
+  admin: &Pubkey,
+  program: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*admin, true),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new_readonly(*program, false),
+      AccountMeta::new(pda::strategy_program(program).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    discriminator::instruction("approve_strategy_program_v0").to_vec(),
+  )
+}
+
+/// Points the child's yield strategy at an approved program
+pub fn set_yield_strategy_v0(
+  authority: &Pubkey,
+  pair: &EntanglerPair,
+  program: &Pubkey,
+  args: SetYieldStrategyV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new_readonly(pda::strategy_program(program).0, false),
+    ],
+    data("set_yield_strategy_v0", &args),
+  )
+}
+
+/// Deposits into or withdraws from the child's strategy. inner is the strategy program's own
+/// instruction, built with the child entangler as the owner of child storage
+pub fn rebalance_strategy_v0(
+  keeper: &Pubkey,
+  pair: &EntanglerPair,
+  deposit: bool,
+  amount: u64,
+  inner: Instruction,
+) -> Instruction {
+  let mut accounts = vec![
+    AccountMeta::new_readonly(*keeper, true),
+    AccountMeta::new_readonly(pair.parent_entangler, false),
+    AccountMeta::new(pair.child_entangler, false),
+    AccountMeta::new(pair.child_storage(), false),
+    AccountMeta::new_readonly(inner.program_id, false),
+  ];
+  // The program signs for the child entangler, the transaction can't
+  accounts.extend(inner.accounts.into_iter().map(|account| AccountMeta {
+    is_signer: account.is_signer && account.pubkey != pair.child_entangler,
+    ..account
+  }));
+
+  instruction(
+    accounts,
+    data(
+      "rebalance_strategy_v0",
+      &RebalanceStrategyV0Args {
+        deposit,
+        amount,
+        data: inner.data,
+      },
+    ),
+  )
+}
+
+/// Sets the weights of a registry page's basket, one per entry. Pages created before baskets
+/// must first be grown by migrate_child_registry_v0
+pub fn set_registry_weights_v0(
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  page: u16,
+  args: SetRegistryWeightsV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(*parent_entangler, false),
+      AccountMeta::new(pda::registry(parent_entangler, page).0, false),
+    ],
+    data("set_registry_weights_v0", &args),
+  )
+}
+
+/// Grows a registry page created before baskets to the current size, paid by payer
+pub fn migrate_child_registry_v0(
+  payer: &Pubkey,
+  parent_entangler: &Pubkey,
+  page: u16,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new(pda::registry(parent_entangler, page).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    discriminator::instruction("migrate_child_registry_v0").to_vec(),
+  )
+}
+
+/// Escrows a swap from source as ticket, the swap queue's next_ticket, before the child goes live
+#[allow(clippy::too_many_arguments)]
+pub fn queue_swap_v0(
+  payer: &Pubkey,
+  owner: &Pubkey,
+  pair: &EntanglerPair,
+  ticket: u64,
+  source: &Pubkey,
+  destination: &Pubkey,
+  args: QueueSwapV0Args,
+) -> Instruction {
+  let queued_swap = pda::queued_swap(&pair.child_entangler, ticket).0;
+  let input_mint = if args.parent_to_child {
+    pair.parent_mint
+  } else {
+    pair.child_mint
+  };
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*owner, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new(pda::swap_queue(&pair.child_entangler).0, false),
+      AccountMeta::new(queued_swap, false),
+      AccountMeta::new(pda::queued_swap_escrow(&queued_swap).0, false),
+      AccountMeta::new_readonly(input_mint, false),
+      AccountMeta::new(*source, false),
+      AccountMeta::new_readonly(*destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("queue_swap_v0", &args),
+  )
+}
+
+/// Settles queued_swap, which must be the swap queue's next_execute ticket. Anyone may sign
+pub fn execute_queued_swap_v0(
+  pair: &EntanglerPair,
+  address: &Pubkey,
+  queued_swap: &QueuedSwapV0,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.parent_storage(), false),
+      AccountMeta::new(pair.parent_mint, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(pair.child_mint, false),
+      AccountMeta::new(pda::swap_queue(&pair.child_entangler).0, false),
+      AccountMeta::new(*address, false),
+      AccountMeta::new(queued_swap.escrow, false),
+      AccountMeta::new(queued_swap.destination, false),
+      AccountMeta::new(queued_swap.refund_account, false),
+      AccountMeta::new(queued_swap.payer, false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new_readonly(pda::rate_schedule(&pair.child_entangler).0, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    discriminator::instruction("execute_queued_swap_v0").to_vec(),
+  )
+}
+
+/// Returns the escrow of a ticket that hasn't executed. Only the owner may sign
+pub fn cancel_queued_swap_v0(address: &Pubkey, queued_swap: &QueuedSwapV0) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(queued_swap.owner, true),
+      AccountMeta::new(*address, false),
+      AccountMeta::new_readonly(queued_swap.child_entangler, false),
+      AccountMeta::new(queued_swap.escrow, false),
+      AccountMeta::new(queued_swap.refund_account, false),
+      AccountMeta::new(queued_swap.payer, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    discriminator::instruction("cancel_queued_swap_v0").to_vec(),
+  )
+}
+
+/// Snapshots the fees an expired child, or a frozen child of a sealed parent, accrued for
+/// ClaimFeeRebateV0. Anyone may sign
+pub fn distribute_fees_v0(parent_entangler: &Pubkey, child_entangler: &Pubkey) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*parent_entangler, false),
+      AccountMeta::new(*child_entangler, false),
+    ],
+    discriminator::instruction("distribute_fees_v0").to_vec(),
+  )
+}
+
+/// Claims owner's share of the distributed fees, pro rata to its swap record. Each record claims
+/// once
+pub fn claim_fee_rebate_v0(
+  pair: &EntanglerPair,
+  owner: &Pubkey,
+  child_destination: &Pubkey,
+  parent_destination: &Pubkey,
+) -> Instruction {
+  let swap_record = pda::swap_record(&pair.child_entangler, owner).0;
+  instruction(
+    vec![
+      AccountMeta::new(*owner, true),
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.parent_storage(), false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new_readonly(swap_record, false),
+      AccountMeta::new(pda::fee_rebate_claim(&swap_record).0, false),
+      AccountMeta::new(*child_destination, false),
+      AccountMeta::new(*parent_destination, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    discriminator::instruction("claim_fee_rebate_v0").to_vec(),
+  )
+}
+
+/// Opens a child to deferred delivery through args.settler
+pub fn set_pending_settlement_v0(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  parent_entangler: &Pubkey,
+  child_entangler: &Pubkey,
+  args: SetPendingSettlementV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(*parent_entangler, false),
+      AccountMeta::new_readonly(*child_entangler, false),
+      AccountMeta::new(pda::pending_settlement(child_entangler).0, false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("set_pending_settlement_v0", &args),
+  )
+}
+
+/// Escrows a swap as entry ticket, the pending settlement's next_ticket, for the settler to
+/// credit off chain and acknowledge
+pub fn defer_swap_v0(
+  payer: &Pubkey,
+  owner: &Pubkey,
+  pair: &EntanglerPair,
+  ticket: u64,
+  source: &Pubkey,
+  args: DeferSwapV0Args,
+) -> Instruction {
+  let pending_settlement = pda::pending_settlement(&pair.child_entangler).0;
+  let settlement_entry = pda::settlement_entry(&pending_settlement, ticket).0;
+  let input_mint = if args.parent_to_child {
+    pair.parent_mint
+  } else {
+    pair.child_mint
+  };
+  instruction(
+    vec![
+      AccountMeta::new(*payer, true),
+      AccountMeta::new_readonly(*owner, true),
+      AccountMeta::new_readonly(pair.parent_entangler, false),
+      AccountMeta::new_readonly(pair.child_entangler, false),
+      AccountMeta::new(pending_settlement, false),
+      AccountMeta::new(settlement_entry, false),
+      AccountMeta::new(pda::settlement_escrow(&settlement_entry).0, false),
+      AccountMeta::new_readonly(input_mint, false),
+      AccountMeta::new(*source, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(system_program::id(), false),
+    ],
+    data("defer_swap_v0", &args),
+  )
+}
+
+/// Swaps the escrow of an entry into destination once the settler credited it off chain. Only
+/// the settler may sign
+pub fn ack_settlement_v0(
+  settler: &Pubkey,
+  pair: &EntanglerPair,
+  address: &Pubkey,
+  entry: &SettlementEntryV0,
+  destination: &Pubkey,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*settler, true),
+      AccountMeta::new(pair.parent_entangler, false),
+      AccountMeta::new(pair.parent_storage(), false),
+      AccountMeta::new(pair.parent_mint, false),
+      AccountMeta::new(pair.child_entangler, false),
+      AccountMeta::new(pair.child_storage(), false),
+      AccountMeta::new(pair.child_mint, false),
+      AccountMeta::new_readonly(entry.pending_settlement, false),
+      AccountMeta::new(*address, false),
+      AccountMeta::new(entry.escrow, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new(entry.payer, false),
+      AccountMeta::new_readonly(pda::global_config().0, false),
+      AccountMeta::new_readonly(pda::rate_schedule(&pair.child_entangler).0, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(spl_memo::id(), false),
+      AccountMeta::new_readonly(noop::id(), false),
+    ],
+    discriminator::instruction("ack_settlement_v0").to_vec(),
+  )
+}
+
+/// Returns the escrow of an entry that timed out. Anyone may sign
+pub fn refund_settlement_v0(
+  child_entangler: &Pubkey,
+  address: &Pubkey,
+  entry: &SettlementEntryV0,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*child_entangler, false),
+      AccountMeta::new_readonly(entry.pending_settlement, false),
+      AccountMeta::new(*address, false),
+      AccountMeta::new(entry.escrow, false),
+      AccountMeta::new(entry.refund_account, false),
+      AccountMeta::new(entry.payer, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    discriminator::instruction("refund_settlement_v0").to_vec(),
+  )
+}
+
+/// Moves storage from source to destination, two entanglers of the same mints under authority
+pub fn rebalance_between_entanglers_v0(
+  authority: &Pubkey,
+  source: &EntanglerPair,
+  destination: &EntanglerPair,
+  args: RebalanceBetweenEntanglersV0Args,
+) -> Instruction {
+  instruction(
+    vec![
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new_readonly(source.parent_entangler, false),
+      AccountMeta::new(source.parent_storage(), false),
+      AccountMeta::new_readonly(source.child_entangler, false),
+      AccountMeta::new(source.child_storage(), false),
+      AccountMeta::new_readonly(destination.parent_entangler, false),
+      AccountMeta::new(destination.parent_storage(), false),
+      AccountMeta::new_readonly(destination.child_entangler, false),
+      AccountMeta::new(destination.child_storage(), false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+    ],
+    data("rebalance_between_entanglers_v0", &args),
+  )
+}
@@ -0,0 +1,48 @@
+//! Client side helpers for the fungible entangler that only depend on solana-program, so bots and
+//! backends can build instructions and decode accounts without pulling in the Anchor runtime.
+//! Layouts here mirror the program's and must be updated alongside it. RPC helpers live
+//! behind the `rpc` feature.
+
+pub mod discriminator;
+pub mod error;
+pub mod events;
+pub mod instruction;
+pub mod pda;
+pub mod quote;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+pub mod state;
+
+pub use error::SdkError;
+
+solana_program::declare_id!("fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB");
+
+/// spl-account-compression, which holds the swap record trees of children that use them
+pub mod account_compression {
+  solana_program::declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+}
+
+/// spl-noop, which the program logs swap and lifecycle events through
+pub mod noop {
+  solana_program::declare_id!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+}
+
+/// Light Protocol's programs, which compressed token swaps move compressed balances through
+pub mod light {
+  pub mod compressed_token {
+    solana_program::declare_id!("cTokenmWW8bLPjZEBAUgYy3zKxQZW6VKi7bqNFEVv3m");
+  }
+
+  pub mod system {
+    solana_program::declare_id!("SySTEM1eSU2p4BGQfQpimFEWWSC1XDFeun3Nqzz3rT7");
+  }
+
+  pub mod account_compression {
+    solana_program::declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
+  }
+}
+
+/// The original Strata fungible entangler, which forks deployed elsewhere import entanglers from
+pub mod legacy_entangler {
+  solana_program::declare_id!("fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB");
+}
@@ -0,0 +1,214 @@
+use crate::light;
+use solana_program::{
+  hash::{hash, hashv},
+  pubkey::Pubkey,
+};
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+  Pubkey::find_program_address(seeds, &crate::id())
+}
+
+/// Parent entanglers are derived from sha256 of the dynamic seed so seeds of any length fit
+pub fn hash_seed(seed: &[u8]) -> [u8; 32] {
+  hash(seed).to_bytes()
+}
+
+pub fn global_config() -> (Pubkey, u8) {
+  find(&[b"global-config"])
+}
+
+pub fn parent_entangler(parent_mint: &Pubkey, dynamic_seed: &[u8]) -> (Pubkey, u8) {
+  find(&[
+    b"entangler",
+    parent_mint.as_ref(),
+    hash_seed(dynamic_seed).as_ref(),
+  ])
+}
+
+pub const NAMESPACED_SEED_DOMAIN: &[u8] = b"namespaced-entangler";
+
+/// Seed hash of a parent created through InitializeNamespacedParentEntanglerV0. Domain separated
+/// from hash_seed, see the program's hash_namespaced_seed
+pub fn hash_namespaced_seed(namespace: &Pubkey, seed: &[u8]) -> [u8; 32] {
+  hashv(&[NAMESPACED_SEED_DOMAIN, namespace.as_ref(), &hash_seed(seed)]).to_bytes()
+}
+
+pub fn namespaced_parent_entangler(
+  parent_mint: &Pubkey,
+  namespace: &Pubkey,
+  dynamic_seed: &[u8],
+) -> (Pubkey, u8) {
+  find(&[
+    b"entangler",
+    parent_mint.as_ref(),
+    hash_namespaced_seed(namespace, dynamic_seed).as_ref(),
+  ])
+}
+
+pub fn namespace_lookup(namespace: &Pubkey, dynamic_seed: &[u8]) -> (Pubkey, u8) {
+  find(&[
+    b"namespace-lookup",
+    namespace.as_ref(),
+    hash_seed(dynamic_seed).as_ref(),
+  ])
+}
+
+pub fn child_entangler(parent_entangler: &Pubkey, child_mint: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"entangler", parent_entangler.as_ref(), child_mint.as_ref()])
+}
+
+/// Storage of either a parent or a child entangler
+pub fn storage(entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"storage", entangler.as_ref()])
+}
+
+/// Names the first child entangler created between parent_mint and child_mint
+pub fn lookup(parent_mint: &Pubkey, child_mint: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"lookup", parent_mint.as_ref(), child_mint.as_ref()])
+}
+
+pub fn registry(parent_entangler: &Pubkey, page: u16) -> (Pubkey, u8) {
+  find(&[b"registry", parent_entangler.as_ref(), &page.to_le_bytes()])
+}
+
+pub fn swap_record(child_entangler: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"swap-record", child_entangler.as_ref(), owner.as_ref()])
+}
+
+pub fn fee_rebate_claim(swap_record: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"fee-rebate", swap_record.as_ref()])
+}
+
+pub fn top_off_record(child_entangler: &Pubkey, depositor: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"top-off", child_entangler.as_ref(), depositor.as_ref()])
+}
+
+pub fn rate_schedule(child_entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"rate-schedule", child_entangler.as_ref()])
+}
+
+pub fn snapshot_history(child_entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"snapshot-history", child_entangler.as_ref()])
+}
+
+pub fn swap_commit(child_entangler: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"swap-commit", child_entangler.as_ref(), owner.as_ref()])
+}
+
+pub fn swap_queue(child_entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"swap-queue", child_entangler.as_ref()])
+}
+
+pub fn queued_swap(child_entangler: &Pubkey, ticket: u64) -> (Pubkey, u8) {
+  find(&[
+    b"queued-swap",
+    child_entangler.as_ref(),
+    &ticket.to_le_bytes(),
+  ])
+}
+
+pub fn queued_swap_escrow(queued_swap: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"queued-swap-escrow", queued_swap.as_ref()])
+}
+
+pub fn pending_settlement(child_entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"pending-settlement", child_entangler.as_ref()])
+}
+
+pub fn settlement_entry(pending_settlement: &Pubkey, ticket: u64) -> (Pubkey, u8) {
+  find(&[
+    b"settlement-entry",
+    pending_settlement.as_ref(),
+    &ticket.to_le_bytes(),
+  ])
+}
+
+pub fn settlement_escrow(settlement_entry: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"settlement-escrow", settlement_entry.as_ref()])
+}
+
+pub fn strategy_program(program: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"strategy-program", program.as_ref()])
+}
+
+pub fn nft_authority(parent_entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"nft-authority", parent_entangler.as_ref()])
+}
+
+pub fn authority_config(parent_entangler: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"authority-config", parent_entangler.as_ref()])
+}
+
+pub fn offer(child_entangler: &Pubkey, maker: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+  find(&[
+    b"offer",
+    child_entangler.as_ref(),
+    maker.as_ref(),
+    &nonce.to_le_bytes(),
+  ])
+}
+
+pub fn offer_escrow(offer: &Pubkey) -> (Pubkey, u8) {
+  find(&[b"offer-escrow", offer.as_ref()])
+}
+
+/// Holds a compressed token swap's tokens of mint between decompressing and compressing them.
+/// Only exists during the swap
+pub fn compressed_token_transit(child_entangler: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+  find(&[
+    b"compressed-token-transit",
+    child_entangler.as_ref(),
+    mint.as_ref(),
+  ])
+}
+
+/// The compressed-token program's authority over its token pools
+pub fn light_cpi_authority() -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"cpi_authority"], &light::compressed_token::id())
+}
+
+/// The SPL token account holding mint's compressed supply
+pub fn light_token_pool(mint: &Pubkey) -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"pool", mint.as_ref()], &light::compressed_token::id())
+}
+
+/// The light system program's registration with Light's account compression program
+pub fn light_registered_program() -> (Pubkey, u8) {
+  Pubkey::find_program_address(
+    &[light::system::id().as_ref()],
+    &light::account_compression::id(),
+  )
+}
+
+pub fn light_account_compression_authority() -> (Pubkey, u8) {
+  Pubkey::find_program_address(&[b"cpi_authority"], &light::system::id())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_namespaced_seeds_do_not_collide_with_plain_seeds() {
+    let namespace = Pubkey::new_unique();
+    let seed = b"seed";
+    let namespaced = hash_namespaced_seed(&namespace, seed);
+
+    // The old undomained preimage is itself a valid plain seed
+    let mut concatenated = namespace.to_bytes().to_vec();
+    concatenated.extend_from_slice(seed);
+    assert_ne!(namespaced, hash_seed(&concatenated));
+    assert_ne!(namespaced, hash_seed(seed));
+
+    let mut preimage = NAMESPACED_SEED_DOMAIN.to_vec();
+    preimage.extend_from_slice(namespace.as_ref());
+    preimage.extend_from_slice(&hash_seed(seed));
+    assert_eq!(namespaced, hash_seed(&preimage));
+    assert!(preimage.len() > 64);
+
+    assert_ne!(
+      namespaced_parent_entangler(&Pubkey::default(), &namespace, seed),
+      parent_entangler(&Pubkey::default(), &concatenated)
+    );
+  }
+}
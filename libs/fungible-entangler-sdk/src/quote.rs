@@ -0,0 +1,197 @@
+//! Swap quotes computed exactly as the program does: convert at the exchange rate rounding down,
+//! take the swap fee rounding up, then apply the configured burn rounding down.
+
+use crate::state::{BurnConfigV0, BurnSideV0, ExchangeRateV0, FungibleChildEntanglerV1};
+use std::convert::TryFrom;
+
+pub const BPS_DENOMINATOR: u64 = 10000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SwapDirection {
+  ParentToChild,
+  ChildToParent,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Quote {
+  /// Taken from the source
+  pub amount: u64,
+  /// Input converted at the exchange rate, before fees
+  pub converted_amount: u64,
+  /// Swap fee, which stays in output storage
+  pub fee_amount: u64,
+  /// Leaves output storage
+  pub output_amount: u64,
+  /// Burned out of input storage after the input arrives
+  pub input_burn_amount: u64,
+  /// Burned out of output storage instead of being paid out
+  pub output_burn_amount: u64,
+  /// Paid to the destination and any output splits
+  pub received_amount: u64,
+}
+
+fn mul_div(a: u64, b: u64, c: u64, round_up: bool) -> Option<u64> {
+  if c == 0 {
+    return None;
+  }
+  let product = (a as u128).checked_mul(b as u128)?;
+  let mut quotient = product / c as u128;
+  if round_up && product % c as u128 != 0 {
+    quotient = quotient.checked_add(1)?;
+  }
+
+  u64::try_from(quotient).ok()
+}
+
+fn apply_bps(amount: u64, bps: u16, round_up: bool) -> Option<u64> {
+  mul_div(amount, bps as u64, BPS_DENOMINATOR, round_up)
+}
+
+/// Converts amount of the input token to the output token at rate, rounding down
+pub fn convert(rate: &ExchangeRateV0, direction: SwapDirection, amount: u64) -> Option<u64> {
+  match direction {
+    SwapDirection::ParentToChild => mul_div(amount, rate.child_units, rate.parent_units, false),
+    SwapDirection::ChildToParent => mul_div(amount, rate.parent_units, rate.child_units, false),
+  }
+}
+
+/// Quotes a swap with explicit parameters. Returns None where the program would overflow
+pub fn quote_with(
+  rate: &ExchangeRateV0,
+  swap_fee_bps: u16,
+  burn: &Option<BurnConfigV0>,
+  direction: SwapDirection,
+  amount: u64,
+) -> Option<Quote> {
+  let converted_amount = convert(rate, direction, amount)?;
+  let fee_amount = apply_bps(converted_amount, swap_fee_bps, true)?;
+  let output_amount = converted_amount.checked_sub(fee_amount)?;
+  let (input_burn_amount, output_burn_amount) = match burn {
+    None => (0, 0),
+    Some(BurnConfigV0 {
+      bps,
+      side: BurnSideV0::Input,
+    }) => (apply_bps(amount, *bps, false)?, 0),
+    Some(BurnConfigV0 {
+      bps,
+      side: BurnSideV0::Output,
+    }) => (0, apply_bps(output_amount, *bps, false)?),
+  };
+
+  Some(Quote {
+    amount,
+    converted_amount,
+    fee_amount,
+    output_amount,
+    input_burn_amount,
+    output_burn_amount,
+    received_amount: output_amount.checked_sub(output_burn_amount)?,
+  })
+}
+
+/// Quotes a swap through child_entangler
+pub fn quote(
+  child_entangler: &FungibleChildEntanglerV1,
+  direction: SwapDirection,
+  amount: u64,
+) -> Option<Quote> {
+  quote_with(
+    &child_entangler.rate,
+    child_entangler.fee_bps(amount),
+    &child_entangler.burn,
+    direction,
+    amount,
+  )
+}
+
+fn reverse(direction: SwapDirection) -> SwapDirection {
+  match direction {
+    SwapDirection::ParentToChild => SwapDirection::ChildToParent,
+    SwapDirection::ChildToParent => SwapDirection::ParentToChild,
+  }
+}
+
+/// The input a partial fill swap of amount takes when output storage holds storage_amount
+pub fn fill_amount(
+  rate: &ExchangeRateV0,
+  direction: SwapDirection,
+  amount: u64,
+  storage_amount: u64,
+) -> Option<u64> {
+  if convert(rate, direction, amount)? <= storage_amount {
+    return Some(amount);
+  }
+
+  convert(rate, reverse(direction), storage_amount)
+}
+
+/// Quotes a swap through child_entangler with allow_partial set
+pub fn quote_partial(
+  child_entangler: &FungibleChildEntanglerV1,
+  direction: SwapDirection,
+  amount: u64,
+  storage_amount: u64,
+) -> Option<Quote> {
+  let amount = fill_amount(&child_entangler.rate, direction, amount, storage_amount)?;
+  quote(child_entangler, direction, amount)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_quote_rounds_in_favor_of_storage() {
+    let rate = ExchangeRateV0 {
+      parent_units: 3,
+      child_units: 2,
+    };
+    let quote = quote_with(
+      &rate,
+      100,
+      &Some(BurnConfigV0 {
+        bps: 50,
+        side: BurnSideV0::Output,
+      }),
+      SwapDirection::ParentToChild,
+      1000,
+    )
+    .unwrap();
+
+    assert_eq!(quote.converted_amount, 666);
+    assert_eq!(quote.fee_amount, 7);
+    assert_eq!(quote.output_amount, 659);
+    assert_eq!(quote.output_burn_amount, 3);
+    assert_eq!(quote.received_amount, 656);
+  }
+
+  #[test]
+  fn test_fill_amount_caps_at_storage() {
+    let rate = ExchangeRateV0 {
+      parent_units: 3,
+      child_units: 2,
+    };
+
+    assert_eq!(
+      fill_amount(&rate, SwapDirection::ParentToChild, 1000, 1000),
+      Some(1000)
+    );
+    assert_eq!(
+      fill_amount(&rate, SwapDirection::ParentToChild, 1000, 100),
+      Some(150)
+    );
+    assert_eq!(
+      fill_amount(&rate, SwapDirection::ParentToChild, 1000, 0),
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn test_quote_rejects_zero_rate() {
+    let rate = ExchangeRateV0 {
+      parent_units: 0,
+      child_units: 1,
+    };
+    assert!(quote_with(&rate, 0, &None, SwapDirection::ParentToChild, 1).is_none());
+  }
+}
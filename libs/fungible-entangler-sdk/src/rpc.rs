@@ -0,0 +1,184 @@
+//! Account fetching over solana-client. getProgramAccounts queries are narrowed server side
+//! with memcmp filters on the account discriminator and the field offsets below.
+
+use crate::{
+  pda,
+  quote::{quote, Quote, SwapDirection},
+  state::{FungibleChildEntanglerV1, FungibleParentEntanglerV1, ProgramAccount, RateScheduleV0},
+  SdkError,
+};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+  rpc_client::RpcClient,
+  rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+  rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Discriminator then the u16 version
+const VERSION_END: usize = 8 + 2;
+pub const PARENT_MINT_OFFSET: usize = VERSION_END;
+pub const PARENT_ENTANGLER_OFFSET: usize = VERSION_END;
+pub const CHILD_MINT_OFFSET: usize = VERSION_END + 32;
+
+fn memcmp(offset: usize, bytes: &[u8]) -> RpcFilterType {
+  RpcFilterType::Memcmp(Memcmp {
+    offset,
+    bytes: MemcmpEncodedBytes::Base58(bs58::encode(bytes).into_string()),
+    encoding: None,
+  })
+}
+
+/// Matches accounts of type T
+pub fn discriminator_filter<T: ProgramAccount>() -> RpcFilterType {
+  memcmp(0, &T::discriminator())
+}
+
+/// Matches accounts holding key at offset
+pub fn pubkey_filter(offset: usize, key: &Pubkey) -> RpcFilterType {
+  memcmp(offset, key.as_ref())
+}
+
+/// Fetches and decodes every account of type T matching filters, in addition to the
+/// discriminator filter
+pub fn get_program_accounts<T: ProgramAccount>(
+  client: &RpcClient,
+  filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, T)>, SdkError> {
+  let mut all_filters = vec![discriminator_filter::<T>()];
+  all_filters.extend(filters);
+  let accounts = client.get_program_accounts_with_config(
+    &crate::id(),
+    RpcProgramAccountsConfig {
+      filters: Some(all_filters),
+      account_config: RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        ..RpcAccountInfoConfig::default()
+      },
+      ..RpcProgramAccountsConfig::default()
+    },
+  )?;
+
+  accounts
+    .into_iter()
+    .map(|(address, account)| Ok((address, T::try_from_account_data(&account.data)?)))
+    .collect()
+}
+
+pub fn get_account<T: ProgramAccount>(client: &RpcClient, address: &Pubkey) -> Result<T, SdkError> {
+  let account = client.get_account(address)?;
+  T::try_from_account_data(&account.data)
+}
+
+/// Like get_account, but None when the account does not exist
+pub fn get_account_if_exists<T: ProgramAccount>(
+  client: &RpcClient,
+  address: &Pubkey,
+) -> Result<Option<T>, SdkError> {
+  let account = client
+    .get_account_with_commitment(address, client.commitment())?
+    .value;
+  account
+    .map(|account| T::try_from_account_data(&account.data))
+    .transpose()
+}
+
+pub fn get_token_balance(client: &RpcClient, address: &Pubkey) -> Result<u64, SdkError> {
+  let account = client.get_account(address)?;
+  let token_account = spl_token::state::Account::unpack(&account.data)
+    .map_err(|_| SdkError::InvalidTokenAccount(*address))?;
+
+  Ok(token_account.amount)
+}
+
+/// Parent entanglers whose parent mint is mint
+pub fn get_parent_entanglers_for_mint(
+  client: &RpcClient,
+  mint: &Pubkey,
+) -> Result<Vec<(Pubkey, FungibleParentEntanglerV1)>, SdkError> {
+  get_program_accounts(client, vec![pubkey_filter(PARENT_MINT_OFFSET, mint)])
+}
+
+/// Child entanglers whose child mint is mint
+pub fn get_child_entanglers_for_mint(
+  client: &RpcClient,
+  mint: &Pubkey,
+) -> Result<Vec<(Pubkey, FungibleChildEntanglerV1)>, SdkError> {
+  get_program_accounts(client, vec![pubkey_filter(CHILD_MINT_OFFSET, mint)])
+}
+
+/// Every child entangler under parent_entangler
+pub fn get_children(
+  client: &RpcClient,
+  parent_entangler: &Pubkey,
+) -> Result<Vec<(Pubkey, FungibleChildEntanglerV1)>, SdkError> {
+  get_program_accounts(
+    client,
+    vec![pubkey_filter(PARENT_ENTANGLER_OFFSET, parent_entangler)],
+  )
+}
+
+/// Every entangler a mint takes part in, as a parent or as a child
+pub struct MintEntanglers {
+  pub parents: Vec<(Pubkey, FungibleParentEntanglerV1)>,
+  pub children: Vec<(Pubkey, FungibleChildEntanglerV1)>,
+}
+
+pub fn get_entanglers_for_mint(
+  client: &RpcClient,
+  mint: &Pubkey,
+) -> Result<MintEntanglers, SdkError> {
+  Ok(MintEntanglers {
+    parents: get_parent_entanglers_for_mint(client, mint)?,
+    children: get_child_entanglers_for_mint(client, mint)?,
+  })
+}
+
+/// A quote against current chain state
+#[derive(Clone, Copy, Debug)]
+pub struct LiveQuote {
+  pub quote: Quote,
+  /// Balance of the storage the output is paid from
+  pub output_storage_amount: u64,
+}
+
+impl LiveQuote {
+  /// Whether output storage holds enough to pay the quote
+  pub fn is_fillable(&self) -> bool {
+    self.quote.output_amount <= self.output_storage_amount
+  }
+}
+
+/// Quotes swapping amount through child_entangler using its current rate, fee and burn, and the
+/// balance of the storage the output would come from. The rate comes from the child's rate
+/// schedule when it has one, judged by the local clock
+pub fn get_live_quote(
+  client: &RpcClient,
+  child_entangler: &Pubkey,
+  direction: SwapDirection,
+  amount: u64,
+) -> Result<LiveQuote, SdkError> {
+  let mut child = get_account::<FungibleChildEntanglerV1>(client, child_entangler)?;
+  let rate_schedule =
+    get_account_if_exists::<RateScheduleV0>(client, &pda::rate_schedule(child_entangler).0)?;
+  if let Some(rate_schedule) = rate_schedule {
+    let now = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    if let Some(rate) = rate_schedule.active_rate(now) {
+      child.rate = rate;
+    }
+  }
+  let output_storage = match direction {
+    SwapDirection::ParentToChild => child.child_storage,
+    SwapDirection::ChildToParent => {
+      get_account::<FungibleParentEntanglerV1>(client, &child.parent_entangler)?.parent_storage
+    }
+  };
+
+  Ok(LiveQuote {
+    quote: quote(&child, direction, amount).ok_or(SdkError::QuoteOverflow)?,
+    output_storage_amount: get_token_balance(client, &output_storage)?,
+  })
+}
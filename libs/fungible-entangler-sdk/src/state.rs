@@ -0,0 +1,611 @@
+//! Mirrors of the program's account layouts. Fields are in program order since borsh decodes
+//! positionally.
+
+use crate::{discriminator, SdkError};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{keccak, pubkey::Pubkey};
+
+pub const ENTANGLER_RESERVED_WORDS: usize = 16;
+pub const CHILD_ENTANGLER_RESERVED_WORDS: usize = ENTANGLER_RESERVED_WORDS - 4;
+pub const PARENT_ENTANGLER_RESERVED_WORDS: usize = ENTANGLER_RESERVED_WORDS - 1;
+
+/// An account owned by the entangler program, prefixed with its Anchor discriminator
+pub trait ProgramAccount: BorshDeserialize {
+  /// The account's struct name in the program
+  const NAME: &'static str;
+
+  fn discriminator() -> [u8; discriminator::DISCRIMINATOR_LEN] {
+    discriminator::account(Self::NAME)
+  }
+
+  /// Decodes account data, checking the discriminator. Trailing bytes such as reserved space are
+  /// ignored
+  fn try_from_account_data(data: &[u8]) -> Result<Self, SdkError> {
+    if data.len() < discriminator::DISCRIMINATOR_LEN {
+      return Err(SdkError::AccountTooSmall);
+    }
+    let (prefix, mut rest) = data.split_at(discriminator::DISCRIMINATOR_LEN);
+    if prefix != Self::discriminator() {
+      return Err(SdkError::InvalidDiscriminator(Self::NAME));
+    }
+
+    Ok(Self::deserialize(&mut rest)?)
+  }
+}
+
+macro_rules! program_account {
+  ($name:ident) => {
+    impl ProgramAccount for $name {
+      const NAME: &'static str = stringify!($name);
+    }
+  };
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct GlobalConfigV0 {
+  pub admin: Pubkey,
+  pub fee_destination: Pubkey,
+  pub creation_fee_lamports: u64,
+  pub bump_seed: u8,
+  pub paused: bool,
+}
+program_account!(GlobalConfigV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct FungibleParentEntanglerV1 {
+  pub version: u16,
+  pub parent_mint: Pubkey,
+  pub parent_storage: Pubkey,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub created_at_unix_time: i64,
+  pub num_children: u32,
+  pub next_child_index: u32,
+  pub authority: Option<Pubkey>,
+  pub name: String,
+  pub uri: String,
+  pub dynamic_seed: Vec<u8>,
+  pub dynamic_seed_hash: [u8; 32],
+  pub rent_refund: Pubkey,
+  pub legacy_seeds: bool,
+  pub default_rate: ExchangeRateV0,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+  pub authority_nft_mint: Option<Pubkey>,
+  pub namespace: Option<Pubkey>,
+  pub withdrawals_frozen_until: Option<i64>,
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+  pub reserved_parent_fee_amount: u64,
+  pub reserved: [u64; PARENT_ENTANGLER_RESERVED_WORDS],
+}
+program_account!(FungibleParentEntanglerV1);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct FungibleChildEntanglerV1 {
+  pub version: u16,
+  pub parent_entangler: Pubkey,
+  pub child_mint: Pubkey,
+  pub child_storage: Pubkey,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub created_at_unix_time: i64,
+  pub index: u32,
+  pub vesting: Option<VestingConfigV0>,
+  pub refund_window_seconds: Option<i64>,
+  pub expiry_unix_time: Option<i64>,
+  pub total_top_off_amount: u64,
+  pub reclaim_snapshot_amount: Option<u64>,
+  pub rewards_pool: Option<Pubkey>,
+  pub min_lock_seconds: Option<i64>,
+  pub burn: Option<BurnConfigV0>,
+  pub rent_refund: Pubkey,
+  pub rate: ExchangeRateV0,
+  pub swap_fee_bps: u16,
+  pub max_parent_to_child_amount: Option<u64>,
+  pub total_parent_to_child_amount: u64,
+  pub frozen: bool,
+  pub cpi_policy: CpiPolicyV0,
+  pub cpi_allowlist: Vec<Pubkey>,
+  pub max_child_to_parent_amount: Option<u64>,
+  pub total_child_to_parent_amount: u64,
+  pub stake_program: Option<Pubkey>,
+  pub liquidity_program: Option<Pubkey>,
+  pub fee_tiers: Vec<FeeTierV0>,
+  pub reverse_rebate_seconds: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+  pub swap_record_tree: Option<Pubkey>,
+  pub compressed_record_count: u64,
+  pub parent_to_child_volume: VolumeStatsV0,
+  pub child_to_parent_volume: VolumeStatsV0,
+  pub commit_reveal_seconds: Option<i64>,
+  pub yield_strategy: Option<YieldStrategyV0>,
+  pub rate_band: Option<RateBandV0>,
+  pub accrued_child_fee_amount: u64,
+  pub accrued_parent_fee_amount: u64,
+  pub fee_distribution: Option<FeeDistributionV0>,
+  pub drawdown_limit: Option<DrawdownLimitV0>,
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+  pub reclaimed_top_off_amount: u64,
+  pub recorded_parent_to_child_amount: u64,
+  pub recorded_child_to_parent_amount: u64,
+  pub queued_swap_count: u64,
+  pub reserved: [u64; CHILD_ENTANGLER_RESERVED_WORDS],
+}
+program_account!(FungibleChildEntanglerV1);
+
+impl FungibleChildEntanglerV1 {
+  /// Fee for swapping amount input tokens, from the largest fee tier amount reaches
+  pub fn fee_bps(&self, amount: u64) -> u16 {
+    self
+      .fee_tiers
+      .iter()
+      .rev()
+      .find(|tier| amount >= tier.min_amount)
+      .map_or(self.swap_fee_bps, |tier| tier.bps)
+  }
+}
+
+pub const VOLUME_HALF_LIFE_SECONDS: i64 = 24 * 60 * 60;
+
+/// Where a child lends its idle storage, and how much principal is out
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct YieldStrategyV0 {
+  pub program: Pubkey,
+  pub keeper: Pubkey,
+  pub min_liquid_bps: u16,
+  pub deposited_amount: u64,
+}
+
+/// Rolling swap volume through one direction of a child. Amounts are in the input token
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct VolumeStatsV0 {
+  pub ema_amount: u64,
+  pub ema_updated_unix_time: i64,
+  pub window_amount: u64,
+  pub window_swap_count: u32,
+  pub window_start_unix_time: i64,
+}
+
+impl VolumeStatsV0 {
+  /// ema_amount decayed to unix_time, exactly as the program decays it on the next swap
+  pub fn decayed_ema_amount(&self, unix_time: i64) -> u64 {
+    let elapsed = unix_time.saturating_sub(self.ema_updated_unix_time).max(0);
+    let halvings = elapsed / VOLUME_HALF_LIFE_SECONDS;
+    if halvings >= 64 {
+      return 0;
+    }
+    let shifted = self.ema_amount >> halvings;
+    let remainder = (elapsed % VOLUME_HALF_LIFE_SECONDS) as u128;
+    let decay = shifted as u128 * remainder / (2 * VOLUME_HALF_LIFE_SECONDS as u128);
+
+    shifted - decay as u64
+  }
+}
+
+/// Swapping parent_units parent tokens yields child_units child tokens, and the reverse
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ExchangeRateV0 {
+  pub parent_units: u64,
+  pub child_units: u64,
+}
+
+impl Default for ExchangeRateV0 {
+  fn default() -> Self {
+    ExchangeRateV0 {
+      parent_units: 1,
+      child_units: 1,
+    }
+  }
+}
+
+/// Acceptable rates for a child's rate schedule in child tokens per parent token, with the rate
+/// swaps use outside of them
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RateBandV0 {
+  pub min_rate: ExchangeRateV0,
+  pub max_rate: ExchangeRateV0,
+  pub fallback_rate: Option<ExchangeRateV0>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum CpiPolicyV0 {
+  Any,
+  Deny,
+  Allowlist,
+}
+
+impl Default for CpiPolicyV0 {
+  fn default() -> Self {
+    CpiPolicyV0::Any
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum BurnSideV0 {
+  Input,
+  Output,
+}
+
+impl Default for BurnSideV0 {
+  fn default() -> Self {
+    BurnSideV0::Output
+  }
+}
+
+/// Caps on what swaps pay out of each storage per window, and what they paid this window
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct DrawdownLimitV0 {
+  pub window_seconds: i64,
+  pub max_child_amount: u64,
+  pub max_parent_amount: u64,
+  pub window_start_unix_time: i64,
+  pub child_amount: u64,
+  pub parent_amount: u64,
+}
+
+/// Fees a child accrued and the swap totals they are shared out by, as of DistributeFeesV0
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FeeDistributionV0 {
+  pub child_fee_amount: u64,
+  pub parent_fee_amount: u64,
+  pub parent_to_child_amount: u64,
+  pub child_to_parent_amount: u64,
+  pub claimed_child_fee_amount: u64,
+  pub claimed_parent_fee_amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FeeTierV0 {
+  pub min_amount: u64,
+  pub bps: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct BurnConfigV0 {
+  pub bps: u16,
+  pub side: BurnSideV0,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct VestingConfigV0 {
+  pub start_unix_time: i64,
+  pub end_unix_time: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct VestingV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub total_amount: u64,
+  pub claimed_amount: u64,
+  pub bump_seed: u8,
+}
+program_account!(VestingV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SwapRecordV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub parent_to_child_amount: u64,
+  pub child_to_parent_amount: u64,
+  pub last_parent_to_child_unix_time: i64,
+  pub refundable_parent_amount: u64,
+  pub refundable_child_amount: u64,
+  pub rebatable_fee_amount: u64,
+  pub bump_seed: u8,
+}
+program_account!(SwapRecordV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct TopOffRecordV0 {
+  pub child_entangler: Pubkey,
+  pub depositor: Pubkey,
+  pub amount: u64,
+  pub reclaimed: bool,
+  pub bump_seed: u8,
+  pub reward_per_token_paid: u128,
+  pub rewards_owed: u64,
+}
+program_account!(TopOffRecordV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct OfferV0 {
+  pub child_entangler: Pubkey,
+  pub maker: Pubkey,
+  pub taker: Pubkey,
+  pub offer_mint: Pubkey,
+  pub offer_amount: u64,
+  pub ask_mint: Pubkey,
+  pub ask_amount: u64,
+  pub escrow: Pubkey,
+  pub expiry_unix_time: Option<i64>,
+  pub nonce: u64,
+  pub bump_seed: u8,
+  pub escrow_bump_seed: u8,
+}
+program_account!(OfferV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RateScheduleEntryV0 {
+  pub effective_unix_time: i64,
+  pub rate: ExchangeRateV0,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct RateScheduleV0 {
+  pub child_entangler: Pubkey,
+  pub entries: Vec<RateScheduleEntryV0>,
+  pub bump_seed: u8,
+}
+program_account!(RateScheduleV0);
+
+impl RateScheduleV0 {
+  /// The rate swaps use at unix_time, None before the first entry takes effect
+  pub fn active_rate(&self, unix_time: i64) -> Option<ExchangeRateV0> {
+    self
+      .entries
+      .iter()
+      .rev()
+      .find(|entry| unix_time >= entry.effective_unix_time)
+      .map(|entry| entry.rate)
+  }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SnapshotEntryV0 {
+  pub epoch: u64,
+  pub unix_time: i64,
+  pub parent_storage_balance: u64,
+  pub child_storage_balance: u64,
+  pub total_parent_to_child_amount: u64,
+  pub total_child_to_parent_amount: u64,
+}
+
+/// Up to 64 most recent epoch checkpoints of a child, oldest first
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SnapshotHistoryV0 {
+  pub child_entangler: Pubkey,
+  pub entries: Vec<SnapshotEntryV0>,
+  pub bump_seed: u8,
+}
+program_account!(SnapshotHistoryV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SwapQueueV0 {
+  pub child_entangler: Pubkey,
+  pub next_ticket: u64,
+  pub next_execute: u64,
+  pub bump_seed: u8,
+}
+program_account!(SwapQueueV0);
+
+/// A swap escrowed before go live. amount is zero once cancelled
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct QueuedSwapV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub ticket: u64,
+  pub parent_to_child: bool,
+  pub amount: u64,
+  pub escrow: Pubkey,
+  pub destination: Pubkey,
+  pub refund_account: Pubkey,
+  pub payer: Pubkey,
+  pub bump_seed: u8,
+  pub escrow_bump_seed: u8,
+}
+program_account!(QueuedSwapV0);
+
+/// Approval of a lending program for yield strategies
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct StrategyProgramV0 {
+  pub program: Pubkey,
+  pub bump_seed: u8,
+}
+program_account!(StrategyProgramV0);
+
+/// Deferred delivery of a child through settler
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct PendingSettlementV0 {
+  pub child_entangler: Pubkey,
+  pub settler: Pubkey,
+  pub timeout_seconds: i64,
+  pub next_ticket: u64,
+  pub bump_seed: u8,
+}
+program_account!(PendingSettlementV0);
+
+/// A swap escrowed until the settler acknowledges it, or it times out
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SettlementEntryV0 {
+  pub pending_settlement: Pubkey,
+  pub owner: Pubkey,
+  pub ticket: u64,
+  pub parent_to_child: bool,
+  pub amount: u64,
+  pub reference: [u8; 32],
+  pub escrow: Pubkey,
+  pub refund_account: Pubkey,
+  pub payer: Pubkey,
+  pub created_at_unix_time: i64,
+  pub bump_seed: u8,
+  pub escrow_bump_seed: u8,
+}
+program_account!(SettlementEntryV0);
+
+/// Native multisig control of a parent, exercised through InvokeAsAuthorityConfigV0
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct AuthorityConfigV0 {
+  pub parent_entangler: Pubkey,
+  pub signers: Vec<Pubkey>,
+  pub threshold: u8,
+  pub bump_seed: u8,
+}
+program_account!(AuthorityConfigV0);
+
+/// Marks that a swap record's owner claimed its fee rebate
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct FeeRebateClaimV0 {
+  pub swap_record: Pubkey,
+  pub bump_seed: u8,
+}
+program_account!(FeeRebateClaimV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct SwapCommitV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub commitment: [u8; 32],
+  pub committed_at_unix_time: i64,
+  pub payer: Pubkey,
+  pub bump_seed: u8,
+}
+program_account!(SwapCommitV0);
+
+impl SwapCommitV0 {
+  /// The hash CommitSwapV0 stores and RevealAndSwapV0 checks the revealed swap against
+  pub fn commitment(
+    child_entangler: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    parent_to_child: bool,
+    salt: &[u8; 32],
+  ) -> [u8; 32] {
+    keccak::hashv(&[
+      child_entangler.as_ref(),
+      owner.as_ref(),
+      &amount.to_le_bytes(),
+      &[parent_to_child as u8],
+      salt,
+    ])
+    .to_bytes()
+  }
+}
+
+/// Leaf of a child's swap record tree, stored as the keccak hash of its borsh encoding
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompressedSwapRecordV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub parent_to_child: bool,
+  pub parent_amount: u64,
+  pub child_amount: u64,
+  pub unix_time: i64,
+  pub refunded: bool,
+}
+
+impl CompressedSwapRecordV0 {
+  pub fn leaf_hash(&self) -> [u8; 32] {
+    keccak::hash(&self.try_to_vec().unwrap()).to_bytes()
+  }
+}
+
+/// Return data of GetVersionV0, not an account
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct VersionV0 {
+  pub program_version: String,
+  pub git_commit: String,
+  pub rustc_version: String,
+  pub entangler_version: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct EntanglerLookupV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+  pub bump_seed: u8,
+}
+program_account!(EntanglerLookupV0);
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct NamespaceLookupV0 {
+  pub namespace: Pubkey,
+  pub parent_entangler: Pubkey,
+  pub parent_mint: Pubkey,
+  pub bump_seed: u8,
+}
+program_account!(NamespaceLookupV0);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_decode_ignores_trailing_bytes() {
+    let lookup = EntanglerLookupV0 {
+      parent_entangler: Pubkey::new_unique(),
+      child_entangler: Pubkey::new_unique(),
+      bump_seed: 254,
+    };
+    let mut data = EntanglerLookupV0::discriminator().to_vec();
+    lookup.serialize(&mut data).unwrap();
+    data.extend_from_slice(&[0; 64]);
+
+    assert_eq!(
+      EntanglerLookupV0::try_from_account_data(&data).unwrap(),
+      lookup
+    );
+  }
+
+  #[test]
+  fn test_decode_rejects_other_accounts() {
+    let mut data = SwapRecordV0::discriminator().to_vec();
+    SwapRecordV0::default().serialize(&mut data).unwrap();
+
+    assert!(matches!(
+      EntanglerLookupV0::try_from_account_data(&data),
+      Err(SdkError::InvalidDiscriminator("EntanglerLookupV0"))
+    ));
+    assert!(matches!(
+      EntanglerLookupV0::try_from_account_data(&data[..4]),
+      Err(SdkError::AccountTooSmall)
+    ));
+  }
+
+  #[test]
+  fn test_active_rate_picks_latest_effective_entry() {
+    let entry = |effective_unix_time, child_units| RateScheduleEntryV0 {
+      effective_unix_time,
+      rate: ExchangeRateV0 {
+        parent_units: 1,
+        child_units,
+      },
+    };
+    let schedule = RateScheduleV0 {
+      entries: vec![entry(100, 10), entry(200, 5)],
+      ..Default::default()
+    };
+
+    assert_eq!(schedule.active_rate(99), None);
+    assert_eq!(schedule.active_rate(100), Some(entry(100, 10).rate));
+    assert_eq!(schedule.active_rate(199), Some(entry(100, 10).rate));
+    assert_eq!(schedule.active_rate(500), Some(entry(200, 5).rate));
+  }
+
+  #[test]
+  fn test_decayed_ema_amount_halves_every_half_life() {
+    let stats = VolumeStatsV0 {
+      ema_amount: 1_000_000,
+      ema_updated_unix_time: 1000,
+      ..Default::default()
+    };
+
+    assert_eq!(stats.decayed_ema_amount(1000), 1_000_000);
+    assert_eq!(stats.decayed_ema_amount(0), 1_000_000);
+    assert_eq!(
+      stats.decayed_ema_amount(1000 + VOLUME_HALF_LIFE_SECONDS / 2),
+      750_000
+    );
+    assert_eq!(
+      stats.decayed_ema_amount(1000 + VOLUME_HALF_LIFE_SECONDS),
+      500_000
+    );
+    assert_eq!(
+      stats.decayed_ema_amount(1000 + 64 * VOLUME_HALF_LIFE_SECONDS),
+      0
+    );
+  }
+}
@@ -0,0 +1,543 @@
+//! State written straight into the bank. Natively run programs can't resize accounts through CPI,
+//! so nothing the program creates with init can be set up by instruction outside of `test-bpf`.
+
+use crate::{pda, EntanglerKeys};
+use anchor_lang::{
+  prelude::{Clock, Pubkey},
+  AccountDeserialize, AccountSerialize, Discriminator,
+};
+use fungible_entangler::{
+  instructions::{
+    initialize_child_registry_v0::CHILD_REGISTRY_SIZE,
+    initialize_fungible_child_entangler_v0::{
+      CHILD_ENTANGLER_SIZE, ENTANGLER_LOOKUP_SIZE, PENDING_SETTLEMENT_SIZE, QUEUED_SWAP_SIZE,
+      SETTLEMENT_ENTRY_SIZE, SWAP_COMMIT_SIZE, SWAP_QUEUE_SIZE, SWAP_RECORD_SIZE,
+      TOP_OFF_RECORD_SIZE,
+    },
+    initialize_fungible_parent_entangler_v0::{initialize_parent_entangler, PARENT_ENTANGLER_SIZE},
+    InitializeFungibleParentEntanglerV0Args,
+  },
+  state::*,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+  account::{Account, AccountSharedData},
+  program_option::COption,
+  program_pack::Pack,
+  rent::Rent,
+  signature::Signer,
+  transport::TransportError,
+};
+use std::collections::BTreeMap;
+
+/// Writes account as a rent exempt account of the entangler program, zero padded to size
+pub fn set_program_account<T: AccountSerialize>(
+  context: &mut ProgramTestContext,
+  address: &Pubkey,
+  account: &T,
+  size: usize,
+) {
+  let mut data = vec![];
+  account.try_serialize(&mut data).unwrap();
+  data.resize(size, 0);
+
+  context.set_account(
+    address,
+    &AccountSharedData::from(Account {
+      lamports: Rent::default().minimum_balance(size),
+      data,
+      owner: fungible_entangler::id(),
+      executable: false,
+      rent_epoch: 0,
+    }),
+  );
+}
+
+pub async fn get_program_account<T: AccountDeserialize>(
+  context: &mut ProgramTestContext,
+  address: &Pubkey,
+) -> Result<T, TransportError> {
+  let account = context
+    .banks_client
+    .get_account(*address)
+    .await?
+    .expect("account does not exist");
+
+  Ok(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+}
+
+/// Writes an empty token account of mint owned by owner
+pub fn set_token_account(
+  context: &mut ProgramTestContext,
+  address: &Pubkey,
+  mint: &Pubkey,
+  owner: &Pubkey,
+) {
+  let mut data = vec![0; spl_token::state::Account::LEN];
+  spl_token::state::Account {
+    mint: *mint,
+    owner: *owner,
+    state: spl_token::state::AccountState::Initialized,
+    delegate: COption::None,
+    close_authority: COption::None,
+    ..Default::default()
+  }
+  .pack_into_slice(&mut data);
+
+  context.set_account(
+    address,
+    &AccountSharedData::from(Account {
+      lamports: Rent::default().minimum_balance(data.len()),
+      data,
+      owner: spl_token::id(),
+      executable: false,
+      rent_epoch: 0,
+    }),
+  );
+}
+
+fn bump_seed(address: &Pubkey, seeds: &[&[u8]]) -> u8 {
+  let (found, bump_seed) = Pubkey::find_program_address(seeds, &fungible_entangler::id());
+  assert_eq!(found, *address);
+  bump_seed
+}
+
+/// Clock time just before now. Swaps require go live to be strictly in the past
+async fn live_clock(context: &mut ProgramTestContext) -> Result<Clock, TransportError> {
+  let mut clock: Clock = context.banks_client.get_sysvar().await?;
+  clock.unix_timestamp -= 1;
+
+  Ok(clock)
+}
+
+/// A live child of keys' parent at index, as InitializeFungibleChildEntanglerV0 would with default
+/// args
+fn new_child_entangler(
+  keys: &EntanglerKeys,
+  rent_refund: Pubkey,
+  clock: &Clock,
+  index: u32,
+) -> FungibleChildEntanglerV1 {
+  FungibleChildEntanglerV1 {
+    version: ENTANGLER_VERSION,
+    parent_entangler: keys.parent_entangler,
+    child_mint: keys.child_mint,
+    child_storage: keys.child_storage(),
+    go_live_unix_time: clock.unix_timestamp,
+    created_at_unix_time: clock.unix_timestamp,
+    index,
+    rent_refund,
+    rate: ExchangeRateV0::default(),
+    bump_seed: bump_seed(
+      &keys.child_entangler,
+      &[
+        b"entangler",
+        keys.parent_entangler.as_ref(),
+        keys.child_mint.as_ref(),
+      ],
+    ),
+    storage_bump_seed: bump_seed(
+      &keys.child_storage(),
+      &[b"storage", keys.child_entangler.as_ref()],
+    ),
+    ..Default::default()
+  }
+}
+
+fn set_child_entangler(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  child: &FungibleChildEntanglerV1,
+) {
+  set_program_account(context, &keys.child_entangler, child, CHILD_ENTANGLER_SIZE);
+  set_token_account(
+    context,
+    &keys.child_storage(),
+    &keys.child_mint,
+    &keys.child_entangler,
+  );
+}
+
+/// Writes a live parent and child entangler with empty storage, as the initialize instructions
+/// would with default args and the context payer as authority. configure adjusts both before they
+/// are written
+pub async fn add_entangler(
+  context: &mut ProgramTestContext,
+  parent_mint: &Pubkey,
+  child_mint: &Pubkey,
+  dynamic_seed: &[u8],
+  configure: impl FnOnce(&mut FungibleParentEntanglerV1, &mut FungibleChildEntanglerV1),
+) -> Result<EntanglerKeys, TransportError> {
+  let keys = EntanglerKeys::new(parent_mint, child_mint, dynamic_seed);
+  let payer = context.payer.pubkey();
+  let clock = live_clock(context).await?;
+
+  let mut parent = FungibleParentEntanglerV1::default();
+  let bumps = BTreeMap::from([
+    (
+      "entangler".to_string(),
+      bump_seed(
+        &keys.parent_entangler,
+        &[
+          b"entangler",
+          parent_mint.as_ref(),
+          &fungible_entangler::util::hash_seed(dynamic_seed),
+        ],
+      ),
+    ),
+    (
+      "parent_storage".to_string(),
+      bump_seed(
+        &keys.parent_storage(),
+        &[b"storage", keys.parent_entangler.as_ref()],
+      ),
+    ),
+  ]);
+  initialize_parent_entangler(
+    &mut parent,
+    *parent_mint,
+    keys.parent_storage(),
+    payer,
+    &bumps,
+    &clock,
+    InitializeFungibleParentEntanglerV0Args {
+      authority: Some(payer),
+      dynamic_seed: dynamic_seed.to_vec(),
+      ..Default::default()
+    },
+  )
+  .unwrap();
+  parent.num_children = 1;
+  parent.next_child_index = 1;
+  let mut child = new_child_entangler(&keys, payer, &clock, 0);
+  configure(&mut parent, &mut child);
+
+  set_program_account(
+    context,
+    &keys.parent_entangler,
+    &parent,
+    PARENT_ENTANGLER_SIZE,
+  );
+  set_token_account(
+    context,
+    &keys.parent_storage(),
+    parent_mint,
+    &keys.parent_entangler,
+  );
+  set_child_entangler(context, &keys, &child);
+
+  Ok(keys)
+}
+
+/// Writes another live child with empty storage under the parent of keys, which add_entangler
+/// wrote. configure adjusts the child before it is written
+pub async fn add_child_entangler(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  child_mint: &Pubkey,
+  configure: impl FnOnce(&mut FungibleChildEntanglerV1),
+) -> Result<EntanglerKeys, TransportError> {
+  let child_keys = EntanglerKeys {
+    child_mint: *child_mint,
+    child_entangler: pda::child_entangler(&keys.parent_entangler, child_mint),
+    ..*keys
+  };
+  let payer = context.payer.pubkey();
+  let clock = live_clock(context).await?;
+
+  let mut parent: FungibleParentEntanglerV1 =
+    get_program_account(context, &keys.parent_entangler).await?;
+  let mut child = new_child_entangler(&child_keys, payer, &clock, parent.next_child_index);
+  child.rate = parent.default_rate;
+  configure(&mut child);
+  parent.num_children += 1;
+  parent.next_child_index += 1;
+
+  set_program_account(
+    context,
+    &keys.parent_entangler,
+    &parent,
+    PARENT_ENTANGLER_SIZE,
+  );
+  set_child_entangler(context, &child_keys, &child);
+
+  Ok(child_keys)
+}
+
+/// Writes the lookup of keys' mint pair held by keys' child, as the first child initialized for the
+/// pair would
+pub fn add_lookup(context: &mut ProgramTestContext, keys: &EntanglerKeys) -> Pubkey {
+  let lookup = pda::lookup(&keys.parent_mint, &keys.child_mint);
+  let bump_seed = bump_seed(
+    &lookup,
+    &[
+      b"lookup",
+      keys.parent_mint.as_ref(),
+      keys.child_mint.as_ref(),
+    ],
+  );
+
+  set_program_account(
+    context,
+    &lookup,
+    &EntanglerLookupV0 {
+      parent_entangler: keys.parent_entangler,
+      child_entangler: keys.child_entangler,
+      bump_seed,
+    },
+    ENTANGLER_LOOKUP_SIZE,
+  );
+
+  lookup
+}
+
+/// Writes registry page 0 of the parent of keys, listing children with their basket weights
+pub fn add_child_registry(
+  context: &mut ProgramTestContext,
+  parent_entangler: &Pubkey,
+  children: &[(Pubkey, u16)],
+) -> Pubkey {
+  let registry = pda::child_registry(parent_entangler, 0);
+  let mut account = ChildRegistryV0 {
+    parent_entangler: *parent_entangler,
+    page: 0,
+    num_entries: children.len() as u16,
+    bump_seed: bump_seed(
+      &registry,
+      &[b"registry", parent_entangler.as_ref(), &0u16.to_le_bytes()],
+    ),
+    _padding: [0; 3],
+    children: [Pubkey::default(); CHILD_REGISTRY_PAGE_SIZE],
+    weights_bps: [0; CHILD_REGISTRY_PAGE_SIZE],
+  };
+  for (index, (child, weight)) in children.iter().enumerate() {
+    account.children[index] = *child;
+    account.weights_bps[index] = *weight;
+  }
+
+  let mut data = ChildRegistryV0::discriminator().to_vec();
+  data.extend_from_slice(bytemuck::bytes_of(&account));
+  data.resize(CHILD_REGISTRY_SIZE, 0);
+  context.set_account(
+    &registry,
+    &AccountSharedData::from(Account {
+      lamports: Rent::default().minimum_balance(data.len()),
+      data,
+      owner: fungible_entangler::id(),
+      executable: false,
+      rent_epoch: 0,
+    }),
+  );
+
+  registry
+}
+
+/// Writes an empty SwapRecordV0 for owner, as InitializeSwapRecordV0 would
+pub fn add_swap_record(context: &mut ProgramTestContext, keys: &EntanglerKeys, owner: &Pubkey) {
+  let swap_record = pda::swap_record(&keys.child_entangler, owner);
+  let bump_seed = bump_seed(
+    &swap_record,
+    &[
+      b"swap-record",
+      keys.child_entangler.as_ref(),
+      owner.as_ref(),
+    ],
+  );
+
+  set_program_account(
+    context,
+    &swap_record,
+    &SwapRecordV0 {
+      child_entangler: keys.child_entangler,
+      owner: *owner,
+      bump_seed,
+      ..Default::default()
+    },
+    SWAP_RECORD_SIZE,
+  );
+}
+
+/// Writes depositor's record of amount topped off into the child of keys, as TopOffChildStorageV0
+/// would. The child's total_top_off_amount is left to the caller
+pub fn add_top_off_record(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  depositor: &Pubkey,
+  amount: u64,
+) {
+  let top_off_record = pda::top_off_record(&keys.child_entangler, depositor);
+  let bump_seed = bump_seed(
+    &top_off_record,
+    &[
+      b"top-off",
+      keys.child_entangler.as_ref(),
+      depositor.as_ref(),
+    ],
+  );
+
+  set_program_account(
+    context,
+    &top_off_record,
+    &TopOffRecordV0 {
+      child_entangler: keys.child_entangler,
+      depositor: *depositor,
+      amount,
+      bump_seed,
+      ..Default::default()
+    },
+    TOP_OFF_RECORD_SIZE,
+  );
+}
+
+/// Writes owner's commitment to a swap paid for by payer, as CommitSwapV0 would have before the
+/// child went live
+pub fn add_swap_commit(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  owner: &Pubkey,
+  payer: &Pubkey,
+  commitment: [u8; 32],
+) {
+  let swap_commit = pda::swap_commit(&keys.child_entangler, owner);
+  let bump_seed = bump_seed(
+    &swap_commit,
+    &[
+      b"swap-commit",
+      keys.child_entangler.as_ref(),
+      owner.as_ref(),
+    ],
+  );
+
+  set_program_account(
+    context,
+    &swap_commit,
+    &SwapCommitV0 {
+      child_entangler: keys.child_entangler,
+      owner: *owner,
+      commitment,
+      committed_at_unix_time: 0,
+      payer: *payer,
+      bump_seed,
+    },
+    SWAP_COMMIT_SIZE,
+  );
+}
+
+/// Writes the child's SwapQueueV0 with tickets issued up to next_ticket and none executed, as
+/// QueueSwapV0 would. The child's queued_swap_count is left to the caller
+pub fn add_swap_queue(context: &mut ProgramTestContext, keys: &EntanglerKeys, next_ticket: u64) {
+  let swap_queue = pda::swap_queue(&keys.child_entangler);
+  let bump_seed = bump_seed(&swap_queue, &[b"swap-queue", keys.child_entangler.as_ref()]);
+
+  set_program_account(
+    context,
+    &swap_queue,
+    &SwapQueueV0 {
+      child_entangler: keys.child_entangler,
+      next_ticket,
+      next_execute: 0,
+      bump_seed,
+    },
+    SWAP_QUEUE_SIZE,
+  );
+}
+
+/// Writes queued_swap's ticket of the child of keys with an empty escrow of its input mint, as
+/// QueueSwapV0 would. Funding the escrow is left to the caller
+pub fn add_queued_swap(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  queued_swap: QueuedSwapV0,
+) -> QueuedSwapV0 {
+  let address = pda::queued_swap(&keys.child_entangler, queued_swap.ticket);
+  let escrow = pda::queued_swap_escrow(&address);
+  let queued_swap = QueuedSwapV0 {
+    child_entangler: keys.child_entangler,
+    escrow,
+    bump_seed: bump_seed(
+      &address,
+      &[
+        b"queued-swap",
+        keys.child_entangler.as_ref(),
+        &queued_swap.ticket.to_le_bytes(),
+      ],
+    ),
+    escrow_bump_seed: bump_seed(&escrow, &[b"queued-swap-escrow", address.as_ref()]),
+    ..queued_swap
+  };
+
+  set_program_account(context, &address, &queued_swap, QUEUED_SWAP_SIZE);
+  let input_mint = if queued_swap.parent_to_child {
+    keys.parent_mint
+  } else {
+    keys.child_mint
+  };
+  set_token_account(context, &escrow, &input_mint, &keys.child_entangler);
+
+  queued_swap
+}
+
+/// Writes the child's PendingSettlementV0 with tickets issued up to next_ticket, as
+/// SetPendingSettlementV0 and DeferSwapV0 would
+pub fn add_pending_settlement(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  settler: &Pubkey,
+  timeout_seconds: i64,
+  next_ticket: u64,
+) {
+  let pending_settlement = pda::pending_settlement(&keys.child_entangler);
+  let bump_seed = bump_seed(
+    &pending_settlement,
+    &[b"pending-settlement", keys.child_entangler.as_ref()],
+  );
+
+  set_program_account(
+    context,
+    &pending_settlement,
+    &PendingSettlementV0 {
+      child_entangler: keys.child_entangler,
+      settler: *settler,
+      timeout_seconds,
+      next_ticket,
+      bump_seed,
+    },
+    PENDING_SETTLEMENT_SIZE,
+  );
+}
+
+/// Writes settlement_entry's ticket of the child of keys with an empty escrow of its input mint,
+/// as DeferSwapV0 would. Funding the escrow is left to the caller
+pub fn add_settlement_entry(
+  context: &mut ProgramTestContext,
+  keys: &EntanglerKeys,
+  settlement_entry: SettlementEntryV0,
+) -> SettlementEntryV0 {
+  let pending_settlement = pda::pending_settlement(&keys.child_entangler);
+  let address = pda::settlement_entry(&pending_settlement, settlement_entry.ticket);
+  let escrow = pda::settlement_escrow(&address);
+  let settlement_entry = SettlementEntryV0 {
+    pending_settlement,
+    escrow,
+    bump_seed: bump_seed(
+      &address,
+      &[
+        b"settlement-entry",
+        pending_settlement.as_ref(),
+        &settlement_entry.ticket.to_le_bytes(),
+      ],
+    ),
+    escrow_bump_seed: bump_seed(&escrow, &[b"settlement-escrow", address.as_ref()]),
+    ..settlement_entry
+  };
+
+  set_program_account(context, &address, &settlement_entry, SETTLEMENT_ENTRY_SIZE);
+  let input_mint = if settlement_entry.parent_to_child {
+    keys.parent_mint
+  } else {
+    keys.child_mint
+  };
+  set_token_account(context, &escrow, &input_mint, &keys.child_entangler);
+
+  settlement_entry
+}
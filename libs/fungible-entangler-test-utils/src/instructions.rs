@@ -0,0 +1,554 @@
+use crate::{pda, EntanglerKeys};
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
+use fungible_entangler::{
+  account_compression::noop,
+  accounts, instruction,
+  instructions::{
+    CommitSwapV0Args, InitializeFungibleChildEntanglerV0Args,
+    InitializeFungibleParentEntanglerV0Args, RebalanceBetweenEntanglersV0Args,
+    RebalanceStrategyV0Args, RefundSwapV0Args, RevealAndSwapV0Args, SwapParentForBasketV0Args,
+    SwapV0Args, TransferChildStorageArgsV0,
+  },
+  state::{QueuedSwapV0, SettlementEntryV0},
+};
+use solana_sdk::{system_program, sysvar};
+
+pub fn initialize_parent_entangler(
+  payer: &Pubkey,
+  parent_mint: &Pubkey,
+  fee_destination: &Pubkey,
+  args: InitializeFungibleParentEntanglerV0Args,
+) -> Instruction {
+  let entangler = pda::parent_entangler(parent_mint, &args.dynamic_seed);
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::InitializeFungibleParentEntanglerV0 {
+      payer: *payer,
+      entangler,
+      parent_storage: pda::storage(&entangler),
+      parent_mint: *parent_mint,
+      global_config: pda::global_config(),
+      fee_destination: *fee_destination,
+      token_program: spl_token::id(),
+      system_program: system_program::id(),
+      noop_program: noop::ID,
+    }
+    .to_account_metas(None),
+    data: instruction::InitializeFungibleParentEntanglerV0 { args }.data(),
+  }
+}
+
+pub fn initialize_child_entangler(
+  payer: &Pubkey,
+  authority: &Pubkey,
+  keys: &EntanglerKeys,
+  fee_destination: &Pubkey,
+  args: InitializeFungibleChildEntanglerV0Args,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::InitializeFungibleChildEntanglerV0 {
+      payer: *payer,
+      authority: *authority,
+      parent_entangler: keys.parent_entangler,
+      entangler: keys.child_entangler,
+      child_storage: pda::storage(&keys.child_entangler),
+      lookup: pda::lookup(&keys.parent_mint, &keys.child_mint),
+      child_mint: keys.child_mint,
+      global_config: pda::global_config(),
+      fee_destination: *fee_destination,
+      token_program: spl_token::id(),
+      system_program: system_program::id(),
+      noop_program: noop::ID,
+    }
+    .to_account_metas(None),
+    data: instruction::InitializeFungibleChildEntanglerV0 { args }.data(),
+  }
+}
+
+pub fn initialize_swap_record(payer: &Pubkey, keys: &EntanglerKeys, owner: &Pubkey) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::InitializeSwapRecordV0 {
+      payer: *payer,
+      child_entangler: keys.child_entangler,
+      owner: *owner,
+      swap_record: pda::swap_record(&keys.child_entangler, owner),
+      system_program: system_program::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::InitializeSwapRecordV0 {}.data(),
+  }
+}
+
+/// The SwapCommonV0 accounts. Anchor does not export client structs for nested account groups,
+/// so these follow the field order of SwapCommonV0 by hand
+fn swap_accounts(
+  payer: &Pubkey,
+  keys: &EntanglerKeys,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+) -> Vec<AccountMeta> {
+  let mut accounts = vec![
+    AccountMeta::new(*payer, true),
+    AccountMeta::new(keys.parent_entangler, false),
+    AccountMeta::new(pda::storage(&keys.parent_entangler), false),
+    AccountMeta::new(keys.parent_mint, false),
+    AccountMeta::new(keys.child_entangler, false),
+    AccountMeta::new(pda::storage(&keys.child_entangler), false),
+    AccountMeta::new(keys.child_mint, false),
+    AccountMeta::new(*source, false),
+    AccountMeta::new_readonly(*source_authority, true),
+    AccountMeta::new(*destination, false),
+    AccountMeta::new(
+      pda::swap_record(&keys.child_entangler, source_authority),
+      false,
+    ),
+    AccountMeta::new_readonly(pda::global_config(), false),
+    AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    AccountMeta::new_readonly(spl_token::id(), false),
+    AccountMeta::new_readonly(system_program::id(), false),
+    AccountMeta::new_readonly(spl_memo::id(), false),
+    AccountMeta::new_readonly(pda::rate_schedule(&keys.child_entangler), false),
+    AccountMeta::new_readonly(noop::ID, false),
+  ];
+  accounts.extend(
+    split_accounts
+      .iter()
+      .map(|account| AccountMeta::new(*account, false)),
+  );
+
+  accounts
+}
+
+/// Swaps parent tokens from source for child tokens sent to destination. Output split accounts
+/// are appended as remaining accounts
+pub fn swap_parent_for_child(
+  payer: &Pubkey,
+  keys: &EntanglerKeys,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+  args: SwapV0Args,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: swap_accounts(
+      payer,
+      keys,
+      source,
+      source_authority,
+      destination,
+      split_accounts,
+    ),
+    data: instruction::SwapParentForChildV0 { args }.data(),
+  }
+}
+
+/// Swaps child tokens from source for parent tokens sent to destination. Output split accounts
+/// are appended as remaining accounts
+pub fn swap_child_for_parent(
+  payer: &Pubkey,
+  keys: &EntanglerKeys,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  split_accounts: &[Pubkey],
+  args: SwapV0Args,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: swap_accounts(
+      payer,
+      keys,
+      source,
+      source_authority,
+      destination,
+      split_accounts,
+    ),
+    data: instruction::SwapChildForParentV0 { args }.data(),
+  }
+}
+
+/// Closes the child of keys and its empty storage as the parent's authority
+pub fn close_child_entangler(
+  refund_to: &Pubkey,
+  authority: &Pubkey,
+  keys: &EntanglerKeys,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::CloseFungibleChildEntanglerV0 {
+      refund_to: *refund_to,
+      authority: *authority,
+      parent_entangler: keys.parent_entangler,
+      entangler: keys.child_entangler,
+      child_storage: pda::storage(&keys.child_entangler),
+      lookup: pda::lookup(&keys.parent_mint, &keys.child_mint),
+      token_program: spl_token::id(),
+      noop_program: noop::ID,
+    }
+    .to_account_metas(None),
+    data: instruction::CloseFungibleChildEntanglerV0 {}.data(),
+  }
+}
+
+/// Returns child tokens from source for a refund of the parent tokens swapped for them, sent to
+/// destination
+pub fn refund_swap(
+  keys: &EntanglerKeys,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  args: RefundSwapV0Args,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::RefundSwapV0 {
+      parent_entangler: keys.parent_entangler,
+      parent_storage: pda::storage(&keys.parent_entangler),
+      child_entangler: keys.child_entangler,
+      child_storage: pda::storage(&keys.child_entangler),
+      swap_record: pda::swap_record(&keys.child_entangler, source_authority),
+      source: *source,
+      source_authority: *source_authority,
+      destination: *destination,
+      global_config: pda::global_config(),
+      token_program: spl_token::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::RefundSwapV0 { args }.data(),
+  }
+}
+
+/// Pays depositor's share of child storage at expiry to destination
+pub fn reclaim_after_expiry(
+  keys: &EntanglerKeys,
+  depositor: &Pubkey,
+  destination: &Pubkey,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::ReclaimAfterExpiryV0 {
+      child_entangler: keys.child_entangler,
+      child_storage: pda::storage(&keys.child_entangler),
+      top_off_record: pda::top_off_record(&keys.child_entangler, depositor),
+      depositor: *depositor,
+      destination: *destination,
+      token_program: spl_token::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::ReclaimAfterExpiryV0 {}.data(),
+  }
+}
+
+/// Withdraws amount of child storage to destination as the parent's authority
+pub fn transfer_child_storage(
+  authority: &Pubkey,
+  keys: &EntanglerKeys,
+  destination: &Pubkey,
+  amount: u64,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::TransferChildStorageV0 {
+      authority: *authority,
+      parent_entangler: keys.parent_entangler,
+      entangler: keys.child_entangler,
+      child_storage: pda::storage(&keys.child_entangler),
+      destination: *destination,
+      token_program: spl_token::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::TransferChildStorageV0 {
+      args: TransferChildStorageArgsV0 { amount },
+    }
+    .data(),
+  }
+}
+
+/// Moves parent_amount and child_amount of storage from the entangler pair of source to that of
+/// destination, as the authority of both parents
+pub fn rebalance_between_entanglers(
+  authority: &Pubkey,
+  source: &EntanglerKeys,
+  destination: &EntanglerKeys,
+  parent_amount: u64,
+  child_amount: u64,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::RebalanceBetweenEntanglersV0 {
+      authority: *authority,
+      source_parent_entangler: source.parent_entangler,
+      source_parent_storage: source.parent_storage(),
+      source_child_entangler: source.child_entangler,
+      source_child_storage: source.child_storage(),
+      destination_parent_entangler: destination.parent_entangler,
+      destination_parent_storage: destination.parent_storage(),
+      destination_child_entangler: destination.child_entangler,
+      destination_child_storage: destination.child_storage(),
+      token_program: spl_token::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::RebalanceBetweenEntanglersV0 {
+      args: RebalanceBetweenEntanglersV0Args {
+        parent_amount,
+        child_amount,
+      },
+    }
+    .data(),
+  }
+}
+
+/// Moves child storage into or out of the child's yield strategy as keeper. strategy_accounts are
+/// handed to the strategy program as remaining accounts
+pub fn rebalance_strategy(
+  keeper: &Pubkey,
+  keys: &EntanglerKeys,
+  strategy_program: &Pubkey,
+  strategy_accounts: &[AccountMeta],
+  args: RebalanceStrategyV0Args,
+) -> Instruction {
+  let mut accounts = accounts::RebalanceStrategyV0 {
+    keeper: *keeper,
+    parent_entangler: keys.parent_entangler,
+    child_entangler: keys.child_entangler,
+    child_storage: keys.child_storage(),
+    strategy_program: *strategy_program,
+  }
+  .to_account_metas(None);
+  accounts.extend_from_slice(strategy_accounts);
+
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts,
+    data: instruction::RebalanceStrategyV0 { args }.data(),
+  }
+}
+
+/// Snapshots the child's accrued fees for ClaimFeeRebateV0
+pub fn distribute_fees(keys: &EntanglerKeys) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::DistributeFeesV0 {
+      parent_entangler: keys.parent_entangler,
+      child_entangler: keys.child_entangler,
+    }
+    .to_account_metas(None),
+    data: instruction::DistributeFeesV0 {}.data(),
+  }
+}
+
+/// Pays owner's share of the distributed fees to their child and parent token accounts
+pub fn claim_fee_rebate(
+  owner: &Pubkey,
+  keys: &EntanglerKeys,
+  child_destination: &Pubkey,
+  parent_destination: &Pubkey,
+) -> Instruction {
+  let swap_record = pda::swap_record(&keys.child_entangler, owner);
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::ClaimFeeRebateV0 {
+      owner: *owner,
+      parent_entangler: keys.parent_entangler,
+      parent_storage: pda::storage(&keys.parent_entangler),
+      child_entangler: keys.child_entangler,
+      child_storage: pda::storage(&keys.child_entangler),
+      swap_record,
+      fee_rebate_claim: pda::fee_rebate_claim(&swap_record),
+      child_destination: *child_destination,
+      parent_destination: *parent_destination,
+      token_program: spl_token::id(),
+      system_program: system_program::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::ClaimFeeRebateV0 {}.data(),
+  }
+}
+
+/// Splits a parent token swap across the basket of registry. legs holds, per weighted child in
+/// registry order, its keys and the destination of its output
+pub fn swap_parent_for_basket(
+  parent_mint: &Pubkey,
+  parent_entangler: &Pubkey,
+  registry: &Pubkey,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  legs: &[(EntanglerKeys, Pubkey)],
+  args: SwapParentForBasketV0Args,
+) -> Instruction {
+  let mut accounts = accounts::SwapParentForBasketV0 {
+    parent_entangler: *parent_entangler,
+    parent_storage: pda::storage(parent_entangler),
+    parent_mint: *parent_mint,
+    source: *source,
+    source_authority: *source_authority,
+    registry: *registry,
+    global_config: pda::global_config(),
+    instructions: sysvar::instructions::id(),
+    token_program: spl_token::id(),
+    noop_program: noop::ID,
+  }
+  .to_account_metas(None);
+  for (keys, destination) in legs {
+    accounts.extend([
+      AccountMeta::new(keys.child_entangler, false),
+      AccountMeta::new(pda::storage(&keys.child_entangler), false),
+      AccountMeta::new(keys.child_mint, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new_readonly(pda::rate_schedule(&keys.child_entangler), false),
+      AccountMeta::new(
+        pda::swap_record(&keys.child_entangler, source_authority),
+        false,
+      ),
+    ]);
+  }
+
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts,
+    data: instruction::SwapParentForBasketV0 { args }.data(),
+  }
+}
+
+/// Commits owner to a swap through the child before it goes live
+pub fn commit_swap(
+  payer: &Pubkey,
+  owner: &Pubkey,
+  keys: &EntanglerKeys,
+  args: CommitSwapV0Args,
+) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::CommitSwapV0 {
+      payer: *payer,
+      owner: *owner,
+      child_entangler: keys.child_entangler,
+      swap_commit: pda::swap_commit(&keys.child_entangler, owner),
+      system_program: system_program::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::CommitSwapV0 { args }.data(),
+  }
+}
+
+/// Executes source_authority's committed swap, closing the commitment to commit_payer. Source and
+/// destination hold the input and output token of the committed direction
+pub fn reveal_and_swap(
+  payer: &Pubkey,
+  commit_payer: &Pubkey,
+  keys: &EntanglerKeys,
+  source: &Pubkey,
+  source_authority: &Pubkey,
+  destination: &Pubkey,
+  args: RevealAndSwapV0Args,
+) -> Instruction {
+  let mut accounts = swap_accounts(payer, keys, source, source_authority, destination, &[]);
+  accounts.push(AccountMeta::new(
+    pda::swap_commit(&keys.child_entangler, source_authority),
+    false,
+  ));
+  accounts.push(AccountMeta::new(*commit_payer, false));
+
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts,
+    data: instruction::RevealAndSwapV0 { args }.data(),
+  }
+}
+
+/// Settles queued_swap, the next ticket of the queue of the child of keys
+pub fn execute_queued_swap(keys: &EntanglerKeys, queued_swap: &QueuedSwapV0) -> Instruction {
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::ExecuteQueuedSwapV0 {
+      parent_entangler: keys.parent_entangler,
+      parent_storage: keys.parent_storage(),
+      parent_mint: keys.parent_mint,
+      child_entangler: keys.child_entangler,
+      child_storage: keys.child_storage(),
+      child_mint: keys.child_mint,
+      swap_queue: pda::swap_queue(&keys.child_entangler),
+      queued_swap: pda::queued_swap(&keys.child_entangler, queued_swap.ticket),
+      escrow: queued_swap.escrow,
+      destination: queued_swap.destination,
+      refund_account: queued_swap.refund_account,
+      payer: queued_swap.payer,
+      global_config: pda::global_config(),
+      rate_schedule: pda::rate_schedule(&keys.child_entangler),
+      token_program: spl_token::id(),
+      noop_program: noop::ID,
+    }
+    .to_account_metas(None),
+    data: instruction::ExecuteQueuedSwapV0 {}.data(),
+  }
+}
+
+/// Swaps amount_in from source to destination through the token-swap adapter of the child of
+/// keys, parent to child when source holds the parent mint
+pub fn token_swap(
+  authority: &Pubkey,
+  keys: &EntanglerKeys,
+  source: &Pubkey,
+  destination: &Pubkey,
+  parent_to_child: bool,
+  amount_in: u64,
+  minimum_amount_out: u64,
+) -> Instruction {
+  let (swap_source, swap_destination) = if parent_to_child {
+    (keys.parent_storage(), keys.child_storage())
+  } else {
+    (keys.child_storage(), keys.parent_storage())
+  };
+  let mut data = vec![1];
+  data.extend_from_slice(&amount_in.to_le_bytes());
+  data.extend_from_slice(&minimum_amount_out.to_le_bytes());
+
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: vec![
+      AccountMeta::new(keys.child_entangler, false),
+      AccountMeta::new_readonly(keys.parent_entangler, false),
+      AccountMeta::new_readonly(*authority, true),
+      AccountMeta::new(*source, false),
+      AccountMeta::new(swap_source, false),
+      AccountMeta::new(swap_destination, false),
+      AccountMeta::new(*destination, false),
+      AccountMeta::new(keys.parent_mint, false),
+      AccountMeta::new(keys.child_mint, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new_readonly(pda::global_config(), false),
+      AccountMeta::new_readonly(sysvar::instructions::id(), false),
+      AccountMeta::new_readonly(pda::rate_schedule(&keys.child_entangler), false),
+      AccountMeta::new_readonly(noop::ID, false),
+    ],
+    data,
+  }
+}
+
+/// Refunds settlement_entry of the child of keys once it has timed out
+pub fn refund_settlement(
+  keys: &EntanglerKeys,
+  settlement_entry: &SettlementEntryV0,
+) -> Instruction {
+  let pending_settlement = pda::pending_settlement(&keys.child_entangler);
+  Instruction {
+    program_id: fungible_entangler::id(),
+    accounts: accounts::RefundSettlementV0 {
+      child_entangler: keys.child_entangler,
+      pending_settlement,
+      settlement_entry: pda::settlement_entry(&pending_settlement, settlement_entry.ticket),
+      escrow: settlement_entry.escrow,
+      refund_account: settlement_entry.refund_account,
+      payer: settlement_entry.payer,
+      token_program: spl_token::id(),
+    }
+    .to_account_metas(None),
+    data: instruction::RefundSettlementV0 {}.data(),
+  }
+}
@@ -0,0 +1,222 @@
+//! Fixtures for testing against the fungible entangler in solana-program-test. Under `cargo test`
+//! the program, spl_token and spl_memo run natively, which can't create accounts through CPI, so
+//! set up entanglers and records with the writers in [fixtures]. Under `cargo test-bpf` they are
+//! loaded from `fungible_entangler.so`, `spl_token.so` and `spl_memo.so` in `BPF_OUT_DIR`, and
+//! every instruction works. The program creates token accounts with InitializeAccount3, so that
+//! `spl_token.so` must be spl_token 3.3 or newer.
+//!
+//! ```ignore
+//! let mut context = fungible_entangler_test_utils::program_test().start_with_context().await;
+//! let parent_mint = create_mint(&mut context, 9).await?;
+//! let child_mint = create_mint(&mut context, 9).await?;
+//! let keys = add_entangler(&mut context, &parent_mint, &child_mint, b"my-entangler", |_, _| {})
+//!   .await?;
+//! ```
+
+pub mod fixtures;
+pub mod instructions;
+mod native;
+pub mod pda;
+pub mod swap_fixture;
+pub mod token;
+
+pub use fixtures::*;
+pub use swap_fixture::SwapFixture;
+pub use token::*;
+
+use anchor_lang::{
+  prelude::{AccountInfo, Clock, Pubkey},
+  solana_program::{entrypoint::ProgramResult, instruction::Instruction},
+  AccountSerialize,
+};
+use fungible_entangler::{
+  account_compression::noop,
+  instructions::{
+    initialize_global_config_v0::GLOBAL_CONFIG_SIZE, InitializeFungibleChildEntanglerV0Args,
+    InitializeFungibleParentEntanglerV0Args,
+  },
+  state::GlobalConfigV0,
+};
+use solana_program_test::{processor, ProgramTest, ProgramTestBanksClientExt, ProgramTestContext};
+use solana_sdk::{
+  account::Account,
+  signature::{Keypair, Signer},
+  transaction::Transaction,
+  transport::TransportError,
+};
+
+/// Receives creation fees in the global config written by program_test
+pub fn fee_destination() -> Pubkey {
+  Pubkey::new_from_array([7; 32])
+}
+
+/// Addresses of a parent and child entangler pair
+#[derive(Clone, Copy, Debug)]
+pub struct EntanglerKeys {
+  pub parent_mint: Pubkey,
+  pub child_mint: Pubkey,
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+}
+
+impl EntanglerKeys {
+  pub fn new(parent_mint: &Pubkey, child_mint: &Pubkey, dynamic_seed: &[u8]) -> Self {
+    let parent_entangler = pda::parent_entangler(parent_mint, dynamic_seed);
+    Self {
+      parent_mint: *parent_mint,
+      child_mint: *child_mint,
+      parent_entangler,
+      child_entangler: pda::child_entangler(&parent_entangler, child_mint),
+    }
+  }
+
+  pub fn parent_storage(&self) -> Pubkey {
+    pda::storage(&self.parent_entangler)
+  }
+
+  pub fn child_storage(&self) -> Pubkey {
+    pda::storage(&self.child_entangler)
+  }
+}
+
+/// Writes the global config directly, since program-test does not deploy the program as
+/// upgradeable and the initialize instruction is gated on the upgrade authority
+pub fn add_global_config(
+  program_test: &mut ProgramTest,
+  fee_destination: Pubkey,
+  creation_fee_lamports: u64,
+) {
+  let (global_config, bump_seed) =
+    Pubkey::find_program_address(&[b"global-config"], &fungible_entangler::id());
+  let mut data = vec![];
+  GlobalConfigV0 {
+    admin: Pubkey::default(),
+    fee_destination,
+    creation_fee_lamports,
+    bump_seed,
+    paused: false,
+  }
+  .try_serialize(&mut data)
+  .unwrap();
+  data.resize(GLOBAL_CONFIG_SIZE, 0);
+
+  program_test.add_account(
+    global_config,
+    Account {
+      lamports: 1_000_000_000,
+      data,
+      owner: fungible_entangler::id(),
+      executable: false,
+      rent_epoch: 0,
+    },
+  );
+}
+
+/// spl-noop, which only has to accept the events the program logs through it
+fn process_noop(_program_id: &Pubkey, _accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+  Ok(())
+}
+
+/// The entangler program with a global config that charges no creation fee
+pub fn program_test() -> ProgramTest {
+  let mut program_test = ProgramTest::new(
+    "fungible_entangler",
+    fungible_entangler::id(),
+    processor!(native::process_instruction),
+  );
+  program_test.add_program(
+    "spl_token",
+    spl_token::id(),
+    processor!(spl_token::processor::Processor::process),
+  );
+  program_test.add_program(
+    "spl_memo",
+    spl_memo::id(),
+    processor!(spl_memo::processor::process_instruction),
+  );
+  program_test.add_builtin_program("spl_noop", noop::ID, processor!(process_noop).unwrap());
+  add_global_config(&mut program_test, fee_destination(), 0);
+
+  program_test
+}
+
+/// Sends instructions in one transaction paid for by the context payer
+pub async fn process_instructions(
+  context: &mut ProgramTestContext,
+  instructions: &[Instruction],
+  signers: &[&Keypair],
+) -> Result<(), TransportError> {
+  let mut all_signers = vec![&context.payer];
+  all_signers.extend_from_slice(signers);
+  let transaction = Transaction::new_signed_with_payer(
+    instructions,
+    Some(&context.payer.pubkey()),
+    &all_signers,
+    context.last_blockhash,
+  );
+
+  context.banks_client.process_transaction(transaction).await
+}
+
+/// Moves the clock forward. Swaps require go live to be strictly in the past
+pub async fn advance_clock(
+  context: &mut ProgramTestContext,
+  seconds: i64,
+) -> Result<(), TransportError> {
+  let mut clock: Clock = context.banks_client.get_sysvar().await?;
+  clock.unix_timestamp += seconds;
+  context.set_sysvar(&clock);
+
+  Ok(())
+}
+
+/// Moves onto a new blockhash, so that a transaction identical to one already processed is run
+/// again rather than rejected as a duplicate
+pub async fn refresh_blockhash(context: &mut ProgramTestContext) -> Result<(), TransportError> {
+  context.last_blockhash = context
+    .banks_client
+    .get_new_latest_blockhash(&context.last_blockhash)
+    .await?;
+
+  Ok(())
+}
+
+/// Creates a parent and child entangler with default settings, authority held by the context
+/// payer, then advances the clock past go live
+pub async fn create_entangler(
+  context: &mut ProgramTestContext,
+  parent_mint: &Pubkey,
+  child_mint: &Pubkey,
+  dynamic_seed: &[u8],
+) -> Result<EntanglerKeys, TransportError> {
+  let payer = context.payer.pubkey();
+  let keys = EntanglerKeys::new(parent_mint, child_mint, dynamic_seed);
+
+  process_instructions(
+    context,
+    &[
+      instructions::initialize_parent_entangler(
+        &payer,
+        parent_mint,
+        &fee_destination(),
+        InitializeFungibleParentEntanglerV0Args {
+          authority: Some(payer),
+          dynamic_seed: dynamic_seed.to_vec(),
+          ..Default::default()
+        },
+      ),
+      instructions::initialize_child_entangler(
+        &payer,
+        &payer,
+        &keys,
+        &fee_destination(),
+        InitializeFungibleChildEntanglerV0Args::default(),
+      ),
+    ],
+    &[],
+  )
+  .await?;
+  advance_clock(context, 1).await?;
+
+  Ok(keys)
+}
@@ -0,0 +1,151 @@
+//! Runs the program natively in solana-program-test. Anchor's token CPIs leave the token program
+//! out of the account infos they invoke with, which the runtime allows but program-test's native
+//! invoke requires, so the program's executable accounts are handed to every invoke it makes.
+
+use anchor_lang::{
+  prelude::{AccountInfo, Pubkey},
+  solana_program::{
+    entrypoint::ProgramResult, instruction::Instruction, program_stubs,
+    program_stubs::set_syscall_stubs,
+  },
+};
+use std::{
+  cell::RefCell,
+  sync::{Once, RwLock, RwLockReadGuard},
+};
+
+thread_local! {
+  // Executable accounts of the instruction the program is processing
+  static PROGRAM_ACCOUNTS: RefCell<Vec<AccountInfo<'static>>> = const { RefCell::new(vec![]) };
+}
+
+type Inner = RwLock<Option<Box<dyn program_stubs::SyscallStubs>>>;
+
+/// Forwards every call to program-test's own stubs
+struct SyscallStubs(&'static Inner);
+
+impl SyscallStubs {
+  fn inner(&self) -> RwLockReadGuard<'_, Option<Box<dyn program_stubs::SyscallStubs>>> {
+    self.0.read().unwrap()
+  }
+}
+
+impl program_stubs::SyscallStubs for SyscallStubs {
+  fn sol_log(&self, message: &str) {
+    self.inner().as_ref().unwrap().sol_log(message)
+  }
+
+  fn sol_log_compute_units(&self) {
+    self.inner().as_ref().unwrap().sol_log_compute_units()
+  }
+
+  fn sol_invoke_signed(
+    &self,
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+  ) -> ProgramResult {
+    let mut account_infos = account_infos.to_vec();
+    if !account_infos
+      .iter()
+      .any(|account| *account.key == instruction.program_id)
+    {
+      PROGRAM_ACCOUNTS.with(|programs| {
+        if let Some(program) = programs
+          .borrow()
+          .iter()
+          .find(|program| *program.key == instruction.program_id)
+        {
+          // SAFETY: the account outlives the instruction being processed, which makes this invoke
+          account_infos.push(unsafe {
+            std::mem::transmute::<AccountInfo<'static>, AccountInfo>(program.clone())
+          });
+        }
+      });
+    }
+
+    self
+      .inner()
+      .as_ref()
+      .unwrap()
+      .sol_invoke_signed(instruction, &account_infos, signers_seeds)
+  }
+
+  fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+    self
+      .inner()
+      .as_ref()
+      .unwrap()
+      .sol_get_clock_sysvar(var_addr)
+  }
+
+  fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+    self
+      .inner()
+      .as_ref()
+      .unwrap()
+      .sol_get_epoch_schedule_sysvar(var_addr)
+  }
+
+  fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+    self.inner().as_ref().unwrap().sol_get_fees_sysvar(var_addr)
+  }
+
+  fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+    self.inner().as_ref().unwrap().sol_get_rent_sysvar(var_addr)
+  }
+
+  fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+    self.inner().as_ref().unwrap().sol_get_return_data()
+  }
+
+  fn sol_set_return_data(&self, data: &[u8]) {
+    self.inner().as_ref().unwrap().sol_set_return_data(data)
+  }
+
+  fn sol_log_data(&self, fields: &[&[u8]]) {
+    self.inner().as_ref().unwrap().sol_log_data(fields)
+  }
+
+  fn sol_get_processed_sibling_instruction(&self, index: usize) -> Option<Instruction> {
+    self
+      .inner()
+      .as_ref()
+      .unwrap()
+      .sol_get_processed_sibling_instruction(index)
+  }
+
+  fn sol_get_stack_height(&self) -> u64 {
+    self.inner().as_ref().unwrap().sol_get_stack_height()
+  }
+}
+
+/// The program's entrypoint for `processor!`. program-test installs its stubs before any bank
+/// processes a transaction, so they are wrapped on the first instruction
+pub fn process_instruction(
+  program_id: &Pubkey,
+  accounts: &[AccountInfo],
+  data: &[u8],
+) -> ProgramResult {
+  static WRAP_STUBS: Once = Once::new();
+  WRAP_STUBS.call_once(|| {
+    let inner: &'static Inner = Box::leak(Box::new(RwLock::new(None)));
+    // Other threads wait for the write to finish before forwarding anything
+    let mut guard = inner.write().unwrap();
+    *guard = Some(set_syscall_stubs(Box::new(SyscallStubs(inner))));
+  });
+
+  let programs = accounts
+    .iter()
+    .filter(|account| account.executable)
+    // SAFETY: the accounts are dropped from PROGRAM_ACCOUNTS before this returns
+    .map(|account| unsafe {
+      std::mem::transmute::<AccountInfo, AccountInfo<'static>>(account.clone())
+    })
+    .collect();
+  let previous = PROGRAM_ACCOUNTS.with(|accounts| accounts.replace(programs));
+  let result = fungible_entangler::entry(program_id, accounts, data);
+  PROGRAM_ACCOUNTS.with(|accounts| accounts.replace(previous));
+
+  result
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::Pubkey;
+use fungible_entangler::util::hash_seed;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+  Pubkey::find_program_address(seeds, &fungible_entangler::id()).0
+}
+
+pub fn global_config() -> Pubkey {
+  find(&[b"global-config"])
+}
+
+pub fn parent_entangler(parent_mint: &Pubkey, dynamic_seed: &[u8]) -> Pubkey {
+  find(&[
+    b"entangler",
+    parent_mint.as_ref(),
+    hash_seed(dynamic_seed).as_ref(),
+  ])
+}
+
+pub fn child_entangler(parent_entangler: &Pubkey, child_mint: &Pubkey) -> Pubkey {
+  find(&[b"entangler", parent_entangler.as_ref(), child_mint.as_ref()])
+}
+
+/// Storage of either a parent or a child entangler
+pub fn storage(entangler: &Pubkey) -> Pubkey {
+  find(&[b"storage", entangler.as_ref()])
+}
+
+pub fn lookup(parent_mint: &Pubkey, child_mint: &Pubkey) -> Pubkey {
+  find(&[b"lookup", parent_mint.as_ref(), child_mint.as_ref()])
+}
+
+pub fn swap_record(child_entangler: &Pubkey, owner: &Pubkey) -> Pubkey {
+  find(&[b"swap-record", child_entangler.as_ref(), owner.as_ref()])
+}
+
+pub fn top_off_record(child_entangler: &Pubkey, depositor: &Pubkey) -> Pubkey {
+  find(&[b"top-off", child_entangler.as_ref(), depositor.as_ref()])
+}
+
+pub fn rate_schedule(child_entangler: &Pubkey) -> Pubkey {
+  find(&[b"rate-schedule", child_entangler.as_ref()])
+}
+
+pub fn fee_rebate_claim(swap_record: &Pubkey) -> Pubkey {
+  find(&[b"fee-rebate", swap_record.as_ref()])
+}
+
+pub fn swap_commit(child_entangler: &Pubkey, owner: &Pubkey) -> Pubkey {
+  find(&[b"swap-commit", child_entangler.as_ref(), owner.as_ref()])
+}
+
+pub fn child_registry(parent_entangler: &Pubkey, page: u16) -> Pubkey {
+  find(&[b"registry", parent_entangler.as_ref(), &page.to_le_bytes()])
+}
+
+pub fn swap_queue(child_entangler: &Pubkey) -> Pubkey {
+  find(&[b"swap-queue", child_entangler.as_ref()])
+}
+
+pub fn queued_swap(child_entangler: &Pubkey, ticket: u64) -> Pubkey {
+  find(&[
+    b"queued-swap",
+    child_entangler.as_ref(),
+    &ticket.to_le_bytes(),
+  ])
+}
+
+pub fn queued_swap_escrow(queued_swap: &Pubkey) -> Pubkey {
+  find(&[b"queued-swap-escrow", queued_swap.as_ref()])
+}
+
+pub fn pending_settlement(child_entangler: &Pubkey) -> Pubkey {
+  find(&[b"pending-settlement", child_entangler.as_ref()])
+}
+
+pub fn settlement_entry(pending_settlement: &Pubkey, ticket: u64) -> Pubkey {
+  find(&[
+    b"settlement-entry",
+    pending_settlement.as_ref(),
+    &ticket.to_le_bytes(),
+  ])
+}
+
+pub fn settlement_escrow(settlement_entry: &Pubkey) -> Pubkey {
+  find(&[b"settlement-escrow", settlement_entry.as_ref()])
+}
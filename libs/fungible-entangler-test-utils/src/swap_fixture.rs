@@ -0,0 +1,157 @@
+use crate::{
+  add_entangler, add_swap_record, create_mint, create_token_account, get_program_account,
+  instructions, mint_to, pda, process_instructions, program_test, token_balance, EntanglerKeys,
+};
+use anchor_lang::prelude::Pubkey;
+use fungible_entangler::{
+  instructions::SwapV0Args,
+  state::{FungibleChildEntanglerV1, FungibleParentEntanglerV1, SwapRecordV0},
+};
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{program_pack::Pack, signature::Signer, transport::TransportError};
+
+pub const DECIMALS: u8 = 9;
+/// Minted into each storage
+pub const STORAGE_AMOUNT: u64 = 1_000_000;
+/// Minted into each of the payer's token accounts
+pub const SOURCE_AMOUNT: u64 = 100_000;
+
+/// A funded entangler written by add_entangler, with a SwapRecordV0 and a parent and child token
+/// account for the context payer
+pub struct SwapFixture {
+  pub context: ProgramTestContext,
+  pub keys: EntanglerKeys,
+  pub parent_account: Pubkey,
+  pub child_account: Pubkey,
+}
+
+impl SwapFixture {
+  pub async fn new(configure: impl FnOnce(&mut FungibleChildEntanglerV1)) -> Self {
+    Self::with_parent(|_, child| configure(child)).await
+  }
+
+  pub async fn with_parent(
+    configure: impl FnOnce(&mut FungibleParentEntanglerV1, &mut FungibleChildEntanglerV1),
+  ) -> Self {
+    Self::start(program_test(), configure).await
+  }
+
+  pub async fn start(
+    program_test: ProgramTest,
+    configure: impl FnOnce(&mut FungibleParentEntanglerV1, &mut FungibleChildEntanglerV1),
+  ) -> Self {
+    let mut context = program_test.start_with_context().await;
+    let payer = context.payer.pubkey();
+    let parent_mint = create_mint(&mut context, DECIMALS).await.unwrap();
+    let child_mint = create_mint(&mut context, DECIMALS).await.unwrap();
+    let keys = add_entangler(
+      &mut context,
+      &parent_mint,
+      &child_mint,
+      b"swap-fixture",
+      configure,
+    )
+    .await
+    .unwrap();
+    add_swap_record(&mut context, &keys, &payer);
+
+    let parent_account = create_token_account(&mut context, &parent_mint, &payer)
+      .await
+      .unwrap();
+    let child_account = create_token_account(&mut context, &child_mint, &payer)
+      .await
+      .unwrap();
+    for (mint, account, amount) in [
+      (parent_mint, keys.parent_storage(), STORAGE_AMOUNT),
+      (child_mint, keys.child_storage(), STORAGE_AMOUNT),
+      (parent_mint, parent_account, SOURCE_AMOUNT),
+      (child_mint, child_account, SOURCE_AMOUNT),
+    ] {
+      mint_to(&mut context, &mint, &account, amount)
+        .await
+        .unwrap();
+    }
+
+    Self {
+      context,
+      keys,
+      parent_account,
+      child_account,
+    }
+  }
+
+  pub fn payer(&self) -> Pubkey {
+    self.context.payer.pubkey()
+  }
+
+  fn swap_args(amount: u64) -> SwapV0Args {
+    SwapV0Args {
+      amount: Some(amount),
+      ..Default::default()
+    }
+  }
+
+  pub async fn swap_parent_for_child(&mut self, amount: u64) -> Result<(), TransportError> {
+    let ix = instructions::swap_parent_for_child(
+      &self.payer(),
+      &self.keys,
+      &self.parent_account,
+      &self.payer(),
+      &self.child_account,
+      &[],
+      Self::swap_args(amount),
+    );
+    process_instructions(&mut self.context, &[ix], &[]).await
+  }
+
+  pub async fn swap_child_for_parent(&mut self, amount: u64) -> Result<(), TransportError> {
+    let ix = instructions::swap_child_for_parent(
+      &self.payer(),
+      &self.keys,
+      &self.child_account,
+      &self.payer(),
+      &self.parent_account,
+      &[],
+      Self::swap_args(amount),
+    );
+    process_instructions(&mut self.context, &[ix], &[]).await
+  }
+
+  pub async fn balance(&mut self, account: Pubkey) -> u64 {
+    token_balance(&mut self.context, &account).await.unwrap()
+  }
+
+  pub async fn supply(&mut self, mint: Pubkey) -> u64 {
+    let account = self
+      .context
+      .banks_client
+      .get_account(mint)
+      .await
+      .unwrap()
+      .unwrap();
+    spl_token::state::Mint::unpack(&account.data)
+      .unwrap()
+      .supply
+  }
+
+  pub async fn parent_entangler(&mut self) -> FungibleParentEntanglerV1 {
+    let address = self.keys.parent_entangler;
+    get_program_account(&mut self.context, &address)
+      .await
+      .unwrap()
+  }
+
+  pub async fn child_entangler(&mut self) -> FungibleChildEntanglerV1 {
+    let address = self.keys.child_entangler;
+    get_program_account(&mut self.context, &address)
+      .await
+      .unwrap()
+  }
+
+  pub async fn swap_record(&mut self) -> SwapRecordV0 {
+    let address = pda::swap_record(&self.keys.child_entangler, &self.payer());
+    get_program_account(&mut self.context, &address)
+      .await
+      .unwrap()
+  }
+}
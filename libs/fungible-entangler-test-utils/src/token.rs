@@ -0,0 +1,107 @@
+use crate::process_instructions;
+use anchor_lang::prelude::Pubkey;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+  program_pack::Pack,
+  signature::{Keypair, Signer},
+  system_instruction,
+  transport::TransportError,
+};
+
+/// Creates a mint with the context payer as mint authority
+pub async fn create_mint(
+  context: &mut ProgramTestContext,
+  decimals: u8,
+) -> Result<Pubkey, TransportError> {
+  let mint = Keypair::new();
+  let rent = context.banks_client.get_rent().await?;
+  let payer = context.payer.pubkey();
+  process_instructions(
+    context,
+    &[
+      system_instruction::create_account(
+        &payer,
+        &mint.pubkey(),
+        rent.minimum_balance(spl_token::state::Mint::LEN),
+        spl_token::state::Mint::LEN as u64,
+        &spl_token::id(),
+      ),
+      spl_token::instruction::initialize_mint(
+        &spl_token::id(),
+        &mint.pubkey(),
+        &payer,
+        None,
+        decimals,
+      )
+      .unwrap(),
+    ],
+    &[&mint],
+  )
+  .await?;
+
+  Ok(mint.pubkey())
+}
+
+pub async fn create_token_account(
+  context: &mut ProgramTestContext,
+  mint: &Pubkey,
+  owner: &Pubkey,
+) -> Result<Pubkey, TransportError> {
+  let account = Keypair::new();
+  let rent = context.banks_client.get_rent().await?;
+  let payer = context.payer.pubkey();
+  process_instructions(
+    context,
+    &[
+      system_instruction::create_account(
+        &payer,
+        &account.pubkey(),
+        rent.minimum_balance(spl_token::state::Account::LEN),
+        spl_token::state::Account::LEN as u64,
+        &spl_token::id(),
+      ),
+      spl_token::instruction::initialize_account(&spl_token::id(), &account.pubkey(), mint, owner)
+        .unwrap(),
+    ],
+    &[&account],
+  )
+  .await?;
+
+  Ok(account.pubkey())
+}
+
+/// Mints from a mint created by create_mint
+pub async fn mint_to(
+  context: &mut ProgramTestContext,
+  mint: &Pubkey,
+  destination: &Pubkey,
+  amount: u64,
+) -> Result<(), TransportError> {
+  let payer = context.payer.pubkey();
+  process_instructions(
+    context,
+    &[
+      spl_token::instruction::mint_to(&spl_token::id(), mint, destination, &payer, &[], amount)
+        .unwrap(),
+    ],
+    &[],
+  )
+  .await
+}
+
+pub async fn token_balance(
+  context: &mut ProgramTestContext,
+  account: &Pubkey,
+) -> Result<u64, TransportError> {
+  let account = context
+    .banks_client
+    .get_account(*account)
+    .await?
+    .expect("token account does not exist");
+
+  Ok(
+    spl_token::state::Account::unpack(&account.data)
+      .unwrap()
+      .amount,
+  )
+}
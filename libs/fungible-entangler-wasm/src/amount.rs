@@ -0,0 +1,73 @@
+//! Decimal string conversions that never go through floating point, so UI amounts round trip
+//! exactly.
+
+/// Parses a decimal string such as "1.25" into base units of a mint with decimals. Returns None
+/// on malformed input, more fractional digits than decimals, or overflow
+pub fn to_base_units(ui_amount: &str, decimals: u8) -> Option<u64> {
+  let (whole, fraction) = match ui_amount.trim().split_once('.') {
+    Some((whole, fraction)) => (whole, fraction),
+    None => (ui_amount.trim(), ""),
+  };
+  if (whole.is_empty() && fraction.is_empty())
+    || fraction.len() > decimals as usize
+    || !whole
+      .chars()
+      .chain(fraction.chars())
+      .all(|c| c.is_ascii_digit())
+  {
+    return None;
+  }
+
+  let scale = 10u64.checked_pow(decimals as u32)?;
+  let whole: u64 = if whole.is_empty() {
+    0
+  } else {
+    whole.parse().ok()?
+  };
+  let fraction: u64 = if fraction.is_empty() {
+    0
+  } else {
+    fraction.parse::<u64>().ok()? * 10u64.pow((decimals as usize - fraction.len()) as u32)
+  };
+
+  whole.checked_mul(scale)?.checked_add(fraction)
+}
+
+/// Formats base units as a decimal string, without trailing zeros
+pub fn to_ui_amount(amount: u64, decimals: u8) -> String {
+  if decimals == 0 {
+    return amount.to_string();
+  }
+
+  let digits = format!("{:0>width$}", amount, width = decimals as usize + 1);
+  let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+  let fraction = fraction.trim_end_matches('0');
+  if fraction.is_empty() {
+    whole.to_string()
+  } else {
+    format!("{}.{}", whole, fraction)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_round_trip() {
+    assert_eq!(to_base_units("1.25", 9), Some(1_250_000_000));
+    assert_eq!(to_base_units(".5", 2), Some(50));
+    assert_eq!(to_base_units("7", 0), Some(7));
+    assert_eq!(to_ui_amount(1_250_000_000, 9), "1.25");
+    assert_eq!(to_ui_amount(5, 3), "0.005");
+    assert_eq!(to_ui_amount(1_000, 3), "1");
+  }
+
+  #[test]
+  fn test_rejects_invalid_amounts() {
+    assert_eq!(to_base_units("1.234", 2), None);
+    assert_eq!(to_base_units("-1", 2), None);
+    assert_eq!(to_base_units(".", 2), None);
+    assert_eq!(to_base_units("18446744073709551616", 0), None);
+  }
+}
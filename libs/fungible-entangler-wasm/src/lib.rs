@@ -0,0 +1,168 @@
+//! JS bindings for PDA derivation and swap quotes. Quotes reuse the SDK's math, which mirrors the
+//! program's rounding, so frontends show exactly what a swap will pay.
+
+pub mod amount;
+
+use fungible_entangler_sdk::{
+  pda,
+  quote::{self, SwapDirection},
+  state::{BurnConfigV0, BurnSideV0, ExchangeRateV0, FungibleChildEntanglerV1, ProgramAccount},
+};
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+fn pubkey(value: &str) -> Result<Pubkey, JsValue> {
+  Pubkey::from_str(value).map_err(|_| JsValue::from_str(&format!("Invalid public key {}", value)))
+}
+
+#[wasm_bindgen(js_name = globalConfigAddress)]
+pub fn global_config_address() -> String {
+  pda::global_config().0.to_string()
+}
+
+#[wasm_bindgen(js_name = parentEntanglerAddress)]
+pub fn parent_entangler_address(parent_mint: &str, dynamic_seed: &[u8]) -> Result<String, JsValue> {
+  Ok(
+    pda::parent_entangler(&pubkey(parent_mint)?, dynamic_seed)
+      .0
+      .to_string(),
+  )
+}
+
+#[wasm_bindgen(js_name = childEntanglerAddress)]
+pub fn child_entangler_address(
+  parent_entangler: &str,
+  child_mint: &str,
+) -> Result<String, JsValue> {
+  Ok(
+    pda::child_entangler(&pubkey(parent_entangler)?, &pubkey(child_mint)?)
+      .0
+      .to_string(),
+  )
+}
+
+#[wasm_bindgen(js_name = storageAddress)]
+pub fn storage_address(entangler: &str) -> Result<String, JsValue> {
+  Ok(pda::storage(&pubkey(entangler)?).0.to_string())
+}
+
+#[wasm_bindgen(js_name = lookupAddress)]
+pub fn lookup_address(parent_mint: &str, child_mint: &str) -> Result<String, JsValue> {
+  Ok(
+    pda::lookup(&pubkey(parent_mint)?, &pubkey(child_mint)?)
+      .0
+      .to_string(),
+  )
+}
+
+#[wasm_bindgen(js_name = swapRecordAddress)]
+pub fn swap_record_address(child_entangler: &str, owner: &str) -> Result<String, JsValue> {
+  Ok(
+    pda::swap_record(&pubkey(child_entangler)?, &pubkey(owner)?)
+      .0
+      .to_string(),
+  )
+}
+
+/// Amounts are in base units
+#[wasm_bindgen]
+pub struct Quote {
+  pub amount: u64,
+  #[wasm_bindgen(js_name = convertedAmount)]
+  pub converted_amount: u64,
+  #[wasm_bindgen(js_name = feeAmount)]
+  pub fee_amount: u64,
+  #[wasm_bindgen(js_name = outputAmount)]
+  pub output_amount: u64,
+  #[wasm_bindgen(js_name = inputBurnAmount)]
+  pub input_burn_amount: u64,
+  #[wasm_bindgen(js_name = outputBurnAmount)]
+  pub output_burn_amount: u64,
+  #[wasm_bindgen(js_name = receivedAmount)]
+  pub received_amount: u64,
+}
+
+impl From<quote::Quote> for Quote {
+  fn from(quote: quote::Quote) -> Self {
+    Self {
+      amount: quote.amount,
+      converted_amount: quote.converted_amount,
+      fee_amount: quote.fee_amount,
+      output_amount: quote.output_amount,
+      input_burn_amount: quote.input_burn_amount,
+      output_burn_amount: quote.output_burn_amount,
+      received_amount: quote.received_amount,
+    }
+  }
+}
+
+fn direction(parent_to_child: bool) -> SwapDirection {
+  if parent_to_child {
+    SwapDirection::ParentToChild
+  } else {
+    SwapDirection::ChildToParent
+  }
+}
+
+/// Quotes with explicit parameters. Rate units are in base units, so they carry both mints'
+/// decimals
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn quote(
+  parent_units: u64,
+  child_units: u64,
+  swap_fee_bps: u16,
+  burn_bps: Option<u16>,
+  burn_input: bool,
+  parent_to_child: bool,
+  amount: u64,
+) -> Result<Quote, JsValue> {
+  let burn = burn_bps.map(|bps| BurnConfigV0 {
+    bps,
+    side: if burn_input {
+      BurnSideV0::Input
+    } else {
+      BurnSideV0::Output
+    },
+  });
+
+  quote::quote_with(
+    &ExchangeRateV0 {
+      parent_units,
+      child_units,
+    },
+    swap_fee_bps,
+    &burn,
+    direction(parent_to_child),
+    amount,
+  )
+  .map(Quote::from)
+  .ok_or_else(|| JsValue::from_str("Quote overflows or the exchange rate is invalid"))
+}
+
+/// Quotes against raw child entangler account data, as fetched from an RPC
+#[wasm_bindgen(js_name = quoteChildEntangler)]
+pub fn quote_child_entangler(
+  child_entangler_data: &[u8],
+  parent_to_child: bool,
+  amount: u64,
+) -> Result<Quote, JsValue> {
+  let child = FungibleChildEntanglerV1::try_from_account_data(child_entangler_data)
+    .map_err(|err| JsValue::from_str(&err.to_string()))?;
+
+  quote::quote(&child, direction(parent_to_child), amount)
+    .map(Quote::from)
+    .ok_or_else(|| JsValue::from_str("Quote overflows or the exchange rate is invalid"))
+}
+
+#[wasm_bindgen(js_name = toBaseUnits)]
+pub fn to_base_units(ui_amount: &str, decimals: u8) -> Result<u64, JsValue> {
+  amount::to_base_units(ui_amount, decimals)
+    .ok_or_else(|| JsValue::from_str(&format!("Invalid amount {}", ui_amount)))
+}
+
+#[wasm_bindgen(js_name = toUiAmount)]
+pub fn to_ui_amount(amount: u64, decimals: u8) -> String {
+  amount::to_ui_amount(amount, decimals)
+}
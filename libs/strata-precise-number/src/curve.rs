@@ -0,0 +1,202 @@
+//! Pricing curves built on PreciseNumber. Every curve is monotonic in its time or supply input,
+//! and the exponential ones are accurate to roughly 9 significant digits.
+
+use crate::precise_number::{PreciseNumber, ZERO_PREC};
+
+/// Past this exponent e^-x is below the 18 decimal resolution, so decays are treated as complete.
+pub const MAX_DECAY_EXPONENT: u128 = 40;
+
+/// Decays linearly from `start` to `end` over `duration_seconds`, then holds at `end`.
+/// Returns None if `start` is below `end`.
+pub fn linear_decay(
+  start: &PreciseNumber,
+  end: &PreciseNumber,
+  duration_seconds: u64,
+  elapsed_seconds: u64,
+) -> Option<PreciseNumber> {
+  if start.less_than(end) {
+    return None;
+  }
+  if elapsed_seconds >= duration_seconds {
+    return Some(end.clone());
+  }
+
+  let decayed = start
+    .checked_sub(end)?
+    .checked_mul(&PreciseNumber::new(elapsed_seconds.into())?)?
+    .checked_div(&PreciseNumber::new(duration_seconds.into())?)?;
+
+  start.checked_sub(&decayed)
+}
+
+/// Decays from `start` towards `end` as `end + (start - end) * e^(-decay_rate * elapsed_seconds)`.
+/// Returns None if `start` is below `end`.
+pub fn exponential_decay(
+  start: &PreciseNumber,
+  end: &PreciseNumber,
+  decay_rate: &PreciseNumber,
+  elapsed_seconds: u64,
+) -> Option<PreciseNumber> {
+  if start.less_than(end) {
+    return None;
+  }
+
+  let exponent = decay_rate.checked_mul(&PreciseNumber::new(elapsed_seconds.into())?)?;
+  if exponent.greater_than(&PreciseNumber::new(MAX_DECAY_EXPONENT)?) {
+    return Some(end.clone());
+  }
+
+  let remaining = exponent.signed().negate().exp()?;
+  end.checked_add(&start.checked_sub(end)?.checked_mul(&remaining)?)
+}
+
+/// Prices along `c * supply^exponent`, the shape used by bonding curves.
+pub fn power_curve(
+  c: &PreciseNumber,
+  supply: &PreciseNumber,
+  exponent: &PreciseNumber,
+) -> Option<PreciseNumber> {
+  if supply.eq(&ZERO_PREC) {
+    return Some(PreciseNumber::zero());
+  }
+
+  c.checked_mul(&supply.pow(exponent)?)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::precise_number::InnerUint;
+  use proptest::prelude::*;
+
+  fn from_thousandths(value: u64) -> PreciseNumber {
+    PreciseNumber::new(value.into())
+      .unwrap()
+      .checked_div(&PreciseNumber::new(1000).unwrap())
+      .unwrap()
+  }
+
+  fn to_f64(value: &PreciseNumber) -> f64 {
+    value.value.as_u128() as f64 / 1e18
+  }
+
+  // Allows `lhs` to fall below `rhs` by at most one part in a billion of `rhs`
+  fn almost_ge(lhs: &PreciseNumber, rhs: &PreciseNumber) -> bool {
+    let tolerance = rhs.value / InnerUint::from(1_000_000_000_u128) + InnerUint::from(1);
+    lhs.value + tolerance >= rhs.value
+  }
+
+  #[test]
+  fn test_linear_decay_endpoints() {
+    let start = PreciseNumber::new(10).unwrap();
+    let end = PreciseNumber::new(2).unwrap();
+
+    assert_eq!(linear_decay(&start, &end, 100, 0).unwrap(), start);
+    assert_eq!(
+      linear_decay(&start, &end, 100, 50).unwrap(),
+      PreciseNumber::new(6).unwrap()
+    );
+    assert_eq!(linear_decay(&start, &end, 100, 100).unwrap(), end);
+    assert_eq!(linear_decay(&start, &end, 100, 1000).unwrap(), end);
+    assert!(linear_decay(&end, &start, 100, 0).is_none());
+  }
+
+  #[test]
+  fn test_exponential_decay() {
+    let precision = InnerUint::from(1_000_000_000_u128);
+    let start = PreciseNumber::new(10).unwrap();
+    let end = PreciseNumber::new(2).unwrap();
+    let rate = from_thousandths(10);
+
+    // 2 + 8 * e^-1
+    let expected = PreciseNumber::new(49430355293715387)
+      .unwrap()
+      .checked_div(&PreciseNumber::new(10000000000000000).unwrap())
+      .unwrap();
+    assert!(exponential_decay(&start, &end, &rate, 100)
+      .unwrap()
+      .almost_eq(&expected, precision));
+    assert_eq!(
+      exponential_decay(&start, &end, &rate, 1_000_000).unwrap(),
+      end
+    );
+    assert!(exponential_decay(&end, &start, &rate, 0).is_none());
+  }
+
+  proptest! {
+    #[test]
+    fn linear_decay_is_monotonic(
+      start in 1_u64..1_000_000_000,
+      end_fraction in 0_u64..1000,
+      duration in 1_u64..10_000_000,
+      t1 in 0_u64..20_000_000,
+      t2 in 0_u64..20_000_000,
+    ) {
+      let (earlier, later) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+      let start_prec = from_thousandths(start);
+      let end_prec = from_thousandths(start * end_fraction / 1000);
+
+      let earlier_price = linear_decay(&start_prec, &end_prec, duration, earlier).unwrap();
+      let later_price = linear_decay(&start_prec, &end_prec, duration, later).unwrap();
+
+      prop_assert!(later_price.less_than_or_equal(&earlier_price));
+      prop_assert!(later_price.greater_than_or_equal(&end_prec));
+      prop_assert!(earlier_price.less_than_or_equal(&start_prec));
+    }
+
+    #[test]
+    fn exponential_decay_is_monotonic(
+      start in 1_u64..1_000_000_000,
+      end_fraction in 0_u64..1000,
+      rate_thousandths in 1_u64..1000,
+      t1 in 0_u64..100_000,
+      t2 in 0_u64..100_000,
+    ) {
+      let (earlier, later) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+      let start_prec = from_thousandths(start);
+      let end_prec = from_thousandths(start * end_fraction / 1000);
+      let rate = from_thousandths(rate_thousandths);
+
+      let earlier_price = exponential_decay(&start_prec, &end_prec, &rate, earlier).unwrap();
+      let later_price = exponential_decay(&start_prec, &end_prec, &rate, later).unwrap();
+
+      prop_assert!(almost_ge(&earlier_price, &later_price));
+      prop_assert!(later_price.greater_than_or_equal(&end_prec));
+      prop_assert!(almost_ge(&start_prec, &earlier_price));
+    }
+
+    #[test]
+    fn power_curve_is_monotonic(
+      c_thousandths in 1_u64..1_000_000,
+      s1 in 1_u64..1_000_000,
+      s2 in 1_u64..1_000_000,
+      exponent_thousandths in 100_u64..3000,
+    ) {
+      let (lower, higher) = if s1 <= s2 { (s1, s2) } else { (s2, s1) };
+      let c = from_thousandths(c_thousandths);
+      let exponent = from_thousandths(exponent_thousandths);
+
+      let lower_price = power_curve(&c, &from_thousandths(lower), &exponent).unwrap();
+      let higher_price = power_curve(&c, &from_thousandths(higher), &exponent).unwrap();
+
+      prop_assert!(almost_ge(&higher_price, &lower_price));
+    }
+
+    #[test]
+    fn power_curve_error_is_bounded(
+      c_thousandths in 1_u64..1_000_000,
+      supply_thousandths in 1_u64..1_000_000,
+      exponent_thousandths in 100_u64..3000,
+    ) {
+      let c = from_thousandths(c_thousandths);
+      let supply = from_thousandths(supply_thousandths);
+      let exponent = from_thousandths(exponent_thousandths);
+
+      let actual = to_f64(&power_curve(&c, &supply, &exponent).unwrap());
+      let expected = (c_thousandths as f64 / 1000.0)
+        * (supply_thousandths as f64 / 1000.0).powf(exponent_thousandths as f64 / 1000.0);
+
+      prop_assert!((actual - expected).abs() <= expected * 1e-6 + 1e-9);
+    }
+  }
+}
@@ -0,0 +1,11 @@
+//! Fixed point math with 18 decimals backed by a U192, ported from the bonding program so other
+//! programs can price along nonlinear curves without floats. All operations are checked and
+//! return `None` on overflow.
+
+pub mod curve;
+pub mod precise_number;
+pub mod signed_precise_number;
+pub mod uint;
+
+pub use precise_number::PreciseNumber;
+pub use signed_precise_number::SignedPreciseNumber;
@@ -0,0 +1,7 @@
+//! Token cpi helpers for programs whose token accounts are owned by PDAs. Every `*_signed`
+//! helper takes the authority's seeds, including its bump, as a single seed set.
+
+pub mod token;
+pub mod token_2022;
+
+pub use token::*;
@@ -0,0 +1,119 @@
+use anchor_lang::{prelude::*, solana_program};
+use anchor_spl::token::{self, Burn, MintTo, Transfer};
+
+pub fn transfer_signed<'info>(
+  token_program: &AccountInfo<'info>,
+  from: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  authority: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+  amount: u64,
+) -> Result<()> {
+  token::transfer(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      Transfer {
+        from: from.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+      },
+      &[signer_seeds],
+    ),
+    amount,
+  )
+}
+
+pub fn burn_signed<'info>(
+  token_program: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  from: &AccountInfo<'info>,
+  authority: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+  amount: u64,
+) -> Result<()> {
+  token::burn(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      Burn {
+        mint: mint.clone(),
+        from: from.clone(),
+        authority: authority.clone(),
+      },
+      &[signer_seeds],
+    ),
+    amount,
+  )
+}
+
+pub fn mint_to_signed<'info>(
+  token_program: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  authority: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+  amount: u64,
+) -> Result<()> {
+  token::mint_to(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      MintTo {
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+      },
+      &[signer_seeds],
+    ),
+    amount,
+  )
+}
+
+#[derive(Accounts)]
+pub struct CloseTokenAccount<'info> {
+  /// CHECK: Used in cpi
+  pub from: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub to: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub authority: AccountInfo<'info>,
+}
+
+pub fn close_token_account<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, CloseTokenAccount<'info>>,
+) -> Result<()> {
+  let ix = spl_token::instruction::close_account(
+    &spl_token::ID,
+    ctx.accounts.from.key,
+    ctx.accounts.to.key,
+    ctx.accounts.authority.key,
+    &[],
+  )?;
+  solana_program::program::invoke_signed(
+    &ix,
+    &[
+      ctx.accounts.from.clone(),
+      ctx.accounts.to.clone(),
+      ctx.accounts.authority.clone(),
+      ctx.program.clone(),
+    ],
+    ctx.signer_seeds,
+  )
+  .map_err(|e| e.into())
+}
+
+pub fn close_token_account_signed<'info>(
+  token_program: &AccountInfo<'info>,
+  from: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  authority: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+) -> Result<()> {
+  close_token_account(CpiContext::new_with_signer(
+    token_program.clone(),
+    CloseTokenAccount {
+      from: from.clone(),
+      to: to.clone(),
+      authority: authority.clone(),
+    },
+    &[signer_seeds],
+  ))
+}
@@ -0,0 +1,245 @@
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    self,
+    instruction::{AccountMeta, Instruction},
+    program_pack::Pack,
+  },
+};
+use spl_token_2022::{
+  extension::{AccountType, ExtensionType},
+  state::{Account, Mint},
+};
+
+pub use spl_token_2022::ID;
+
+/// ExtensionType of the permanent delegate, which spl-token-2022 0.1 predates
+pub const PERMANENT_DELEGATE_EXTENSION: u16 = 12;
+/// TokenInstruction tag of InitializePermanentDelegate
+const INITIALIZE_PERMANENT_DELEGATE: u8 = 35;
+// Type, length and delegate
+const PERMANENT_DELEGATE_TLV_LEN: usize = 2 + 2 + 32;
+
+#[derive(Clone)]
+pub struct Token2022;
+
+impl anchor_lang::Id for Token2022 {
+  fn id() -> Pubkey {
+    ID
+  }
+}
+
+#[derive(Accounts)]
+pub struct MintTo<'info> {
+  /// CHECK: Used in cpi
+  pub mint: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub to: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub authority: AccountInfo<'info>,
+}
+
+pub fn mint_to<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, MintTo<'info>>,
+  amount: u64,
+) -> Result<()> {
+  let ix = spl_token_2022::instruction::mint_to(
+    &ID,
+    ctx.accounts.mint.key,
+    ctx.accounts.to.key,
+    ctx.accounts.authority.key,
+    &[],
+    amount,
+  )?;
+  solana_program::program::invoke_signed(
+    &ix,
+    &[
+      ctx.accounts.mint.clone(),
+      ctx.accounts.to.clone(),
+      ctx.accounts.authority.clone(),
+      ctx.program.clone(),
+    ],
+    ctx.signer_seeds,
+  )
+  .map_err(|e| e.into())
+}
+
+#[derive(Accounts)]
+pub struct Burn<'info> {
+  /// CHECK: Used in cpi
+  pub mint: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub from: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub authority: AccountInfo<'info>,
+}
+
+pub fn burn<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, Burn<'info>>,
+  amount: u64,
+) -> Result<()> {
+  let ix = spl_token_2022::instruction::burn(
+    &ID,
+    ctx.accounts.from.key,
+    ctx.accounts.mint.key,
+    ctx.accounts.authority.key,
+    &[],
+    amount,
+  )?;
+  solana_program::program::invoke_signed(
+    &ix,
+    &[
+      ctx.accounts.from.clone(),
+      ctx.accounts.mint.clone(),
+      ctx.accounts.authority.clone(),
+      ctx.program.clone(),
+    ],
+    ctx.signer_seeds,
+  )
+  .map_err(|e| e.into())
+}
+
+/// Mints wrapped tokens backed by underlying tokens held in PDA storage
+pub fn mint_to_signed<'info>(
+  token_program: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  authority: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+  amount: u64,
+) -> Result<()> {
+  mint_to(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      MintTo {
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+      },
+      &[signer_seeds],
+    ),
+    amount,
+  )
+}
+
+#[derive(Accounts)]
+pub struct TransferChecked<'info> {
+  /// CHECK: Used in cpi
+  pub from: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub mint: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub to: AccountInfo<'info>,
+  /// CHECK: Used in cpi
+  pub authority: AccountInfo<'info>,
+}
+
+pub fn transfer_checked<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, TransferChecked<'info>>,
+  amount: u64,
+  decimals: u8,
+) -> Result<()> {
+  let ix = spl_token_2022::instruction::transfer_checked(
+    &ID,
+    ctx.accounts.from.key,
+    ctx.accounts.mint.key,
+    ctx.accounts.to.key,
+    ctx.accounts.authority.key,
+    &[],
+    amount,
+    decimals,
+  )?;
+  solana_program::program::invoke_signed(
+    &ix,
+    &[
+      ctx.accounts.from.clone(),
+      ctx.accounts.mint.clone(),
+      ctx.accounts.to.clone(),
+      ctx.accounts.authority.clone(),
+      ctx.program.clone(),
+    ],
+    ctx.signer_seeds,
+  )
+  .map_err(|e| e.into())
+}
+
+/// Moves tokens out of an account the PDA authority is delegate of, such as the permanent
+/// delegate of the mint
+pub fn transfer_checked_signed<'info>(
+  token_program: &AccountInfo<'info>,
+  from: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  to: &AccountInfo<'info>,
+  authority: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+  amount: u64,
+) -> Result<()> {
+  let decimals = Mint::unpack_from_slice(&mint.try_borrow_data()?[..Mint::LEN])?.decimals;
+  transfer_checked(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      TransferChecked {
+        from: from.clone(),
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+      },
+      &[signer_seeds],
+    ),
+    amount,
+    decimals,
+  )
+}
+
+/// Data length of a mint with extension_types, and the permanent delegate extension when
+/// permanent_delegate is set
+pub fn mint_len(extension_types: &[ExtensionType], permanent_delegate: bool) -> usize {
+  if !permanent_delegate {
+    return ExtensionType::get_account_len::<Mint>(extension_types);
+  }
+
+  let len = if extension_types.is_empty() {
+    Account::LEN + 1
+  } else {
+    ExtensionType::get_account_len::<Mint>(extension_types)
+  };
+  len + PERMANENT_DELEGATE_TLV_LEN
+}
+
+/// Makes delegate the permanent delegate of mint, which may transfer or burn from any of its
+/// token accounts. Must come before the mint is initialized
+pub fn initialize_permanent_delegate(mint: &Pubkey, delegate: &Pubkey) -> Instruction {
+  let mut data = vec![INITIALIZE_PERMANENT_DELEGATE];
+  data.extend_from_slice(delegate.as_ref());
+
+  Instruction {
+    program_id: ID,
+    accounts: vec![AccountMeta::new(*mint, false)],
+    data,
+  }
+}
+
+/// The permanent delegate set in a Token-2022 mint's extensions, if any
+pub fn permanent_delegate(mint_data: &[u8]) -> Option<Pubkey> {
+  if mint_data.get(Account::LEN) != Some(&(AccountType::Mint as u8)) {
+    return None;
+  }
+
+  let mut tlv_data = &mint_data[Account::LEN + 1..];
+  while tlv_data.len() >= 4 {
+    let extension_type = u16::from_le_bytes([tlv_data[0], tlv_data[1]]);
+    let len = u16::from_le_bytes([tlv_data[2], tlv_data[3]]) as usize;
+    let value = tlv_data.get(4..4 + len)?;
+    if extension_type == ExtensionType::Uninitialized as u16 {
+      return None;
+    }
+    if extension_type == PERMANENT_DELEGATE_EXTENSION && len == 32 {
+      let delegate = Pubkey::new(value);
+      return (delegate != Pubkey::default()).then(|| delegate);
+    }
+
+    tlv_data = &tlv_data[4 + len..];
+  }
+
+  None
+}
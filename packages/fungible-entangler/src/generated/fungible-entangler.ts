@@ -27,22 +27,27 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "globalConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "feeDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "clock",
+          "name": "noopProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -84,28 +89,38 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "lookup",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "childMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "globalConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "feeDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "clock",
+          "name": "noopProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -125,6 +140,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
         {
           "name": "common",
           "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
             {
               "name": "parentEntangler",
               "isMut": true,
@@ -135,6 +155,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
               "isMut": true,
               "isSigner": false
             },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
             {
               "name": "childEntangler",
               "isMut": true,
@@ -145,6 +170,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
               "isMut": true,
               "isSigner": false
             },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
             {
               "name": "source",
               "isMut": true,
@@ -160,13 +190,43 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
               "isMut": true,
               "isSigner": false
             },
+            {
+              "name": "swapRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
             {
               "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "clock",
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
               "isMut": false,
               "isSigner": false
             }
@@ -188,6 +248,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
         {
           "name": "common",
           "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
             {
               "name": "parentEntangler",
               "isMut": true,
@@ -198,6 +263,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
               "isMut": true,
               "isSigner": false
             },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
             {
               "name": "childEntangler",
               "isMut": true,
@@ -208,6 +278,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
               "isMut": true,
               "isSigner": false
             },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
             {
               "name": "source",
               "isMut": true,
@@ -223,13 +298,43 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
               "isMut": true,
               "isSigner": false
             },
+            {
+              "name": "swapRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
             {
               "name": "tokenProgram",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "clock",
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
               "isMut": false,
               "isSigner": false
             }
@@ -249,7 +354,7 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
       "name": "closeFungibleChildEntanglerV0",
       "accounts": [
         {
-          "name": "refund",
+          "name": "refundTo",
           "isMut": true,
           "isSigner": false
         },
@@ -273,10 +378,20 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "lookup",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -285,7 +400,7 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
       "name": "closeFungibleParentEntanglerV0",
       "accounts": [
         {
-          "name": "refund",
+          "name": "refundTo",
           "isMut": true,
           "isSigner": false
         },
@@ -308,6 +423,11 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
           "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": []
@@ -392,253 +512,8410 @@ export const FungibleEntanglerIDLJson: Idl & { metadata?: { address: string } }
           }
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "FungibleParentEntanglerV0",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "parentMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "parentStorage",
-            "type": "publicKey"
-          },
-          {
-            "name": "goLiveUnixTime",
-            "type": "i64"
-          },
-          {
-            "name": "freezeSwapUnixTime",
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "createdAtUnixTime",
-            "type": "i64"
-          },
-          {
-            "name": "numChildren",
-            "type": "u32"
-          },
-          {
-            "name": "authority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "dynamicSeed",
-            "type": "bytes"
-          },
-          {
-            "name": "bumpSeed",
-            "type": "u8"
-          },
-          {
-            "name": "storageBumpSeed",
-            "type": "u8"
-          }
-        ]
-      }
     },
     {
-      "name": "FungibleChildEntanglerV0",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "parentEntangler",
-            "type": "publicKey"
-          },
-          {
-            "name": "childMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "childStorage",
-            "type": "publicKey"
-          },
-          {
-            "name": "goLiveUnixTime",
-            "type": "i64"
-          },
-          {
-            "name": "freezeSwapUnixTime",
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "createdAtUnixTime",
-            "type": "i64"
-          },
-          {
-            "name": "bumpSeed",
-            "type": "u8"
-          },
-          {
-            "name": "storageBumpSeed",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "InitializeFungibleChildEntanglerV0Args",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "goLiveUnixTime",
-            "type": "i64"
-          },
-          {
-            "name": "freezeSwapUnixTime",
-            "type": {
-              "option": "i64"
-            }
+      "name": "initializeChildRegistryV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeChildRegistryV0Args"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "InitializeFungibleParentEntanglerV0Args",
-      "type": {
-        "kind": "struct",
+      "name": "appendChildRegistryV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeChildRegistryV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RemoveChildRegistryV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMetadataV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateMetadataV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mirrorChildMetadataV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MirrorChildMetadataV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeChildMintV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lookup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeChildMintV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "wrapMintV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "wrapper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "underlyingStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "underlyingMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WrapMintV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "wrapV0",
+      "accounts": [
+        {
+          "name": "wrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "underlyingStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WrapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unwrapV0",
+      "accounts": [
+        {
+          "name": "wrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "underlyingStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UnwrapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "clawbackV0",
+      "accounts": [
+        {
+          "name": "clawbackAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "wrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrappedMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "token2022Program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ClawbackV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapParentForChildVestedV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vestingStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vesting",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "swapRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimVestedV0",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vestingStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vesting",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundSwapV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RefundSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "topOffChildStorageV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "topOffRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TopOffChildStorageV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reclaimAfterExpiryV0",
+      "accounts": [
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "topOffRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeRewardsPoolV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardsPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeRewardsPoolV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRewardsV0",
+      "accounts": [
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardsPool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "topOffRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "lockV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "LockV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapLockedV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lockStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unlockV0",
+      "accounts": [
+        {
+          "name": "refund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lock",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lockStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeGlobalConfigV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "upgradeAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeGlobalConfigV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGlobalConfigV0",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateGlobalConfigV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateParentEntanglerV0ToV1",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateChildEntanglerV0ToV1",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lookup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resizeEntanglerV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ResizeEntanglerV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "enforceFreezeV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setGlobalPauseV0",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetGlobalPauseV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweepDustV0",
+      "accounts": [
+        {
+          "name": "sweeper",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "storage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "sealEntanglerV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createOfferV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "maker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "offer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offerMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "askMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateOfferV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptOfferV0",
+      "accounts": [
+        {
+          "name": "taker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "offer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "makerDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOfferV0",
+      "accounts": [
+        {
+          "name": "maker",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "offer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "swapParentForChildAndStakeV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swapRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapAndStakeV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapChildForParentAndProvideLiquidityV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swapRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "liquidityProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapAndProvideLiquidityV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRateScheduleV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateSchedule",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRateScheduleV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "appendRateScheduleV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateSchedule",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AppendRateScheduleV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFreezeTimeV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetFreezeTimeV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setParentFreezeTimeV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetParentFreezeTimeV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeesV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetFeesV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRateV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRateV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAuthorityV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetAuthorityV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "getVersionV0",
+      "accounts": [],
+      "args": []
+    },
+    {
+      "name": "initializeSwapRecordTreeV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeSwapRecordTreeV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapParentForChildCompressedV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "merkleTree",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "compressionProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapChildForParentCompressedV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "merkleTree",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "compressionProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "refundCompressedSwapV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RefundCompressedSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapParentForChildCompressedTokenV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "recipient",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "parentTransit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childTransit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentTokenPool",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childTokenPool",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "cpiAuthorityPda",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "registeredProgramPda",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "accountCompressionAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "compressedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lightSystemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "accountCompressionProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapCompressedTokenV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapChildForParentCompressedTokenV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "owner",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "recipient",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "parentTransit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childTransit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentTokenPool",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childTokenPool",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "cpiAuthorityPda",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "registeredProgramPda",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "accountCompressionAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "compressedTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lightSystemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "accountCompressionProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapCompressedTokenV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeEntanglerWithChildrenV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeEntanglerWithChildrenV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ensureChildEntanglerV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lookup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeFungibleChildEntanglerV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "snapshotV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "snapshotHistory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "commitSwapV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapCommit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CommitSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revealAndSwapV0",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "parentEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "parentMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childEntangler",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childStorage",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "childMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "source",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sourceAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "destination",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swapRecord",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "instructions",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "memoProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rateSchedule",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "noopProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "swapCommit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RevealAndSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setNftAuthorityV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "invokeAsNftAuthorityV0",
+      "accounts": [
+        {
+          "name": "holder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "holderNftAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entanglerProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InvokeAsNftAuthorityV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "importLegacyEntanglerV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "legacyParentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyParentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ImportLegacyEntanglerV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "importLegacyChildEntanglerV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "legacyParentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "legacyChildEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyChildStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lookup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "legacyProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "approveStrategyProgramV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "strategyProgram",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setYieldStrategyV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategyProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetYieldStrategyV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rebalanceStrategyV0",
+      "accounts": [
+        {
+          "name": "keeper",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "strategyProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RebalanceStrategyV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRegistryWeightsV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRegistryWeightsV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapParentForBasketV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "registry",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SwapParentForBasketV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRateBandV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetRateBandV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueSwapV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "queuedSwap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inputMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "QueueSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeQueuedSwapV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapQueue",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "queuedSwap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "refundAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelQueuedSwapV0",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "queuedSwap",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "refundAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeNamespacedParentEntanglerV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "namespace",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "entangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "namespaceLookup",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeFungibleParentEntanglerV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWithdrawalsFrozenUntilV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetWithdrawalsFrozenUntilV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "distributeFeesV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimFeeRebateV0",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapRecord",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeRebateClaim",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setAuthorityConfigV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorityConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetAuthorityConfigV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "invokeAsAuthorityConfigV0",
+      "accounts": [
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorityConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entanglerProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InvokeAsAuthorityConfigV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPendingSettlementV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingSettlement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPendingSettlementV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deferSwapV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingSettlement",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "settlementEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inputMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "DeferSwapV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ackSettlementV0",
+      "accounts": [
+        {
+          "name": "settler",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "parentMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "childMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingSettlement",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateSchedule",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memoProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "noopProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundSettlementV0",
+      "accounts": [
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingSettlement",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "escrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "refundAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setDrawdownLimitV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetDrawdownLimitV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "validateInitParamsV0",
+      "accounts": [
+        {
+          "name": "parentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "entangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeEntanglerWithChildrenV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rebalanceBetweenEntanglersV0",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "sourceParentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceParentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceChildEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sourceChildStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationParentEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationParentStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationChildEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destinationChildStorage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RebalanceBetweenEntanglersV0Args"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateParentEntanglerV1ToV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "parentEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateChildEntanglerV1ToV2",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "childEntangler",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeSwapRecordV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "childEntangler",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateChildRegistryV0",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "GlobalConfigV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleParentEntanglerV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "parentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "numChildren",
+            "type": "u32"
+          },
+          {
+            "name": "nextChildIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "dynamicSeed",
+            "type": "bytes"
+          },
+          {
+            "name": "dynamicSeedHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "rentRefund",
+            "type": "publicKey"
+          },
+          {
+            "name": "legacySeeds",
+            "type": "bool"
+          },
+          {
+            "name": "defaultRate",
+            "type": {
+              "defined": "ExchangeRateV0"
+            }
+          },
+          {
+            "name": "freezeParentToChildUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeChildToParentUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "authorityNftMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "namespace",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "withdrawalsFrozenUntil",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "reservedParentFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                15
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleChildEntanglerV1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "childMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "childStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "vesting",
+            "type": {
+              "option": {
+                "defined": "VestingConfigV0"
+              }
+            }
+          },
+          {
+            "name": "refundWindowSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "expiryUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "totalTopOffAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reclaimSnapshotAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "rewardsPool",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "minLockSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "burn",
+            "type": {
+              "option": {
+                "defined": "BurnConfigV0"
+              }
+            }
+          },
+          {
+            "name": "rentRefund",
+            "type": "publicKey"
+          },
+          {
+            "name": "rate",
+            "type": {
+              "defined": "ExchangeRateV0"
+            }
+          },
+          {
+            "name": "swapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxParentToChildAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "totalParentToChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "frozen",
+            "type": "bool"
+          },
+          {
+            "name": "cpiPolicy",
+            "type": {
+              "defined": "CpiPolicyV0"
+            }
+          },
+          {
+            "name": "cpiAllowlist",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "maxChildToParentAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "totalChildToParentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "stakeProgram",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "liquidityProgram",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTierV0"
+              }
+            }
+          },
+          {
+            "name": "reverseRebateSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeParentToChildUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeChildToParentUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "swapRecordTree",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "compressedRecordCount",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChildVolume",
+            "type": {
+              "defined": "VolumeStatsV0"
+            }
+          },
+          {
+            "name": "childToParentVolume",
+            "type": {
+              "defined": "VolumeStatsV0"
+            }
+          },
+          {
+            "name": "commitRevealSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "yieldStrategy",
+            "type": {
+              "option": {
+                "defined": "YieldStrategyV0"
+              }
+            }
+          },
+          {
+            "name": "rateBand",
+            "type": {
+              "option": {
+                "defined": "RateBandV0"
+              }
+            }
+          },
+          {
+            "name": "accruedChildFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "accruedParentFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeDistribution",
+            "type": {
+              "option": {
+                "defined": "FeeDistributionV0"
+              }
+            }
+          },
+          {
+            "name": "drawdownLimit",
+            "type": {
+              "option": {
+                "defined": "DrawdownLimitV0"
+              }
+            }
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "reclaimedTopOffAmount",
+            "type": "u64"
+          },
+          {
+            "name": "recordedParentToChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "recordedChildToParentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "queuedSwapCount",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                12
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalAmount",
+            "type": "u64"
+          },
+          {
+            "name": "claimedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapRecordV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentToChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "childToParentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lastParentToChildUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "refundableParentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "refundableChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "rebatableFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeRebateClaimV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swapRecord",
+            "type": "publicKey"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "StrategyProgramV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program",
+            "type": "publicKey"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapCommitV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "committedAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapQueueV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "nextTicket",
+            "type": "u64"
+          },
+          {
+            "name": "nextExecute",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueuedSwapV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "ticket",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChild",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "escrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "destination",
+            "type": "publicKey"
+          },
+          {
+            "name": "refundAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingSettlementV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "settler",
+            "type": "publicKey"
+          },
+          {
+            "name": "timeoutSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "nextTicket",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettlementEntryV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pendingSettlement",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "ticket",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChild",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reference",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "escrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "refundAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapLockV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "lockStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "lockedAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OfferV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "maker",
+            "type": "publicKey"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "askMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "askAmount",
+            "type": "u64"
+          },
+          {
+            "name": "escrow",
+            "type": "publicKey"
+          },
+          {
+            "name": "expiryUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "escrowBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateScheduleV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "RateScheduleEntryV0"
+              }
+            }
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SnapshotHistoryV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "SnapshotEntryV0"
+              }
+            }
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TopOffRecordV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "depositor",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reclaimed",
+            "type": "bool"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "rewardPerTokenPaid",
+            "type": "u128"
+          },
+          {
+            "name": "rewardsOwed",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RewardsPoolV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardPerSecond",
+            "type": "u64"
+          },
+          {
+            "name": "startUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "endUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "rewardPerTokenStored",
+            "type": "u128"
+          },
+          {
+            "name": "lastUpdateUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "totalStaked",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EntanglerLookupV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "NamespaceLookupV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "namespace",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuthorityConfigV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "signers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChildRegistryV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "page",
+            "type": "u16"
+          },
+          {
+            "name": "numEntries",
+            "type": "u16"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
+          },
+          {
+            "name": "children",
+            "type": {
+              "array": [
+                "publicKey",
+                128
+              ]
+            }
+          },
+          {
+            "name": "weightsBps",
+            "type": {
+              "array": [
+                "u16",
+                128
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "WrappedMintV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "underlyingMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrappedMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "underlyingStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "transferFeeBasisPoints",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "clawbackAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleParentEntanglerV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "numChildren",
+            "type": "u32"
+          },
+          {
+            "name": "authority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "dynamicSeed",
+            "type": "bytes"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleChildEntanglerV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "childMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "childStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "CompressedProof",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "a",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "b",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "c",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueIndex",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "queueId",
+            "type": "u8"
+          },
+          {
+            "name": "index",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PackedMerkleContext",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleTreePubkeyIndex",
+            "type": "u8"
+          },
+          {
+            "name": "nullifierQueuePubkeyIndex",
+            "type": "u8"
+          },
+          {
+            "name": "leafIndex",
+            "type": "u32"
+          },
+          {
+            "name": "queueIndex",
+            "type": {
+              "option": {
+                "defined": "QueueIndex"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InputTokenDataWithContext",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "delegateIndex",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "merkleContext",
+            "type": {
+              "defined": "PackedMerkleContext"
+            }
+          },
+          {
+            "name": "rootIndex",
+            "type": "u16"
+          },
+          {
+            "name": "lamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "tlv",
+            "type": {
+              "option": "bytes"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PackedTokenTransferOutputData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "lamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "merkleTreeIndex",
+            "type": "u8"
+          },
+          {
+            "name": "tlv",
+            "type": {
+              "option": "bytes"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DelegatedTransfer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegateChangeAccountIndex",
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedCpiContext",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "setContext",
+            "type": "bool"
+          },
+          {
+            "name": "firstSetContext",
+            "type": "bool"
+          },
+          {
+            "name": "cpiContextAccountIndex",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proof",
+            "type": {
+              "option": {
+                "defined": "CompressedProof"
+              }
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegatedTransfer",
+            "type": {
+              "option": {
+                "defined": "DelegatedTransfer"
+              }
+            }
+          },
+          {
+            "name": "inputTokenDataWithContext",
+            "type": {
+              "vec": {
+                "defined": "InputTokenDataWithContext"
+              }
+            }
+          },
+          {
+            "name": "outputCompressedAccounts",
+            "type": {
+              "vec": {
+                "defined": "PackedTokenTransferOutputData"
+              }
+            }
+          },
+          {
+            "name": "isCompress",
+            "type": "bool"
+          },
+          {
+            "name": "compressOrDecompressAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cpiContext",
+            "type": {
+              "option": {
+                "defined": "CompressedCpiContext"
+              }
+            }
+          },
+          {
+            "name": "lamportsChangeAccountMerkleTreeIndex",
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InvokeAsAuthorityConfigV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signerCount",
+            "type": "u8"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetAuthorityConfigV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signers",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommitSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevealAndSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChild",
+            "type": "bool"
+          },
+          {
+            "name": "salt",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeSwapRecordTreeV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxDepth",
+            "type": "u32"
+          },
+          {
+            "name": "maxBufferSize",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RefundCompressedSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "record",
+            "type": {
+              "defined": "CompressedSwapRecordV0"
+            }
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapCompressedTokenV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "allowPartial",
+            "type": "bool"
+          },
+          {
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "proof",
+            "type": {
+              "option": {
+                "defined": "CompressedProof"
+              }
+            }
+          },
+          {
+            "name": "inputTokenAccounts",
+            "type": {
+              "vec": {
+                "defined": "InputTokenDataWithContext"
+              }
+            }
+          },
+          {
+            "name": "outputMerkleTreeIndex",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VersionV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programVersion",
+            "type": "string"
+          },
+          {
+            "name": "gitCommit",
+            "type": "string"
+          },
+          {
+            "name": "rustcVersion",
+            "type": "string"
+          },
+          {
+            "name": "entanglerVersion",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeChildMintV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entanglerArgs",
+            "type": {
+              "defined": "InitializeFungibleChildEntanglerV0Args"
+            }
+          },
+          {
+            "name": "freezeAuthority",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeChildRegistryV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "page",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeEntanglerWithChildrenV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentArgs",
+            "type": {
+              "defined": "InitializeFungibleParentEntanglerV0Args"
+            }
+          },
+          {
+            "name": "childArgs",
+            "type": {
+              "vec": {
+                "defined": "InitializeFungibleChildEntanglerV0Args"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeFungibleChildEntanglerV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "vesting",
+            "type": {
+              "option": {
+                "defined": "VestingConfigV0"
+              }
+            }
+          },
+          {
+            "name": "refundWindowSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "expiryUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "minLockSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "burn",
+            "type": {
+              "option": {
+                "defined": "BurnConfigV0"
+              }
+            }
+          },
+          {
+            "name": "rentRefund",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "rate",
+            "type": {
+              "option": {
+                "defined": "ExchangeRateV0"
+              }
+            }
+          },
+          {
+            "name": "swapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "maxParentToChildAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cpiPolicy",
+            "type": {
+              "defined": "CpiPolicyV0"
+            }
+          },
+          {
+            "name": "cpiAllowlist",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "rejectFreezeAuthority",
+            "type": "bool"
+          },
+          {
+            "name": "maxChildToParentAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "stakeProgram",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "liquidityProgram",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTierV0"
+              }
+            }
+          },
+          {
+            "name": "reverseRebateSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeParentToChildUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeChildToParentUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "commitRevealSeconds",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeFungibleParentEntanglerV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "dynamicSeed",
+            "type": "bytes"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "rentRefund",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "rejectFreezeAuthority",
+            "type": "bool"
+          },
+          {
+            "name": "defaultRate",
+            "type": {
+              "option": {
+                "defined": "ExchangeRateV0"
+              }
+            }
+          },
+          {
+            "name": "freezeParentToChildUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeChildToParentUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeGlobalConfigV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeDestination",
+            "type": "publicKey"
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeRewardsPoolV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardPerSecond",
+            "type": "u64"
+          },
+          {
+            "name": "startUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "endUnixTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LockV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ImportLegacyEntanglerV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dynamicSeed",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MirrorChildMetadataV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nameSuffix",
+            "type": "string"
+          },
+          {
+            "name": "symbolSuffix",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InvokeAsNftAuthorityV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateOfferV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "taker",
+            "type": "publicKey"
+          },
+          {
+            "name": "offerAmount",
+            "type": "u64"
+          },
+          {
+            "name": "askAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expiryUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "QueueSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChild",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AppendRateScheduleV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entry",
+            "type": {
+              "defined": "RateScheduleEntryV0"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRateScheduleV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "RateScheduleEntryV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebalanceBetweenEntanglersV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "childAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveChildRegistryV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ResizeEntanglerV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newSize",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetAuthorityV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newAuthority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DrawdownLimitArgsV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "maxChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxParentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetDrawdownLimitV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "drawdownLimit",
+            "type": {
+              "option": {
+                "defined": "DrawdownLimitArgsV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetFeesV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swapFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "feeTiers",
+            "type": {
+              "vec": {
+                "defined": "FeeTierV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetFreezeTimeV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeParentToChildUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeChildToParentUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetGlobalPauseV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetParentFreezeTimeV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeParentToChildUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "freezeChildToParentUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRateBandV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateBand",
+            "type": {
+              "option": {
+                "defined": "RateBandV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRateV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rate",
+            "type": {
+              "defined": "ExchangeRateV0"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetRegistryWeightsV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "weightsBps",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetWithdrawalsFrozenUntilV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "withdrawalsFrozenUntil",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DeferSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChild",
+            "type": "bool"
+          },
+          {
+            "name": "reference",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPendingSettlementV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settler",
+            "type": "publicKey"
+          },
+          {
+            "name": "timeoutSeconds",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebalanceStrategyV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deposit",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetYieldStrategyV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keeper",
+            "type": "publicKey"
+          },
+          {
+            "name": "minLiquidBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "all",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "outputSplitsBps",
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "memo",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "allowPartial",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapFillV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "outputAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RefundSwapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapAndProvideLiquidityV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swap",
+            "type": {
+              "defined": "SwapV0Args"
+            }
+          },
+          {
+            "name": "depositData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapParentForBasketV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "minOutputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapAndStakeV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swap",
+            "type": {
+              "defined": "SwapV0Args"
+            }
+          },
+          {
+            "name": "stakeData",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TopOffChildStorageV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferChildStorageArgsV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferParentStorageArgsV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGlobalConfigV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "feeDestination",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateMetadataV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitValidationV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentIssues",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "childIssues",
+            "type": {
+              "vec": {
+                "vec": "u32"
+              }
+            }
+          },
+          {
+            "name": "parentDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "childDecimals",
+            "type": "bytes"
+          },
+          {
+            "name": "creationFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "rentLamports",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClawbackV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UnwrapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferFeeArgsV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "basisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "maximumFee",
+            "type": "u64"
+          },
+          {
+            "name": "feeAuthority",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WrapMintV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "transferFee",
+            "type": {
+              "option": {
+                "defined": "TransferFeeArgsV0"
+              }
+            }
+          },
+          {
+            "name": "clawbackAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "WrapV0Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VolumeStatsV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "emaAmount",
+            "type": "u64"
+          },
+          {
+            "name": "emaUpdatedUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "windowAmount",
+            "type": "u64"
+          },
+          {
+            "name": "windowSwapCount",
+            "type": "u32"
+          },
+          {
+            "name": "windowStartUnixTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DrawdownLimitV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "maxChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxParentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "windowStartUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "childAmount",
+            "type": "u64"
+          },
+          {
+            "name": "parentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeDistributionV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "parentFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "parentToChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "childToParentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "claimedChildFeeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "claimedParentFeeAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "YieldStrategyV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program",
+            "type": "publicKey"
+          },
+          {
+            "name": "keeper",
+            "type": "publicKey"
+          },
+          {
+            "name": "minLiquidBps",
+            "type": "u16"
+          },
+          {
+            "name": "depositedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExchangeRateV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentUnits",
+            "type": "u64"
+          },
+          {
+            "name": "childUnits",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateBandV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minRate",
+            "type": {
+              "defined": "ExchangeRateV0"
+            }
+          },
+          {
+            "name": "maxRate",
+            "type": {
+              "defined": "ExchangeRateV0"
+            }
+          },
+          {
+            "name": "fallbackRate",
+            "type": {
+              "option": {
+                "defined": "ExchangeRateV0"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeTierV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BurnConfigV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bps",
+            "type": "u16"
+          },
+          {
+            "name": "side",
+            "type": {
+              "defined": "BurnSideV0"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VestingConfigV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "endUnixTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompressedSwapRecordV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "childEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentToChild",
+            "type": "bool"
+          },
+          {
+            "name": "parentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "childAmount",
+            "type": "u64"
+          },
+          {
+            "name": "unixTime",
+            "type": "i64"
+          },
+          {
+            "name": "refunded",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateScheduleEntryV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "effectiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "rate",
+            "type": {
+              "defined": "ExchangeRateV0"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SnapshotEntryV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "unixTime",
+            "type": "i64"
+          },
+          {
+            "name": "parentStorageBalance",
+            "type": "u64"
+          },
+          {
+            "name": "childStorageBalance",
+            "type": "u64"
+          },
+          {
+            "name": "totalParentToChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "totalChildToParentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleParentEntanglerV1Version1",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "parentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "numChildren",
+            "type": "u32"
+          },
+          {
+            "name": "nextChildIndex",
+            "type": "u32"
+          },
+          {
+            "name": "authority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "dynamicSeed",
+            "type": "bytes"
+          },
+          {
+            "name": "dynamicSeedHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "rentRefund",
+            "type": "publicKey"
+          },
+          {
+            "name": "legacySeeds",
+            "type": "bool"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u64",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FungibleChildEntanglerV1Version1",
+      "type": {
+        "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "parentEntangler",
+            "type": "publicKey"
+          },
+          {
+            "name": "childMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "childStorage",
+            "type": "publicKey"
+          },
+          {
+            "name": "goLiveUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "freezeSwapUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "createdAtUnixTime",
+            "type": "i64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "vesting",
+            "type": {
+              "option": {
+                "defined": "VestingConfigV0"
+              }
+            }
+          },
+          {
+            "name": "refundWindowSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "expiryUnixTime",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "totalTopOffAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reclaimSnapshotAmount",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "rewardsPool",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "minLockSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "burn",
+            "type": {
+              "option": {
+                "defined": "BurnConfigV0"
+              }
+            }
+          },
+          {
+            "name": "rentRefund",
+            "type": "publicKey"
+          },
+          {
+            "name": "rate",
             "type": {
-              "option": "publicKey"
+              "defined": "ExchangeRateV0"
             }
           },
           {
-            "name": "dynamicSeed",
-            "type": "bytes"
+            "name": "swapFeeBps",
+            "type": "u16"
           },
           {
-            "name": "goLiveUnixTime",
-            "type": "i64"
+            "name": "maxParentToChildAmount",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "freezeSwapUnixTime",
+            "name": "totalParentToChildAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "storageBumpSeed",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
             "type": {
-              "option": "i64"
+              "array": [
+                "u64",
+                16
+              ]
             }
           }
         ]
       }
     },
     {
-      "name": "SwapV0Args",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "all",
-            "type": {
-              "option": "bool"
-            }
-          }
-        ]
-      }
+      "name": "MetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapDirection",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ParentToChild"
+          },
+          {
+            "name": "ChildToParent"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Rounding",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Down"
+          },
+          {
+            "name": "Up"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CpiPolicyV0",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Any"
+          },
+          {
+            "name": "Deny"
+          },
+          {
+            "name": "Allowlist"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BurnSideV0",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Input"
+          },
+          {
+            "name": "Output"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "SwapEventV0",
+      "fields": [
+        {
+          "name": "parentEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "childEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sourceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parentToChild",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "feeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unixTime",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ParentEntanglerCreatedV0",
+      "fields": [
+        {
+          "name": "parentEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "parentMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChildEntanglerCreatedV0",
+      "fields": [
+        {
+          "name": "parentEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "childEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "childMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChildEntanglerFrozenV0",
+      "fields": [
+        {
+          "name": "parentEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "childEntangler",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChildEntanglerClosedV0",
+      "fields": [
+        {
+          "name": "parentEntangler",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "childEntangler",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ParentEntanglerClosedV0",
+      "fields": [
+        {
+          "name": "parentEntangler",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidAmount",
+      "msg": "Invalid amount"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidAuthority",
+      "msg": "Invalid Authority"
+    },
+    {
+      "code": 6002,
+      "name": "TokenAccountAmountTooLow",
+      "msg": "Cannot swap more than the source account holds or has delegated to the signer"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidArgs",
+      "msg": "Amount or All must be provided"
+    },
+    {
+      "code": 6004,
+      "name": "ParentNotLiveYet",
+      "msg": "This parent entangler is not live yet"
+    },
+    {
+      "code": 6005,
+      "name": "ChildNotLiveYet",
+      "msg": "This child entangler is not live yet"
+    },
+    {
+      "code": 6006,
+      "name": "ParentSwapFrozen",
+      "msg": "Swap is frozen on the parent entangler, swapping not allowed"
+    },
+    {
+      "code": 6007,
+      "name": "ChildSwapFrozen",
+      "msg": "Swap is frozen on the child entangler, swapping not allowed"
+    },
+    {
+      "code": 6008,
+      "name": "NoAuthority",
+      "msg": "This entangler has no authority on it"
+    },
+    {
+      "code": 6009,
+      "name": "ChildrenRemaining",
+      "msg": "Cannot close a parent entangler while it still has children"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidRegistry",
+      "msg": "This registry does not belong to the given parent entangler"
+    },
+    {
+      "code": 6011,
+      "name": "RegistryFull",
+      "msg": "This registry page is full, initialize the next page"
+    },
+    {
+      "code": 6012,
+      "name": "ChildAlreadyRegistered",
+      "msg": "This child entangler is already in the registry"
+    },
+    {
+      "code": 6013,
+      "name": "ChildNotInRegistry",
+      "msg": "This child entangler is not in the registry"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidDynamicSeed",
+      "msg": "Dynamic seed must be between 1 and 64 bytes"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidStringLength",
+      "msg": "Invalid string length, your string was likely too long"
+    },
+    {
+      "code": 6016,
+      "name": "ChildMintNotControlled",
+      "msg": "The child entangler must be the mint authority of the child mint"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidTransferFee",
+      "msg": "Transfer fee basis points cannot exceed 10000"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidVestingSchedule",
+      "msg": "Vesting end must be after vesting start"
+    },
+    {
+      "code": 6019,
+      "name": "VestingRequired",
+      "msg": "This child entangler vests swap output, use swap_parent_for_child_vested_v0"
+    },
+    {
+      "code": 6020,
+      "name": "NoVesting",
+      "msg": "This child entangler does not vest swap output"
+    },
+    {
+      "code": 6021,
+      "name": "NoRefundWindow",
+      "msg": "This child entangler does not allow refunds"
+    },
+    {
+      "code": 6022,
+      "name": "RefundWindowClosed",
+      "msg": "The refund window for this swap has closed"
+    },
+    {
+      "code": 6023,
+      "name": "Expired",
+      "msg": "This child entangler has expired"
+    },
+    {
+      "code": 6024,
+      "name": "NotExpired",
+      "msg": "This child entangler has not expired yet"
+    },
+    {
+      "code": 6025,
+      "name": "AlreadyReclaimed",
+      "msg": "This deposit has already been reclaimed"
+    },
+    {
+      "code": 6026,
+      "name": "MissingRewardsPool",
+      "msg": "This child entangler has rewards, pass its rewards pool as the first remaining account"
+    },
+    {
+      "code": 6027,
+      "name": "RewardsPoolExists",
+      "msg": "This child entangler already has a rewards pool"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidRewardsSchedule",
+      "msg": "Rewards end must be after rewards start"
+    },
+    {
+      "code": 6029,
+      "name": "LockRequired",
+      "msg": "This child entangler requires locking parent tokens first, use lock_v0 and swap_locked_v0"
+    },
+    {
+      "code": 6030,
+      "name": "NoLock",
+      "msg": "This child entangler does not use locks"
+    },
+    {
+      "code": 6031,
+      "name": "LockNotElapsed",
+      "msg": "Tokens have not been locked for long enough"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidOutputSplits",
+      "msg": "At most 4 output splits totalling no more than 10000 bps, each with a remaining account"
+    },
+    {
+      "code": 6033,
+      "name": "OutputSplitsNotSupported",
+      "msg": "Output splits are not supported by this instruction"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidBurnBps",
+      "msg": "Burn basis points cannot exceed 10000"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidUpgradeAuthority",
+      "msg": "Only the program upgrade authority may initialize the global config"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidRefundRecipient",
+      "msg": "Rent must be refunded to the rent refund recipient set at init"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidExchangeRate",
+      "msg": "Exchange rate units must be greater than zero"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidSwapFee",
+      "msg": "Swap fee basis points cannot exceed 10000"
+    },
+    {
+      "code": 6039,
+      "name": "SwapCapExceeded",
+      "msg": "Swap would exceed the child's parent to child cap"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidDataIncrease",
+      "msg": "The realloc increase was too large"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidMigration",
+      "msg": "Legacy child entangler does not belong to this parent or mint"
     },
     {
-      "name": "TransferChildStorageArgsV0",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
+      "code": 6042,
+      "name": "InvalidResize",
+      "msg": "Entanglers can only grow"
     },
     {
-      "name": "TransferParentStorageArgsV0",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
+      "code": 6043,
+      "name": "UnsupportedVersion",
+      "msg": "Entangler account version is not supported by this program"
+    },
     {
-      "code": 6000,
-      "name": "InvalidAmount",
-      "msg": "Invalid amount"
+      "code": 6044,
+      "name": "MathOverflow",
+      "msg": "Math overflow"
     },
     {
-      "code": 6001,
-      "name": "InvalidAuthority",
-      "msg": "Invalid Authority"
+      "code": 6045,
+      "name": "MathUnderflow",
+      "msg": "Math underflow"
     },
     {
-      "code": 6002,
-      "name": "TokenAccountAmountTooLow",
-      "msg": "Cannot swap more than the token account currently has"
+      "code": 6046,
+      "name": "DivideByZero",
+      "msg": "Division by zero"
     },
     {
-      "code": 6003,
-      "name": "InvalidArgs",
-      "msg": "Amount or All must be provided"
+      "code": 6047,
+      "name": "CastOverflow",
+      "msg": "Value does not fit in a u64"
     },
     {
-      "code": 6004,
-      "name": "ParentNotLiveYet",
-      "msg": "This parent entangler is not live yet"
+      "code": 6048,
+      "name": "FreezeNotDue",
+      "msg": "Neither this child nor its parent has reached its freeze time"
     },
     {
-      "code": 6005,
-      "name": "ChildNotLiveYet",
-      "msg": "This child entangler is not live yet"
+      "code": 6049,
+      "name": "AlreadyFrozen",
+      "msg": "This child entangler is already frozen"
     },
     {
-      "code": 6006,
-      "name": "ParentSwapFrozen",
-      "msg": "Swap is frozen on the parent entangler, swapping not allowed"
+      "code": 6050,
+      "name": "GloballyPaused",
+      "msg": "Swaps are paused program wide"
     },
     {
-      "code": 6007,
-      "name": "ChildSwapFrozen",
-      "msg": "Swap is frozen on the child entangler, swapping not allowed"
+      "code": 6051,
+      "name": "CpiNotAllowed",
+      "msg": "This child entangler does not allow swaps via CPI from the calling program"
     },
     {
-      "code": 6008,
-      "name": "NoAuthority",
-      "msg": "This entangler has no authority on it"
+      "code": 6052,
+      "name": "InvalidCpiAllowlist",
+      "msg": "At most 4 programs may be allowlisted for CPI"
+    },
+    {
+      "code": 6053,
+      "name": "SourceAccountFrozen",
+      "msg": "The source token account is frozen"
+    },
+    {
+      "code": 6054,
+      "name": "DestinationAccountFrozen",
+      "msg": "The destination token account is frozen"
+    },
+    {
+      "code": 6055,
+      "name": "StorageAccountFrozen",
+      "msg": "Entangler storage is frozen by the mint's freeze authority"
+    },
+    {
+      "code": 6056,
+      "name": "MintHasFreezeAuthority",
+      "msg": "Mint has an active freeze authority"
+    },
+    {
+      "code": 6057,
+      "name": "ParentEntanglerMismatch",
+      "msg": "Child entangler does not belong to this parent entangler"
+    },
+    {
+      "code": 6058,
+      "name": "ChildEntanglerMismatch",
+      "msg": "Account does not belong to this child entangler"
+    },
+    {
+      "code": 6059,
+      "name": "ParentMintMismatch",
+      "msg": "Parent mint does not match the parent entangler"
+    },
+    {
+      "code": 6060,
+      "name": "ChildMintMismatch",
+      "msg": "Child mint does not match the child entangler"
+    },
+    {
+      "code": 6061,
+      "name": "StorageMismatch",
+      "msg": "Storage account does not belong to this entangler"
+    },
+    {
+      "code": 6062,
+      "name": "WrappedMintMismatch",
+      "msg": "Wrapped mint does not match the wrapper"
+    },
+    {
+      "code": 6063,
+      "name": "FeeDestinationMismatch",
+      "msg": "Fee destination does not match the global config"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidOwner",
+      "msg": "Signer does not own this account"
+    },
+    {
+      "code": 6065,
+      "name": "MintNotInitialized",
+      "msg": "Mint is not initialized"
+    },
+    {
+      "code": 6066,
+      "name": "SameMint",
+      "msg": "Parent and child mints must differ"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidProgramData",
+      "msg": "Program data does not belong to this program"
+    },
+    {
+      "code": 6068,
+      "name": "InsufficientStorage",
+      "msg": "Output storage does not hold enough to fill this swap"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidDustDestination",
+      "msg": "Only the parent's authority may sweep dust anywhere but the fee destination"
+    },
+    {
+      "code": 6070,
+      "name": "NoDust",
+      "msg": "Storage holds nothing that swaps at the current rate can't reach"
+    },
+    {
+      "code": 6071,
+      "name": "ConversionCapExceeded",
+      "msg": "Swap would exceed the child's child to parent conversion cap"
+    },
+    {
+      "code": 6072,
+      "name": "InvalidOfferMints",
+      "msg": "Offer must trade the parent mint for the child mint or the reverse"
+    },
+    {
+      "code": 6073,
+      "name": "OfferExpired",
+      "msg": "This offer has expired"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidStakeProgram",
+      "msg": "This child entangler does not stake through this program"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidLiquidityProgram",
+      "msg": "This child entangler does not provide liquidity through this program"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidDelegate",
+      "msg": "Signer is neither the owner nor the delegate of the source account"
+    },
+    {
+      "code": 6077,
+      "name": "InvalidFeeTiers",
+      "msg": "At most 4 fee tiers, sorted by strictly increasing min amount, each at most 10000 bps"
+    },
+    {
+      "code": 6078,
+      "name": "InvalidRateSchedule",
+      "msg": "At most 8 rate schedule entries, sorted by strictly increasing effective time, with valid rates"
+    },
+    {
+      "code": 6079,
+      "name": "InvalidSwapRecordTree",
+      "msg": "Merkle tree is not this child's swap record tree, or the child already has one"
+    },
+    {
+      "code": 6080,
+      "name": "InvalidCompressedRecord",
+      "msg": "Compressed swap record is not a refundable parent to child swap of the signer"
+    },
+    {
+      "code": 6081,
+      "name": "AccountAlreadyInitialized",
+      "msg": "Account to create already exists"
+    },
+    {
+      "code": 6082,
+      "name": "InvalidChildAccounts",
+      "msg": "Remaining accounts must be child mint, entangler, storage and lookup for each child, up to 4 children"
+    },
+    {
+      "code": 6083,
+      "name": "ChildEntanglerArgsMismatch",
+      "msg": "Child entangler already exists with different parameters"
+    },
+    {
+      "code": 6084,
+      "name": "SnapshotTooSoon",
+      "msg": "A snapshot was already taken this epoch"
+    },
+    {
+      "code": 6085,
+      "name": "NoCommitReveal",
+      "msg": "Child does not use commit reveal swaps"
+    },
+    {
+      "code": 6086,
+      "name": "CommitRevealOnly",
+      "msg": "Only committed swaps revealed through RevealAndSwapV0 may swap during the commit reveal window"
+    },
+    {
+      "code": 6087,
+      "name": "CommitWindowClosed",
+      "msg": "Swaps may only be committed before the child goes live"
+    },
+    {
+      "code": 6088,
+      "name": "InvalidReveal",
+      "msg": "Revealed swap does not match the commitment"
+    },
+    {
+      "code": 6089,
+      "name": "InvalidNftMint",
+      "msg": "Authority NFT mint must have zero decimals and a supply of one"
+    },
+    {
+      "code": 6090,
+      "name": "NoNftAuthority",
+      "msg": "Parent entangler authority is not held by an NFT"
+    },
+    {
+      "code": 6091,
+      "name": "NotNftHolder",
+      "msg": "Signer does not hold the authority NFT"
+    },
+    {
+      "code": 6092,
+      "name": "InvalidLegacyEntangler",
+      "msg": "Legacy entangler is not owned by the original program, does not match, or this program is the original and should use the migrate instructions"
+    },
+    {
+      "code": 6093,
+      "name": "SlippageExceeded",
+      "msg": "Swap output is below the minimum amount out"
+    },
+    {
+      "code": 6094,
+      "name": "InvalidStrategy",
+      "msg": "Strategy program is not approved, or differs from the one holding the child's deposits"
+    },
+    {
+      "code": 6095,
+      "name": "StrategyLiquidityBuffer",
+      "msg": "Deposit would leave child storage below the strategy's liquid share"
+    },
+    {
+      "code": 6096,
+      "name": "InvalidStrategyTransfer",
+      "msg": "Strategy instruction moved a different amount than requested"
+    },
+    {
+      "code": 6097,
+      "name": "StrategyNotEmpty",
+      "msg": "Withdraw the strategy's deposits first"
+    },
+    {
+      "code": 6098,
+      "name": "InvalidBasketWeights",
+      "msg": "Basket weights must cover every registry entry and sum to 10000, or all be zero"
+    },
+    {
+      "code": 6099,
+      "name": "NoBasket",
+      "msg": "Registry has no basket, set its weights first"
+    },
+    {
+      "code": 6100,
+      "name": "RateOutOfBand",
+      "msg": "Rate the child or its rate schedule sets is outside the child's rate band"
+    },
+    {
+      "code": 6101,
+      "name": "InvalidRateBand",
+      "msg": "Rate schedule band bounds must be valid rates with min at most max, and any fallback inside them"
+    },
+    {
+      "code": 6102,
+      "name": "QueueClosed",
+      "msg": "Swaps can only be queued before the child goes live"
+    },
+    {
+      "code": 6103,
+      "name": "QueuedSwapOutOfOrder",
+      "msg": "Queued swaps execute in ticket order"
+    },
+    {
+      "code": 6104,
+      "name": "InvalidInputMint",
+      "msg": "Input mint must be the mint the swap direction spends"
+    },
+    {
+      "code": 6105,
+      "name": "WithdrawalsFrozen",
+      "msg": "Storage withdrawals are frozen"
+    },
+    {
+      "code": 6106,
+      "name": "InvalidWithdrawalFreeze",
+      "msg": "Withdrawal freezes can only be extended"
+    },
+    {
+      "code": 6107,
+      "name": "FeesNotDistributable",
+      "msg": "Fees can only be distributed once the child has expired, or is frozen and its parent sealed"
+    },
+    {
+      "code": 6108,
+      "name": "FeesAlreadyDistributing",
+      "msg": "Fees are already being distributed"
+    },
+    {
+      "code": 6109,
+      "name": "FeesNotDistributing",
+      "msg": "Fees are not being distributed"
+    },
+    {
+      "code": 6110,
+      "name": "InvalidAuthorityConfig",
+      "msg": "Authority configs need distinct signers, at most the max, and a threshold between one and their count"
+    },
+    {
+      "code": 6111,
+      "name": "NoAuthorityConfig",
+      "msg": "The parent's authority is not its authority config"
+    },
+    {
+      "code": 6112,
+      "name": "ThresholdNotMet",
+      "msg": "Not enough authority config signers approved"
+    },
+    {
+      "code": 6113,
+      "name": "InvalidSettlementTimeout",
+      "msg": "Settlement timeouts must be positive"
+    },
+    {
+      "code": 6114,
+      "name": "SettlementTimedOut",
+      "msg": "The settlement entry timed out and can only be refunded"
+    },
+    {
+      "code": 6115,
+      "name": "SettlementNotTimedOut",
+      "msg": "The settlement entry has not timed out yet"
+    },
+    {
+      "code": 6116,
+      "name": "PendingSettlementMismatch",
+      "msg": "Settlement entry does not belong to this pending settlement"
+    },
+    {
+      "code": 6117,
+      "name": "DrawdownLimitExceeded",
+      "msg": "Swap would take more out of storage than the drawdown limit allows this window"
+    },
+    {
+      "code": 6118,
+      "name": "InvalidDrawdownLimit",
+      "msg": "Drawdown limit windows must be positive"
+    },
+    {
+      "code": 6119,
+      "name": "UnsupportedMint",
+      "msg": "Mint is not owned by the SPL token program, token extensions are not supported"
+    },
+    {
+      "code": 6120,
+      "name": "InvalidTimestampOrder",
+      "msg": "Freeze and expiry times must come after go live"
+    },
+    {
+      "code": 6121,
+      "name": "DecimalsMismatch",
+      "msg": "Mints have different decimals but the rate is one raw unit to one"
+    },
+    {
+      "code": 6122,
+      "name": "InvalidRebalancePair",
+      "msg": "Rebalances need two distinct entanglers of the same parent and child mints"
+    },
+    {
+      "code": 6123,
+      "name": "NotVersion1",
+      "msg": "Entangler is not a version 1 layout"
+    },
+    {
+      "code": 6124,
+      "name": "SwapRecordRequired",
+      "msg": "Child tracks swaps, create the swap record via InitializeSwapRecordV0 first"
+    },
+    {
+      "code": 6125,
+      "name": "SwapRecordNotTracked",
+      "msg": "Child does not track swaps, it needs no swap record"
+    },
+    {
+      "code": 6126,
+      "name": "TopOffReclaimPending",
+      "msg": "Child storage is owed to TopOff depositors until they reclaim it"
+    },
+    {
+      "code": 6127,
+      "name": "RegistryAlreadyMigrated",
+      "msg": "Registry page already has basket weights"
+    },
+    {
+      "code": 6128,
+      "name": "FeeRebatesPending",
+      "msg": "Parent storage holds fees owed to swappers as rebates"
+    },
+    {
+      "code": 6129,
+      "name": "InvalidSwapRecord",
+      "msg": "Account is not the source authority's swap record for the child"
+    },
+    {
+      "code": 6130,
+      "name": "NotPermanentDelegate",
+      "msg": "Wrapped mint does not make the wrapper its permanent delegate"
+    },
+    {
+      "code": 6131,
+      "name": "InsufficientCompressedTokens",
+      "msg": "Compressed token inputs hold less than the swap amount"
+    },
+    {
+      "code": 6132,
+      "name": "StrategyAccountNotAllowed",
+      "msg": "Strategy programs may only be handed the child's storage of its token accounts"
+    },
+    {
+      "code": 6133,
+      "name": "QueuedSwapsPending",
+      "msg": "Swaps open once every queued swap of the child has executed"
     }
   ],
   "metadata": {
     "address": "fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB"
   }
-};
-export type FungibleEntanglerIDL = {"version":"1.0.0","name":"fungible_entangler","instructions":[{"name":"initializeFungibleParentEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"entangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeFungibleParentEntanglerV0Args"}}]},{"name":"initializeFungibleChildEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeFungibleChildEntanglerV0Args"}}]},{"name":"swapParentForChildV0","accounts":[{"name":"common","accounts":[{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"swapChildForParentV0","accounts":[{"name":"common","accounts":[{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"clock","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"closeFungibleChildEntanglerV0","accounts":[{"name":"refund","isMut":true,"isSigner":false},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"closeFungibleParentEntanglerV0","accounts":[{"name":"refund","isMut":true,"isSigner":false},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"transferChildStorageV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":false,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferChildStorageArgsV0"}}]},{"name":"transferParentStorageV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferParentStorageArgsV0"}}]}],"accounts":[{"name":"fungibleParentEntanglerV0","type":{"kind":"struct","fields":[{"name":"parentMint","type":"publicKey"},{"name":"parentStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"numChildren","type":"u32"},{"name":"authority","type":{"option":"publicKey"}},{"name":"dynamicSeed","type":"bytes"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"}]}},{"name":"fungibleChildEntanglerV0","type":{"kind":"struct","fields":[{"name":"parentEntangler","type":"publicKey"},{"name":"childMint","type":"publicKey"},{"name":"childStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"}]}}],"types":[{"name":"InitializeFungibleChildEntanglerV0Args","type":{"kind":"struct","fields":[{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}}]}},{"name":"InitializeFungibleParentEntanglerV0Args","type":{"kind":"struct","fields":[{"name":"authority","type":{"option":"publicKey"}},{"name":"dynamicSeed","type":"bytes"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}}]}},{"name":"SwapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":{"option":"u64"}},{"name":"all","type":{"option":"bool"}}]}},{"name":"TransferChildStorageArgsV0","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"TransferParentStorageArgsV0","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}}],"errors":[{"code":6000,"name":"InvalidAmount","msg":"Invalid amount"},{"code":6001,"name":"InvalidAuthority","msg":"Invalid Authority"},{"code":6002,"name":"TokenAccountAmountTooLow","msg":"Cannot swap more than the token account currently has"},{"code":6003,"name":"InvalidArgs","msg":"Amount or All must be provided"},{"code":6004,"name":"ParentNotLiveYet","msg":"This parent entangler is not live yet"},{"code":6005,"name":"ChildNotLiveYet","msg":"This child entangler is not live yet"},{"code":6006,"name":"ParentSwapFrozen","msg":"Swap is frozen on the parent entangler, swapping not allowed"},{"code":6007,"name":"ChildSwapFrozen","msg":"Swap is frozen on the child entangler, swapping not allowed"},{"code":6008,"name":"NoAuthority","msg":"This entangler has no authority on it"}],"metadata":{"address":"fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB"}};
+}
+;
+export type FungibleEntanglerIDL = {"version":"1.0.0","name":"fungible_entangler","instructions":[{"name":"initializeFungibleParentEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"entangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"feeDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeFungibleParentEntanglerV0Args"}}]},{"name":"initializeFungibleChildEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"lookup","isMut":true,"isSigner":false},{"name":"childMint","isMut":false,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"feeDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeFungibleChildEntanglerV0Args"}}]},{"name":"swapParentForChildV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"swapChildForParentV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"closeFungibleChildEntanglerV0","accounts":[{"name":"refundTo","isMut":true,"isSigner":false},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"lookup","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"closeFungibleParentEntanglerV0","accounts":[{"name":"refundTo","isMut":true,"isSigner":false},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"transferChildStorageV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":false,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferChildStorageArgsV0"}}]},{"name":"transferParentStorageV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TransferParentStorageArgsV0"}}]},{"name":"initializeChildRegistryV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"registry","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeChildRegistryV0Args"}}]},{"name":"appendChildRegistryV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"registry","isMut":true,"isSigner":false}],"args":[]},{"name":"removeChildRegistryV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"registry","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RemoveChildRegistryV0Args"}}]},{"name":"updateMetadataV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateMetadataV0Args"}}]},{"name":"mirrorChildMetadataV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"childMint","isMut":false,"isSigner":false},{"name":"parentMetadata","isMut":false,"isSigner":false},{"name":"childMetadata","isMut":true,"isSigner":false},{"name":"tokenMetadataProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"rent","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"MirrorChildMetadataV0Args"}}]},{"name":"initializeChildMintV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":true},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"lookup","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"feeDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeChildMintV0Args"}}]},{"name":"wrapMintV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"wrapper","isMut":true,"isSigner":false},{"name":"underlyingStorage","isMut":true,"isSigner":false},{"name":"underlyingMint","isMut":false,"isSigner":false},{"name":"wrappedMint","isMut":true,"isSigner":true},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"token2022Program","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"WrapMintV0Args"}}]},{"name":"wrapV0","accounts":[{"name":"wrapper","isMut":false,"isSigner":false},{"name":"underlyingStorage","isMut":true,"isSigner":false},{"name":"wrappedMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"token2022Program","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"WrapV0Args"}}]},{"name":"unwrapV0","accounts":[{"name":"wrapper","isMut":false,"isSigner":false},{"name":"underlyingStorage","isMut":true,"isSigner":false},{"name":"wrappedMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"token2022Program","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UnwrapV0Args"}}]},{"name":"clawbackV0","accounts":[{"name":"clawbackAuthority","isMut":false,"isSigner":true},{"name":"wrapper","isMut":false,"isSigner":false},{"name":"wrappedMint","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"token2022Program","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"ClawbackV0Args"}}]},{"name":"swapParentForChildVestedV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"vestingStorage","isMut":true,"isSigner":false},{"name":"vesting","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"claimVestedV0","accounts":[{"name":"owner","isMut":false,"isSigner":true},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"vestingStorage","isMut":true,"isSigner":false},{"name":"vesting","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"refundSwapV0","accounts":[{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RefundSwapV0Args"}}]},{"name":"topOffChildStorageV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"topOffRecord","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"depositor","isMut":false,"isSigner":true},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"TopOffChildStorageV0Args"}}]},{"name":"reclaimAfterExpiryV0","accounts":[{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"topOffRecord","isMut":true,"isSigner":false},{"name":"depositor","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"initializeRewardsPoolV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"rewardsPool","isMut":true,"isSigner":false},{"name":"rewardStorage","isMut":true,"isSigner":false},{"name":"rewardMint","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeRewardsPoolV0Args"}}]},{"name":"claimRewardsV0","accounts":[{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"rewardsPool","isMut":true,"isSigner":false},{"name":"rewardStorage","isMut":true,"isSigner":false},{"name":"topOffRecord","isMut":true,"isSigner":false},{"name":"depositor","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"lockV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"lock","isMut":true,"isSigner":false},{"name":"lockStorage","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"LockV0Args"}}]},{"name":"swapLockedV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"lock","isMut":false,"isSigner":false},{"name":"lockStorage","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"unlockV0","accounts":[{"name":"refund","isMut":true,"isSigner":false},{"name":"lock","isMut":true,"isSigner":false},{"name":"lockStorage","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"initializeGlobalConfigV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"upgradeAuthority","isMut":false,"isSigner":true},{"name":"globalConfig","isMut":true,"isSigner":false},{"name":"program","isMut":false,"isSigner":false},{"name":"programData","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeGlobalConfigV0Args"}}]},{"name":"updateGlobalConfigV0","accounts":[{"name":"admin","isMut":false,"isSigner":true},{"name":"globalConfig","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"UpdateGlobalConfigV0Args"}}]},{"name":"migrateParentEntanglerV0ToV1","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"migrateChildEntanglerV0ToV1","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childMint","isMut":false,"isSigner":false},{"name":"lookup","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"resizeEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"ResizeEntanglerV0Args"}}]},{"name":"enforceFreezeV0","accounts":[{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"setGlobalPauseV0","accounts":[{"name":"admin","isMut":false,"isSigner":true},{"name":"globalConfig","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetGlobalPauseV0Args"}}]},{"name":"sweepDustV0","accounts":[{"name":"sweeper","isMut":false,"isSigner":true},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"storage","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"sealEntanglerV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false}],"args":[]},{"name":"createOfferV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"maker","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"offer","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"offerMint","isMut":false,"isSigner":false},{"name":"askMint","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"CreateOfferV0Args"}}]},{"name":"acceptOfferV0","accounts":[{"name":"taker","isMut":false,"isSigner":true},{"name":"maker","isMut":true,"isSigner":false},{"name":"offer","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"makerDestination","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"cancelOfferV0","accounts":[{"name":"maker","isMut":true,"isSigner":true},{"name":"offer","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"swapParentForChildAndStakeV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]},{"name":"stakeProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SwapAndStakeV0Args"}}]},{"name":"swapChildForParentAndProvideLiquidityV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]},{"name":"liquidityProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SwapAndProvideLiquidityV0Args"}}]},{"name":"setRateScheduleV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetRateScheduleV0Args"}}]},{"name":"appendRateScheduleV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"AppendRateScheduleV0Args"}}]},{"name":"setFreezeTimeV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetFreezeTimeV0Args"}}]},{"name":"setParentFreezeTimeV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetParentFreezeTimeV0Args"}}]},{"name":"setFeesV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetFeesV0Args"}}]},{"name":"setRateV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetRateV0Args"}}]},{"name":"setAuthorityV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetAuthorityV0Args"}}]},{"name":"getVersionV0","accounts":[],"args":[]},{"name":"initializeSwapRecordTreeV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"merkleTree","isMut":true,"isSigner":false},{"name":"compressionProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeSwapRecordTreeV0Args"}}]},{"name":"swapParentForChildCompressedV0","accounts":[{"name":"common","accounts":[{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"merkleTree","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"compressionProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"swapChildForParentCompressedV0","accounts":[{"name":"common","accounts":[{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"merkleTree","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"compressionProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapV0Args"}}]},{"name":"refundCompressedSwapV0","accounts":[{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"merkleTree","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"compressionProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RefundCompressedSwapV0Args"}}]},{"name":"swapParentForChildCompressedTokenV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"recipient","isMut":false,"isSigner":false},{"name":"parentTransit","isMut":true,"isSigner":false},{"name":"childTransit","isMut":true,"isSigner":false},{"name":"parentTokenPool","isMut":true,"isSigner":false},{"name":"childTokenPool","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"cpiAuthorityPda","isMut":false,"isSigner":false},{"name":"registeredProgramPda","isMut":false,"isSigner":false},{"name":"accountCompressionAuthority","isMut":false,"isSigner":false},{"name":"compressedTokenProgram","isMut":false,"isSigner":false},{"name":"lightSystemProgram","isMut":false,"isSigner":false},{"name":"accountCompressionProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapCompressedTokenV0Args"}}]},{"name":"swapChildForParentCompressedTokenV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"owner","isMut":false,"isSigner":true},{"name":"recipient","isMut":false,"isSigner":false},{"name":"parentTransit","isMut":true,"isSigner":false},{"name":"childTransit","isMut":true,"isSigner":false},{"name":"parentTokenPool","isMut":true,"isSigner":false},{"name":"childTokenPool","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"cpiAuthorityPda","isMut":false,"isSigner":false},{"name":"registeredProgramPda","isMut":false,"isSigner":false},{"name":"accountCompressionAuthority","isMut":false,"isSigner":false},{"name":"compressedTokenProgram","isMut":false,"isSigner":false},{"name":"lightSystemProgram","isMut":false,"isSigner":false},{"name":"accountCompressionProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]}],"args":[{"name":"args","type":{"defined":"SwapCompressedTokenV0Args"}}]},{"name":"initializeEntanglerWithChildrenV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"entangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"feeDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeEntanglerWithChildrenV0Args"}}]},{"name":"ensureChildEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"lookup","isMut":true,"isSigner":false},{"name":"childMint","isMut":false,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"feeDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeFungibleChildEntanglerV0Args"}}]},{"name":"snapshotV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"parentStorage","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"childStorage","isMut":false,"isSigner":false},{"name":"snapshotHistory","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"commitSwapV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"owner","isMut":false,"isSigner":true},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"swapCommit","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"CommitSwapV0Args"}}]},{"name":"revealAndSwapV0","accounts":[{"name":"common","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"destination","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}]},{"name":"swapCommit","isMut":true,"isSigner":false},{"name":"payer","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RevealAndSwapV0Args"}}]},{"name":"setNftAuthorityV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"nftMint","isMut":false,"isSigner":false},{"name":"nftAuthority","isMut":false,"isSigner":false}],"args":[]},{"name":"invokeAsNftAuthorityV0","accounts":[{"name":"holder","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"holderNftAccount","isMut":false,"isSigner":false},{"name":"nftAuthority","isMut":false,"isSigner":false},{"name":"entanglerProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InvokeAsNftAuthorityV0Args"}}]},{"name":"importLegacyEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"legacyParentEntangler","isMut":true,"isSigner":false},{"name":"legacyParentStorage","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"legacyProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"ImportLegacyEntanglerV0Args"}}]},{"name":"importLegacyChildEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"legacyParentEntangler","isMut":true,"isSigner":false},{"name":"legacyChildEntangler","isMut":false,"isSigner":false},{"name":"legacyChildStorage","isMut":true,"isSigner":false},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"entangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"lookup","isMut":true,"isSigner":false},{"name":"childMint","isMut":false,"isSigner":false},{"name":"legacyProgram","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"approveStrategyProgramV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"admin","isMut":false,"isSigner":true},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"program","isMut":false,"isSigner":false},{"name":"strategyProgram","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"setYieldStrategyV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"strategyProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetYieldStrategyV0Args"}}]},{"name":"rebalanceStrategyV0","accounts":[{"name":"keeper","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"strategyProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RebalanceStrategyV0Args"}}]},{"name":"setRegistryWeightsV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"registry","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetRegistryWeightsV0Args"}}]},{"name":"swapParentForBasketV0","accounts":[{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"sourceAuthority","isMut":false,"isSigner":true},{"name":"registry","isMut":false,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"instructions","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SwapParentForBasketV0Args"}}]},{"name":"setRateBandV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetRateBandV0Args"}}]},{"name":"queueSwapV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"owner","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"swapQueue","isMut":true,"isSigner":false},{"name":"queuedSwap","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"inputMint","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"destination","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"QueueSwapV0Args"}}]},{"name":"executeQueuedSwapV0","accounts":[{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"swapQueue","isMut":true,"isSigner":false},{"name":"queuedSwap","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"refundAccount","isMut":true,"isSigner":false},{"name":"payer","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"cancelQueuedSwapV0","accounts":[{"name":"owner","isMut":false,"isSigner":true},{"name":"queuedSwap","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"refundAccount","isMut":true,"isSigner":false},{"name":"payer","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"initializeNamespacedParentEntanglerV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"namespace","isMut":false,"isSigner":true},{"name":"entangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":false,"isSigner":false},{"name":"namespaceLookup","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"feeDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeFungibleParentEntanglerV0Args"}}]},{"name":"setWithdrawalsFrozenUntilV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetWithdrawalsFrozenUntilV0Args"}}]},{"name":"distributeFeesV0","accounts":[{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false}],"args":[]},{"name":"claimFeeRebateV0","accounts":[{"name":"owner","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"swapRecord","isMut":false,"isSigner":false},{"name":"feeRebateClaim","isMut":true,"isSigner":false},{"name":"childDestination","isMut":true,"isSigner":false},{"name":"parentDestination","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"setAuthorityConfigV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"authorityConfig","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetAuthorityConfigV0Args"}}]},{"name":"invokeAsAuthorityConfigV0","accounts":[{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"authorityConfig","isMut":false,"isSigner":false},{"name":"entanglerProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InvokeAsAuthorityConfigV0Args"}}]},{"name":"setPendingSettlementV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"pendingSettlement","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetPendingSettlementV0Args"}}]},{"name":"deferSwapV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"owner","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"pendingSettlement","isMut":true,"isSigner":false},{"name":"settlementEntry","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"inputMint","isMut":false,"isSigner":false},{"name":"source","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"DeferSwapV0Args"}}]},{"name":"ackSettlementV0","accounts":[{"name":"settler","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"parentStorage","isMut":true,"isSigner":false},{"name":"parentMint","isMut":true,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"childStorage","isMut":true,"isSigner":false},{"name":"childMint","isMut":true,"isSigner":false},{"name":"pendingSettlement","isMut":false,"isSigner":false},{"name":"settlementEntry","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"destination","isMut":true,"isSigner":false},{"name":"payer","isMut":true,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false},{"name":"rateSchedule","isMut":false,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false},{"name":"memoProgram","isMut":false,"isSigner":false},{"name":"noopProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"refundSettlementV0","accounts":[{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"pendingSettlement","isMut":false,"isSigner":false},{"name":"settlementEntry","isMut":true,"isSigner":false},{"name":"escrow","isMut":true,"isSigner":false},{"name":"refundAccount","isMut":true,"isSigner":false},{"name":"payer","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"setDrawdownLimitV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"parentEntangler","isMut":false,"isSigner":false},{"name":"childEntangler","isMut":true,"isSigner":false}],"args":[{"name":"args","type":{"defined":"SetDrawdownLimitV0Args"}}]},{"name":"validateInitParamsV0","accounts":[{"name":"parentMint","isMut":false,"isSigner":false},{"name":"entangler","isMut":false,"isSigner":false},{"name":"globalConfig","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"InitializeEntanglerWithChildrenV0Args"}}]},{"name":"rebalanceBetweenEntanglersV0","accounts":[{"name":"authority","isMut":false,"isSigner":true},{"name":"sourceParentEntangler","isMut":false,"isSigner":false},{"name":"sourceParentStorage","isMut":true,"isSigner":false},{"name":"sourceChildEntangler","isMut":false,"isSigner":false},{"name":"sourceChildStorage","isMut":true,"isSigner":false},{"name":"destinationParentEntangler","isMut":false,"isSigner":false},{"name":"destinationParentStorage","isMut":true,"isSigner":false},{"name":"destinationChildEntangler","isMut":false,"isSigner":false},{"name":"destinationChildStorage","isMut":true,"isSigner":false},{"name":"tokenProgram","isMut":false,"isSigner":false}],"args":[{"name":"args","type":{"defined":"RebalanceBetweenEntanglersV0Args"}}]},{"name":"migrateParentEntanglerV1ToV2","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"parentEntangler","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"migrateChildEntanglerV1ToV2","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"childEntangler","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"initializeSwapRecordV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"childEntangler","isMut":false,"isSigner":false},{"name":"owner","isMut":false,"isSigner":false},{"name":"swapRecord","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]},{"name":"migrateChildRegistryV0","accounts":[{"name":"payer","isMut":true,"isSigner":true},{"name":"registry","isMut":true,"isSigner":false},{"name":"systemProgram","isMut":false,"isSigner":false}],"args":[]}],"accounts":[{"name":"globalConfigV0","type":{"kind":"struct","fields":[{"name":"admin","type":"publicKey"},{"name":"feeDestination","type":"publicKey"},{"name":"creationFeeLamports","type":"u64"},{"name":"bumpSeed","type":"u8"},{"name":"paused","type":"bool"}]}},{"name":"fungibleParentEntanglerV1","type":{"kind":"struct","fields":[{"name":"version","type":"u16"},{"name":"parentMint","type":"publicKey"},{"name":"parentStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"numChildren","type":"u32"},{"name":"nextChildIndex","type":"u32"},{"name":"authority","type":{"option":"publicKey"}},{"name":"name","type":"string"},{"name":"uri","type":"string"},{"name":"dynamicSeed","type":"bytes"},{"name":"dynamicSeedHash","type":{"array":["u8",32]}},{"name":"rentRefund","type":"publicKey"},{"name":"legacySeeds","type":"bool"},{"name":"defaultRate","type":{"defined":"ExchangeRateV0"}},{"name":"freezeParentToChildUnixTime","type":{"option":"i64"}},{"name":"freezeChildToParentUnixTime","type":{"option":"i64"}},{"name":"authorityNftMint","type":{"option":"publicKey"}},{"name":"namespace","type":{"option":"publicKey"}},{"name":"withdrawalsFrozenUntil","type":{"option":"i64"}},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"},{"name":"reservedParentFeeAmount","type":"u64"},{"name":"reserved","type":{"array":["u64",15]}}]}},{"name":"fungibleChildEntanglerV1","type":{"kind":"struct","fields":[{"name":"version","type":"u16"},{"name":"parentEntangler","type":"publicKey"},{"name":"childMint","type":"publicKey"},{"name":"childStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"index","type":"u32"},{"name":"vesting","type":{"option":{"defined":"VestingConfigV0"}}},{"name":"refundWindowSeconds","type":{"option":"i64"}},{"name":"expiryUnixTime","type":{"option":"i64"}},{"name":"totalTopOffAmount","type":"u64"},{"name":"reclaimSnapshotAmount","type":{"option":"u64"}},{"name":"rewardsPool","type":{"option":"publicKey"}},{"name":"minLockSeconds","type":{"option":"i64"}},{"name":"burn","type":{"option":{"defined":"BurnConfigV0"}}},{"name":"rentRefund","type":"publicKey"},{"name":"rate","type":{"defined":"ExchangeRateV0"}},{"name":"swapFeeBps","type":"u16"},{"name":"maxParentToChildAmount","type":{"option":"u64"}},{"name":"totalParentToChildAmount","type":"u64"},{"name":"frozen","type":"bool"},{"name":"cpiPolicy","type":{"defined":"CpiPolicyV0"}},{"name":"cpiAllowlist","type":{"vec":"publicKey"}},{"name":"maxChildToParentAmount","type":{"option":"u64"}},{"name":"totalChildToParentAmount","type":"u64"},{"name":"stakeProgram","type":{"option":"publicKey"}},{"name":"liquidityProgram","type":{"option":"publicKey"}},{"name":"feeTiers","type":{"vec":{"defined":"FeeTierV0"}}},{"name":"reverseRebateSeconds","type":{"option":"i64"}},{"name":"freezeParentToChildUnixTime","type":{"option":"i64"}},{"name":"freezeChildToParentUnixTime","type":{"option":"i64"}},{"name":"swapRecordTree","type":{"option":"publicKey"}},{"name":"compressedRecordCount","type":"u64"},{"name":"parentToChildVolume","type":{"defined":"VolumeStatsV0"}},{"name":"childToParentVolume","type":{"defined":"VolumeStatsV0"}},{"name":"commitRevealSeconds","type":{"option":"i64"}},{"name":"yieldStrategy","type":{"option":{"defined":"YieldStrategyV0"}}},{"name":"rateBand","type":{"option":{"defined":"RateBandV0"}}},{"name":"accruedChildFeeAmount","type":"u64"},{"name":"accruedParentFeeAmount","type":"u64"},{"name":"feeDistribution","type":{"option":{"defined":"FeeDistributionV0"}}},{"name":"drawdownLimit","type":{"option":{"defined":"DrawdownLimitV0"}}},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"},{"name":"reclaimedTopOffAmount","type":"u64"},{"name":"recordedParentToChildAmount","type":"u64"},{"name":"recordedChildToParentAmount","type":"u64"},{"name":"queuedSwapCount","type":"u64"},{"name":"reserved","type":{"array":["u64",12]}}]}},{"name":"vestingV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"totalAmount","type":"u64"},{"name":"claimedAmount","type":"u64"},{"name":"bumpSeed","type":"u8"}]}},{"name":"swapRecordV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"parentToChildAmount","type":"u64"},{"name":"childToParentAmount","type":"u64"},{"name":"lastParentToChildUnixTime","type":"i64"},{"name":"refundableParentAmount","type":"u64"},{"name":"refundableChildAmount","type":"u64"},{"name":"rebatableFeeAmount","type":"u64"},{"name":"bumpSeed","type":"u8"}]}},{"name":"feeRebateClaimV0","type":{"kind":"struct","fields":[{"name":"swapRecord","type":"publicKey"},{"name":"bumpSeed","type":"u8"}]}},{"name":"strategyProgramV0","type":{"kind":"struct","fields":[{"name":"program","type":"publicKey"},{"name":"bumpSeed","type":"u8"}]}},{"name":"swapCommitV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"commitment","type":{"array":["u8",32]}},{"name":"committedAtUnixTime","type":"i64"},{"name":"payer","type":"publicKey"},{"name":"bumpSeed","type":"u8"}]}},{"name":"swapQueueV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"nextTicket","type":"u64"},{"name":"nextExecute","type":"u64"},{"name":"bumpSeed","type":"u8"}]}},{"name":"queuedSwapV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"ticket","type":"u64"},{"name":"parentToChild","type":"bool"},{"name":"amount","type":"u64"},{"name":"escrow","type":"publicKey"},{"name":"destination","type":"publicKey"},{"name":"refundAccount","type":"publicKey"},{"name":"payer","type":"publicKey"},{"name":"bumpSeed","type":"u8"},{"name":"escrowBumpSeed","type":"u8"}]}},{"name":"pendingSettlementV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"settler","type":"publicKey"},{"name":"timeoutSeconds","type":"i64"},{"name":"nextTicket","type":"u64"},{"name":"bumpSeed","type":"u8"}]}},{"name":"settlementEntryV0","type":{"kind":"struct","fields":[{"name":"pendingSettlement","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"ticket","type":"u64"},{"name":"parentToChild","type":"bool"},{"name":"amount","type":"u64"},{"name":"reference","type":{"array":["u8",32]}},{"name":"escrow","type":"publicKey"},{"name":"refundAccount","type":"publicKey"},{"name":"payer","type":"publicKey"},{"name":"createdAtUnixTime","type":"i64"},{"name":"bumpSeed","type":"u8"},{"name":"escrowBumpSeed","type":"u8"}]}},{"name":"swapLockV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"lockStorage","type":"publicKey"},{"name":"lockedAtUnixTime","type":"i64"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"}]}},{"name":"offerV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"maker","type":"publicKey"},{"name":"taker","type":"publicKey"},{"name":"offerMint","type":"publicKey"},{"name":"offerAmount","type":"u64"},{"name":"askMint","type":"publicKey"},{"name":"askAmount","type":"u64"},{"name":"escrow","type":"publicKey"},{"name":"expiryUnixTime","type":{"option":"i64"}},{"name":"nonce","type":"u64"},{"name":"bumpSeed","type":"u8"},{"name":"escrowBumpSeed","type":"u8"}]}},{"name":"rateScheduleV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"entries","type":{"vec":{"defined":"RateScheduleEntryV0"}}},{"name":"bumpSeed","type":"u8"}]}},{"name":"snapshotHistoryV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"entries","type":{"vec":{"defined":"SnapshotEntryV0"}}},{"name":"bumpSeed","type":"u8"}]}},{"name":"topOffRecordV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"depositor","type":"publicKey"},{"name":"amount","type":"u64"},{"name":"reclaimed","type":"bool"},{"name":"bumpSeed","type":"u8"},{"name":"rewardPerTokenPaid","type":"u128"},{"name":"rewardsOwed","type":"u64"}]}},{"name":"rewardsPoolV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"rewardMint","type":"publicKey"},{"name":"rewardStorage","type":"publicKey"},{"name":"rewardPerSecond","type":"u64"},{"name":"startUnixTime","type":"i64"},{"name":"endUnixTime","type":"i64"},{"name":"rewardPerTokenStored","type":"u128"},{"name":"lastUpdateUnixTime","type":"i64"},{"name":"totalStaked","type":"u64"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"}]}},{"name":"entanglerLookupV0","type":{"kind":"struct","fields":[{"name":"parentEntangler","type":"publicKey"},{"name":"childEntangler","type":"publicKey"},{"name":"bumpSeed","type":"u8"}]}},{"name":"namespaceLookupV0","type":{"kind":"struct","fields":[{"name":"namespace","type":"publicKey"},{"name":"parentEntangler","type":"publicKey"},{"name":"parentMint","type":"publicKey"},{"name":"bumpSeed","type":"u8"}]}},{"name":"authorityConfigV0","type":{"kind":"struct","fields":[{"name":"parentEntangler","type":"publicKey"},{"name":"signers","type":{"vec":"publicKey"}},{"name":"threshold","type":"u8"},{"name":"bumpSeed","type":"u8"}]}},{"name":"childRegistryV0","type":{"kind":"struct","fields":[{"name":"parentEntangler","type":"publicKey"},{"name":"page","type":"u16"},{"name":"numEntries","type":"u16"},{"name":"bumpSeed","type":"u8"},{"name":"padding","type":{"array":["u8",3]}},{"name":"children","type":{"array":["publicKey",128]}},{"name":"weightsBps","type":{"array":["u16",128]}}]}},{"name":"wrappedMintV0","type":{"kind":"struct","fields":[{"name":"underlyingMint","type":"publicKey"},{"name":"wrappedMint","type":"publicKey"},{"name":"underlyingStorage","type":"publicKey"},{"name":"transferFeeBasisPoints","type":{"option":"u16"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"},{"name":"clawbackAuthority","type":{"option":"publicKey"}}]}},{"name":"fungibleParentEntanglerV0","type":{"kind":"struct","fields":[{"name":"parentMint","type":"publicKey"},{"name":"parentStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"numChildren","type":"u32"},{"name":"authority","type":{"option":"publicKey"}},{"name":"dynamicSeed","type":"bytes"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"}]}},{"name":"fungibleChildEntanglerV0","type":{"kind":"struct","fields":[{"name":"parentEntangler","type":"publicKey"},{"name":"childMint","type":"publicKey"},{"name":"childStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"}]}}],"types":[{"name":"CompressedProof","type":{"kind":"struct","fields":[{"name":"a","type":{"array":["u8",32]}},{"name":"b","type":{"array":["u8",64]}},{"name":"c","type":{"array":["u8",32]}}]}},{"name":"QueueIndex","type":{"kind":"struct","fields":[{"name":"queueId","type":"u8"},{"name":"index","type":"u16"}]}},{"name":"PackedMerkleContext","type":{"kind":"struct","fields":[{"name":"merkleTreePubkeyIndex","type":"u8"},{"name":"nullifierQueuePubkeyIndex","type":"u8"},{"name":"leafIndex","type":"u32"},{"name":"queueIndex","type":{"option":{"defined":"QueueIndex"}}}]}},{"name":"InputTokenDataWithContext","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"delegateIndex","type":{"option":"u8"}},{"name":"merkleContext","type":{"defined":"PackedMerkleContext"}},{"name":"rootIndex","type":"u16"},{"name":"lamports","type":{"option":"u64"}},{"name":"tlv","type":{"option":"bytes"}}]}},{"name":"PackedTokenTransferOutputData","type":{"kind":"struct","fields":[{"name":"owner","type":"publicKey"},{"name":"amount","type":"u64"},{"name":"lamports","type":{"option":"u64"}},{"name":"merkleTreeIndex","type":"u8"},{"name":"tlv","type":{"option":"bytes"}}]}},{"name":"DelegatedTransfer","type":{"kind":"struct","fields":[{"name":"owner","type":"publicKey"},{"name":"delegateChangeAccountIndex","type":{"option":"u8"}}]}},{"name":"CompressedCpiContext","type":{"kind":"struct","fields":[{"name":"setContext","type":"bool"},{"name":"firstSetContext","type":"bool"},{"name":"cpiContextAccountIndex","type":"u8"}]}},{"name":"TransferData","type":{"kind":"struct","fields":[{"name":"proof","type":{"option":{"defined":"CompressedProof"}}},{"name":"mint","type":"publicKey"},{"name":"delegatedTransfer","type":{"option":{"defined":"DelegatedTransfer"}}},{"name":"inputTokenDataWithContext","type":{"vec":{"defined":"InputTokenDataWithContext"}}},{"name":"outputCompressedAccounts","type":{"vec":{"defined":"PackedTokenTransferOutputData"}}},{"name":"isCompress","type":"bool"},{"name":"compressOrDecompressAmount","type":{"option":"u64"}},{"name":"cpiContext","type":{"option":{"defined":"CompressedCpiContext"}}},{"name":"lamportsChangeAccountMerkleTreeIndex","type":{"option":"u8"}}]}},{"name":"InvokeAsAuthorityConfigV0Args","type":{"kind":"struct","fields":[{"name":"signerCount","type":"u8"},{"name":"data","type":"bytes"}]}},{"name":"SetAuthorityConfigV0Args","type":{"kind":"struct","fields":[{"name":"signers","type":{"vec":"publicKey"}},{"name":"threshold","type":"u8"}]}},{"name":"CommitSwapV0Args","type":{"kind":"struct","fields":[{"name":"commitment","type":{"array":["u8",32]}}]}},{"name":"RevealAndSwapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"parentToChild","type":"bool"},{"name":"salt","type":{"array":["u8",32]}},{"name":"memo","type":{"option":"string"}}]}},{"name":"InitializeSwapRecordTreeV0Args","type":{"kind":"struct","fields":[{"name":"maxDepth","type":"u32"},{"name":"maxBufferSize","type":"u32"}]}},{"name":"RefundCompressedSwapV0Args","type":{"kind":"struct","fields":[{"name":"root","type":{"array":["u8",32]}},{"name":"record","type":{"defined":"CompressedSwapRecordV0"}},{"name":"index","type":"u32"}]}},{"name":"SwapCompressedTokenV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"allowPartial","type":"bool"},{"name":"memo","type":{"option":"string"}},{"name":"proof","type":{"option":{"defined":"CompressedProof"}}},{"name":"inputTokenAccounts","type":{"vec":{"defined":"InputTokenDataWithContext"}}},{"name":"outputMerkleTreeIndex","type":"u8"}]}},{"name":"VersionV0","type":{"kind":"struct","fields":[{"name":"programVersion","type":"string"},{"name":"gitCommit","type":"string"},{"name":"rustcVersion","type":"string"},{"name":"entanglerVersion","type":"u16"}]}},{"name":"InitializeChildMintV0Args","type":{"kind":"struct","fields":[{"name":"entanglerArgs","type":{"defined":"InitializeFungibleChildEntanglerV0Args"}},{"name":"freezeAuthority","type":"bool"}]}},{"name":"InitializeChildRegistryV0Args","type":{"kind":"struct","fields":[{"name":"page","type":"u16"}]}},{"name":"InitializeEntanglerWithChildrenV0Args","type":{"kind":"struct","fields":[{"name":"parentArgs","type":{"defined":"InitializeFungibleParentEntanglerV0Args"}},{"name":"childArgs","type":{"vec":{"defined":"InitializeFungibleChildEntanglerV0Args"}}}]}},{"name":"InitializeFungibleChildEntanglerV0Args","type":{"kind":"struct","fields":[{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"vesting","type":{"option":{"defined":"VestingConfigV0"}}},{"name":"refundWindowSeconds","type":{"option":"i64"}},{"name":"expiryUnixTime","type":{"option":"i64"}},{"name":"minLockSeconds","type":{"option":"i64"}},{"name":"burn","type":{"option":{"defined":"BurnConfigV0"}}},{"name":"rentRefund","type":{"option":"publicKey"}},{"name":"rate","type":{"option":{"defined":"ExchangeRateV0"}}},{"name":"swapFeeBps","type":"u16"},{"name":"maxParentToChildAmount","type":{"option":"u64"}},{"name":"cpiPolicy","type":{"defined":"CpiPolicyV0"}},{"name":"cpiAllowlist","type":{"vec":"publicKey"}},{"name":"rejectFreezeAuthority","type":"bool"},{"name":"maxChildToParentAmount","type":{"option":"u64"}},{"name":"stakeProgram","type":{"option":"publicKey"}},{"name":"liquidityProgram","type":{"option":"publicKey"}},{"name":"feeTiers","type":{"vec":{"defined":"FeeTierV0"}}},{"name":"reverseRebateSeconds","type":{"option":"i64"}},{"name":"freezeParentToChildUnixTime","type":{"option":"i64"}},{"name":"freezeChildToParentUnixTime","type":{"option":"i64"}},{"name":"commitRevealSeconds","type":{"option":"i64"}}]}},{"name":"InitializeFungibleParentEntanglerV0Args","type":{"kind":"struct","fields":[{"name":"authority","type":{"option":"publicKey"}},{"name":"dynamicSeed","type":"bytes"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"name","type":"string"},{"name":"uri","type":"string"},{"name":"rentRefund","type":{"option":"publicKey"}},{"name":"rejectFreezeAuthority","type":"bool"},{"name":"defaultRate","type":{"option":{"defined":"ExchangeRateV0"}}},{"name":"freezeParentToChildUnixTime","type":{"option":"i64"}},{"name":"freezeChildToParentUnixTime","type":{"option":"i64"}}]}},{"name":"InitializeGlobalConfigV0Args","type":{"kind":"struct","fields":[{"name":"admin","type":"publicKey"},{"name":"feeDestination","type":"publicKey"},{"name":"creationFeeLamports","type":"u64"}]}},{"name":"InitializeRewardsPoolV0Args","type":{"kind":"struct","fields":[{"name":"rewardPerSecond","type":"u64"},{"name":"startUnixTime","type":"i64"},{"name":"endUnixTime","type":"i64"}]}},{"name":"LockV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"ImportLegacyEntanglerV0Args","type":{"kind":"struct","fields":[{"name":"dynamicSeed","type":"bytes"}]}},{"name":"MirrorChildMetadataV0Args","type":{"kind":"struct","fields":[{"name":"nameSuffix","type":"string"},{"name":"symbolSuffix","type":"string"}]}},{"name":"InvokeAsNftAuthorityV0Args","type":{"kind":"struct","fields":[{"name":"data","type":"bytes"}]}},{"name":"CreateOfferV0Args","type":{"kind":"struct","fields":[{"name":"nonce","type":"u64"},{"name":"taker","type":"publicKey"},{"name":"offerAmount","type":"u64"},{"name":"askAmount","type":"u64"},{"name":"expiryUnixTime","type":{"option":"i64"}}]}},{"name":"QueueSwapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"parentToChild","type":"bool"}]}},{"name":"AppendRateScheduleV0Args","type":{"kind":"struct","fields":[{"name":"entry","type":{"defined":"RateScheduleEntryV0"}}]}},{"name":"SetRateScheduleV0Args","type":{"kind":"struct","fields":[{"name":"entries","type":{"vec":{"defined":"RateScheduleEntryV0"}}}]}},{"name":"RebalanceBetweenEntanglersV0Args","type":{"kind":"struct","fields":[{"name":"parentAmount","type":"u64"},{"name":"childAmount","type":"u64"}]}},{"name":"RemoveChildRegistryV0Args","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"}]}},{"name":"ResizeEntanglerV0Args","type":{"kind":"struct","fields":[{"name":"newSize","type":"u32"}]}},{"name":"SetAuthorityV0Args","type":{"kind":"struct","fields":[{"name":"newAuthority","type":"publicKey"}]}},{"name":"DrawdownLimitArgsV0","type":{"kind":"struct","fields":[{"name":"windowSeconds","type":"i64"},{"name":"maxChildAmount","type":"u64"},{"name":"maxParentAmount","type":"u64"}]}},{"name":"SetDrawdownLimitV0Args","type":{"kind":"struct","fields":[{"name":"drawdownLimit","type":{"option":{"defined":"DrawdownLimitArgsV0"}}}]}},{"name":"SetFeesV0Args","type":{"kind":"struct","fields":[{"name":"swapFeeBps","type":"u16"},{"name":"feeTiers","type":{"vec":{"defined":"FeeTierV0"}}}]}},{"name":"SetFreezeTimeV0Args","type":{"kind":"struct","fields":[{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"freezeParentToChildUnixTime","type":{"option":"i64"}},{"name":"freezeChildToParentUnixTime","type":{"option":"i64"}}]}},{"name":"SetGlobalPauseV0Args","type":{"kind":"struct","fields":[{"name":"paused","type":"bool"}]}},{"name":"SetParentFreezeTimeV0Args","type":{"kind":"struct","fields":[{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"freezeParentToChildUnixTime","type":{"option":"i64"}},{"name":"freezeChildToParentUnixTime","type":{"option":"i64"}}]}},{"name":"SetRateBandV0Args","type":{"kind":"struct","fields":[{"name":"rateBand","type":{"option":{"defined":"RateBandV0"}}}]}},{"name":"SetRateV0Args","type":{"kind":"struct","fields":[{"name":"rate","type":{"defined":"ExchangeRateV0"}}]}},{"name":"SetRegistryWeightsV0Args","type":{"kind":"struct","fields":[{"name":"weightsBps","type":{"vec":"u16"}}]}},{"name":"SetWithdrawalsFrozenUntilV0Args","type":{"kind":"struct","fields":[{"name":"withdrawalsFrozenUntil","type":"i64"}]}},{"name":"DeferSwapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"parentToChild","type":"bool"},{"name":"reference","type":{"array":["u8",32]}}]}},{"name":"SetPendingSettlementV0Args","type":{"kind":"struct","fields":[{"name":"settler","type":"publicKey"},{"name":"timeoutSeconds","type":"i64"}]}},{"name":"RebalanceStrategyV0Args","type":{"kind":"struct","fields":[{"name":"deposit","type":"bool"},{"name":"amount","type":"u64"},{"name":"data","type":"bytes"}]}},{"name":"SetYieldStrategyV0Args","type":{"kind":"struct","fields":[{"name":"keeper","type":"publicKey"},{"name":"minLiquidBps","type":"u16"}]}},{"name":"SwapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":{"option":"u64"}},{"name":"all","type":{"option":"bool"}},{"name":"outputSplitsBps","type":{"vec":"u16"}},{"name":"memo","type":{"option":"string"}},{"name":"allowPartial","type":"bool"}]}},{"name":"SwapFillV0","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"outputAmount","type":"u64"}]}},{"name":"RefundSwapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"SwapAndProvideLiquidityV0Args","type":{"kind":"struct","fields":[{"name":"swap","type":{"defined":"SwapV0Args"}},{"name":"depositData","type":"bytes"}]}},{"name":"SwapParentForBasketV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"},{"name":"minOutputAmounts","type":{"vec":"u64"}}]}},{"name":"SwapAndStakeV0Args","type":{"kind":"struct","fields":[{"name":"swap","type":{"defined":"SwapV0Args"}},{"name":"stakeData","type":"bytes"}]}},{"name":"TopOffChildStorageV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"TransferChildStorageArgsV0","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"TransferParentStorageArgsV0","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"UpdateGlobalConfigV0Args","type":{"kind":"struct","fields":[{"name":"admin","type":{"option":"publicKey"}},{"name":"feeDestination","type":{"option":"publicKey"}},{"name":"creationFeeLamports","type":{"option":"u64"}}]}},{"name":"UpdateMetadataV0Args","type":{"kind":"struct","fields":[{"name":"name","type":"string"},{"name":"uri","type":"string"}]}},{"name":"InitValidationV0","type":{"kind":"struct","fields":[{"name":"parentIssues","type":{"vec":"u32"}},{"name":"childIssues","type":{"vec":{"vec":"u32"}}},{"name":"parentDecimals","type":"u8"},{"name":"childDecimals","type":"bytes"},{"name":"creationFeeLamports","type":"u64"},{"name":"rentLamports","type":"u64"}]}},{"name":"ClawbackV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"UnwrapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"TransferFeeArgsV0","type":{"kind":"struct","fields":[{"name":"basisPoints","type":"u16"},{"name":"maximumFee","type":"u64"},{"name":"feeAuthority","type":"publicKey"}]}},{"name":"WrapMintV0Args","type":{"kind":"struct","fields":[{"name":"transferFee","type":{"option":{"defined":"TransferFeeArgsV0"}}},{"name":"clawbackAuthority","type":{"option":"publicKey"}}]}},{"name":"WrapV0Args","type":{"kind":"struct","fields":[{"name":"amount","type":"u64"}]}},{"name":"VolumeStatsV0","type":{"kind":"struct","fields":[{"name":"emaAmount","type":"u64"},{"name":"emaUpdatedUnixTime","type":"i64"},{"name":"windowAmount","type":"u64"},{"name":"windowSwapCount","type":"u32"},{"name":"windowStartUnixTime","type":"i64"}]}},{"name":"DrawdownLimitV0","type":{"kind":"struct","fields":[{"name":"windowSeconds","type":"i64"},{"name":"maxChildAmount","type":"u64"},{"name":"maxParentAmount","type":"u64"},{"name":"windowStartUnixTime","type":"i64"},{"name":"childAmount","type":"u64"},{"name":"parentAmount","type":"u64"}]}},{"name":"FeeDistributionV0","type":{"kind":"struct","fields":[{"name":"childFeeAmount","type":"u64"},{"name":"parentFeeAmount","type":"u64"},{"name":"parentToChildAmount","type":"u64"},{"name":"childToParentAmount","type":"u64"},{"name":"claimedChildFeeAmount","type":"u64"},{"name":"claimedParentFeeAmount","type":"u64"}]}},{"name":"YieldStrategyV0","type":{"kind":"struct","fields":[{"name":"program","type":"publicKey"},{"name":"keeper","type":"publicKey"},{"name":"minLiquidBps","type":"u16"},{"name":"depositedAmount","type":"u64"}]}},{"name":"ExchangeRateV0","type":{"kind":"struct","fields":[{"name":"parentUnits","type":"u64"},{"name":"childUnits","type":"u64"}]}},{"name":"RateBandV0","type":{"kind":"struct","fields":[{"name":"minRate","type":{"defined":"ExchangeRateV0"}},{"name":"maxRate","type":{"defined":"ExchangeRateV0"}},{"name":"fallbackRate","type":{"option":{"defined":"ExchangeRateV0"}}}]}},{"name":"FeeTierV0","type":{"kind":"struct","fields":[{"name":"minAmount","type":"u64"},{"name":"bps","type":"u16"}]}},{"name":"BurnConfigV0","type":{"kind":"struct","fields":[{"name":"bps","type":"u16"},{"name":"side","type":{"defined":"BurnSideV0"}}]}},{"name":"VestingConfigV0","type":{"kind":"struct","fields":[{"name":"startUnixTime","type":"i64"},{"name":"endUnixTime","type":"i64"}]}},{"name":"CompressedSwapRecordV0","type":{"kind":"struct","fields":[{"name":"childEntangler","type":"publicKey"},{"name":"owner","type":"publicKey"},{"name":"parentToChild","type":"bool"},{"name":"parentAmount","type":"u64"},{"name":"childAmount","type":"u64"},{"name":"unixTime","type":"i64"},{"name":"refunded","type":"bool"}]}},{"name":"RateScheduleEntryV0","type":{"kind":"struct","fields":[{"name":"effectiveUnixTime","type":"i64"},{"name":"rate","type":{"defined":"ExchangeRateV0"}}]}},{"name":"SnapshotEntryV0","type":{"kind":"struct","fields":[{"name":"epoch","type":"u64"},{"name":"unixTime","type":"i64"},{"name":"parentStorageBalance","type":"u64"},{"name":"childStorageBalance","type":"u64"},{"name":"totalParentToChildAmount","type":"u64"},{"name":"totalChildToParentAmount","type":"u64"}]}},{"name":"FungibleParentEntanglerV1Version1","type":{"kind":"struct","fields":[{"name":"version","type":"u16"},{"name":"parentMint","type":"publicKey"},{"name":"parentStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"numChildren","type":"u32"},{"name":"nextChildIndex","type":"u32"},{"name":"authority","type":{"option":"publicKey"}},{"name":"name","type":"string"},{"name":"uri","type":"string"},{"name":"dynamicSeed","type":"bytes"},{"name":"dynamicSeedHash","type":{"array":["u8",32]}},{"name":"rentRefund","type":"publicKey"},{"name":"legacySeeds","type":"bool"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"},{"name":"reserved","type":{"array":["u64",16]}}]}},{"name":"FungibleChildEntanglerV1Version1","type":{"kind":"struct","fields":[{"name":"version","type":"u16"},{"name":"parentEntangler","type":"publicKey"},{"name":"childMint","type":"publicKey"},{"name":"childStorage","type":"publicKey"},{"name":"goLiveUnixTime","type":"i64"},{"name":"freezeSwapUnixTime","type":{"option":"i64"}},{"name":"createdAtUnixTime","type":"i64"},{"name":"index","type":"u32"},{"name":"vesting","type":{"option":{"defined":"VestingConfigV0"}}},{"name":"refundWindowSeconds","type":{"option":"i64"}},{"name":"expiryUnixTime","type":{"option":"i64"}},{"name":"totalTopOffAmount","type":"u64"},{"name":"reclaimSnapshotAmount","type":{"option":"u64"}},{"name":"rewardsPool","type":{"option":"publicKey"}},{"name":"minLockSeconds","type":{"option":"i64"}},{"name":"burn","type":{"option":{"defined":"BurnConfigV0"}}},{"name":"rentRefund","type":"publicKey"},{"name":"rate","type":{"defined":"ExchangeRateV0"}},{"name":"swapFeeBps","type":"u16"},{"name":"maxParentToChildAmount","type":{"option":"u64"}},{"name":"totalParentToChildAmount","type":"u64"},{"name":"bumpSeed","type":"u8"},{"name":"storageBumpSeed","type":"u8"},{"name":"reserved","type":{"array":["u64",16]}}]}},{"name":"MetadataArgs","type":{"kind":"struct","fields":[{"name":"name","type":"string"},{"name":"symbol","type":"string"},{"name":"uri","type":"string"}]}},{"name":"SwapDirection","type":{"kind":"enum","variants":[{"name":"ParentToChild"},{"name":"ChildToParent"}]}},{"name":"Rounding","type":{"kind":"enum","variants":[{"name":"Down"},{"name":"Up"}]}},{"name":"CpiPolicyV0","type":{"kind":"enum","variants":[{"name":"Any"},{"name":"Deny"},{"name":"Allowlist"}]}},{"name":"BurnSideV0","type":{"kind":"enum","variants":[{"name":"Input"},{"name":"Output"}]}}],"events":[{"name":"SwapEventV0","fields":[{"name":"parentEntangler","type":"publicKey","index":false},{"name":"childEntangler","type":"publicKey","index":false},{"name":"sourceAuthority","type":"publicKey","index":false},{"name":"parentToChild","type":"bool","index":false},{"name":"amount","type":"u64","index":false},{"name":"outputAmount","type":"u64","index":false},{"name":"feeAmount","type":"u64","index":false},{"name":"unixTime","type":"i64","index":false}]},{"name":"ParentEntanglerCreatedV0","fields":[{"name":"parentEntangler","type":"publicKey","index":false},{"name":"parentMint","type":"publicKey","index":false},{"name":"authority","type":{"option":"publicKey"},"index":false}]},{"name":"ChildEntanglerCreatedV0","fields":[{"name":"parentEntangler","type":"publicKey","index":false},{"name":"childEntangler","type":"publicKey","index":false},{"name":"childMint","type":"publicKey","index":false}]},{"name":"ChildEntanglerFrozenV0","fields":[{"name":"parentEntangler","type":"publicKey","index":false},{"name":"childEntangler","type":"publicKey","index":false}]},{"name":"ChildEntanglerClosedV0","fields":[{"name":"parentEntangler","type":"publicKey","index":false},{"name":"childEntangler","type":"publicKey","index":false}]},{"name":"ParentEntanglerClosedV0","fields":[{"name":"parentEntangler","type":"publicKey","index":false}]}],"errors":[{"code":6000,"name":"InvalidAmount","msg":"Invalid amount"},{"code":6001,"name":"InvalidAuthority","msg":"Invalid Authority"},{"code":6002,"name":"TokenAccountAmountTooLow","msg":"Cannot swap more than the source account holds or has delegated to the signer"},{"code":6003,"name":"InvalidArgs","msg":"Amount or All must be provided"},{"code":6004,"name":"ParentNotLiveYet","msg":"This parent entangler is not live yet"},{"code":6005,"name":"ChildNotLiveYet","msg":"This child entangler is not live yet"},{"code":6006,"name":"ParentSwapFrozen","msg":"Swap is frozen on the parent entangler, swapping not allowed"},{"code":6007,"name":"ChildSwapFrozen","msg":"Swap is frozen on the child entangler, swapping not allowed"},{"code":6008,"name":"NoAuthority","msg":"This entangler has no authority on it"},{"code":6009,"name":"ChildrenRemaining","msg":"Cannot close a parent entangler while it still has children"},{"code":6010,"name":"InvalidRegistry","msg":"This registry does not belong to the given parent entangler"},{"code":6011,"name":"RegistryFull","msg":"This registry page is full, initialize the next page"},{"code":6012,"name":"ChildAlreadyRegistered","msg":"This child entangler is already in the registry"},{"code":6013,"name":"ChildNotInRegistry","msg":"This child entangler is not in the registry"},{"code":6014,"name":"InvalidDynamicSeed","msg":"Dynamic seed must be between 1 and 64 bytes"},{"code":6015,"name":"InvalidStringLength","msg":"Invalid string length, your string was likely too long"},{"code":6016,"name":"ChildMintNotControlled","msg":"The child entangler must be the mint authority of the child mint"},{"code":6017,"name":"InvalidTransferFee","msg":"Transfer fee basis points cannot exceed 10000"},{"code":6018,"name":"InvalidVestingSchedule","msg":"Vesting end must be after vesting start"},{"code":6019,"name":"VestingRequired","msg":"This child entangler vests swap output, use swap_parent_for_child_vested_v0"},{"code":6020,"name":"NoVesting","msg":"This child entangler does not vest swap output"},{"code":6021,"name":"NoRefundWindow","msg":"This child entangler does not allow refunds"},{"code":6022,"name":"RefundWindowClosed","msg":"The refund window for this swap has closed"},{"code":6023,"name":"Expired","msg":"This child entangler has expired"},{"code":6024,"name":"NotExpired","msg":"This child entangler has not expired yet"},{"code":6025,"name":"AlreadyReclaimed","msg":"This deposit has already been reclaimed"},{"code":6026,"name":"MissingRewardsPool","msg":"This child entangler has rewards, pass its rewards pool as the first remaining account"},{"code":6027,"name":"RewardsPoolExists","msg":"This child entangler already has a rewards pool"},{"code":6028,"name":"InvalidRewardsSchedule","msg":"Rewards end must be after rewards start"},{"code":6029,"name":"LockRequired","msg":"This child entangler requires locking parent tokens first, use lock_v0 and swap_locked_v0"},{"code":6030,"name":"NoLock","msg":"This child entangler does not use locks"},{"code":6031,"name":"LockNotElapsed","msg":"Tokens have not been locked for long enough"},{"code":6032,"name":"InvalidOutputSplits","msg":"At most 4 output splits totalling no more than 10000 bps, each with a remaining account"},{"code":6033,"name":"OutputSplitsNotSupported","msg":"Output splits are not supported by this instruction"},{"code":6034,"name":"InvalidBurnBps","msg":"Burn basis points cannot exceed 10000"},{"code":6035,"name":"InvalidUpgradeAuthority","msg":"Only the program upgrade authority may initialize the global config"},{"code":6036,"name":"InvalidRefundRecipient","msg":"Rent must be refunded to the rent refund recipient set at init"},{"code":6037,"name":"InvalidExchangeRate","msg":"Exchange rate units must be greater than zero"},{"code":6038,"name":"InvalidSwapFee","msg":"Swap fee basis points cannot exceed 10000"},{"code":6039,"name":"SwapCapExceeded","msg":"Swap would exceed the child's parent to child cap"},{"code":6040,"name":"InvalidDataIncrease","msg":"The realloc increase was too large"},{"code":6041,"name":"InvalidMigration","msg":"Legacy child entangler does not belong to this parent or mint"},{"code":6042,"name":"InvalidResize","msg":"Entanglers can only grow"},{"code":6043,"name":"UnsupportedVersion","msg":"Entangler account version is not supported by this program"},{"code":6044,"name":"MathOverflow","msg":"Math overflow"},{"code":6045,"name":"MathUnderflow","msg":"Math underflow"},{"code":6046,"name":"DivideByZero","msg":"Division by zero"},{"code":6047,"name":"CastOverflow","msg":"Value does not fit in a u64"},{"code":6048,"name":"FreezeNotDue","msg":"Neither this child nor its parent has reached its freeze time"},{"code":6049,"name":"AlreadyFrozen","msg":"This child entangler is already frozen"},{"code":6050,"name":"GloballyPaused","msg":"Swaps are paused program wide"},{"code":6051,"name":"CpiNotAllowed","msg":"This child entangler does not allow swaps via CPI from the calling program"},{"code":6052,"name":"InvalidCpiAllowlist","msg":"At most 4 programs may be allowlisted for CPI"},{"code":6053,"name":"SourceAccountFrozen","msg":"The source token account is frozen"},{"code":6054,"name":"DestinationAccountFrozen","msg":"The destination token account is frozen"},{"code":6055,"name":"StorageAccountFrozen","msg":"Entangler storage is frozen by the mint's freeze authority"},{"code":6056,"name":"MintHasFreezeAuthority","msg":"Mint has an active freeze authority"},{"code":6057,"name":"ParentEntanglerMismatch","msg":"Child entangler does not belong to this parent entangler"},{"code":6058,"name":"ChildEntanglerMismatch","msg":"Account does not belong to this child entangler"},{"code":6059,"name":"ParentMintMismatch","msg":"Parent mint does not match the parent entangler"},{"code":6060,"name":"ChildMintMismatch","msg":"Child mint does not match the child entangler"},{"code":6061,"name":"StorageMismatch","msg":"Storage account does not belong to this entangler"},{"code":6062,"name":"WrappedMintMismatch","msg":"Wrapped mint does not match the wrapper"},{"code":6063,"name":"FeeDestinationMismatch","msg":"Fee destination does not match the global config"},{"code":6064,"name":"InvalidOwner","msg":"Signer does not own this account"},{"code":6065,"name":"MintNotInitialized","msg":"Mint is not initialized"},{"code":6066,"name":"SameMint","msg":"Parent and child mints must differ"},{"code":6067,"name":"InvalidProgramData","msg":"Program data does not belong to this program"},{"code":6068,"name":"InsufficientStorage","msg":"Output storage does not hold enough to fill this swap"},{"code":6069,"name":"InvalidDustDestination","msg":"Only the parent's authority may sweep dust anywhere but the fee destination"},{"code":6070,"name":"NoDust","msg":"Storage holds nothing that swaps at the current rate can't reach"},{"code":6071,"name":"ConversionCapExceeded","msg":"Swap would exceed the child's child to parent conversion cap"},{"code":6072,"name":"InvalidOfferMints","msg":"Offer must trade the parent mint for the child mint or the reverse"},{"code":6073,"name":"OfferExpired","msg":"This offer has expired"},{"code":6074,"name":"InvalidStakeProgram","msg":"This child entangler does not stake through this program"},{"code":6075,"name":"InvalidLiquidityProgram","msg":"This child entangler does not provide liquidity through this program"},{"code":6076,"name":"InvalidDelegate","msg":"Signer is neither the owner nor the delegate of the source account"},{"code":6077,"name":"InvalidFeeTiers","msg":"At most 4 fee tiers, sorted by strictly increasing min amount, each at most 10000 bps"},{"code":6078,"name":"InvalidRateSchedule","msg":"At most 8 rate schedule entries, sorted by strictly increasing effective time, with valid rates"},{"code":6079,"name":"InvalidSwapRecordTree","msg":"Merkle tree is not this child's swap record tree, or the child already has one"},{"code":6080,"name":"InvalidCompressedRecord","msg":"Compressed swap record is not a refundable parent to child swap of the signer"},{"code":6081,"name":"AccountAlreadyInitialized","msg":"Account to create already exists"},{"code":6082,"name":"InvalidChildAccounts","msg":"Remaining accounts must be child mint, entangler, storage and lookup for each child, up to 4 children"},{"code":6083,"name":"ChildEntanglerArgsMismatch","msg":"Child entangler already exists with different parameters"},{"code":6084,"name":"SnapshotTooSoon","msg":"A snapshot was already taken this epoch"},{"code":6085,"name":"NoCommitReveal","msg":"Child does not use commit reveal swaps"},{"code":6086,"name":"CommitRevealOnly","msg":"Only committed swaps revealed through RevealAndSwapV0 may swap during the commit reveal window"},{"code":6087,"name":"CommitWindowClosed","msg":"Swaps may only be committed before the child goes live"},{"code":6088,"name":"InvalidReveal","msg":"Revealed swap does not match the commitment"},{"code":6089,"name":"InvalidNftMint","msg":"Authority NFT mint must have zero decimals and a supply of one"},{"code":6090,"name":"NoNftAuthority","msg":"Parent entangler authority is not held by an NFT"},{"code":6091,"name":"NotNftHolder","msg":"Signer does not hold the authority NFT"},{"code":6092,"name":"InvalidLegacyEntangler","msg":"Legacy entangler is not owned by the original program, does not match, or this program is the original and should use the migrate instructions"},{"code":6093,"name":"SlippageExceeded","msg":"Swap output is below the minimum amount out"},{"code":6094,"name":"InvalidStrategy","msg":"Strategy program is not approved, or differs from the one holding the child's deposits"},{"code":6095,"name":"StrategyLiquidityBuffer","msg":"Deposit would leave child storage below the strategy's liquid share"},{"code":6096,"name":"InvalidStrategyTransfer","msg":"Strategy instruction moved a different amount than requested"},{"code":6097,"name":"StrategyNotEmpty","msg":"Withdraw the strategy's deposits first"},{"code":6098,"name":"InvalidBasketWeights","msg":"Basket weights must cover every registry entry and sum to 10000, or all be zero"},{"code":6099,"name":"NoBasket","msg":"Registry has no basket, set its weights first"},{"code":6100,"name":"RateOutOfBand","msg":"Rate the child or its rate schedule sets is outside the child's rate band"},{"code":6101,"name":"InvalidRateBand","msg":"Rate schedule band bounds must be valid rates with min at most max, and any fallback inside them"},{"code":6102,"name":"QueueClosed","msg":"Swaps can only be queued before the child goes live"},{"code":6103,"name":"QueuedSwapOutOfOrder","msg":"Queued swaps execute in ticket order"},{"code":6104,"name":"InvalidInputMint","msg":"Input mint must be the mint the swap direction spends"},{"code":6105,"name":"WithdrawalsFrozen","msg":"Storage withdrawals are frozen"},{"code":6106,"name":"InvalidWithdrawalFreeze","msg":"Withdrawal freezes can only be extended"},{"code":6107,"name":"FeesNotDistributable","msg":"Fees can only be distributed once the child has expired, or is frozen and its parent sealed"},{"code":6108,"name":"FeesAlreadyDistributing","msg":"Fees are already being distributed"},{"code":6109,"name":"FeesNotDistributing","msg":"Fees are not being distributed"},{"code":6110,"name":"InvalidAuthorityConfig","msg":"Authority configs need distinct signers, at most the max, and a threshold between one and their count"},{"code":6111,"name":"NoAuthorityConfig","msg":"The parent's authority is not its authority config"},{"code":6112,"name":"ThresholdNotMet","msg":"Not enough authority config signers approved"},{"code":6113,"name":"InvalidSettlementTimeout","msg":"Settlement timeouts must be positive"},{"code":6114,"name":"SettlementTimedOut","msg":"The settlement entry timed out and can only be refunded"},{"code":6115,"name":"SettlementNotTimedOut","msg":"The settlement entry has not timed out yet"},{"code":6116,"name":"PendingSettlementMismatch","msg":"Settlement entry does not belong to this pending settlement"},{"code":6117,"name":"DrawdownLimitExceeded","msg":"Swap would take more out of storage than the drawdown limit allows this window"},{"code":6118,"name":"InvalidDrawdownLimit","msg":"Drawdown limit windows must be positive"},{"code":6119,"name":"UnsupportedMint","msg":"Mint is not owned by the SPL token program, token extensions are not supported"},{"code":6120,"name":"InvalidTimestampOrder","msg":"Freeze and expiry times must come after go live"},{"code":6121,"name":"DecimalsMismatch","msg":"Mints have different decimals but the rate is one raw unit to one"},{"code":6122,"name":"InvalidRebalancePair","msg":"Rebalances need two distinct entanglers of the same parent and child mints"},{"code":6123,"name":"NotVersion1","msg":"Entangler is not a version 1 layout"},{"code":6124,"name":"SwapRecordRequired","msg":"Child tracks swaps, create the swap record via InitializeSwapRecordV0 first"},{"code":6125,"name":"SwapRecordNotTracked","msg":"Child does not track swaps, it needs no swap record"},{"code":6126,"name":"TopOffReclaimPending","msg":"Child storage is owed to TopOff depositors until they reclaim it"},{"code":6127,"name":"RegistryAlreadyMigrated","msg":"Registry page already has basket weights"},{"code":6128,"name":"FeeRebatesPending","msg":"Parent storage holds fees owed to swappers as rebates"},{"code":6129,"name":"InvalidSwapRecord","msg":"Account is not the source authority's swap record for the child"},{"code":6130,"name":"NotPermanentDelegate","msg":"Wrapped mint does not make the wrapper its permanent delegate"},{"code":6131,"name":"InsufficientCompressedTokens","msg":"Compressed token inputs hold less than the swap amount"},{"code":6132,"name":"StrategyAccountNotAllowed","msg":"Strategy programs may only be handed the child's storage of its token accounts"},{"code":6133,"name":"QueuedSwapsPending","msg":"Swaps open once every queued swap of the child has executed"}],"metadata":{"address":"fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB"}};
+
+export type SwapDirection = Record<string, Record<string, any>>
+export const SwapDirection = {
+  ParentToChild: { parenttochild: {} },
+  ChildToParent: { childtoparent: {} }
+}
+    
 
+export type Rounding = Record<string, Record<string, any>>
+export const Rounding = {
+  Down: { down: {} },
+  Up: { up: {} }
+}
+    
 
+export type CpiPolicyV0 = Record<string, Record<string, any>>
+export const CpiPolicyV0 = {
+  Any: { any: {} },
+  Deny: { deny: {} },
+  Allowlist: { allowlist: {} }
+}
+    
+
+export type BurnSideV0 = Record<string, Record<string, any>>
+export const BurnSideV0 = {
+  Input: { input: {} },
+  Output: { output: {} }
+}
+    
 
   
 
+export type GlobalConfigV0 = IdlAccounts<FungibleEntanglerIDL>["globalConfigV0"]
+
+export type FungibleParentEntanglerV1 = IdlAccounts<FungibleEntanglerIDL>["fungibleParentEntanglerV1"]
+
+export type FungibleChildEntanglerV1 = IdlAccounts<FungibleEntanglerIDL>["fungibleChildEntanglerV1"]
+
+export type VestingV0 = IdlAccounts<FungibleEntanglerIDL>["vestingV0"]
+
+export type SwapRecordV0 = IdlAccounts<FungibleEntanglerIDL>["swapRecordV0"]
+
+export type FeeRebateClaimV0 = IdlAccounts<FungibleEntanglerIDL>["feeRebateClaimV0"]
+
+export type StrategyProgramV0 = IdlAccounts<FungibleEntanglerIDL>["strategyProgramV0"]
+
+export type SwapCommitV0 = IdlAccounts<FungibleEntanglerIDL>["swapCommitV0"]
+
+export type SwapQueueV0 = IdlAccounts<FungibleEntanglerIDL>["swapQueueV0"]
+
+export type QueuedSwapV0 = IdlAccounts<FungibleEntanglerIDL>["queuedSwapV0"]
+
+export type PendingSettlementV0 = IdlAccounts<FungibleEntanglerIDL>["pendingSettlementV0"]
+
+export type SettlementEntryV0 = IdlAccounts<FungibleEntanglerIDL>["settlementEntryV0"]
+
+export type SwapLockV0 = IdlAccounts<FungibleEntanglerIDL>["swapLockV0"]
+
+export type OfferV0 = IdlAccounts<FungibleEntanglerIDL>["offerV0"]
+
+export type RateScheduleV0 = IdlAccounts<FungibleEntanglerIDL>["rateScheduleV0"]
+
+export type SnapshotHistoryV0 = IdlAccounts<FungibleEntanglerIDL>["snapshotHistoryV0"]
+
+export type TopOffRecordV0 = IdlAccounts<FungibleEntanglerIDL>["topOffRecordV0"]
+
+export type RewardsPoolV0 = IdlAccounts<FungibleEntanglerIDL>["rewardsPoolV0"]
+
+export type EntanglerLookupV0 = IdlAccounts<FungibleEntanglerIDL>["entanglerLookupV0"]
+
+export type NamespaceLookupV0 = IdlAccounts<FungibleEntanglerIDL>["namespaceLookupV0"]
+
+export type AuthorityConfigV0 = IdlAccounts<FungibleEntanglerIDL>["authorityConfigV0"]
+
+export type ChildRegistryV0 = IdlAccounts<FungibleEntanglerIDL>["childRegistryV0"]
+
+export type WrappedMintV0 = IdlAccounts<FungibleEntanglerIDL>["wrappedMintV0"]
+
 export type FungibleParentEntanglerV0 = IdlAccounts<FungibleEntanglerIDL>["fungibleParentEntanglerV0"]
 
 export type FungibleChildEntanglerV0 = IdlAccounts<FungibleEntanglerIDL>["fungibleChildEntanglerV0"]
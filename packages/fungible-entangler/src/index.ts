@@ -12,7 +12,7 @@ import {
   PublicKey,
   SystemProgram,
   SYSVAR_CLOCK_PUBKEY,
-  SYSVAR_RENT_PUBKEY,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   TransactionInstruction,
 } from "@solana/web3.js";
 import {
@@ -23,34 +23,91 @@ import {
   TypedAccountParser,
 } from "@strata-foundation/spl-utils";
 import BN from "bn.js";
+import { sha256 } from "js-sha256";
 import {
   FungibleEntanglerIDL,
-  FungibleParentEntanglerV0,
-  FungibleChildEntanglerV0,
+  FungibleParentEntanglerV1,
+  FungibleChildEntanglerV1,
+  GlobalConfigV0,
+  CpiPolicyV0,
 } from "./generated/fungible-entangler";
 import { toBN, toNumber } from "./utils";
 
 export * from "./generated/fungible-entangler";
 
+type ExchangeRateV0 = IdlTypes<FungibleEntanglerIDL>["ExchangeRateV0"];
+
 type Truthy<T> = T extends false | "" | 0 | null | undefined ? never : T; // from lodash
 
 const truthy = <T>(value: T): value is Truthy<T> => !!value;
 const encode = anchor.utils.bytes.utf8.encode;
 
+export const MEMO_PROGRAM_ID = new PublicKey(
+  "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+);
+export const NOOP_PROGRAM_ID = new PublicKey(
+  "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+);
+const NAMESPACED_SEED_DOMAIN = "namespaced-entangler";
+
+/**
+ * The hash parent entanglers are derived from in place of their dynamic seed
+ */
+export function hashSeed(seed: Buffer): Buffer {
+  return Buffer.from(sha256.digest(seed));
+}
+
+/**
+ * The seed hash of a parent entangler created in a namespace, so that namespaces can't claim
+ * each other's seeds
+ */
+export function hashNamespacedSeed(namespace: PublicKey, seed: Buffer): Buffer {
+  return hashSeed(
+    Buffer.concat([
+      Buffer.from(encode(NAMESPACED_SEED_DOMAIN)),
+      namespace.toBuffer(),
+      hashSeed(seed),
+    ])
+  );
+}
+
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+/**
+ * Unified fungible entangler interface wrapping the raw FungibleParentEntanglerV1
+ */
+export interface IFungibleParentEntangler extends FungibleParentEntanglerV1 {
+  publicKey: PublicKey;
+}
+
 /**
- * Unified fungible entangler interface wrapping the raw FungibleEntanglerV0
+ * Unified fungible child entangler interface wrapping the raw FungibleChildEntanglerV1
  */
-export interface IFungibleParentEntangler extends FungibleParentEntanglerV0 {
+export interface IFungibleChildEntangler extends FungibleChildEntanglerV1 {
   publicKey: PublicKey;
 }
 
 /**
- * Unified fungible child entangler interface wrapping the raw FungibleChildEntanglerV0*
+ * The program wide config every entangler is created under
  */
-export interface IFungibleChildEntangler extends FungibleChildEntanglerV0 {
+export interface IGlobalConfig extends GlobalConfigV0 {
   publicKey: PublicKey;
 }
 
+interface IInitializeGlobalConfigArgs {
+  payer?: PublicKey;
+  /** The upgrade authority of the program. **Default:** Wallet public key */
+  upgradeAuthority?: PublicKey;
+  /** The admin that can pause the program and update the config. **Default:** Wallet public key */
+  admin?: PublicKey;
+  /** Where creation fees are paid. **Default:** Wallet public key */
+  feeDestination?: PublicKey;
+  /** Lamports charged for creating each entangler. **Default:** 0 */
+  creationFeeLamports?: BN | number;
+}
+
 interface ICreateFungibleParentEntanglerArgs {
   payer?: PublicKey;
   /** The source for the amount (**Default:** ata of provider wallet) */
@@ -70,6 +127,16 @@ interface ICreateFungibleParentEntanglerArgs {
   goLiveDate?: Date | number;
   /** The date this entangler will shut down. After this date, {@link FungibleEntangler.swap} is disabled. **Default:** null */
   freezeSwapDate?: Date | number;
+  /** Display name of the entangler. **Default:** "" */
+  name?: string;
+  /** Metadata uri of the entangler. **Default:** "" */
+  uri?: string;
+  /** Where rent goes when the entangler is closed. **Default:** payer */
+  rentRefund?: PublicKey;
+  /** Refuse mints that have a freeze authority. **Default:** false */
+  rejectFreezeAuthority?: boolean;
+  /** The rate children take unless they set their own. **Default:** 1:1 */
+  defaultRate?: ExchangeRateV0;
 }
 
 export interface ICreateFungibleParentEntanglerOutput {
@@ -82,6 +149,8 @@ interface ICreateFungibleChildEntanglerArgs {
   payer?: PublicKey;
   /** The parent entangler this child will be associated to */
   parentEntangler: PublicKey;
+  /** The mint of the parent entangler. **Default:** Fetched from `parentEntangler` */
+  parentMint?: PublicKey;
   /** The mint we will be creating an entangler for */
   mint: PublicKey;
   /**
@@ -93,6 +162,18 @@ interface ICreateFungibleChildEntanglerArgs {
   goLiveDate?: Date | number;
   /** The date this entangler will shut down. After this date, {@link FungibleEntangler.swap} is disabled. **Default:** null */
   freezeSwapDate?: Date | number;
+  /** Where rent goes when the entangler is closed. **Default:** payer */
+  rentRefund?: PublicKey;
+  /** The rate of this child. **Default:** the parent's default rate */
+  rate?: ExchangeRateV0;
+  /** Fee taken from the output of swaps, in basis points. **Default:** 0 */
+  swapFeeBps?: number;
+  /** Which programs may swap through CPI. **Default:** Any */
+  cpiPolicy?: CpiPolicyV0;
+  /** The programs allowed to swap through CPI under the Allowlist policy. **Default:** [] */
+  cpiAllowlist?: PublicKey[];
+  /** Refuse mints that have a freeze authority. **Default:** false */
+  rejectFreezeAuthority?: boolean;
 }
 
 export interface ICreateFungibleChildEntanglerOutput {
@@ -147,10 +228,14 @@ interface ISwapArgs {
   sourceAuthority?: PublicKey;
   /** The source destination to purchase to. (**Default:** ata of `sourceAuthority`) */
   destination?: PublicKey;
+  /** Memo logged with the swap. **Default:** null */
+  memo?: string;
+  /** Fill what storage can pay out instead of failing. **Default:** false */
+  allowPartial?: boolean;
 }
 
 interface ICloseArgs {
-  refund?: PublicKey;
+  payer?: PublicKey;
 }
 
 interface ICloseArgsParent extends ICloseArgs {
@@ -267,7 +352,51 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     programId: PublicKey = FungibleEntangler.ID
   ): Promise<[PublicKey, number]> {
     return PublicKey.findProgramAddress(
-      [encode("entangler"), mint.toBuffer(), dynamicSeed],
+      [encode("entangler"), mint.toBuffer(), hashSeed(dynamicSeed)],
+      programId
+    );
+  }
+
+  /**
+   * Get the PDA key of a Parent Entangler created in a namespace given the mint, namespace and dynamicSeed
+   *
+   *
+   * @param mint
+   * @param namespace
+   * @param dynamicSeed
+   * @returns
+   */
+  static async namespacedFungibleParentEntanglerKey(
+    mint: PublicKey,
+    namespace: PublicKey,
+    dynamicSeed: Buffer,
+    programId: PublicKey = FungibleEntangler.ID
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [
+        encode("entangler"),
+        mint.toBuffer(),
+        hashNamespacedSeed(namespace, dynamicSeed),
+      ],
+      programId
+    );
+  }
+
+  /**
+   * Get the PDA key of the lookup claiming a dynamicSeed in a namespace
+   *
+   *
+   * @param namespace
+   * @param dynamicSeed
+   * @returns
+   */
+  static async namespaceLookupKey(
+    namespace: PublicKey,
+    dynamicSeed: Buffer,
+    programId: PublicKey = FungibleEntangler.ID
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [encode("namespace-lookup"), namespace.toBuffer(), hashSeed(dynamicSeed)],
       programId
     );
   }
@@ -308,12 +437,83 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     );
   }
 
+  /**
+   * Get the PDA key of the program's global config
+   *
+   *
+   * @returns
+   */
+  static async globalConfigKey(
+    programId: PublicKey = FungibleEntangler.ID
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress([encode("global-config")], programId);
+  }
+
+  /**
+   * Get the PDA key of the lookup claiming a parent mint and child mint pair
+   *
+   *
+   * @param parentMint
+   * @param childMint
+   * @returns
+   */
+  static async lookupKey(
+    parentMint: PublicKey,
+    childMint: PublicKey,
+    programId: PublicKey = FungibleEntangler.ID
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [encode("lookup"), parentMint.toBuffer(), childMint.toBuffer()],
+      programId
+    );
+  }
+
+  /**
+   * Get the PDA key of the swap record of a wallet's swaps through a child entangler
+   *
+   *
+   * @param childEntangler
+   * @param sourceAuthority
+   * @returns
+   */
+  static async swapRecordKey(
+    childEntangler: PublicKey,
+    sourceAuthority: PublicKey,
+    programId: PublicKey = FungibleEntangler.ID
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [
+        encode("swap-record"),
+        childEntangler.toBuffer(),
+        sourceAuthority.toBuffer(),
+      ],
+      programId
+    );
+  }
+
+  /**
+   * Get the PDA key of the rate schedule of a child entangler
+   *
+   *
+   * @param childEntangler
+   * @returns
+   */
+  static async rateScheduleKey(
+    childEntangler: PublicKey,
+    programId: PublicKey = FungibleEntangler.ID
+  ): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+      [encode("rate-schedule"), childEntangler.toBuffer()],
+      programId
+    );
+  }
+
   parentEntanglerDecoder: TypedAccountParser<IFungibleParentEntangler> = (
     pubkey,
     account
   ) => {
     const coded = this.program.coder.accounts.decode<IFungibleParentEntangler>(
-      "FungibleParentEntanglerV0",
+      "FungibleParentEntanglerV1",
       account.data
     );
 
@@ -334,7 +534,7 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
   > = (pubkey, account) => {
     try {
       const coded = this.program.coder.accounts.decode<IFungibleChildEntangler>(
-        "FungibleChildEntanglerV0",
+        "FungibleChildEntanglerV1",
         account.data
       );
       return {
@@ -352,6 +552,28 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     return this.getAccount(entanglerKey, this.childEntanglerDecoder);
   }
 
+  globalConfigDecoder: TypedAccountParser<IGlobalConfig> = (
+    pubkey,
+    account
+  ) => {
+    const coded = this.program.coder.accounts.decode<IGlobalConfig>(
+      "GlobalConfigV0",
+      account.data
+    );
+
+    return {
+      ...coded,
+      publicKey: pubkey,
+    };
+  };
+
+  async getGlobalConfig(): Promise<IGlobalConfig | null> {
+    const [globalConfig] = await FungibleEntangler.globalConfigKey(
+      this.programId
+    );
+    return this.getAccount(globalConfig, this.globalConfigDecoder);
+  }
+
   async getUnixTime(): Promise<number> {
     const acc = await this.provider.connection.getAccountInfo(
       SYSVAR_CLOCK_PUBKEY
@@ -359,6 +581,79 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     return Number(acc!.data.readBigInt64LE(8 * 4));
   }
 
+  async initializeGlobalConfigInstructions({
+    payer = this.provider.wallet.publicKey,
+    upgradeAuthority = this.provider.wallet.publicKey,
+    admin = this.provider.wallet.publicKey,
+    feeDestination = this.provider.wallet.publicKey,
+    creationFeeLamports = 0,
+  }: IInitializeGlobalConfigArgs): Promise<
+    InstructionResult<{ globalConfig: PublicKey }>
+  > {
+    const [globalConfig] = await FungibleEntangler.globalConfigKey(
+      this.programId
+    );
+    const [programData] = await PublicKey.findProgramAddress(
+      [this.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
+
+    return {
+      instructions: [
+        await this.instruction.initializeGlobalConfigV0(
+          {
+            admin,
+            feeDestination,
+            creationFeeLamports: new BN(creationFeeLamports),
+          },
+          {
+            accounts: {
+              payer,
+              upgradeAuthority,
+              globalConfig,
+              program: this.programId,
+              programData,
+              systemProgram: SystemProgram.programId,
+            },
+          }
+        ),
+      ],
+      signers: [],
+      output: { globalConfig },
+    };
+  }
+
+  async initializeGlobalConfig(
+    args: IInitializeGlobalConfigArgs = {},
+    commitment: Commitment = "confirmed"
+  ): Promise<{ globalConfig: PublicKey }> {
+    return this.execute(
+      this.initializeGlobalConfigInstructions(args),
+      args.payer,
+      commitment
+    );
+  }
+
+  /**
+   * The global config and the fee destination creation fees are paid to
+   */
+  async creationFeeAccounts(): Promise<{
+    globalConfig: PublicKey;
+    feeDestination: PublicKey;
+  }> {
+    const globalConfig = await this.getGlobalConfig();
+    if (!globalConfig) {
+      throw new Error(
+        "The global config has not been initialized, see initializeGlobalConfig"
+      );
+    }
+
+    return {
+      globalConfig: globalConfig.publicKey,
+      feeDestination: globalConfig.feeDestination,
+    };
+  }
+
   async createFungibleParentEntanglerInstructions({
     authority = this.provider.wallet.publicKey,
     payer = this.provider.wallet.publicKey,
@@ -368,6 +663,11 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     amount,
     goLiveDate,
     freezeSwapDate,
+    name = "",
+    uri = "",
+    rentRefund = payer,
+    rejectFreezeAuthority = false,
+    defaultRate,
   }: ICreateFungibleParentEntanglerArgs): Promise<
     InstructionResult<ICreateFungibleParentEntanglerOutput>
   > {
@@ -408,6 +708,7 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     const [storage, _storageBump] = await FungibleEntangler.storageKey(
       entangler
     );
+    const { globalConfig, feeDestination } = await this.creationFeeAccounts();
 
     instructions.push(
       await this.instruction.initializeFungibleParentEntanglerV0(
@@ -418,6 +719,14 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
           freezeSwapUnixTime: freezeSwapDate
             ? new BN(Math.floor(freezeSwapDate.valueOf() / 1000))
             : null,
+          name,
+          uri,
+          rentRefund,
+          rejectFreezeAuthority,
+          // @ts-ignore
+          defaultRate: defaultRate || null,
+          freezeParentToChildUnixTime: null,
+          freezeChildToParentUnixTime: null,
         },
         {
           accounts: {
@@ -425,10 +734,11 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
             entangler,
             parentStorage: storage,
             parentMint: mint,
+            globalConfig,
+            feeDestination,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
-            clock: SYSVAR_CLOCK_PUBKEY,
+            noopProgram: NOOP_PROGRAM_ID,
           },
         }
       ),
@@ -475,9 +785,16 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     authority = this.provider.wallet.publicKey,
     payer = this.provider.wallet.publicKey,
     parentEntangler,
+    parentMint,
     mint,
     goLiveDate,
     freezeSwapDate,
+    rentRefund = payer,
+    rate,
+    swapFeeBps = 0,
+    cpiPolicy = CpiPolicyV0.Any,
+    cpiAllowlist = [],
+    rejectFreezeAuthority = false,
   }: ICreateFungibleChildEntanglerArgs): Promise<
     InstructionResult<ICreateFungibleChildEntanglerOutput>
   > {
@@ -488,12 +805,18 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       goLiveDate = new Date(0).setUTCSeconds((await this.getUnixTime()) - 60);
     }
 
+    if (!parentMint) {
+      parentMint = (await this.getParentEntangler(parentEntangler))!.parentMint;
+    }
+
     const [entangler, _entanglerBump] =
       await FungibleEntangler.fungibleChildEntanglerKey(parentEntangler, mint);
 
     const [storage, _storageBump] = await FungibleEntangler.storageKey(
       entangler
     );
+    const [lookup] = await FungibleEntangler.lookupKey(parentMint, mint);
+    const { globalConfig, feeDestination } = await this.creationFeeAccounts();
 
     instructions.push(
       await this.instruction.initializeFungibleChildEntanglerV0(
@@ -502,19 +825,42 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
           freezeSwapUnixTime: freezeSwapDate
             ? new BN(Math.floor(freezeSwapDate.valueOf() / 1000))
             : null,
+          vesting: null,
+          refundWindowSeconds: null,
+          expiryUnixTime: null,
+          minLockSeconds: null,
+          burn: null,
+          rentRefund,
+          // @ts-ignore
+          rate: rate || null,
+          swapFeeBps,
+          maxParentToChildAmount: null,
+          cpiPolicy,
+          cpiAllowlist,
+          rejectFreezeAuthority,
+          maxChildToParentAmount: null,
+          stakeProgram: null,
+          liquidityProgram: null,
+          feeTiers: [],
+          reverseRebateSeconds: null,
+          freezeParentToChildUnixTime: null,
+          freezeChildToParentUnixTime: null,
+          commitRevealSeconds: null,
         },
         {
           accounts: {
             payer,
+            authority: authority!,
             parentEntangler,
             entangler,
-            authority: authority!,
             childStorage: storage,
+            lookup,
             childMint: mint,
+            globalConfig,
+            feeDestination,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
-            rent: SYSVAR_RENT_PUBKEY,
-            clock: SYSVAR_CLOCK_PUBKEY,
+            noopProgram: NOOP_PROGRAM_ID,
           },
         }
       )
@@ -595,6 +941,7 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       authority,
       payer,
       parentEntangler: parentOutput.entangler,
+      parentMint,
       mint: childMint,
       goLiveDate: childGoLiveDate,
       freezeSwapDate: childFreezeSwapDate,
@@ -634,6 +981,8 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     parentEntangler,
     childEntangler,
     destination,
+    memo,
+    allowPartial = false,
     ...rest
   }: ISwapParentForChildArgs): Promise<InstructionResult<null>> {
     let { amount, all } = { amount: null, all: null, ...rest };
@@ -692,21 +1041,42 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       amount,
       // @ts-ignore
       all,
+      outputSplitsBps: [],
+      // @ts-ignore
+      memo: memo || null,
+      allowPartial,
     };
+    const [swapRecord] = await FungibleEntangler.swapRecordKey(
+      childEntangler,
+      sourceAuthority
+    );
+    const [globalConfig] = await FungibleEntangler.globalConfigKey();
+    const [rateSchedule] = await FungibleEntangler.rateScheduleKey(
+      childEntangler
+    );
 
     instructions.push(
       await this.instruction.swapParentForChildV0(args, {
         accounts: {
           common: {
+            payer,
             parentEntangler,
             parentStorage: parentAcct.parentStorage,
+            parentMint: parentAcct.parentMint,
             childEntangler,
             childStorage: childAcct.childStorage,
+            childMint: childAcct.childMint,
             source,
             sourceAuthority,
             destination,
+            swapRecord,
+            globalConfig,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
             tokenProgram: TOKEN_PROGRAM_ID,
-            clock: SYSVAR_CLOCK_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            memoProgram: MEMO_PROGRAM_ID,
+            rateSchedule,
+            noopProgram: NOOP_PROGRAM_ID,
           },
         },
       })
@@ -737,6 +1107,8 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     parentEntangler,
     childEntangler,
     destination,
+    memo,
+    allowPartial = false,
     ...rest
   }: ISwapChildForParentArgs): Promise<InstructionResult<null>> {
     let { amount, all } = { amount: null, all: null, ...rest };
@@ -797,21 +1169,42 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       amount,
       // @ts-ignore
       all,
+      outputSplitsBps: [],
+      // @ts-ignore
+      memo: memo || null,
+      allowPartial,
     };
+    const [swapRecord] = await FungibleEntangler.swapRecordKey(
+      childEntangler,
+      sourceAuthority
+    );
+    const [globalConfig] = await FungibleEntangler.globalConfigKey();
+    const [rateSchedule] = await FungibleEntangler.rateScheduleKey(
+      childEntangler
+    );
 
     instructions.push(
       await this.instruction.swapChildForParentV0(args, {
         accounts: {
           common: {
+            payer,
             parentEntangler,
             parentStorage: parentAcct.parentStorage,
+            parentMint: parentAcct.parentMint,
             childEntangler,
             childStorage: childAcct.childStorage,
+            childMint: childAcct.childMint,
             source,
             sourceAuthority,
             destination,
+            swapRecord,
+            globalConfig,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
             tokenProgram: TOKEN_PROGRAM_ID,
-            clock: SYSVAR_CLOCK_PUBKEY,
+            systemProgram: SystemProgram.programId,
+            memoProgram: MEMO_PROGRAM_ID,
+            rateSchedule,
+            noopProgram: NOOP_PROGRAM_ID,
           },
         },
       })
@@ -1024,7 +1417,7 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
   }
 
   async closeInstructions({
-    refund = this.wallet.publicKey,
+    payer = this.wallet.publicKey,
     ...rest
   }: CloseArgs): Promise<InstructionResult<null>> {
     let { parentEntangler, childEntangler } = {
@@ -1041,10 +1434,6 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       (childEntanglerAcct && childEntanglerAcct.parentEntangler))!;
     const parentEntanglerAcct = await this.getParentEntangler(parentEntangler);
 
-    const mint = isCloseChild
-      ? childEntanglerAcct!.childMint
-      : parentEntanglerAcct!.parentMint;
-
     const instructions: TransactionInstruction[] = [];
     const signers: Keypair[] = [];
 
@@ -1052,12 +1441,19 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       instructions.push(
         await this.instruction.closeFungibleChildEntanglerV0({
           accounts: {
-            refund,
+            refundTo: childEntanglerAcct!.rentRefund,
             authority: parentEntanglerAcct!.authority!,
             parentEntangler,
             entangler: childEntangler!,
             childStorage: childEntanglerAcct!.childStorage,
+            lookup: (
+              await FungibleEntangler.lookupKey(
+                parentEntanglerAcct!.parentMint,
+                childEntanglerAcct!.childMint
+              )
+            )[0],
             tokenProgram: TOKEN_PROGRAM_ID,
+            noopProgram: NOOP_PROGRAM_ID,
           },
         })
       );
@@ -1065,11 +1461,12 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
       instructions.push(
         await this.instruction.closeFungibleParentEntanglerV0({
           accounts: {
-            refund,
+            refundTo: parentEntanglerAcct!.rentRefund,
             authority: parentEntanglerAcct!.authority!,
             parentEntangler,
             parentStorage: parentEntanglerAcct!.parentStorage,
             tokenProgram: TOKEN_PROGRAM_ID,
+            noopProgram: NOOP_PROGRAM_ID,
           },
         })
       );
@@ -1086,6 +1483,6 @@ export class FungibleEntangler extends AnchorSdk<FungibleEntanglerIDL> {
     args: CloseArgs,
     commitment: Commitment = "confirmed"
   ): Promise<void> {
-    await this.execute(this.closeInstructions(args), args.refund, commitment);
+    await this.execute(this.closeInstructions(args), args.payer, commitment);
   }
 }
@@ -30,7 +30,8 @@ export const TokenLaunches = ({ mintKey, name, image }: TokenPreviewProps) => {
         filters: [
           {
             memcmp: {
-              offset: 8,
+              // discriminator, version
+              offset: 8 + 2,
               bytes: mintKey?.toBase58() || "",
             },
           },
@@ -50,7 +51,8 @@ export const TokenLaunches = ({ mintKey, name, image }: TokenPreviewProps) => {
             filters: [
               {
                 memcmp: {
-                  offset: 8,
+                  // discriminator, version
+                  offset: 8 + 2,
                   bytes: acc.publicKey.toString() || "",
                 }
               }
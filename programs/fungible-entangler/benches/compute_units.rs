@@ -0,0 +1,226 @@
+//! Records the compute units each hot instruction consumes against the compiled program.
+//!
+//! Build the program first with `anchor build`, then run
+//! `BPF_OUT_DIR=target/deploy cargo bench -p fungible-entangler --bench compute_units`.
+//!
+//! Each measurement binary searches the smallest compute budget the instruction succeeds under,
+//! rebuilding the fixture for every probe since a successful probe mutates state.
+
+use anchor_lang::prelude::*;
+use fungible_entangler::instructions::{
+  InitializeFungibleChildEntanglerV0Args, InitializeFungibleParentEntanglerV0Args, SwapV0Args,
+};
+use fungible_entangler_test_utils::{
+  advance_clock, create_mint, create_token_account, fee_destination, instructions, mint_to,
+  process_instructions, program_test, EntanglerKeys,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+  compute_budget::ComputeBudgetInstruction, instruction::Instruction, signature::Signer,
+};
+
+const MAX_COMPUTE_UNITS: u32 = 400_000;
+const DECIMALS: u8 = 9;
+const SUPPLY: u64 = 1_000_000_000_000;
+const SWAP_AMOUNT: u64 = 1_000_000_000;
+const DYNAMIC_SEED: &[u8] = b"bench";
+
+#[derive(Clone, Copy, Debug)]
+enum Scenario {
+  InitializeParent,
+  InitializeChild,
+  SwapParentForChild,
+  SwapChildForParent,
+}
+
+struct Fixture {
+  context: ProgramTestContext,
+  keys: EntanglerKeys,
+  parent_source: Pubkey,
+  child_source: Pubkey,
+}
+
+impl Fixture {
+  async fn new() -> Self {
+    let mut context = program_test().start_with_context().await;
+    let payer = context.payer.pubkey();
+    let parent_mint = create_mint(&mut context, DECIMALS).await.unwrap();
+    let child_mint = create_mint(&mut context, DECIMALS).await.unwrap();
+    let parent_source = create_token_account(&mut context, &parent_mint, &payer)
+      .await
+      .unwrap();
+    let child_source = create_token_account(&mut context, &child_mint, &payer)
+      .await
+      .unwrap();
+    mint_to(&mut context, &parent_mint, &parent_source, SUPPLY)
+      .await
+      .unwrap();
+    mint_to(&mut context, &child_mint, &child_source, SUPPLY)
+      .await
+      .unwrap();
+
+    Self {
+      context,
+      keys: EntanglerKeys::new(&parent_mint, &child_mint, DYNAMIC_SEED),
+      parent_source,
+      child_source,
+    }
+  }
+
+  fn payer(&self) -> Pubkey {
+    self.context.payer.pubkey()
+  }
+
+  async fn process(&mut self, instructions: &[Instruction]) -> bool {
+    process_instructions(&mut self.context, instructions, &[])
+      .await
+      .is_ok()
+  }
+
+  fn initialize_parent_ix(&self) -> Instruction {
+    instructions::initialize_parent_entangler(
+      &self.payer(),
+      &self.keys.parent_mint,
+      &fee_destination(),
+      InitializeFungibleParentEntanglerV0Args {
+        authority: Some(self.payer()),
+        dynamic_seed: DYNAMIC_SEED.to_vec(),
+        ..Default::default()
+      },
+    )
+  }
+
+  fn initialize_child_ix(&self) -> Instruction {
+    instructions::initialize_child_entangler(
+      &self.payer(),
+      &self.payer(),
+      &self.keys,
+      &fee_destination(),
+      InitializeFungibleChildEntanglerV0Args::default(),
+    )
+  }
+
+  fn swap_args() -> SwapV0Args {
+    SwapV0Args {
+      amount: Some(SWAP_AMOUNT),
+      ..Default::default()
+    }
+  }
+
+  fn swap_parent_for_child_ix(&self) -> Instruction {
+    instructions::swap_parent_for_child(
+      &self.payer(),
+      &self.keys,
+      &self.parent_source,
+      &self.payer(),
+      &self.child_source,
+      &[],
+      Self::swap_args(),
+    )
+  }
+
+  fn swap_child_for_parent_ix(&self) -> Instruction {
+    instructions::swap_child_for_parent(
+      &self.payer(),
+      &self.keys,
+      &self.child_source,
+      &self.payer(),
+      &self.parent_source,
+      &[],
+      Self::swap_args(),
+    )
+  }
+
+  /// Brings the fixture to the state right before the scenario's instruction and returns it
+  async fn prepare(scenario: Scenario) -> (Self, Instruction) {
+    let mut fixture = Self::new().await;
+    if let Scenario::InitializeParent = scenario {
+      let ix = fixture.initialize_parent_ix();
+      return (fixture, ix);
+    }
+
+    let ix = fixture.initialize_parent_ix();
+    assert!(fixture.process(&[ix]).await);
+    if let Scenario::InitializeChild = scenario {
+      let ix = fixture.initialize_child_ix();
+      return (fixture, ix);
+    }
+
+    let ix = fixture.initialize_child_ix();
+    assert!(fixture.process(&[ix]).await);
+    let keys = fixture.keys;
+    mint_to(
+      &mut fixture.context,
+      &keys.parent_mint,
+      &keys.parent_storage(),
+      SUPPLY,
+    )
+    .await
+    .unwrap();
+    mint_to(
+      &mut fixture.context,
+      &keys.child_mint,
+      &keys.child_storage(),
+      SUPPLY,
+    )
+    .await
+    .unwrap();
+    advance_clock(&mut fixture.context, 60).await.unwrap();
+
+    let ix = match scenario {
+      Scenario::SwapParentForChild => fixture.swap_parent_for_child_ix(),
+      _ => fixture.swap_child_for_parent_ix(),
+    };
+    (fixture, ix)
+  }
+}
+
+async fn succeeds_within(scenario: Scenario, compute_units: u32) -> bool {
+  let (mut fixture, ix) = Fixture::prepare(scenario).await;
+  fixture
+    .process(&[
+      ComputeBudgetInstruction::request_units(compute_units, 0),
+      ix,
+    ])
+    .await
+}
+
+async fn measure(scenario: Scenario) -> u32 {
+  assert!(
+    succeeds_within(scenario, MAX_COMPUTE_UNITS).await,
+    "{:?} failed within the maximum budget",
+    scenario
+  );
+
+  let mut low = 0;
+  let mut high = MAX_COMPUTE_UNITS;
+  while high - low > 1 {
+    let mid = low + (high - low) / 2;
+    if succeeds_within(scenario, mid).await {
+      high = mid;
+    } else {
+      low = mid;
+    }
+  }
+
+  high
+}
+
+#[tokio::main]
+async fn main() {
+  let scenarios = [
+    Scenario::InitializeParent,
+    Scenario::InitializeChild,
+    Scenario::SwapParentForChild,
+    Scenario::SwapChildForParent,
+  ];
+
+  println!("{:<24} {:>14}", "instruction", "compute units");
+  for scenario in scenarios.iter().copied() {
+    println!(
+      "{:<24} {:>14}",
+      format!("{:?}", scenario),
+      measure(scenario).await
+    );
+  }
+}
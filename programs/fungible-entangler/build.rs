@@ -0,0 +1,28 @@
+//! Embeds the git commit and toolchain the program was built from, reported by GetVersionV0 and
+//! the security.txt
+
+use std::{env, process::Command};
+
+fn command_output(program: &str, args: &[&str]) -> String {
+  Command::new(program)
+    .args(args)
+    .output()
+    .ok()
+    .filter(|output| output.status.success())
+    .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    .unwrap_or_default()
+}
+
+fn main() {
+  let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+  println!(
+    "cargo:rustc-env=GIT_COMMIT={}",
+    command_output("git", &["rev-parse", "HEAD"])
+  );
+  println!(
+    "cargo:rustc-env=RUSTC_VERSION={}",
+    command_output(&rustc, &["--version"])
+  );
+  println!("cargo:rerun-if-changed=../../.git/HEAD");
+  println!("cargo:rerun-if-changed=../../.git/refs/heads");
+}
@@ -0,0 +1,107 @@
+//! CPIs into spl-account-compression. Its crate depends on a newer Anchor than this program, so
+//! the instructions are built by hand from the program's Anchor discriminators and borsh layout
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+  self,
+  instruction::{AccountMeta, Instruction},
+};
+use anchor_lang::Accounts;
+
+pub mod program {
+  anchor_lang::declare_id!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+}
+
+/// spl-noop, which the compression program logs tree changelogs through for indexers
+pub mod noop {
+  anchor_lang::declare_id!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+}
+
+// sha256("global:<instruction name>")[..8]
+const INIT_EMPTY_MERKLE_TREE: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+const APPEND: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+const REPLACE_LEAF: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+
+#[derive(Accounts)]
+pub struct Modify<'info> {
+  /// CHECK: Checked with cpi
+  pub merkle_tree: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub authority: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub noop: AccountInfo<'info>,
+}
+
+fn invoke_modify<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, Modify<'info>>,
+  data: Vec<u8>,
+) -> Result<()> {
+  let mut accounts = vec![
+    AccountMeta::new(*ctx.accounts.merkle_tree.key, false),
+    AccountMeta::new_readonly(*ctx.accounts.authority.key, true),
+    AccountMeta::new_readonly(*ctx.accounts.noop.key, false),
+  ];
+  let mut account_infos = vec![
+    ctx.accounts.merkle_tree.clone(),
+    ctx.accounts.authority.clone(),
+    ctx.accounts.noop.clone(),
+  ];
+  // Proof nodes, from the leaf's sibling up
+  for node in &ctx.remaining_accounts {
+    accounts.push(AccountMeta::new_readonly(*node.key, false));
+    account_infos.push(node.clone());
+  }
+  account_infos.push(ctx.program.clone());
+
+  solana_program::program::invoke_signed(
+    &Instruction {
+      program_id: program::ID,
+      accounts,
+      data,
+    },
+    &account_infos,
+    ctx.signer_seeds,
+  )
+  .map_err(|e| e.into())
+}
+
+/// The tree must already be allocated for max_depth and max_buffer_size and owned by the
+/// compression program. authority becomes the only key that can modify it
+pub fn init_empty_merkle_tree<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, Modify<'info>>,
+  max_depth: u32,
+  max_buffer_size: u32,
+) -> Result<()> {
+  let mut data = INIT_EMPTY_MERKLE_TREE.to_vec();
+  data.extend_from_slice(&max_depth.to_le_bytes());
+  data.extend_from_slice(&max_buffer_size.to_le_bytes());
+
+  invoke_modify(ctx, data)
+}
+
+pub fn append<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, Modify<'info>>,
+  leaf: [u8; 32],
+) -> Result<()> {
+  let mut data = APPEND.to_vec();
+  data.extend_from_slice(&leaf);
+
+  invoke_modify(ctx, data)
+}
+
+/// Fails unless previous_leaf sits at index under root, proven by the context's remaining accounts
+pub fn replace_leaf<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, Modify<'info>>,
+  root: [u8; 32],
+  previous_leaf: [u8; 32],
+  new_leaf: [u8; 32],
+  index: u32,
+) -> Result<()> {
+  let mut data = REPLACE_LEAF.to_vec();
+  data.extend_from_slice(&root);
+  data.extend_from_slice(&previous_leaf);
+  data.extend_from_slice(&new_leaf);
+  data.extend_from_slice(&index.to_le_bytes());
+
+  invoke_modify(ctx, data)
+}
@@ -0,0 +1,284 @@
+//! CPIs into Light Protocol's compressed-token program. Like spl-account-compression, its crates
+//! depend on a newer Solana and Anchor than this program, so transfers are built by hand from the
+//! program's Anchor discriminator and the borsh layout of light-compressed-token 1.x
+
+use crate::{error::ErrorCode, math};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+  self,
+  instruction::{AccountMeta, Instruction},
+};
+use anchor_lang::Accounts;
+
+pub mod program {
+  anchor_lang::declare_id!("cTokenmWW8bLPjZEBAUgYy3zKxQZW6VKi7bqNFEVv3m");
+}
+
+/// Verifies validity proofs and nullifies spent accounts for the compressed-token program
+pub mod light_system {
+  anchor_lang::declare_id!("SySTEM1eSU2p4BGQfQpimFEWWSC1XDFeun3Nqzz3rT7");
+}
+
+/// Light's account compression program, which owns the state trees compressed accounts live in
+pub mod account_compression {
+  anchor_lang::declare_id!("compr6CUsB5m2jS4Y3831ztGSTnDpnKJTKS95d64XVq");
+}
+
+// sha256("global:transfer")[..8]
+pub const TRANSFER: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CompressedProof {
+  pub a: [u8; 32],
+  pub b: [u8; 64],
+  pub c: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct QueueIndex {
+  pub queue_id: u8,
+  pub index: u16,
+}
+
+/// Where an input account sits, as indices into the remaining accounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct PackedMerkleContext {
+  pub merkle_tree_pubkey_index: u8,
+  pub nullifier_queue_pubkey_index: u8,
+  pub leaf_index: u32,
+  // Set while the account is still in the output queue, so it is proven by index
+  pub queue_index: Option<QueueIndex>,
+}
+
+/// A compressed token account being spent, as the indexer returns it packed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct InputTokenDataWithContext {
+  pub amount: u64,
+  pub delegate_index: Option<u8>,
+  pub merkle_context: PackedMerkleContext,
+  pub root_index: u16,
+  pub lamports: Option<u64>,
+  pub tlv: Option<Vec<u8>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct PackedTokenTransferOutputData {
+  pub owner: Pubkey,
+  pub amount: u64,
+  pub lamports: Option<u64>,
+  pub merkle_tree_index: u8,
+  pub tlv: Option<Vec<u8>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DelegatedTransfer {
+  pub owner: Pubkey,
+  pub delegate_change_account_index: Option<u8>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompressedCpiContext {
+  pub set_context: bool,
+  pub first_set_context: bool,
+  pub cpi_context_account_index: u8,
+}
+
+/// The compressed-token program's CompressedTokenInstructionDataTransfer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransferData {
+  pub proof: Option<CompressedProof>,
+  pub mint: Pubkey,
+  pub delegated_transfer: Option<DelegatedTransfer>,
+  pub input_token_data_with_context: Vec<InputTokenDataWithContext>,
+  pub output_compressed_accounts: Vec<PackedTokenTransferOutputData>,
+  pub is_compress: bool,
+  pub compress_or_decompress_amount: Option<u64>,
+  pub cpi_context: Option<CompressedCpiContext>,
+  pub lamports_change_account_merkle_tree_index: Option<u8>,
+}
+
+impl TransferData {
+  /// The instruction data of a transfer, which takes its inputs as one borsh encoded byte vector
+  pub fn instruction_data(&self) -> Result<Vec<u8>> {
+    let mut data = TRANSFER.to_vec();
+    data.extend_from_slice(&self.try_to_vec()?.try_to_vec()?);
+
+    Ok(data)
+  }
+}
+
+#[derive(Accounts)]
+pub struct CompressedTransfer<'info> {
+  /// CHECK: Checked with cpi
+  pub fee_payer: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub authority: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub cpi_authority_pda: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub light_system_program: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub registered_program_pda: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub noop_program: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub account_compression_authority: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub account_compression_program: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub token_pool_pda: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub token_account: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub token_program: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub system_program: AccountInfo<'info>,
+}
+
+fn invoke_transfer<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, CompressedTransfer<'info>>,
+  data: &TransferData,
+) -> Result<()> {
+  let accounts = &ctx.accounts;
+  let mut metas = vec![
+    AccountMeta::new(*accounts.fee_payer.key, true),
+    AccountMeta::new_readonly(*accounts.authority.key, true),
+    AccountMeta::new_readonly(*accounts.cpi_authority_pda.key, false),
+    AccountMeta::new_readonly(*accounts.light_system_program.key, false),
+    AccountMeta::new_readonly(*accounts.registered_program_pda.key, false),
+    AccountMeta::new_readonly(*accounts.noop_program.key, false),
+    AccountMeta::new_readonly(*accounts.account_compression_authority.key, false),
+    AccountMeta::new_readonly(*accounts.account_compression_program.key, false),
+    // self_program, the compressed-token program itself
+    AccountMeta::new_readonly(program::ID, false),
+    AccountMeta::new(*accounts.token_pool_pda.key, false),
+    AccountMeta::new(*accounts.token_account.key, false),
+    AccountMeta::new_readonly(*accounts.token_program.key, false),
+    AccountMeta::new_readonly(*accounts.system_program.key, false),
+  ];
+  let mut account_infos = vec![
+    accounts.fee_payer.clone(),
+    accounts.authority.clone(),
+    accounts.cpi_authority_pda.clone(),
+    accounts.light_system_program.clone(),
+    accounts.registered_program_pda.clone(),
+    accounts.noop_program.clone(),
+    accounts.account_compression_authority.clone(),
+    accounts.account_compression_program.clone(),
+    ctx.program.clone(),
+    accounts.token_pool_pda.clone(),
+    accounts.token_account.clone(),
+    accounts.token_program.clone(),
+    accounts.system_program.clone(),
+  ];
+  // State trees and queues, referenced by index from the packed inputs and outputs
+  for tree in &ctx.remaining_accounts {
+    metas.push(AccountMeta::new(*tree.key, false));
+    account_infos.push(tree.clone());
+  }
+
+  solana_program::program::invoke_signed(
+    &Instruction {
+      program_id: program::ID,
+      accounts: metas,
+      data: data.instruction_data()?,
+    },
+    &account_infos,
+    ctx.signer_seeds,
+  )
+  .map_err(|e| e.into())
+}
+
+/// The compressed account returning what inputs hold beyond amount to owner, along with all of
+/// their lamports. None when nothing is left over
+pub fn change_output(
+  owner: Pubkey,
+  inputs: &[InputTokenDataWithContext],
+  amount: u64,
+  merkle_tree_index: u8,
+) -> Result<Option<PackedTokenTransferOutputData>> {
+  let mut input_amount: u64 = 0;
+  let mut lamports: Option<u64> = None;
+  for input in inputs {
+    input_amount = math::add(input_amount, input.amount)?;
+    if let Some(input_lamports) = input.lamports {
+      lamports = Some(math::add(lamports.unwrap_or(0), input_lamports)?);
+    }
+  }
+  let change = input_amount
+    .checked_sub(amount)
+    .ok_or(ErrorCode::InsufficientCompressedTokens)?;
+  if change == 0 && lamports.is_none() {
+    return Ok(None);
+  }
+
+  Ok(Some(PackedTokenTransferOutputData {
+    owner,
+    amount: change,
+    lamports,
+    merkle_tree_index,
+    tlv: None,
+  }))
+}
+
+/// Spends inputs, owned by the context's authority, into amount of mint in the context's token
+/// account. The rest of the inputs goes back to the authority under the tree at
+/// change_merkle_tree_index
+pub fn decompress<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, CompressedTransfer<'info>>,
+  mint: Pubkey,
+  proof: Option<CompressedProof>,
+  inputs: Vec<InputTokenDataWithContext>,
+  amount: u64,
+  change_merkle_tree_index: u8,
+) -> Result<()> {
+  let change = change_output(
+    *ctx.accounts.authority.key,
+    &inputs,
+    amount,
+    change_merkle_tree_index,
+  )?;
+  let data = TransferData {
+    proof,
+    mint,
+    delegated_transfer: None,
+    input_token_data_with_context: inputs,
+    output_compressed_accounts: change.into_iter().collect(),
+    is_compress: false,
+    compress_or_decompress_amount: Some(amount),
+    cpi_context: None,
+    lamports_change_account_merkle_tree_index: None,
+  };
+
+  invoke_transfer(ctx, &data)
+}
+
+/// Moves amount of mint out of the context's token account, held by its authority, into a new
+/// compressed account of owner's under the tree at merkle_tree_index
+pub fn compress<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, CompressedTransfer<'info>>,
+  mint: Pubkey,
+  owner: Pubkey,
+  amount: u64,
+  merkle_tree_index: u8,
+) -> Result<()> {
+  let data = TransferData {
+    proof: None,
+    mint,
+    delegated_transfer: None,
+    input_token_data_with_context: vec![],
+    output_compressed_accounts: vec![PackedTokenTransferOutputData {
+      owner,
+      amount,
+      lamports: None,
+      merkle_tree_index,
+      tlv: None,
+    }],
+    is_compress: true,
+    compress_or_decompress_amount: Some(amount),
+    cpi_context: None,
+    lamports_change_account_merkle_tree_index: None,
+  };
+
+  invoke_transfer(ctx, &data)
+}
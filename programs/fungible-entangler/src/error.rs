@@ -6,7 +6,7 @@ pub enum ErrorCode {
   InvalidAmount,
   #[msg("Invalid Authority")]
   InvalidAuthority,
-  #[msg("Cannot swap more than the token account currently has")]
+  #[msg("Cannot swap more than the source account holds or has delegated to the signer")]
   TokenAccountAmountTooLow,
   #[msg("Amount or All must be provided")]
   InvalidArgs,
@@ -20,4 +20,261 @@ pub enum ErrorCode {
   ChildSwapFrozen,
   #[msg("This entangler has no authority on it")]
   NoAuthority,
+  #[msg("Cannot close a parent entangler while it still has children")]
+  ChildrenRemaining,
+  #[msg("This registry does not belong to the given parent entangler")]
+  InvalidRegistry,
+  #[msg("This registry page is full, initialize the next page")]
+  RegistryFull,
+  #[msg("This child entangler is already in the registry")]
+  ChildAlreadyRegistered,
+  #[msg("This child entangler is not in the registry")]
+  ChildNotInRegistry,
+  #[msg("Dynamic seed must be between 1 and 64 bytes")]
+  InvalidDynamicSeed,
+  #[msg("Invalid string length, your string was likely too long")]
+  InvalidStringLength,
+  #[msg("The child entangler must be the mint authority of the child mint")]
+  ChildMintNotControlled,
+  #[msg("Transfer fee basis points cannot exceed 10000")]
+  InvalidTransferFee,
+  #[msg("Vesting end must be after vesting start")]
+  InvalidVestingSchedule,
+  #[msg("This child entangler vests swap output, use swap_parent_for_child_vested_v0")]
+  VestingRequired,
+  #[msg("This child entangler does not vest swap output")]
+  NoVesting,
+  #[msg("This child entangler does not allow refunds")]
+  NoRefundWindow,
+  #[msg("The refund window for this swap has closed")]
+  RefundWindowClosed,
+  #[msg("This child entangler has expired")]
+  Expired,
+  #[msg("This child entangler has not expired yet")]
+  NotExpired,
+  #[msg("This deposit has already been reclaimed")]
+  AlreadyReclaimed,
+  #[msg("This child entangler has rewards, pass its rewards pool as the first remaining account")]
+  MissingRewardsPool,
+  #[msg("This child entangler already has a rewards pool")]
+  RewardsPoolExists,
+  #[msg("Rewards end must be after rewards start")]
+  InvalidRewardsSchedule,
+  #[msg(
+    "This child entangler requires locking parent tokens first, use lock_v0 and swap_locked_v0"
+  )]
+  LockRequired,
+  #[msg("This child entangler does not use locks")]
+  NoLock,
+  #[msg("Tokens have not been locked for long enough")]
+  LockNotElapsed,
+  #[msg("At most 4 output splits totalling no more than 10000 bps, each with a remaining account")]
+  InvalidOutputSplits,
+  #[msg("Output splits are not supported by this instruction")]
+  OutputSplitsNotSupported,
+  #[msg("Burn basis points cannot exceed 10000")]
+  InvalidBurnBps,
+  #[msg("Only the program upgrade authority may initialize the global config")]
+  InvalidUpgradeAuthority,
+  #[msg("Rent must be refunded to the rent refund recipient set at init")]
+  InvalidRefundRecipient,
+  #[msg("Exchange rate units must be greater than zero")]
+  InvalidExchangeRate,
+  #[msg("Swap fee basis points cannot exceed 10000")]
+  InvalidSwapFee,
+  #[msg("Swap would exceed the child's parent to child cap")]
+  SwapCapExceeded,
+  #[msg("The realloc increase was too large")]
+  InvalidDataIncrease,
+  #[msg("Legacy child entangler does not belong to this parent or mint")]
+  InvalidMigration,
+  #[msg("Entanglers can only grow")]
+  InvalidResize,
+  #[msg("Entangler account version is not supported by this program")]
+  UnsupportedVersion,
+  #[msg("Math overflow")]
+  MathOverflow,
+  #[msg("Math underflow")]
+  MathUnderflow,
+  #[msg("Division by zero")]
+  DivideByZero,
+  #[msg("Value does not fit in a u64")]
+  CastOverflow,
+  #[msg("Neither this child nor its parent has reached its freeze time")]
+  FreezeNotDue,
+  #[msg("This child entangler is already frozen")]
+  AlreadyFrozen,
+  #[msg("Swaps are paused program wide")]
+  GloballyPaused,
+  #[msg("This child entangler does not allow swaps via CPI from the calling program")]
+  CpiNotAllowed,
+  #[msg("At most 4 programs may be allowlisted for CPI")]
+  InvalidCpiAllowlist,
+  #[msg("The source token account is frozen")]
+  SourceAccountFrozen,
+  #[msg("The destination token account is frozen")]
+  DestinationAccountFrozen,
+  #[msg("Entangler storage is frozen by the mint's freeze authority")]
+  StorageAccountFrozen,
+  #[msg("Mint has an active freeze authority")]
+  MintHasFreezeAuthority,
+  #[msg("Child entangler does not belong to this parent entangler")]
+  ParentEntanglerMismatch,
+  #[msg("Account does not belong to this child entangler")]
+  ChildEntanglerMismatch,
+  #[msg("Parent mint does not match the parent entangler")]
+  ParentMintMismatch,
+  #[msg("Child mint does not match the child entangler")]
+  ChildMintMismatch,
+  #[msg("Storage account does not belong to this entangler")]
+  StorageMismatch,
+  #[msg("Wrapped mint does not match the wrapper")]
+  WrappedMintMismatch,
+  #[msg("Fee destination does not match the global config")]
+  FeeDestinationMismatch,
+  #[msg("Signer does not own this account")]
+  InvalidOwner,
+  #[msg("Mint is not initialized")]
+  MintNotInitialized,
+  #[msg("Parent and child mints must differ")]
+  SameMint,
+  #[msg("Program data does not belong to this program")]
+  InvalidProgramData,
+  #[msg("Output storage does not hold enough to fill this swap")]
+  InsufficientStorage,
+  #[msg("Only the parent's authority may sweep dust anywhere but the fee destination")]
+  InvalidDustDestination,
+  #[msg("Storage holds nothing that swaps at the current rate can't reach")]
+  NoDust,
+  #[msg("Swap would exceed the child's child to parent conversion cap")]
+  ConversionCapExceeded,
+  #[msg("Offer must trade the parent mint for the child mint or the reverse")]
+  InvalidOfferMints,
+  #[msg("This offer has expired")]
+  OfferExpired,
+  #[msg("This child entangler does not stake through this program")]
+  InvalidStakeProgram,
+  #[msg("This child entangler does not provide liquidity through this program")]
+  InvalidLiquidityProgram,
+  #[msg("Signer is neither the owner nor the delegate of the source account")]
+  InvalidDelegate,
+  #[msg("At most 4 fee tiers, sorted by strictly increasing min amount, each at most 10000 bps")]
+  InvalidFeeTiers,
+  #[msg("At most 8 rate schedule entries, sorted by strictly increasing effective time, with valid rates")]
+  InvalidRateSchedule,
+  #[msg("Merkle tree is not this child's swap record tree, or the child already has one")]
+  InvalidSwapRecordTree,
+  #[msg("Compressed swap record is not a refundable parent to child swap of the signer")]
+  InvalidCompressedRecord,
+  #[msg("Account to create already exists")]
+  AccountAlreadyInitialized,
+  #[msg("Remaining accounts must be child mint, entangler, storage and lookup for each child, up to 4 children")]
+  InvalidChildAccounts,
+  #[msg("Child entangler already exists with different parameters")]
+  ChildEntanglerArgsMismatch,
+  #[msg("A snapshot was already taken this epoch")]
+  SnapshotTooSoon,
+  #[msg("Child does not use commit reveal swaps")]
+  NoCommitReveal,
+  #[msg("Only committed swaps revealed through RevealAndSwapV0 may swap during the commit reveal window")]
+  CommitRevealOnly,
+  #[msg("Swaps may only be committed before the child goes live")]
+  CommitWindowClosed,
+  #[msg("Revealed swap does not match the commitment")]
+  InvalidReveal,
+  #[msg("Authority NFT mint must have zero decimals and a supply of one")]
+  InvalidNftMint,
+  #[msg("Parent entangler authority is not held by an NFT")]
+  NoNftAuthority,
+  #[msg("Signer does not hold the authority NFT")]
+  NotNftHolder,
+  #[msg("Legacy entangler is not owned by the original program, does not match, or this program is the original and should use the migrate instructions")]
+  InvalidLegacyEntangler,
+  #[msg("Swap output is below the minimum amount out")]
+  SlippageExceeded,
+  #[msg("Strategy program is not approved, or differs from the one holding the child's deposits")]
+  InvalidStrategy,
+  #[msg("Deposit would leave child storage below the strategy's liquid share")]
+  StrategyLiquidityBuffer,
+  #[msg("Strategy instruction moved a different amount than requested")]
+  InvalidStrategyTransfer,
+  #[msg("Withdraw the strategy's deposits first")]
+  StrategyNotEmpty,
+  #[msg("Basket weights must cover every registry entry and sum to 10000, or all be zero")]
+  InvalidBasketWeights,
+  #[msg("Registry has no basket, set its weights first")]
+  NoBasket,
+  #[msg("Rate the child or its rate schedule sets is outside the child's rate band")]
+  RateOutOfBand,
+  #[msg("Rate schedule band bounds must be valid rates with min at most max, and any fallback inside them")]
+  InvalidRateBand,
+  #[msg("Swaps can only be queued before the child goes live")]
+  QueueClosed,
+  #[msg("Queued swaps execute in ticket order")]
+  QueuedSwapOutOfOrder,
+  #[msg("Input mint must be the mint the swap direction spends")]
+  InvalidInputMint,
+  #[msg("Storage withdrawals are frozen")]
+  WithdrawalsFrozen,
+  #[msg("Withdrawal freezes can only be extended")]
+  InvalidWithdrawalFreeze,
+
+  #[msg(
+    "Fees can only be distributed once the child has expired, or is frozen and its parent sealed"
+  )]
+  FeesNotDistributable,
+  #[msg("Fees are already being distributed")]
+  FeesAlreadyDistributing,
+  #[msg("Fees are not being distributed")]
+  FeesNotDistributing,
+
+  #[msg("Authority configs need distinct signers, at most the max, and a threshold between one and their count")]
+  InvalidAuthorityConfig,
+  #[msg("The parent's authority is not its authority config")]
+  NoAuthorityConfig,
+  #[msg("Not enough authority config signers approved")]
+  ThresholdNotMet,
+
+  #[msg("Settlement timeouts must be positive")]
+  InvalidSettlementTimeout,
+  #[msg("The settlement entry timed out and can only be refunded")]
+  SettlementTimedOut,
+  #[msg("The settlement entry has not timed out yet")]
+  SettlementNotTimedOut,
+  #[msg("Settlement entry does not belong to this pending settlement")]
+  PendingSettlementMismatch,
+  #[msg("Swap would take more out of storage than the drawdown limit allows this window")]
+  DrawdownLimitExceeded,
+  #[msg("Drawdown limit windows must be positive")]
+  InvalidDrawdownLimit,
+  #[msg("Mint is not owned by the SPL token program, token extensions are not supported")]
+  UnsupportedMint,
+  #[msg("Freeze and expiry times must come after go live")]
+  InvalidTimestampOrder,
+  #[msg("Mints have different decimals but the rate is one raw unit to one")]
+  DecimalsMismatch,
+  #[msg("Rebalances need two distinct entanglers of the same parent and child mints")]
+  InvalidRebalancePair,
+  #[msg("Entangler is not a version 1 layout")]
+  NotVersion1,
+  #[msg("Child tracks swaps, create the swap record via InitializeSwapRecordV0 first")]
+  SwapRecordRequired,
+  #[msg("Child does not track swaps, it needs no swap record")]
+  SwapRecordNotTracked,
+  #[msg("Child storage is owed to TopOff depositors until they reclaim it")]
+  TopOffReclaimPending,
+  #[msg("Registry page already has basket weights")]
+  RegistryAlreadyMigrated,
+  #[msg("Parent storage holds fees owed to swappers as rebates")]
+  FeeRebatesPending,
+  #[msg("Account is not the source authority's swap record for the child")]
+  InvalidSwapRecord,
+  #[msg("Wrapped mint does not make the wrapper its permanent delegate")]
+  NotPermanentDelegate,
+  #[msg("Compressed token inputs hold less than the swap amount")]
+  InsufficientCompressedTokens,
+  #[msg("Strategy programs may only be handed the child's storage of its token accounts")]
+  StrategyAccountNotAllowed,
+  #[msg("Swaps open once every queued swap of the child has executed")]
+  QueuedSwapsPending,
 }
@@ -0,0 +1,71 @@
+//! Swap and lifecycle events, logged as spl-noop instruction data rather than program logs so that
+//! log truncation can't drop them. Each is its Anchor event discriminator followed by its borsh
+//! encoding. Anyone can invoke spl-noop, so indexers must only trust noop instructions invoked
+//! directly by this program
+
+use crate::account_compression::noop;
+use anchor_lang::{
+  prelude::*,
+  solana_program::{instruction::Instruction, program::invoke},
+  Event,
+};
+
+#[event]
+pub struct SwapEventV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+  pub source_authority: Pubkey,
+  pub parent_to_child: bool,
+  // Taken from the source
+  pub amount: u64,
+  // Paid out, after the exchange rate and swap fee
+  pub output_amount: u64,
+  pub fee_amount: u64,
+  pub unix_time: i64,
+}
+
+#[event]
+pub struct ParentEntanglerCreatedV0 {
+  pub parent_entangler: Pubkey,
+  pub parent_mint: Pubkey,
+  pub authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct ChildEntanglerCreatedV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+  pub child_mint: Pubkey,
+}
+
+#[event]
+pub struct ChildEntanglerFrozenV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+}
+
+#[event]
+pub struct ChildEntanglerClosedV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+}
+
+#[event]
+pub struct ParentEntanglerClosedV0 {
+  pub parent_entangler: Pubkey,
+}
+
+/// Logs event through spl-noop. noop_program only has to be present, the instruction always targets
+/// spl-noop itself
+pub fn emit_noop<'info, E: Event>(noop_program: &AccountInfo<'info>, event: &E) -> Result<()> {
+  invoke(
+    &Instruction {
+      program_id: noop::ID,
+      accounts: vec![],
+      data: event.data(),
+    },
+    std::slice::from_ref(noop_program),
+  )?;
+
+  Ok(())
+}
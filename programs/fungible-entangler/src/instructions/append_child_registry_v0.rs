@@ -0,0 +1,44 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AppendChildRegistryV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub registry: AccountLoader<'info, ChildRegistryV0>,
+}
+
+pub fn handler(ctx: Context<AppendChildRegistryV0>) -> Result<()> {
+  let mut registry = ctx.accounts.registry.load_mut()?;
+  let child_entangler = ctx.accounts.child_entangler.key();
+
+  require!(
+    registry.parent_entangler == ctx.accounts.parent_entangler.key(),
+    ErrorCode::InvalidRegistry
+  );
+  require!(
+    !registry.entries().contains(&child_entangler),
+    ErrorCode::ChildAlreadyRegistered
+  );
+  require!(
+    (registry.num_entries as usize) < CHILD_REGISTRY_PAGE_SIZE,
+    ErrorCode::RegistryFull
+  );
+
+  let index = registry.num_entries as usize;
+  registry.children[index] = child_entangler;
+  registry.weights_bps[index] = 0;
+  registry.num_entries += 1;
+
+  Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+  },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InvokeAsAuthorityConfigV0Args {
+  // How many of the remaining accounts, from the front, are approving signers
+  pub signer_count: u8,
+  // Data of the authority gated instruction of this program to invoke
+  pub data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InvokeAsAuthorityConfigV0Args)]
+pub struct InvokeAsAuthorityConfigV0<'info> {
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    seeds = [b"authority-config", parent_entangler.key().as_ref()],
+    bump = authority_config.bump_seed,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = parent_entangler.authority == Some(authority_config.key()) @ ErrorCode::NoAuthorityConfig,
+  )]
+  pub authority_config: Box<Account<'info, AuthorityConfigV0>>,
+  /// CHECK: This program, invoked recursively
+  #[account(address = crate::ID)]
+  pub entangler_program: UncheckedAccount<'info>,
+}
+
+/// Invokes an authority gated instruction of this program with the authority_config PDA signing,
+/// once at least threshold of its signers approve. Remaining accounts are the approving signers
+/// followed by the inner instruction's accounts, with authority_config in its authority slot
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, InvokeAsAuthorityConfigV0<'info>>,
+  args: InvokeAsAuthorityConfigV0Args,
+) -> Result<()> {
+  let signer_count = args.signer_count as usize;
+  require!(
+    signer_count <= ctx.remaining_accounts.len(),
+    ErrorCode::InvalidArgs
+  );
+  let (signers, inner_accounts) = ctx.remaining_accounts.split_at(signer_count);
+
+  let authority_config = &ctx.accounts.authority_config;
+  let approvals = authority_config.approvals(
+    &signers
+      .iter()
+      .filter(|signer| signer.is_signer)
+      .map(|signer| signer.key())
+      .collect::<Vec<_>>(),
+  );
+  require!(
+    approvals >= authority_config.threshold as usize,
+    ErrorCode::ThresholdNotMet
+  );
+
+  let authority = authority_config.key();
+  let accounts = inner_accounts
+    .iter()
+    .map(|account| AccountMeta {
+      pubkey: account.key(),
+      is_signer: account.is_signer || account.key() == authority,
+      is_writable: account.is_writable,
+    })
+    .collect();
+
+  let mut account_infos = inner_accounts.to_vec();
+  account_infos.push(ctx.accounts.entangler_program.to_account_info());
+  invoke_signed(
+    &Instruction {
+      program_id: crate::ID,
+      accounts,
+      data: args.data,
+    },
+    &account_infos,
+    &[&authority_config.signer_seeds()],
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod invoke_as_authority_config_v0;
+pub mod set_authority_config_v0;
+
+pub use invoke_as_authority_config_v0::*;
+pub use set_authority_config_v0::*;
@@ -0,0 +1,59 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_parent_entangler_v0::AUTHORITY_CONFIG_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetAuthorityConfigV0Args {
+  pub signers: Vec<Pubkey>,
+  pub threshold: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetAuthorityConfigV0Args)]
+pub struct SetAuthorityConfigV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = AUTHORITY_CONFIG_SIZE,
+    seeds = [b"authority-config", parent_entangler.key().as_ref()],
+    bump,
+  )]
+  pub authority_config: Box<Account<'info, AuthorityConfigV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Hands the parent, and every child under it, to threshold of signers acting together through
+/// InvokeAsAuthorityConfigV0. Invoked that way it changes the signers, and SetAuthorityV0 or
+/// SealEntanglerV0 leave multisig control
+pub fn handler(ctx: Context<SetAuthorityConfigV0>, args: SetAuthorityConfigV0Args) -> Result<()> {
+  let authority_config = &mut ctx.accounts.authority_config;
+  authority_config.parent_entangler = ctx.accounts.parent_entangler.key();
+  authority_config.signers = args.signers;
+  authority_config.threshold = args.threshold;
+  authority_config.bump_seed = *ctx.bumps.get("authority_config").unwrap();
+  require!(
+    authority_config.is_valid(),
+    ErrorCode::InvalidAuthorityConfig
+  );
+
+  msg!(
+    "Setting authority to {} of {} signers",
+    authority_config.threshold,
+    authority_config.signers.len()
+  );
+  ctx.accounts.parent_entangler.authority = Some(authority_config.key());
+  ctx.accounts.parent_entangler.authority_nft_mint = None;
+
+  Ok(())
+}
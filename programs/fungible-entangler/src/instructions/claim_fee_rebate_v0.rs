@@ -0,0 +1,110 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::FEE_REBATE_CLAIM_SIZE,
+  math, state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
+
+#[derive(Accounts)]
+pub struct ClaimFeeRebateV0<'info> {
+  #[account(mut)]
+  pub owner: Signer<'info>,
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    seeds = [b"swap-record", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump = swap_record.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = owner @ ErrorCode::InvalidOwner,
+  )]
+  pub swap_record: Box<Account<'info, SwapRecordV0>>,
+  #[account(
+    init,
+    payer = owner,
+    space = FEE_REBATE_CLAIM_SIZE,
+    seeds = [b"fee-rebate", swap_record.key().as_ref()],
+    bump,
+  )]
+  pub fee_rebate_claim: Box<Account<'info, FeeRebateClaimV0>>,
+  #[account(mut)]
+  pub child_destination: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Pays the owner of a SwapRecordV0 their share of the fees DistributeFeesV0 snapshotted: child
+/// fees pro rata to the parent tokens they swapped in, and parent fees pro rata to the child
+/// tokens they swapped in. Each record claims once
+pub fn handler(ctx: Context<ClaimFeeRebateV0>) -> Result<()> {
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  let swap_record = &ctx.accounts.swap_record;
+  let mut distribution = child_entangler
+    .fee_distribution
+    .ok_or(error!(ErrorCode::FeesNotDistributing))?;
+
+  let (child_rebate, parent_rebate) = distribution.rebate(
+    swap_record.parent_to_child_amount,
+    swap_record.child_to_parent_amount,
+  )?;
+  require!(
+    child_rebate > 0 || parent_rebate > 0,
+    ErrorCode::InvalidAmount
+  );
+  distribution.claimed_child_fee_amount =
+    math::add(distribution.claimed_child_fee_amount, child_rebate)?;
+  distribution.claimed_parent_fee_amount =
+    math::add(distribution.claimed_parent_fee_amount, parent_rebate)?;
+  child_entangler.fee_distribution = Some(distribution);
+
+  let fee_rebate_claim = &mut ctx.accounts.fee_rebate_claim;
+  fee_rebate_claim.swap_record = swap_record.key();
+  fee_rebate_claim.bump_seed = *ctx.bumps.get("fee_rebate_claim").unwrap();
+
+  if child_rebate > 0 {
+    msg!("Rebating {} child tokens of fees", child_rebate);
+    transfer_signed(
+      &ctx.accounts.token_program.to_account_info(),
+      &ctx.accounts.child_storage.to_account_info(),
+      &ctx.accounts.child_destination.to_account_info(),
+      &child_entangler.to_account_info(),
+      &child_entangler.signer_seeds(),
+      child_rebate,
+    )?;
+  }
+  if parent_rebate > 0 {
+    msg!("Rebating {} parent tokens of fees", parent_rebate);
+    let parent_entangler = &mut ctx.accounts.parent_entangler;
+    parent_entangler.reserved_parent_fee_amount = parent_entangler
+      .reserved_parent_fee_amount
+      .saturating_sub(parent_rebate);
+    transfer_signed(
+      &ctx.accounts.token_program.to_account_info(),
+      &ctx.accounts.parent_storage.to_account_info(),
+      &ctx.accounts.parent_destination.to_account_info(),
+      &parent_entangler.to_account_info(),
+      &parent_entangler.signer_seeds(),
+      parent_rebate,
+    )?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::{error::ErrorCode, math, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
+
+#[derive(Accounts)]
+pub struct ClaimRewardsV0<'info> {
+  #[account(
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    mut,
+    seeds = [b"rewards", child_entangler.key().as_ref()],
+    bump = rewards_pool.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = reward_storage @ ErrorCode::StorageMismatch,
+  )]
+  pub rewards_pool: Box<Account<'info, RewardsPoolV0>>,
+  #[account(mut)]
+  pub reward_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = [b"top-off", child_entangler.key().as_ref(), depositor.key().as_ref()],
+    bump = top_off_record.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = depositor @ ErrorCode::InvalidOwner,
+  )]
+  pub top_off_record: Box<Account<'info, TopOffRecordV0>>,
+  pub depositor: Signer<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimRewardsV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  let rewards_pool = &mut ctx.accounts.rewards_pool;
+  let top_off_record = &mut ctx.accounts.top_off_record;
+
+  rewards_pool.update(clock.unix_timestamp)?;
+  rewards_pool.settle(top_off_record)?;
+
+  // An underfunded pool pays what it has, the rest stays owed
+  let amount = top_off_record
+    .rewards_owed
+    .min(ctx.accounts.reward_storage.amount);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+  top_off_record.rewards_owed = math::sub(top_off_record.rewards_owed, amount)?;
+
+  msg!("Claiming {} rewards", amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.reward_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &rewards_pool.to_account_info(),
+    &rewards_pool.signer_seeds(),
+    amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::{error::ErrorCode, math, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
+
+#[derive(Accounts)]
+pub struct ClaimVestedV0<'info> {
+  pub owner: Signer<'info>,
+  #[account(
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    mut,
+    seeds = [b"vesting-storage", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub vesting_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = [b"vesting", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump = vesting.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = owner @ ErrorCode::InvalidOwner,
+  )]
+  pub vesting: Box<Account<'info, VestingV0>>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimVestedV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &ctx.accounts.child_entangler;
+  let vesting = &mut ctx.accounts.vesting;
+  let config = child_entangler
+    .vesting
+    .ok_or(error!(ErrorCode::NoVesting))?;
+
+  let unlocked = config.unlocked_amount(vesting.total_amount, clock.unix_timestamp)?;
+  let amount = unlocked.saturating_sub(vesting.claimed_amount);
+  require!(amount > 0, ErrorCode::InvalidAmount);
+
+  vesting.claimed_amount = math::add(vesting.claimed_amount, amount)?;
+
+  msg!("Claiming {} vested tokens", amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.vesting_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+    amount,
+  )?;
+
+  Ok(())
+}
@@ -1,55 +1,85 @@
 use crate::{
+  account_compression::noop,
   error::ErrorCode,
+  events::{emit_noop, ChildEntanglerClosedV0},
+  math,
   state::*,
-  util::{close_token_account, CloseTokenAccount},
+  util::close_lookup_if_held,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::close_token_account_signed;
 
 #[derive(Accounts)]
 pub struct CloseFungibleChildEntanglerV0<'info> {
-  /// CHECK: Just used to get a refund of sol from closing the child entangler.
+  /// CHECK: Receives the rent, checked against the entangler's rent refund
   #[account(mut)]
-  pub refund: AccountInfo<'info>,
+  pub refund_to: AccountInfo<'info>,
   pub authority: Signer<'info>,
   #[account(
     mut,
-    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key(),
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = !parent_entangler.are_withdrawals_frozen(Clock::get()?.unix_timestamp) @ ErrorCode::WithdrawalsFrozen,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
   #[account(
     mut,
-    close = refund,
-    has_one = parent_entangler,
-    has_one = child_storage,
+    close = refund_to,
+    constraint = entangler.rent_refund == refund_to.key() @ ErrorCode::InvalidRefundRecipient,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = entangler.yield_strategy.map_or(true, |strategy| strategy.deposited_amount == 0) @ ErrorCode::StrategyNotEmpty,
+    constraint = entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub entangler: Box<Account<'info, FungibleChildEntanglerV0>>,
+  pub entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
   #[account(mut)]
   pub child_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: The mint pair's lookup, closed along with the child if the child holds it
+  #[account(
+    mut,
+    seeds = [b"lookup", parent_entangler.parent_mint.as_ref(), entangler.child_mint.as_ref()],
+    bump,
+  )]
+  pub lookup: UncheckedAccount<'info>,
   pub token_program: Program<'info, Token>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CloseFungibleChildEntanglerV0>) -> Result<()> {
   let entangler = &mut ctx.accounts.entangler;
-  let child_entangler_seeds: &[&[&[u8]]] = &[&[
-    b"entangler",
-    entangler.parent_entangler.as_ref(),
-    entangler.child_mint.as_ref(),
-    &[entangler.bump_seed],
-  ]];
 
-  ctx.accounts.parent_entangler.num_children -= 1;
+  ctx.accounts.parent_entangler.num_children =
+    math::sub_u32(ctx.accounts.parent_entangler.num_children, 1)?;
+  // Nobody can claim the child's fees once it is gone
+  ctx.accounts.parent_entangler.reserved_parent_fee_amount = ctx
+    .accounts
+    .parent_entangler
+    .reserved_parent_fee_amount
+    .saturating_sub(entangler.reserved_parent_fee_amount());
 
   msg!("Closing child storage");
-  close_token_account(CpiContext::new_with_signer(
-    ctx.accounts.token_program.to_account_info().clone(),
-    CloseTokenAccount {
-      from: ctx.accounts.child_storage.to_account_info().clone(),
-      to: ctx.accounts.refund.to_account_info().clone(),
-      authority: entangler.to_account_info().clone(),
-    },
-    child_entangler_seeds,
-  ))?;
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.refund_to.to_account_info(),
+    &entangler.to_account_info(),
+    &entangler.signer_seeds(),
+  )?;
+
+  close_lookup_if_held(
+    &ctx.accounts.lookup,
+    &entangler.key(),
+    &ctx.accounts.refund_to,
+  )?;
 
-  Ok(())
+  emit_noop(
+    &ctx.accounts.noop_program,
+    &ChildEntanglerClosedV0 {
+      parent_entangler: ctx.accounts.parent_entangler.key(),
+      child_entangler: entangler.key(),
+    },
+  )
 }
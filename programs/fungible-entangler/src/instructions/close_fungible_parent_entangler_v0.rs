@@ -1,49 +1,54 @@
 use crate::{
+  account_compression::noop,
   error::ErrorCode,
+  events::{emit_noop, ParentEntanglerClosedV0},
   state::*,
-  util::{close_token_account, CloseTokenAccount},
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::close_token_account_signed;
 
 #[derive(Accounts)]
 pub struct CloseFungibleParentEntanglerV0<'info> {
-  /// CHECK: Just used to get a refund of sol from closing the child entangler.
+  /// CHECK: Receives the rent, checked against the entangler's rent refund
   #[account(mut)]
-  pub refund: AccountInfo<'info>,
+  pub refund_to: AccountInfo<'info>,
   pub authority: Signer<'info>,
   #[account(
     mut,
-    close = refund,
-    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key(),
-    constraint = parent_entangler.num_children == 0,
-    has_one = parent_storage,
+    close = refund_to,
+    constraint = parent_entangler.rent_refund == refund_to.key() @ ErrorCode::InvalidRefundRecipient,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.num_children == 0 @ ErrorCode::ChildrenRemaining,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    constraint = !parent_entangler.are_withdrawals_frozen(Clock::get()?.unix_timestamp) @ ErrorCode::WithdrawalsFrozen,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
   #[account(mut)]
   pub parent_storage: Box<Account<'info, TokenAccount>>,
   pub token_program: Program<'info, Token>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CloseFungibleParentEntanglerV0>) -> Result<()> {
   let entangler = &mut ctx.accounts.parent_entangler;
-  let parent_entangler_seeds: &[&[&[u8]]] = &[&[
-    b"entangler",
-    entangler.parent_mint.as_ref(),
-    &entangler.dynamic_seed,
-    &[entangler.bump_seed],
-  ]];
 
   msg!("Closing parent storage");
-  close_token_account(CpiContext::new_with_signer(
-    ctx.accounts.token_program.to_account_info().clone(),
-    CloseTokenAccount {
-      from: ctx.accounts.parent_storage.to_account_info().clone(),
-      to: ctx.accounts.refund.to_account_info().clone(),
-      authority: entangler.to_account_info().clone(),
-    },
-    parent_entangler_seeds,
-  ))?;
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.refund_to.to_account_info(),
+    &entangler.to_account_info(),
+    &entangler.signer_seeds(),
+  )?;
 
-  Ok(())
+  emit_noop(
+    &ctx.accounts.noop_program,
+    &ParentEntanglerClosedV0 {
+      parent_entangler: entangler.key(),
+    },
+  )
 }
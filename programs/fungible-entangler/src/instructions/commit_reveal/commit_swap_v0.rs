@@ -0,0 +1,57 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::SWAP_COMMIT_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CommitSwapV0Args {
+  // SwapCommitV0::commitment of the swap to reveal
+  pub commitment: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(args: CommitSwapV0Args)]
+pub struct CommitSwapV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub owner: Signer<'info>,
+  #[account(
+    constraint = child_entangler.commit_reveal_seconds.is_some() @ ErrorCode::NoCommitReveal,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = SWAP_COMMIT_SIZE,
+    seeds = [b"swap-commit", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump,
+  )]
+  pub swap_commit: Box<Account<'info, SwapCommitV0>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Commits to a swap, or replaces the owner's commitment, before the child goes live. Only the
+/// hash goes onchain, so bots can't see the amount or direction until it is revealed. The rent
+/// goes back to whoever paid it first once the swap is revealed
+pub fn handler(ctx: Context<CommitSwapV0>, args: CommitSwapV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(
+    clock.unix_timestamp < ctx.accounts.child_entangler.go_live_unix_time,
+    ErrorCode::CommitWindowClosed
+  );
+
+  let swap_commit = &mut ctx.accounts.swap_commit;
+  swap_commit.child_entangler = ctx.accounts.child_entangler.key();
+  swap_commit.owner = ctx.accounts.owner.key();
+  swap_commit.commitment = args.commitment;
+  swap_commit.committed_at_unix_time = clock.unix_timestamp;
+  if swap_commit.payer == Pubkey::default() {
+    swap_commit.payer = ctx.accounts.payer.key();
+  }
+  swap_commit.bump_seed = *ctx.bumps.get("swap_commit").unwrap();
+
+  Ok(())
+}
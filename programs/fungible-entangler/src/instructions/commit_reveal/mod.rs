@@ -0,0 +1,5 @@
+pub mod commit_swap_v0;
+pub mod reveal_and_swap_v0;
+
+pub use commit_swap_v0::*;
+pub use reveal_and_swap_v0::*;
@@ -0,0 +1,64 @@
+use crate::{
+  error::ErrorCode,
+  instructions::swap::{
+    account::*, arg::SwapV0Args, common::send_memo, swap_child_for_parent, swap_parent_for_child,
+  },
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RevealAndSwapV0Args {
+  pub amount: u64,
+  pub parent_to_child: bool,
+  pub salt: [u8; 32],
+  pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RevealAndSwapV0Args)]
+pub struct RevealAndSwapV0<'info> {
+  pub common: SwapCommonV0<'info>,
+  #[account(
+    mut,
+    close = payer,
+    seeds = [b"swap-commit", common.child_entangler.key().as_ref(), common.source_authority.key().as_ref()],
+    bump = swap_commit.bump_seed,
+    has_one = payer @ ErrorCode::InvalidOwner,
+  )]
+  pub swap_commit: Box<Account<'info, SwapCommitV0>>,
+  /// CHECK: Receives the rent of the commitment, checked against it
+  #[account(mut)]
+  pub payer: UncheckedAccount<'info>,
+}
+
+/// Executes a committed swap, the only way to swap during the child's commit reveal window, and
+/// closes the commitment to whoever paid its rent
+pub fn handler(ctx: Context<RevealAndSwapV0>, args: RevealAndSwapV0Args) -> Result<()> {
+  let common = &mut ctx.accounts.common;
+  require!(
+    ctx.accounts.swap_commit.commitment
+      == SwapCommitV0::commitment(
+        &common.child_entangler.key(),
+        &common.source_authority.key(),
+        args.amount,
+        args.parent_to_child,
+        &args.salt,
+      ),
+    ErrorCode::InvalidReveal
+  );
+
+  let swap_args = SwapV0Args {
+    amount: Some(args.amount),
+    all: None,
+    output_splits_bps: vec![],
+    memo: args.memo,
+    allow_partial: false,
+  };
+  if args.parent_to_child {
+    swap_parent_for_child(common, &[], true, &swap_args)?;
+  } else {
+    swap_child_for_parent(common, &[], true, &swap_args)?;
+  }
+  send_memo(&common.memo_program, &swap_args.memo)
+}
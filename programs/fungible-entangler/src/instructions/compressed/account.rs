@@ -0,0 +1,133 @@
+use crate::{
+  account_compression::{self, Modify},
+  error::ErrorCode,
+  instructions::swap::account::SwapAccounts,
+  math,
+  state::*,
+  util::{immediate_caller, is_frozen_token_account},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// SwapCommonV0 without the per-user SwapRecordV0. The swap is appended to the child's
+/// swap_record_tree instead
+#[derive(Accounts)]
+pub struct SwapCompressedCommonV0<'info> {
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+    constraint = child_entangler.swap_record_tree == Some(merkle_tree.key()) @ ErrorCode::InvalidSwapRecordTree,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    constraint = !source.is_frozen() @ ErrorCode::SourceAccountFrozen,
+  )]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub source_authority: Signer<'info>,
+  /// CHECK: Only receives tokens, the token program validates it in the transfer
+  #[account(mut,
+    constraint = !is_frozen_token_account(&destination) @ ErrorCode::DestinationAccountFrozen,
+  )]
+  pub destination: UncheckedAccount<'info>,
+  /// CHECK: The child's swap record tree, validated by the compression program on append
+  #[account(mut)]
+  pub merkle_tree: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The instructions sysvar, read to enforce the child's CPI policy
+  #[account(
+    address = sysvar::instructions::ID,
+    constraint = child_entangler.allows_caller(immediate_caller(&instructions)?) @ ErrorCode::CpiNotAllowed,
+  )]
+  pub instructions: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  /// CHECK: The SPL memo program, only invoked when the swap carries a memo
+  #[account(address = spl_memo::id())]
+  pub memo_program: UncheckedAccount<'info>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+  /// CHECK: spl-account-compression
+  #[account(address = account_compression::program::ID)]
+  pub compression_program: UncheckedAccount<'info>,
+  /// CHECK: spl-noop
+  #[account(address = account_compression::noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+impl<'info> SwapCompressedCommonV0<'info> {
+  pub fn swap_accounts(&mut self) -> SwapAccounts<'_, 'info> {
+    SwapAccounts {
+      parent_entangler: &mut self.parent_entangler,
+      parent_storage: &mut self.parent_storage,
+      parent_mint: &mut self.parent_mint,
+      child_entangler: &mut self.child_entangler,
+      child_storage: &mut self.child_storage,
+      child_mint: &mut self.child_mint,
+      source: &self.source,
+      source_authority: &self.source_authority,
+      source_signer_seeds: &[],
+      destination: &self.destination,
+      rate_schedule: &self.rate_schedule,
+      token_program: &self.token_program,
+      noop_program: &self.noop_program,
+      revealed_commit: false,
+      vested: false,
+      locked: false,
+      queued: false,
+    }
+  }
+
+  /// Appends record to the child's swap record tree, signed by the child
+  pub fn append_record(&mut self, record: &CompressedSwapRecordV0) -> Result<()> {
+    msg!(
+      "Appending swap record {} to the swap record tree",
+      self.child_entangler.compressed_record_count
+    );
+    account_compression::append(
+      CpiContext::new_with_signer(
+        self.compression_program.to_account_info(),
+        Modify {
+          merkle_tree: self.merkle_tree.to_account_info(),
+          authority: self.child_entangler.to_account_info(),
+          noop: self.noop_program.to_account_info(),
+        },
+        &[&self.child_entangler.signer_seeds()],
+      ),
+      record.leaf_hash()?,
+    )?;
+    self.child_entangler.compressed_record_count =
+      math::add(self.child_entangler.compressed_record_count, 1)?;
+
+    Ok(())
+  }
+}
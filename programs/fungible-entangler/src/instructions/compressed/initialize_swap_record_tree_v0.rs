@@ -0,0 +1,69 @@
+use crate::{
+  account_compression::{self, Modify},
+  error::ErrorCode,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeSwapRecordTreeV0Args {
+  pub max_depth: u32,
+  pub max_buffer_size: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeSwapRecordTreeV0Args)]
+pub struct InitializeSwapRecordTreeV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+    constraint = child_entangler.swap_record_tree.is_none() @ ErrorCode::InvalidSwapRecordTree,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// CHECK: Allocated by the client and owned by the compression program, which initializes it
+  #[account(mut, owner = account_compression::program::ID)]
+  pub merkle_tree: UncheckedAccount<'info>,
+
+  /// CHECK: spl-account-compression
+  #[account(address = account_compression::program::ID)]
+  pub compression_program: UncheckedAccount<'info>,
+  /// CHECK: spl-noop
+  #[account(address = account_compression::noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Gives the child a concurrent merkle tree, owned by the child entangler, for
+/// SwapParentForChildCompressedV0 and SwapChildForParentCompressedV0 to record swaps in without a
+/// rent paying SwapRecordV0 per wallet
+pub fn handler(
+  ctx: Context<InitializeSwapRecordTreeV0>,
+  args: InitializeSwapRecordTreeV0Args,
+) -> Result<()> {
+  let child_entangler = &mut ctx.accounts.child_entangler;
+
+  account_compression::init_empty_merkle_tree(
+    CpiContext::new_with_signer(
+      ctx.accounts.compression_program.to_account_info(),
+      Modify {
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        authority: child_entangler.to_account_info(),
+        noop: ctx.accounts.noop_program.to_account_info(),
+      },
+      &[&child_entangler.signer_seeds()],
+    ),
+    args.max_depth,
+    args.max_buffer_size,
+  )?;
+
+  child_entangler.swap_record_tree = Some(ctx.accounts.merkle_tree.key());
+  child_entangler.compressed_record_count = 0;
+
+  Ok(())
+}
@@ -0,0 +1,11 @@
+pub mod account;
+pub mod initialize_swap_record_tree_v0;
+pub mod refund_compressed_swap_v0;
+pub mod swap_child_for_parent_compressed_v0;
+pub mod swap_parent_for_child_compressed_v0;
+
+pub use account::*;
+pub use initialize_swap_record_tree_v0::*;
+pub use refund_compressed_swap_v0::*;
+pub use swap_child_for_parent_compressed_v0::*;
+pub use swap_parent_for_child_compressed_v0::*;
@@ -0,0 +1,158 @@
+use crate::{
+  account_compression::{self, Modify},
+  error::ErrorCode,
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use strata_spl_utils::transfer_signed;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RefundCompressedSwapV0Args {
+  // Current root of the swap record tree, which the proof in remaining accounts leads to
+  pub root: [u8; 32],
+  pub record: CompressedSwapRecordV0,
+  pub index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RefundCompressedSwapV0Args)]
+pub struct RefundCompressedSwapV0<'info> {
+  #[account(
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+    constraint = child_entangler.swap_record_tree == Some(merkle_tree.key()) @ ErrorCode::InvalidSwapRecordTree,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: The child's swap record tree, validated by the compression program on replace
+  #[account(mut)]
+  pub merkle_tree: UncheckedAccount<'info>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub source_authority: Signer<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+
+  pub token_program: Program<'info, Token>,
+  /// CHECK: spl-account-compression
+  #[account(address = account_compression::program::ID)]
+  pub compression_program: UncheckedAccount<'info>,
+  /// CHECK: spl-noop
+  #[account(address = account_compression::noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// RefundSwapV0 for a compressed parent to child swap. The whole swap is reversed and its leaf
+/// replaced with a refunded copy, proven by the proof nodes passed as remaining accounts
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, RefundCompressedSwapV0<'info>>,
+  args: RefundCompressedSwapV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &ctx.accounts.child_entangler;
+  let window = child_entangler
+    .refund_window_seconds
+    .ok_or(error!(ErrorCode::NoRefundWindow))?;
+  require!(
+    !child_entangler.is_expired(clock.unix_timestamp),
+    ErrorCode::Expired
+  );
+  require!(
+    child_entangler.fee_distribution.is_none(),
+    ErrorCode::FeesAlreadyDistributing
+  );
+  let record = args.record;
+
+  require!(
+    record.child_entangler == ctx.accounts.child_entangler.key()
+      && record.owner == ctx.accounts.source_authority.key()
+      && record.parent_to_child
+      && !record.refunded,
+    ErrorCode::InvalidCompressedRecord
+  );
+  require!(
+    record
+      .unix_time
+      .checked_add(window)
+      .map_or(false, |end| clock.unix_timestamp <= end),
+    ErrorCode::RefundWindowClosed
+  );
+
+  let child_entangler = &ctx.accounts.child_entangler;
+  let refunded = CompressedSwapRecordV0 {
+    refunded: true,
+    ..record
+  };
+  account_compression::replace_leaf(
+    CpiContext::new_with_signer(
+      ctx.accounts.compression_program.to_account_info(),
+      Modify {
+        merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+        authority: child_entangler.to_account_info(),
+        noop: ctx.accounts.noop_program.to_account_info(),
+      },
+      &[&child_entangler.signer_seeds()],
+    )
+    .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+    args.root,
+    record.leaf_hash()?,
+    refunded.leaf_hash()?,
+    args.index,
+  )?;
+
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.total_parent_to_child_amount = child_entangler
+    .total_parent_to_child_amount
+    .saturating_sub(record.parent_amount);
+
+  let token_program = ctx.accounts.token_program.to_account_info();
+  let parent_entangler = &ctx.accounts.parent_entangler;
+
+  msg!(
+    "Refunding {} from source to child storage",
+    record.child_amount
+  );
+  token::transfer(
+    CpiContext::new(
+      token_program.clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.child_storage.to_account_info().clone(),
+        authority: ctx.accounts.source_authority.to_account_info().clone(),
+      },
+    ),
+    record.child_amount,
+  )?;
+
+  msg!(
+    "Refunding {} from parent storage to destination",
+    record.parent_amount
+  );
+  transfer_signed(
+    &token_program,
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &parent_entangler.to_account_info(),
+    &parent_entangler.signer_seeds(),
+    record.parent_amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,45 @@
+use super::account::*;
+use crate::{
+  instructions::swap::{
+    arg::SwapV0Args,
+    common::{return_fill, send_memo},
+    execute_child_for_parent,
+  },
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: SwapV0Args)]
+pub struct SwapChildForParentCompressedV0<'info> {
+  pub common: SwapCompressedCommonV0<'info>,
+}
+
+/// SwapChildForParentV0 recording to the child's swap record tree
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapChildForParentCompressedV0<'info>>,
+  args: SwapV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  let common = &mut ctx.accounts.common;
+  let outcome = execute_child_for_parent(
+    common.swap_accounts(),
+    ctx.remaining_accounts,
+    &clock,
+    &args,
+  )?;
+
+  let record = CompressedSwapRecordV0 {
+    child_entangler: common.child_entangler.key(),
+    owner: common.source_authority.key(),
+    parent_to_child: false,
+    parent_amount: outcome.output_amount,
+    child_amount: outcome.amount,
+    unix_time: clock.unix_timestamp,
+    refunded: false,
+  };
+  common.append_record(&record)?;
+
+  return_fill(&args, &outcome)?;
+  send_memo(&common.memo_program, &args.memo)
+}
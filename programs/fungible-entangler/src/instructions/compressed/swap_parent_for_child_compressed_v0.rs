@@ -0,0 +1,46 @@
+use super::account::*;
+use crate::{
+  instructions::swap::{
+    arg::SwapV0Args,
+    common::{return_fill, send_memo},
+    execute_parent_for_child,
+  },
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: SwapV0Args)]
+pub struct SwapParentForChildCompressedV0<'info> {
+  pub common: SwapCompressedCommonV0<'info>,
+}
+
+/// SwapParentForChildV0 recording to the child's swap record tree. Refunds go through
+/// RefundCompressedSwapV0, and reverse swap fee rebates are not available
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapParentForChildCompressedV0<'info>>,
+  args: SwapV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  let common = &mut ctx.accounts.common;
+  let outcome = execute_parent_for_child(
+    common.swap_accounts(),
+    ctx.remaining_accounts,
+    &clock,
+    &args,
+  )?;
+
+  let record = CompressedSwapRecordV0 {
+    child_entangler: common.child_entangler.key(),
+    owner: common.source_authority.key(),
+    parent_to_child: true,
+    parent_amount: outcome.retained_amount()?,
+    child_amount: outcome.output_amount,
+    unix_time: clock.unix_timestamp,
+    refunded: false,
+  };
+  common.append_record(&record)?;
+
+  return_fill(&args, &outcome)?;
+  send_memo(&common.memo_program, &args.memo)
+}
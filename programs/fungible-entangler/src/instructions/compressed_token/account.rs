@@ -0,0 +1,338 @@
+use crate::{
+  account_compression::noop,
+  compressed_token::{self, CompressedProof, CompressedTransfer, InputTokenDataWithContext},
+  error::ErrorCode,
+  instructions::swap::{
+    account::SwapAccounts,
+    arg::SwapV0Args,
+    common::{return_fill, send_memo, SwapDirection},
+    execute_child_for_parent, execute_parent_for_child,
+  },
+  state::*,
+  util::{create_pda_token_account, immediate_caller},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use std::collections::BTreeMap;
+use strata_spl_utils::close_token_account_signed;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct SwapCompressedTokenV0Args {
+  pub amount: u64,
+  // Swap what output storage can cover rather than failing, and return a SwapFillV0
+  pub allow_partial: bool,
+  // Logged through the SPL memo program after the swap, for deposit attribution
+  pub memo: Option<String>,
+  // Validity proof of the inputs, None when every input is proven by its queue index
+  pub proof: Option<CompressedProof>,
+  // The owner's compressed input mint accounts to spend, together holding at least amount
+  pub input_token_accounts: Vec<InputTokenDataWithContext>,
+  // Index into the remaining accounts of the state tree that change and output land in
+  pub output_merkle_tree_index: u8,
+}
+
+impl SwapCompressedTokenV0Args {
+  fn swap_args(&self) -> SwapV0Args {
+    SwapV0Args {
+      amount: Some(self.amount),
+      all: None,
+      output_splits_bps: vec![],
+      memo: None,
+      allow_partial: self.allow_partial,
+    }
+  }
+}
+
+/// Swaps between Light Protocol compressed token accounts. The owner's compressed input is
+/// decompressed into a transit token account held by the child, swapped from there like a queued
+/// swap's escrow, and the output and any unswapped input are compressed back out. Both transit
+/// accounts are created and closed within the instruction. The remaining accounts are the state
+/// trees and queues the packed inputs and outputs index into
+#[derive(Accounts)]
+pub struct SwapCompressedTokenCommonV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  /// Owns the compressed input accounts
+  pub owner: Signer<'info>,
+  /// CHECK: Only owns the compressed output
+  pub recipient: UncheckedAccount<'info>,
+  /// CHECK: Created in the handler as a parent mint token account owned by child_entangler
+  #[account(
+    mut,
+    seeds = [b"compressed-token-transit", child_entangler.key().as_ref(), parent_mint.key().as_ref()],
+    bump,
+  )]
+  pub parent_transit: UncheckedAccount<'info>,
+  /// CHECK: Created in the handler as a child mint token account owned by child_entangler
+  #[account(
+    mut,
+    seeds = [b"compressed-token-transit", child_entangler.key().as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub child_transit: UncheckedAccount<'info>,
+  /// CHECK: The compressed-token program's token pool for the parent mint, checked with cpi
+  #[account(mut)]
+  pub parent_token_pool: UncheckedAccount<'info>,
+  /// CHECK: The compressed-token program's token pool for the child mint, checked with cpi
+  #[account(mut)]
+  pub child_token_pool: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The instructions sysvar, read to enforce the child's CPI policy
+  #[account(
+    address = sysvar::instructions::ID,
+    constraint = child_entangler.allows_caller(immediate_caller(&instructions)?) @ ErrorCode::CpiNotAllowed,
+  )]
+  pub instructions: UncheckedAccount<'info>,
+  /// CHECK: The compressed-token program's cpi authority, checked with cpi
+  pub cpi_authority_pda: UncheckedAccount<'info>,
+  /// CHECK: The light system program's registration with account compression, checked with cpi
+  pub registered_program_pda: UncheckedAccount<'info>,
+  /// CHECK: The light system program's account compression authority, checked with cpi
+  pub account_compression_authority: UncheckedAccount<'info>,
+  /// CHECK: Light's compressed-token program
+  #[account(address = compressed_token::program::ID)]
+  pub compressed_token_program: UncheckedAccount<'info>,
+  /// CHECK: Light's system program
+  #[account(address = compressed_token::light_system::ID)]
+  pub light_system_program: UncheckedAccount<'info>,
+  /// CHECK: Light's account compression program
+  #[account(address = compressed_token::account_compression::ID)]
+  pub account_compression_program: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: The SPL memo program, only invoked when the swap carries a memo
+  #[account(address = spl_memo::id())]
+  pub memo_program: UncheckedAccount<'info>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+  /// CHECK: spl-noop, which swap events and compressed account changes are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+impl<'info> SwapCompressedTokenCommonV0<'info> {
+  fn transfer_accounts(
+    &self,
+    authority: AccountInfo<'info>,
+    token_pool: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+  ) -> CompressedTransfer<'info> {
+    CompressedTransfer {
+      fee_payer: self.payer.to_account_info(),
+      authority,
+      cpi_authority_pda: self.cpi_authority_pda.to_account_info(),
+      light_system_program: self.light_system_program.to_account_info(),
+      registered_program_pda: self.registered_program_pda.to_account_info(),
+      noop_program: self.noop_program.to_account_info(),
+      account_compression_authority: self.account_compression_authority.to_account_info(),
+      account_compression_program: self.account_compression_program.to_account_info(),
+      token_pool_pda: token_pool.clone(),
+      token_account: token_account.clone(),
+      token_program: self.token_program.to_account_info(),
+      system_program: self.system_program.to_account_info(),
+    }
+  }
+}
+
+/// Runs a swap in direction between compressed token accounts, see SwapCompressedTokenCommonV0.
+/// Compressed token swaps are not recorded to a SwapRecordV0, so they can't be refunded or rebated
+pub fn swap_compressed_token<'info>(
+  accounts: &mut SwapCompressedTokenCommonV0<'info>,
+  trees: &[AccountInfo<'info>],
+  bumps: &BTreeMap<String, u8>,
+  direction: SwapDirection,
+  args: &SwapCompressedTokenV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_key = accounts.child_entangler.key();
+  let parent_key = accounts.child_entangler.parent_entangler;
+  let child_mint_key = accounts.child_entangler.child_mint;
+  let bump_seed = [accounts.child_entangler.bump_seed];
+  let child_seeds: &[&[u8]] = &[
+    b"entangler",
+    parent_key.as_ref(),
+    child_mint_key.as_ref(),
+    &bump_seed,
+  ];
+  let child_info = accounts.child_entangler.to_account_info();
+
+  let parent_bump = [*bumps.get("parent_transit").unwrap()];
+  let child_bump = [*bumps.get("child_transit").unwrap()];
+  let parent_mint_key = accounts.parent_mint.key();
+  let parent_transit_seeds: &[&[u8]] = &[
+    b"compressed-token-transit",
+    child_key.as_ref(),
+    parent_mint_key.as_ref(),
+    &parent_bump,
+  ];
+  let child_transit_seeds: &[&[u8]] = &[
+    b"compressed-token-transit",
+    child_key.as_ref(),
+    child_mint_key.as_ref(),
+    &child_bump,
+  ];
+  for (transit, mint, seeds) in [
+    (
+      &accounts.parent_transit,
+      accounts.parent_mint.to_account_info(),
+      parent_transit_seeds,
+    ),
+    (
+      &accounts.child_transit,
+      accounts.child_mint.to_account_info(),
+      child_transit_seeds,
+    ),
+  ] {
+    create_pda_token_account(
+      &accounts.payer.to_account_info(),
+      &accounts.system_program.to_account_info(),
+      &accounts.token_program.to_account_info(),
+      &transit.to_account_info(),
+      &mint,
+      &child_key,
+      seeds,
+    )?;
+  }
+
+  let parent_transit = accounts.parent_transit.to_account_info();
+  let child_transit = accounts.child_transit.to_account_info();
+  let parent_pool = accounts.parent_token_pool.to_account_info();
+  let child_pool = accounts.child_token_pool.to_account_info();
+  let (input_mint, input_transit, input_pool, output_mint, output_transit, output_pool) =
+    match direction {
+      SwapDirection::ParentToChild => (
+        parent_mint_key,
+        &parent_transit,
+        &parent_pool,
+        child_mint_key,
+        &child_transit,
+        &child_pool,
+      ),
+      SwapDirection::ChildToParent => (
+        child_mint_key,
+        &child_transit,
+        &child_pool,
+        parent_mint_key,
+        &parent_transit,
+        &parent_pool,
+      ),
+    };
+
+  msg!("Decompressing {} into transit", args.amount);
+  compressed_token::decompress(
+    CpiContext::new(
+      accounts.compressed_token_program.to_account_info(),
+      accounts.transfer_accounts(accounts.owner.to_account_info(), input_pool, input_transit),
+    )
+    .with_remaining_accounts(trees.to_vec()),
+    input_mint,
+    args.proof,
+    args.input_token_accounts.clone(),
+    args.amount,
+    args.output_merkle_tree_index,
+  )?;
+
+  let source: Account<TokenAccount> = Account::try_from(input_transit)?;
+  let swap_args = args.swap_args();
+  let swap_accounts = SwapAccounts {
+    parent_entangler: &mut accounts.parent_entangler,
+    parent_storage: &mut accounts.parent_storage,
+    parent_mint: &mut accounts.parent_mint,
+    child_entangler: &mut accounts.child_entangler,
+    child_storage: &mut accounts.child_storage,
+    child_mint: &mut accounts.child_mint,
+    source: &source,
+    source_authority: &child_info,
+    source_signer_seeds: &[child_seeds],
+    destination: output_transit,
+    rate_schedule: &accounts.rate_schedule,
+    token_program: &accounts.token_program,
+    noop_program: &accounts.noop_program,
+    revealed_commit: false,
+    vested: false,
+    locked: false,
+    queued: false,
+  };
+  let outcome = match direction {
+    SwapDirection::ParentToChild => {
+      execute_parent_for_child(swap_accounts, &[], &clock, &swap_args)?
+    }
+    SwapDirection::ChildToParent => {
+      execute_child_for_parent(swap_accounts, &[], &clock, &swap_args)?
+    }
+  };
+
+  // The output goes to the recipient, and what a partial fill left of the input back to the owner
+  for (transit, pool, mint, owner) in [
+    (
+      output_transit,
+      output_pool,
+      output_mint,
+      accounts.recipient.key(),
+    ),
+    (input_transit, input_pool, input_mint, accounts.owner.key()),
+  ] {
+    let amount = Account::<TokenAccount>::try_from(transit)?.amount;
+    if amount > 0 {
+      msg!("Compressing {} out of transit to {}", amount, owner);
+      compressed_token::compress(
+        CpiContext::new_with_signer(
+          accounts.compressed_token_program.to_account_info(),
+          accounts.transfer_accounts(child_info.clone(), pool, transit),
+          &[child_seeds],
+        )
+        .with_remaining_accounts(trees.to_vec()),
+        mint,
+        owner,
+        amount,
+        args.output_merkle_tree_index,
+      )?;
+    }
+    close_token_account_signed(
+      &accounts.token_program.to_account_info(),
+      transit,
+      &accounts.payer.to_account_info(),
+      &child_info,
+      child_seeds,
+    )?;
+  }
+
+  return_fill(&swap_args, &outcome)?;
+  send_memo(&accounts.memo_program, &args.memo)
+}
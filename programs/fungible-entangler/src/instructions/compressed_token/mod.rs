@@ -0,0 +1,7 @@
+pub mod account;
+pub mod swap_child_for_parent_compressed_token_v0;
+pub mod swap_parent_for_child_compressed_token_v0;
+
+pub use account::*;
+pub use swap_child_for_parent_compressed_token_v0::*;
+pub use swap_parent_for_child_compressed_token_v0::*;
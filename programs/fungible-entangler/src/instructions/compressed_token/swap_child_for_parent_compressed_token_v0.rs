@@ -0,0 +1,23 @@
+use super::account::*;
+use crate::instructions::swap::common::SwapDirection;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: SwapCompressedTokenV0Args)]
+pub struct SwapChildForParentCompressedTokenV0<'info> {
+  pub common: SwapCompressedTokenCommonV0<'info>,
+}
+
+/// SwapChildForParentV0 from the owner's compressed child tokens to compressed parent tokens
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapChildForParentCompressedTokenV0<'info>>,
+  args: SwapCompressedTokenV0Args,
+) -> Result<()> {
+  swap_compressed_token(
+    &mut ctx.accounts.common,
+    ctx.remaining_accounts,
+    &ctx.bumps,
+    SwapDirection::ChildToParent,
+    &args,
+  )
+}
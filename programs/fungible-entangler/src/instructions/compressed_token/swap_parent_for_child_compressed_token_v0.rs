@@ -0,0 +1,23 @@
+use super::account::*;
+use crate::instructions::swap::common::SwapDirection;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(args: SwapCompressedTokenV0Args)]
+pub struct SwapParentForChildCompressedTokenV0<'info> {
+  pub common: SwapCompressedTokenCommonV0<'info>,
+}
+
+/// SwapParentForChildV0 from the owner's compressed parent tokens to compressed child tokens
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapParentForChildCompressedTokenV0<'info>>,
+  args: SwapCompressedTokenV0Args,
+) -> Result<()> {
+  swap_compressed_token(
+    &mut ctx.accounts.common,
+    ctx.remaining_accounts,
+    &ctx.bumps,
+    SwapDirection::ParentToChild,
+    &args,
+  )
+}
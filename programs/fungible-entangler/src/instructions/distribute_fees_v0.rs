@@ -0,0 +1,49 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DistributeFeesV0<'info> {
+  #[account(
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+}
+
+/// Snapshots the fees an expired child, or a frozen child of a sealed parent, accrued so that
+/// swappers can claim them back via ClaimFeeRebateV0. Swaps through the child close from then on.
+/// Anyone may call it
+pub fn handler(ctx: Context<DistributeFeesV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &mut ctx.accounts.child_entangler;
+
+  require!(
+    child_entangler.is_fee_distributable(&ctx.accounts.parent_entangler, clock.unix_timestamp),
+    ErrorCode::FeesNotDistributable
+  );
+  require!(
+    child_entangler.fee_distribution.is_none(),
+    ErrorCode::FeesAlreadyDistributing
+  );
+
+  msg!(
+    "Distributing {} child and {} parent tokens of fees",
+    child_entangler.accrued_child_fee_amount,
+    child_entangler.accrued_parent_fee_amount
+  );
+  child_entangler.fee_distribution = Some(FeeDistributionV0 {
+    child_fee_amount: child_entangler.accrued_child_fee_amount,
+    parent_fee_amount: child_entangler.accrued_parent_fee_amount,
+    parent_to_child_amount: child_entangler.recorded_parent_to_child_amount,
+    child_to_parent_amount: child_entangler.recorded_child_to_parent_amount,
+    claimed_child_fee_amount: 0,
+    claimed_parent_fee_amount: 0,
+  });
+
+  Ok(())
+}
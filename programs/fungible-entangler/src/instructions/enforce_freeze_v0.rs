@@ -0,0 +1,45 @@
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  events::{emit_noop, ChildEntanglerFrozenV0},
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct EnforceFreezeV0<'info> {
+  #[account(
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = !child_entangler.frozen @ ErrorCode::AlreadyFrozen,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<EnforceFreezeV0>) -> Result<()> {
+  let unix_time = Clock::get()?.unix_timestamp;
+  require!(
+    ctx.accounts.parent_entangler.is_freeze_due(unix_time)
+      || ctx.accounts.child_entangler.is_freeze_due(unix_time),
+    ErrorCode::FreezeNotDue
+  );
+
+  msg!("Freezing child entangler");
+  ctx.accounts.child_entangler.frozen = true;
+
+  emit_noop(
+    &ctx.accounts.noop_program,
+    &ChildEntanglerFrozenV0 {
+      parent_entangler: ctx.accounts.parent_entangler.key(),
+      child_entangler: ctx.accounts.child_entangler.key(),
+    },
+  )
+}
@@ -0,0 +1,160 @@
+use super::initialize_fungible_child_entangler_v0::{
+  initialize_child_entangler, InitializeFungibleChildEntanglerV0Args, CHILD_ENTANGLER_SIZE,
+  ENTANGLER_LOOKUP_SIZE,
+};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  state::*,
+  util::{charge_creation_fee, create_pda_token_account},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(args: InitializeFungibleChildEntanglerV0Args)]
+pub struct EnsureChildEntanglerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.parent_mint != child_mint.key() @ ErrorCode::SameMint,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = CHILD_ENTANGLER_SIZE,
+    seeds = [b"entangler", parent_entangler.key().as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// CHECK: Created in the handler along with the entangler, as a child_mint token account owned
+  /// by entangler
+  #[account(
+    mut,
+    seeds = [b"storage", entangler.key().as_ref()],
+    bump,
+  )]
+  pub child_storage: UncheckedAccount<'info>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = ENTANGLER_LOOKUP_SIZE,
+    seeds = [b"lookup", parent_entangler.parent_mint.as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub lookup: Box<Account<'info, EntanglerLookupV0>>,
+  #[account(
+    constraint = child_mint.is_initialized @ ErrorCode::MintNotInitialized,
+    constraint = child_mint.key() != parent_entangler.parent_mint @ ErrorCode::SameMint,
+    constraint = !args.reject_freeze_authority || child_mint.freeze_authority.is_none() @ ErrorCode::MintHasFreezeAuthority,
+  )]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = fee_destination @ ErrorCode::FeeDestinationMismatch,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: Only receives lamports, checked against the global config
+  #[account(mut)]
+  pub fee_destination: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Whether an existing child was initialized with these args. Go live time is skipped since it
+/// is clamped to the creation time, and rent refund since it defaults to whoever paid.
+fn matches_args(
+  entangler: &FungibleChildEntanglerV1,
+  parent_entangler: &FungibleParentEntanglerV1,
+  args: &InitializeFungibleChildEntanglerV0Args,
+) -> bool {
+  entangler.freeze_swap_unix_time == args.freeze_swap_unix_time
+    && entangler.vesting == args.vesting
+    && entangler.refund_window_seconds == args.refund_window_seconds
+    && entangler.expiry_unix_time == args.expiry_unix_time
+    && entangler.min_lock_seconds == args.min_lock_seconds
+    && entangler.burn == args.burn
+    && args
+      .rent_refund
+      .map_or(true, |r| r == entangler.rent_refund)
+    && entangler.rate == args.rate.unwrap_or(parent_entangler.default_rate)
+    && entangler.swap_fee_bps == args.swap_fee_bps
+    && entangler.max_parent_to_child_amount == args.max_parent_to_child_amount
+    && entangler.cpi_policy == args.cpi_policy
+    && entangler.cpi_allowlist == args.cpi_allowlist
+    && entangler.max_child_to_parent_amount == args.max_child_to_parent_amount
+    && entangler.stake_program == args.stake_program
+    && entangler.liquidity_program == args.liquidity_program
+    && entangler.fee_tiers == args.fee_tiers
+    && entangler.reverse_rebate_seconds == args.reverse_rebate_seconds
+    && entangler.freeze_parent_to_child_unix_time == args.freeze_parent_to_child_unix_time
+    && entangler.freeze_child_to_parent_unix_time == args.freeze_child_to_parent_unix_time
+    && entangler.commit_reveal_seconds == args.commit_reveal_seconds
+}
+
+/// InitializeFungibleChildEntanglerV0 that also succeeds when the child already exists with the
+/// same parameters, so retried deployments don't fail on their second attempt
+pub fn handler(
+  ctx: Context<EnsureChildEntanglerV0>,
+  args: InitializeFungibleChildEntanglerV0Args,
+) -> Result<()> {
+  // Freshly allocated accounts are zeroed, and every initialized child has a nonzero version
+  if ctx.accounts.entangler.version != 0 {
+    msg!("Child entangler already exists");
+    let entangler = &ctx.accounts.entangler;
+    require!(
+      entangler.is_supported_version()
+        && entangler.child_storage == ctx.accounts.child_storage.key()
+        && matches_args(entangler, &ctx.accounts.parent_entangler, &args),
+      ErrorCode::ChildEntanglerArgsMismatch
+    );
+
+    return Ok(());
+  }
+
+  let clock = Clock::get()?;
+  charge_creation_fee(
+    &ctx.accounts.global_config,
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.fee_destination.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
+  let entangler_key = ctx.accounts.entangler.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.child_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("child_storage").unwrap()],
+    ],
+  )?;
+
+  initialize_child_entangler(
+    &mut ctx.accounts.parent_entangler,
+    &mut ctx.accounts.entangler,
+    &mut ctx.accounts.lookup,
+    ctx.accounts.child_mint.key(),
+    ctx.accounts.child_storage.key(),
+    &ctx.accounts.noop_program,
+    ctx.accounts.payer.key(),
+    &ctx.bumps,
+    &clock,
+    &args,
+  )
+}
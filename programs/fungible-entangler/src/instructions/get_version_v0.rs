@@ -0,0 +1,29 @@
+use crate::state::ENTANGLER_VERSION;
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+
+/// Build of the deployed program, returned as the instruction's return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct VersionV0 {
+  pub program_version: String,
+  pub git_commit: String,
+  pub rustc_version: String,
+  // Version written to newly created entanglers
+  pub entangler_version: u16,
+}
+
+#[derive(Accounts)]
+pub struct GetVersionV0 {}
+
+/// Lets explorers and auditors match the deployed program to source. Simulate and read the
+/// borsh encoded VersionV0 from the return data
+pub fn handler(_ctx: Context<GetVersionV0>) -> Result<()> {
+  let version = VersionV0 {
+    program_version: env!("CARGO_PKG_VERSION").to_string(),
+    git_commit: env!("GIT_COMMIT").to_string(),
+    rustc_version: env!("RUSTC_VERSION").to_string(),
+    entangler_version: ENTANGLER_VERSION,
+  };
+  set_return_data(&version.try_to_vec()?);
+
+  Ok(())
+}
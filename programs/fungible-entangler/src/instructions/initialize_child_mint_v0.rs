@@ -0,0 +1,128 @@
+use super::initialize_fungible_child_entangler_v0::{
+  initialize_child_entangler, InitializeFungibleChildEntanglerV0Args, CHILD_ENTANGLER_SIZE,
+  ENTANGLER_LOOKUP_SIZE,
+};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  state::*,
+  util::{charge_creation_fee, create_mint_account, create_pda_token_account},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeChildMintV0Args {
+  pub entangler_args: InitializeFungibleChildEntanglerV0Args,
+  // Whether the child entangler should keep freeze authority over the new mint
+  pub freeze_authority: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeChildMintV0Args)]
+pub struct InitializeChildMintV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(
+    init,
+    payer = payer,
+    space = CHILD_ENTANGLER_SIZE,
+    seeds = [b"entangler", parent_entangler.key().as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  // Created and initialized as a mint in this instruction, with the entangler as mint authority
+  #[account(mut)]
+  pub child_mint: Signer<'info>,
+  /// CHECK: Created in the handler as a child_mint token account owned by entangler
+  #[account(
+    mut,
+    seeds = [b"storage", entangler.key().as_ref()],
+    bump,
+  )]
+  pub child_storage: UncheckedAccount<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = ENTANGLER_LOOKUP_SIZE,
+    seeds = [b"lookup", parent_entangler.parent_mint.as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub lookup: Box<Account<'info, EntanglerLookupV0>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = fee_destination @ ErrorCode::FeeDestinationMismatch,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: Only receives lamports, checked against the global config
+  #[account(mut)]
+  pub fee_destination: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<InitializeChildMintV0>, args: InitializeChildMintV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  charge_creation_fee(
+    &ctx.accounts.global_config,
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.fee_destination.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
+  let entangler_key = ctx.accounts.entangler.key();
+  let freeze_authority = if args.freeze_authority {
+    Some(&entangler_key)
+  } else {
+    None
+  };
+  create_mint_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_mint.to_account_info(),
+    ctx.accounts.parent_mint.decimals,
+    &entangler_key,
+    freeze_authority,
+  )?;
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.child_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("child_storage").unwrap()],
+    ],
+  )?;
+
+  initialize_child_entangler(
+    &mut ctx.accounts.parent_entangler,
+    &mut ctx.accounts.entangler,
+    &mut ctx.accounts.lookup,
+    ctx.accounts.child_mint.key(),
+    ctx.accounts.child_storage.key(),
+    &ctx.accounts.noop_program,
+    ctx.accounts.payer.key(),
+    &ctx.bumps,
+    &clock,
+    &args.entangler_args,
+  )
+}
@@ -0,0 +1,46 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+pub const CHILD_REGISTRY_SIZE: usize = 8 + std::mem::size_of::<ChildRegistryV0>();
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeChildRegistryV0Args {
+  pub page: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeChildRegistryV0Args)]
+pub struct InitializeChildRegistryV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    init,
+    payer = payer,
+    space = CHILD_REGISTRY_SIZE,
+    seeds = [b"registry", parent_entangler.key().as_ref(), &args.page.to_le_bytes()],
+    bump,
+  )]
+  pub registry: AccountLoader<'info, ChildRegistryV0>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+  ctx: Context<InitializeChildRegistryV0>,
+  args: InitializeChildRegistryV0Args,
+) -> Result<()> {
+  let mut registry = ctx.accounts.registry.load_init()?;
+
+  registry.parent_entangler = ctx.accounts.parent_entangler.key();
+  registry.page = args.page;
+  registry.num_entries = 0;
+  registry.bump_seed = *ctx.bumps.get("registry").unwrap();
+
+  Ok(())
+}
@@ -0,0 +1,241 @@
+use super::{
+  initialize_fungible_child_entangler_v0::{
+    initialize_child_entangler, InitializeFungibleChildEntanglerV0Args, CHILD_ENTANGLER_SIZE,
+    ENTANGLER_LOOKUP_SIZE,
+  },
+  initialize_fungible_parent_entangler_v0::{
+    initialize_parent_entangler, InitializeFungibleParentEntanglerV0Args, PARENT_ENTANGLER_SIZE,
+  },
+};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  events::{emit_noop, ParentEntanglerCreatedV0},
+  state::*,
+  util::{charge_creation_fee, create_pda_account, create_pda_token_account, hash_seed},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use std::collections::BTreeMap;
+
+// Each child takes four remaining accounts, so more than this would not fit in a transaction
+pub const MAX_CHILDREN_PER_INIT: usize = 4;
+pub const ACCOUNTS_PER_CHILD: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeEntanglerWithChildrenV0Args {
+  pub parent_args: InitializeFungibleParentEntanglerV0Args,
+  pub child_args: Vec<InitializeFungibleChildEntanglerV0Args>,
+}
+
+/// Remaining accounts are, for each entry of `child_args` in order:
+/// child mint, child entangler (mut), child storage (mut), lookup (mut).
+#[derive(Accounts)]
+#[instruction(args: InitializeEntanglerWithChildrenV0Args)]
+pub struct InitializeEntanglerWithChildrenV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = PARENT_ENTANGLER_SIZE,
+    seeds = [b"entangler", parent_mint.key().as_ref(), hash_seed(&args.parent_args.dynamic_seed).as_ref()],
+    bump,
+  )]
+  pub entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  /// CHECK: Created in the handler as a parent_mint token account owned by entangler
+  #[account(
+    mut,
+    seeds = [b"storage", entangler.key().as_ref()],
+    bump,
+  )]
+  pub parent_storage: UncheckedAccount<'info>,
+  #[account(
+    constraint = parent_mint.is_initialized @ ErrorCode::MintNotInitialized,
+    constraint = !args.parent_args.reject_freeze_authority || parent_mint.freeze_authority.is_none() @ ErrorCode::MintHasFreezeAuthority,
+  )]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = fee_destination @ ErrorCode::FeeDestinationMismatch,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: Only receives lamports, checked against the global config
+  #[account(mut)]
+  pub fee_destination: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Creates the parent and every child in one instruction. Any failure aborts the whole
+/// transaction, so either all entanglers exist afterwards or none do.
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, InitializeEntanglerWithChildrenV0<'info>>,
+  args: InitializeEntanglerWithChildrenV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(
+    args.child_args.len() <= MAX_CHILDREN_PER_INIT
+      && ctx.remaining_accounts.len() == args.child_args.len() * ACCOUNTS_PER_CHILD,
+    ErrorCode::InvalidChildAccounts
+  );
+
+  let payer = ctx.accounts.payer.to_account_info();
+  let system_program = ctx.accounts.system_program.to_account_info();
+  for _ in 0..=args.child_args.len() {
+    charge_creation_fee(
+      &ctx.accounts.global_config,
+      &payer,
+      &ctx.accounts.fee_destination.to_account_info(),
+      &system_program,
+    )?;
+  }
+
+  let parent_key = ctx.accounts.entangler.key();
+  let parent_mint = ctx.accounts.parent_mint.key();
+  create_pda_token_account(
+    &payer,
+    &system_program,
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.parent_mint.to_account_info(),
+    &parent_key,
+    &[
+      b"storage",
+      parent_key.as_ref(),
+      &[*ctx.bumps.get("parent_storage").unwrap()],
+    ],
+  )?;
+
+  initialize_parent_entangler(
+    &mut ctx.accounts.entangler,
+    parent_mint,
+    ctx.accounts.parent_storage.key(),
+    payer.key(),
+    &ctx.bumps,
+    &clock,
+    args.parent_args,
+  )?;
+  emit_noop(
+    &ctx.accounts.noop_program,
+    &ParentEntanglerCreatedV0 {
+      parent_entangler: ctx.accounts.entangler.key(),
+      parent_mint: ctx.accounts.entangler.parent_mint,
+      authority: ctx.accounts.entangler.authority,
+    },
+  )?;
+
+  for (child_args, accounts) in args
+    .child_args
+    .iter()
+    .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_CHILD))
+  {
+    let child_mint_info = &accounts[0];
+    let entangler_info = &accounts[1];
+    let storage_info = &accounts[2];
+    let lookup_info = &accounts[3];
+
+    let child_mint: Account<Mint> = Account::try_from(child_mint_info)?;
+    require!(child_mint.is_initialized, ErrorCode::MintNotInitialized);
+    require!(child_mint.key() != parent_mint, ErrorCode::SameMint);
+    require!(
+      !child_args.reject_freeze_authority || child_mint.freeze_authority.is_none(),
+      ErrorCode::MintHasFreezeAuthority
+    );
+
+    let (entangler_key, entangler_bump) = Pubkey::find_program_address(
+      &[b"entangler", parent_key.as_ref(), child_mint.key().as_ref()],
+      ctx.program_id,
+    );
+    let (storage_key, storage_bump) =
+      Pubkey::find_program_address(&[b"storage", entangler_key.as_ref()], ctx.program_id);
+    let (lookup_key, lookup_bump) = Pubkey::find_program_address(
+      &[b"lookup", parent_mint.as_ref(), child_mint.key().as_ref()],
+      ctx.program_id,
+    );
+    require!(
+      entangler_info.key() == entangler_key
+        && storage_info.key() == storage_key
+        && lookup_info.key() == lookup_key,
+      ErrorCode::InvalidChildAccounts
+    );
+
+    create_pda_account(
+      &payer,
+      &system_program,
+      entangler_info,
+      CHILD_ENTANGLER_SIZE,
+      ctx.program_id,
+      &[
+        b"entangler",
+        parent_key.as_ref(),
+        child_mint.key().as_ref(),
+        &[entangler_bump],
+      ],
+    )?;
+    create_pda_token_account(
+      &payer,
+      &system_program,
+      &ctx.accounts.token_program.to_account_info(),
+      storage_info,
+      child_mint_info,
+      &entangler_key,
+      &[b"storage", entangler_key.as_ref(), &[storage_bump]],
+    )?;
+    // A child of another parent of the same mints may already hold the lookup
+    let lookup_exists = !lookup_info.data_is_empty();
+    if !lookup_exists {
+      create_pda_account(
+        &payer,
+        &system_program,
+        lookup_info,
+        ENTANGLER_LOOKUP_SIZE,
+        ctx.program_id,
+        &[
+          b"lookup",
+          parent_mint.as_ref(),
+          child_mint.key().as_ref(),
+          &[lookup_bump],
+        ],
+      )?;
+    }
+
+    // Freshly created accounts are zeroed, so skip the discriminator check on load. exit writes
+    // the discriminator along with the data.
+    let mut entangler: Account<FungibleChildEntanglerV1> =
+      Account::try_from_unchecked(entangler_info)?;
+    let mut lookup: Account<EntanglerLookupV0> = if lookup_exists {
+      Account::try_from(lookup_info)?
+    } else {
+      Account::try_from_unchecked(lookup_info)?
+    };
+
+    let mut bumps = BTreeMap::new();
+    bumps.insert("entangler".to_string(), entangler_bump);
+    bumps.insert("child_storage".to_string(), storage_bump);
+    bumps.insert("lookup".to_string(), lookup_bump);
+
+    initialize_child_entangler(
+      &mut ctx.accounts.entangler,
+      &mut entangler,
+      &mut lookup,
+      child_mint.key(),
+      storage_info.key(),
+      &ctx.accounts.noop_program,
+      payer.key(),
+      &bumps,
+      &clock,
+      child_args,
+    )?;
+
+    entangler.exit(ctx.program_id)?;
+    lookup.exit(ctx.program_id)?;
+  }
+
+  Ok(())
+}
@@ -1,23 +1,212 @@
-use crate::{error::ErrorCode, state::*};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  events::{emit_noop, ChildEntanglerCreatedV0},
+  state::*,
+  util::{charge_creation_fee, create_pda_token_account, validate_fees},
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token::{Mint, Token};
+use std::collections::BTreeMap;
 
-pub const CHILD_ENTANGLER_SIZE: usize = 1 + // key
-32 + // authority
+pub const CHILD_ENTANGLER_SIZE: usize = 8 + // key
+2 + // version
 32 + // parent entangler
-32 + // mint
-32 + // storage
+32 + // child mint
+32 + // child storage
 8 + // go live
-8 + // freeze swap
+1 + 8 + // freeze swap
 8 + // created
+4 + // index
+1 + 16 + // vesting
+1 + 8 + // refund window
+1 + 8 + // expiry
+8 + // total top off
+1 + 8 + // reclaim snapshot
+1 + 32 + // rewards pool
+1 + 8 + // min lock seconds
+1 + 2 + 1 + // burn
+32 + // rent refund
+8 + 8 + // rate
+2 + // swap fee
+1 + 8 + // max parent to child
+8 + // total parent to child
+1 + // frozen
+1 + // cpi policy
+4 + 32 * MAX_CPI_ALLOWLIST + // cpi allowlist
+1 + 8 + // max child to parent
+8 + // total child to parent
+1 + 32 + // stake program
+1 + 32 + // liquidity program
+4 + (8 + 2) * MAX_FEE_TIERS + // fee tiers
+1 + 8 + // reverse rebate seconds
+1 + 8 + // freeze parent to child
+1 + 8 + // freeze child to parent
+1 + 32 + // swap record tree
+8 + // compressed record count
+36 + // parent to child volume
+36 + // child to parent volume
+1 + 8 + // commit reveal seconds
+1 + 32 + 32 + 2 + 8 + // yield strategy
+1 + 16 + 16 + 1 + 16 + // rate band
+8 + // accrued child fee amount
+8 + // accrued parent fee amount
+1 + 8 * 6 + // fee distribution
+1 + 8 * 6 + // drawdown limit
 1 + // bump
 1 + // storage bump
-80; // padding
+8 + // reclaimed top off amount
+8 + // recorded parent to child
+8 + // recorded child to parent
+8 + // queued swap count
+8 * CHILD_ENTANGLER_RESERVED_WORDS; // reserved
+
+pub const VESTING_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // owner
+8 + // total amount
+8 + // claimed amount
+1; // bump
+
+pub const SWAP_RECORD_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // owner
+8 + // parent to child amount
+8 + // child to parent amount
+8 + // last parent to child
+8 + // refundable parent amount
+8 + // refundable child amount
+8 + // rebatable fee amount
+1; // bump
+
+pub const FEE_REBATE_CLAIM_SIZE: usize = 8 + // key
+32 + // swap record
+1; // bump
+
+pub const TOP_OFF_RECORD_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // depositor
+8 + // amount
+1 + // reclaimed
+1 + // bump
+16 + // reward per token paid
+8; // rewards owed
+
+pub const SWAP_LOCK_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // owner
+32 + // lock storage
+8 + // locked at
+1 + // bump
+1; // storage bump
+
+pub const OFFER_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // maker
+32 + // taker
+32 + // offer mint
+8 + // offer amount
+32 + // ask mint
+8 + // ask amount
+32 + // escrow
+1 + 8 + // expiry
+8 + // nonce
+1 + // bump
+1; // escrow bump
+
+pub const RATE_SCHEDULE_SIZE: usize = 8 + // key
+32 + // child entangler
+4 + (8 + 8 + 8) * MAX_RATE_SCHEDULE_ENTRIES + // entries
+1; // bump
+
+pub const SNAPSHOT_HISTORY_SIZE: usize = 8 + // key
+32 + // child entangler
+4 + (8 * 6) * MAX_SNAPSHOT_ENTRIES + // entries
+1; // bump
+
+pub const SWAP_COMMIT_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // owner
+32 + // commitment
+8 + // committed at
+32 + // payer
+1; // bump
+
+pub const SWAP_QUEUE_SIZE: usize = 8 + // key
+32 + // child entangler
+8 + // next ticket
+8 + // next execute
+1; // bump
+
+pub const QUEUED_SWAP_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // owner
+8 + // ticket
+1 + // parent to child
+8 + // amount
+32 + // escrow
+32 + // destination
+32 + // refund account
+32 + // payer
+1 + // bump
+1; // escrow bump
+
+pub const PENDING_SETTLEMENT_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // settler
+8 + // timeout seconds
+8 + // next ticket
+1; // bump
+
+pub const SETTLEMENT_ENTRY_SIZE: usize = 8 + // key
+32 + // pending settlement
+32 + // owner
+8 + // ticket
+1 + // parent to child
+8 + // amount
+32 + // reference
+32 + // escrow
+32 + // refund account
+32 + // payer
+8 + // created at
+1 + // bump
+1; // escrow bump
+
+pub const STRATEGY_PROGRAM_SIZE: usize = 8 + // key
+32 + // program
+1; // bump
+
+pub const ENTANGLER_LOOKUP_SIZE: usize = 8 + // key
+32 + // parent entangler
+32 + // child entangler
+1; // bump
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct InitializeFungibleChildEntanglerV0Args {
   pub go_live_unix_time: i64,
   pub freeze_swap_unix_time: Option<i64>,
+  pub vesting: Option<VestingConfigV0>,
+  pub refund_window_seconds: Option<i64>,
+  pub expiry_unix_time: Option<i64>,
+  pub min_lock_seconds: Option<i64>,
+  pub burn: Option<BurnConfigV0>,
+  pub rent_refund: Option<Pubkey>,
+  // Defaults to the parent's default rate
+  pub rate: Option<ExchangeRateV0>,
+  pub swap_fee_bps: u16,
+  pub max_parent_to_child_amount: Option<u64>,
+  pub cpi_policy: CpiPolicyV0,
+  pub cpi_allowlist: Vec<Pubkey>,
+  /// Refuse child mints with a freeze authority, which could freeze storage and halt swaps
+  pub reject_freeze_authority: bool,
+  pub max_child_to_parent_amount: Option<u64>,
+  pub stake_program: Option<Pubkey>,
+  pub liquidity_program: Option<Pubkey>,
+  pub fee_tiers: Vec<FeeTierV0>,
+  pub reverse_rebate_seconds: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+  pub commit_reveal_seconds: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -28,10 +217,11 @@ pub struct InitializeFungibleChildEntanglerV0<'info> {
   pub authority: Signer<'info>,
   #[account(
     mut,
-    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key(),
-    constraint = parent_entangler.parent_mint != child_mint.key()
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.parent_mint != child_mint.key() @ ErrorCode::SameMint,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
   #[account(
     init,
     payer = payer,
@@ -39,48 +229,181 @@ pub struct InitializeFungibleChildEntanglerV0<'info> {
     seeds = [b"entangler", parent_entangler.key().as_ref(), child_mint.key().as_ref()],
     bump,
   )]
-  pub entangler: Box<Account<'info, FungibleChildEntanglerV0>>,
+  pub entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// CHECK: Created in the handler as a child_mint token account owned by entangler
   #[account(
-    init,
-    payer = payer,
+    mut,
     seeds = [b"storage", entangler.key().as_ref()],
     bump,
-    token::mint = child_mint,
-    token::authority = entangler,
   )]
-  pub child_storage: Box<Account<'info, TokenAccount>>,
+  pub child_storage: UncheckedAccount<'info>,
+  // Names the first entangler of the mint pair, so that wallets can find it from the two mints
   #[account(
-    constraint = child_mint.is_initialized,
-    constraint = child_mint.key() != parent_entangler.parent_mint
+    init_if_needed,
+    payer = payer,
+    space = ENTANGLER_LOOKUP_SIZE,
+    seeds = [b"lookup", parent_entangler.parent_mint.as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub lookup: Box<Account<'info, EntanglerLookupV0>>,
+  #[account(
+    constraint = child_mint.is_initialized @ ErrorCode::MintNotInitialized,
+    constraint = child_mint.key() != parent_entangler.parent_mint @ ErrorCode::SameMint,
+    constraint = !args.reject_freeze_authority || child_mint.freeze_authority.is_none() @ ErrorCode::MintHasFreezeAuthority,
   )]
   pub child_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = fee_destination @ ErrorCode::FeeDestinationMismatch,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: Only receives lamports, checked against the global config
+  #[account(mut)]
+  pub fee_destination: UncheckedAccount<'info>,
 
   pub token_program: Program<'info, Token>,
   pub system_program: Program<'info, System>,
-  pub rent: Sysvar<'info, Rent>,
-  pub clock: Sysvar<'info, Clock>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
 }
 
-pub fn handler(
-  ctx: Context<InitializeFungibleChildEntanglerV0>,
-  args: InitializeFungibleChildEntanglerV0Args,
+/// The checks on args that don't depend on any account, shared with ValidateInitParamsV0
+pub fn validate_child_entangler_args(args: &InitializeFungibleChildEntanglerV0Args) -> Result<()> {
+  require!(
+    args.rate.map_or(true, |rate| rate.is_valid()),
+    ErrorCode::InvalidExchangeRate
+  );
+  validate_fees(args.swap_fee_bps, &args.fee_tiers)?;
+  require!(
+    args.cpi_allowlist.len() <= MAX_CPI_ALLOWLIST,
+    ErrorCode::InvalidCpiAllowlist
+  );
+  if let Some(burn) = &args.burn {
+    require!(burn.bps <= 10000, ErrorCode::InvalidBurnBps);
+  }
+  require!(
+    args
+      .commit_reveal_seconds
+      .map_or(true, |seconds| seconds > 0),
+    ErrorCode::InvalidArgs
+  );
+  if let Some(vesting) = &args.vesting {
+    require!(
+      vesting.end_unix_time > vesting.start_unix_time,
+      ErrorCode::InvalidVestingSchedule
+    );
+  }
+
+  Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_child_entangler<'info>(
+  parent_entangler: &mut Account<'info, FungibleParentEntanglerV1>,
+  entangler: &mut Account<'info, FungibleChildEntanglerV1>,
+  lookup: &mut Account<'info, EntanglerLookupV0>,
+  child_mint: Pubkey,
+  child_storage: Pubkey,
+  noop_program: &AccountInfo<'info>,
+  payer: Pubkey,
+  bumps: &BTreeMap<String, u8>,
+  clock: &Clock,
+  args: &InitializeFungibleChildEntanglerV0Args,
 ) -> Result<()> {
-  let entangler = &mut ctx.accounts.entangler;
+  validate_child_entangler_args(args)?;
 
-  entangler.parent_entangler = ctx.accounts.parent_entangler.key();
-  entangler.child_mint = ctx.accounts.child_mint.key();
-  entangler.child_storage = ctx.accounts.child_storage.key();
-  entangler.go_live_unix_time = if args.go_live_unix_time < ctx.accounts.clock.unix_timestamp {
-    ctx.accounts.clock.unix_timestamp
+  entangler.version = ENTANGLER_VERSION;
+  entangler.parent_entangler = parent_entangler.key();
+  entangler.child_mint = child_mint;
+  entangler.child_storage = child_storage;
+  entangler.go_live_unix_time = if args.go_live_unix_time < clock.unix_timestamp {
+    clock.unix_timestamp
   } else {
     args.go_live_unix_time
   };
   entangler.freeze_swap_unix_time = args.freeze_swap_unix_time;
-  entangler.created_at_unix_time = ctx.accounts.clock.unix_timestamp;
-  entangler.bump_seed = *ctx.bumps.get("entangler").unwrap();
-  entangler.storage_bump_seed = *ctx.bumps.get("child_storage").unwrap();
+  entangler.created_at_unix_time = clock.unix_timestamp;
+  entangler.index = parent_entangler.next_child_index;
+  entangler.vesting = args.vesting;
+  entangler.refund_window_seconds = args.refund_window_seconds;
+  entangler.expiry_unix_time = args.expiry_unix_time;
+  entangler.min_lock_seconds = args.min_lock_seconds;
+  entangler.burn = args.burn;
+  entangler.rent_refund = args.rent_refund.unwrap_or(payer);
+  entangler.rate = args.rate.unwrap_or(parent_entangler.default_rate);
+  entangler.swap_fee_bps = args.swap_fee_bps;
+  entangler.max_parent_to_child_amount = args.max_parent_to_child_amount;
+  entangler.cpi_policy = args.cpi_policy;
+  entangler.cpi_allowlist = args.cpi_allowlist.clone();
+  entangler.max_child_to_parent_amount = args.max_child_to_parent_amount;
+  entangler.stake_program = args.stake_program;
+  entangler.liquidity_program = args.liquidity_program;
+  entangler.fee_tiers = args.fee_tiers.clone();
+  entangler.reverse_rebate_seconds = args.reverse_rebate_seconds;
+  entangler.freeze_parent_to_child_unix_time = args.freeze_parent_to_child_unix_time;
+  entangler.freeze_child_to_parent_unix_time = args.freeze_child_to_parent_unix_time;
+  entangler.commit_reveal_seconds = args.commit_reveal_seconds;
+  entangler.bump_seed = *bumps.get("entangler").unwrap();
+  entangler.storage_bump_seed = *bumps.get("child_storage").unwrap();
 
-  ctx.accounts.parent_entangler.num_children += 1;
+  lookup.claim(
+    parent_entangler.key(),
+    entangler.key(),
+    *bumps.get("lookup").unwrap(),
+  );
 
-  Ok(())
+  parent_entangler.num_children += 1;
+  parent_entangler.next_child_index += 1;
+
+  emit_noop(
+    noop_program,
+    &ChildEntanglerCreatedV0 {
+      parent_entangler: parent_entangler.key(),
+      child_entangler: entangler.key(),
+      child_mint: entangler.child_mint,
+    },
+  )
+}
+
+pub fn handler(
+  ctx: Context<InitializeFungibleChildEntanglerV0>,
+  args: InitializeFungibleChildEntanglerV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  charge_creation_fee(
+    &ctx.accounts.global_config,
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.fee_destination.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
+  let entangler_key = ctx.accounts.entangler.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.child_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("child_storage").unwrap()],
+    ],
+  )?;
+
+  initialize_child_entangler(
+    &mut ctx.accounts.parent_entangler,
+    &mut ctx.accounts.entangler,
+    &mut ctx.accounts.lookup,
+    ctx.accounts.child_mint.key(),
+    ctx.accounts.child_storage.key(),
+    &ctx.accounts.noop_program,
+    ctx.accounts.payer.key(),
+    &ctx.bumps,
+    &clock,
+    &args,
+  )
 }
@@ -1,25 +1,72 @@
-use crate::state::*;
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  events::{emit_noop, ParentEntanglerCreatedV0},
+  state::*,
+  util::{
+    charge_creation_fee, create_pda_token_account, hash_seed, validate_metadata,
+    MAX_DYNAMIC_SEED_LEN, MAX_NAME_LEN, MAX_URI_LEN,
+  },
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token::{Mint, Token};
+use std::collections::BTreeMap;
 
-pub const PARENT_ENTANGLER_SIZE: usize = 1 + // key
-32 + // authority
-32 + // mint
-32 + // dynamicSeed
-32 + // storage
+pub const PARENT_ENTANGLER_SIZE: usize = 8 + // key
+2 + // version
+32 + // parent mint
+32 + // parent storage
 8 + // go live
-8 + // freeze swap
+1 + 8 + // freeze swap
 8 + // created
+4 + // num children
+4 + // next child index
+1 + 32 + // authority
+4 + MAX_NAME_LEN + // name
+4 + MAX_URI_LEN + // uri
+4 + MAX_DYNAMIC_SEED_LEN + // dynamic seed
+32 + // dynamic seed hash
+32 + // rent refund
+1 + // legacy seeds
+8 + 8 + // default rate
+1 + 8 + // freeze parent to child
+1 + 8 + // freeze child to parent
+1 + 32 + // authority nft mint
+1 + 32 + // namespace
+1 + 8 + // withdrawals frozen until
 1 + // bump
 1 + // storage bump
-80; // padding
+8 + // reserved parent fee amount
+8 * PARENT_ENTANGLER_RESERVED_WORDS; // reserved
+
+pub const AUTHORITY_CONFIG_SIZE: usize = 8 + // key
+32 + // parent entangler
+4 + 32 * MAX_AUTHORITY_SIGNERS + // signers
+1 + // threshold
+1; // bump
+
+pub const NAMESPACE_LOOKUP_SIZE: usize = 8 + // key
+32 + // namespace
+32 + // parent entangler
+32 + // parent mint
+1; // bump
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct InitializeFungibleParentEntanglerV0Args {
   pub authority: Option<Pubkey>,
+  /// Arbitrary bytes or a utf-8 name. Clients resolve the entangler from sha256(dynamic_seed)
   pub dynamic_seed: Vec<u8>,
   pub go_live_unix_time: i64,
   pub freeze_swap_unix_time: Option<i64>,
+  pub name: String,
+  pub uri: String,
+  pub rent_refund: Option<Pubkey>,
+  /// Refuse parent mints with a freeze authority, which could freeze storage and halt every child
+  pub reject_freeze_authority: bool,
+  // Rate for children that don't set their own. Defaults to one child token per parent token
+  pub default_rate: Option<ExchangeRateV0>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -31,47 +78,135 @@ pub struct InitializeFungibleParentEntanglerV0<'info> {
     init,
     payer = payer,
     space = PARENT_ENTANGLER_SIZE,
-    seeds = [b"entangler", parent_mint.key().as_ref(), &args.dynamic_seed],
+    seeds = [b"entangler", parent_mint.key().as_ref(), hash_seed(&args.dynamic_seed).as_ref()],
     bump,
   )]
-  pub entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
+  pub entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  /// CHECK: Created in the handler as a parent_mint token account owned by entangler
   #[account(
-    init,
-    payer = payer,
+    mut,
     seeds = [b"storage", entangler.key().as_ref()],
     bump,
-    token::mint = parent_mint,
-    token::authority = entangler,
   )]
-  pub parent_storage: Box<Account<'info, TokenAccount>>,
-  #[account( constraint = parent_mint.is_initialized)]
+  pub parent_storage: UncheckedAccount<'info>,
+  #[account(
+    constraint = parent_mint.is_initialized @ ErrorCode::MintNotInitialized,
+    constraint = !args.reject_freeze_authority || parent_mint.freeze_authority.is_none() @ ErrorCode::MintHasFreezeAuthority,
+  )]
   pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = fee_destination @ ErrorCode::FeeDestinationMismatch,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: Only receives lamports, checked against the global config
+  #[account(mut)]
+  pub fee_destination: UncheckedAccount<'info>,
 
   pub token_program: Program<'info, Token>,
   pub system_program: Program<'info, System>,
-  pub rent: Sysvar<'info, Rent>,
-  pub clock: Sysvar<'info, Clock>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
 }
 
 pub fn handler(
   ctx: Context<InitializeFungibleParentEntanglerV0>,
   args: InitializeFungibleParentEntanglerV0Args,
 ) -> Result<()> {
-  let entangler = &mut ctx.accounts.entangler;
+  let clock = Clock::get()?;
+  charge_creation_fee(
+    &ctx.accounts.global_config,
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.fee_destination.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
+  let entangler_key = ctx.accounts.entangler.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.parent_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("parent_storage").unwrap()],
+    ],
+  )?;
+
+  initialize_parent_entangler(
+    &mut ctx.accounts.entangler,
+    ctx.accounts.parent_mint.key(),
+    ctx.accounts.parent_storage.key(),
+    ctx.accounts.payer.key(),
+    &ctx.bumps,
+    &clock,
+    args,
+  )?;
+
+  emit_noop(
+    &ctx.accounts.noop_program,
+    &ParentEntanglerCreatedV0 {
+      parent_entangler: ctx.accounts.entangler.key(),
+      parent_mint: ctx.accounts.entangler.parent_mint,
+      authority: ctx.accounts.entangler.authority,
+    },
+  )
+}
+
+/// The checks on args that don't depend on any account, shared with ValidateInitParamsV0
+pub fn validate_parent_entangler_args(
+  args: &InitializeFungibleParentEntanglerV0Args,
+) -> Result<()> {
+  require!(
+    !args.dynamic_seed.is_empty() && args.dynamic_seed.len() <= MAX_DYNAMIC_SEED_LEN,
+    ErrorCode::InvalidDynamicSeed
+  );
+  validate_metadata(&args.name, &args.uri)?;
+  require!(
+    args.default_rate.map_or(true, |rate| rate.is_valid()),
+    ErrorCode::InvalidExchangeRate
+  );
+
+  Ok(())
+}
+
+pub fn initialize_parent_entangler(
+  entangler: &mut FungibleParentEntanglerV1,
+  parent_mint: Pubkey,
+  parent_storage: Pubkey,
+  payer: Pubkey,
+  bumps: &BTreeMap<String, u8>,
+  clock: &Clock,
+  args: InitializeFungibleParentEntanglerV0Args,
+) -> Result<()> {
+  validate_parent_entangler_args(&args)?;
 
+  entangler.version = ENTANGLER_VERSION;
   entangler.authority = args.authority;
-  entangler.parent_mint = ctx.accounts.parent_mint.key();
-  entangler.parent_storage = ctx.accounts.parent_storage.key();
-  entangler.go_live_unix_time = if args.go_live_unix_time < ctx.accounts.clock.unix_timestamp {
-    ctx.accounts.clock.unix_timestamp
+  entangler.parent_mint = parent_mint;
+  entangler.parent_storage = parent_storage;
+  entangler.go_live_unix_time = if args.go_live_unix_time < clock.unix_timestamp {
+    clock.unix_timestamp
   } else {
     args.go_live_unix_time
   };
   entangler.freeze_swap_unix_time = args.freeze_swap_unix_time;
-  entangler.created_at_unix_time = ctx.accounts.clock.unix_timestamp;
+  entangler.name = args.name;
+  entangler.uri = args.uri;
+  entangler.created_at_unix_time = clock.unix_timestamp;
+  entangler.dynamic_seed_hash = hash_seed(&args.dynamic_seed);
   entangler.dynamic_seed = args.dynamic_seed;
-  entangler.bump_seed = *ctx.bumps.get("entangler").unwrap();
-  entangler.storage_bump_seed = *ctx.bumps.get("parent_storage").unwrap();
+  entangler.rent_refund = args.rent_refund.unwrap_or(payer);
+  entangler.default_rate = args.default_rate.unwrap_or_default();
+  entangler.freeze_parent_to_child_unix_time = args.freeze_parent_to_child_unix_time;
+  entangler.freeze_child_to_parent_unix_time = args.freeze_child_to_parent_unix_time;
+  entangler.bump_seed = *bumps.get("entangler").unwrap();
+  entangler.storage_bump_seed = *bumps.get("parent_storage").unwrap();
 
   Ok(())
 }
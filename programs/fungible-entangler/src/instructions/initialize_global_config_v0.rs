@@ -0,0 +1,57 @@
+use crate::{error::ErrorCode, program::FungibleEntangler, state::*};
+use anchor_lang::prelude::*;
+
+pub const GLOBAL_CONFIG_SIZE: usize = 8 + // key
+32 + // admin
+32 + // fee destination
+8 + // creation fee
+1 + // bump
+1 + // paused
+63; // padding
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeGlobalConfigV0Args {
+  pub admin: Pubkey,
+  pub fee_destination: Pubkey,
+  pub creation_fee_lamports: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeGlobalConfigV0Args)]
+pub struct InitializeGlobalConfigV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub upgrade_authority: Signer<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = GLOBAL_CONFIG_SIZE,
+    seeds = [b"global-config"],
+    bump,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  #[account(
+    constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::InvalidProgramData
+  )]
+  pub program: Program<'info, FungibleEntangler>,
+  #[account(
+    constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ ErrorCode::InvalidUpgradeAuthority
+  )]
+  pub program_data: Account<'info, ProgramData>,
+
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+  ctx: Context<InitializeGlobalConfigV0>,
+  args: InitializeGlobalConfigV0Args,
+) -> Result<()> {
+  let global_config = &mut ctx.accounts.global_config;
+
+  global_config.admin = args.admin;
+  global_config.fee_destination = args.fee_destination;
+  global_config.creation_fee_lamports = args.creation_fee_lamports;
+  global_config.bump_seed = *ctx.bumps.get("global_config").unwrap();
+
+  Ok(())
+}
@@ -0,0 +1,125 @@
+use super::initialize_fungible_parent_entangler_v0::{
+  initialize_parent_entangler, InitializeFungibleParentEntanglerV0Args, NAMESPACE_LOOKUP_SIZE,
+  PARENT_ENTANGLER_SIZE,
+};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  events::{emit_noop, ParentEntanglerCreatedV0},
+  state::*,
+  util::{charge_creation_fee, create_pda_token_account, hash_namespaced_seed, hash_seed},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+#[derive(Accounts)]
+#[instruction(args: InitializeFungibleParentEntanglerV0Args)]
+pub struct InitializeNamespacedParentEntanglerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  // Owns the namespace the dynamic seed is claimed in
+  pub namespace: Signer<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = PARENT_ENTANGLER_SIZE,
+    seeds = [b"entangler", parent_mint.key().as_ref(), hash_namespaced_seed(&namespace.key(), &args.dynamic_seed).as_ref()],
+    bump,
+  )]
+  pub entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  /// CHECK: Created in the handler as a parent_mint token account owned by entangler
+  #[account(
+    mut,
+    seeds = [b"storage", entangler.key().as_ref()],
+    bump,
+  )]
+  pub parent_storage: UncheckedAccount<'info>,
+  #[account(
+    constraint = parent_mint.is_initialized @ ErrorCode::MintNotInitialized,
+    constraint = !args.reject_freeze_authority || parent_mint.freeze_authority.is_none() @ ErrorCode::MintHasFreezeAuthority,
+  )]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(
+    init,
+    payer = payer,
+    space = NAMESPACE_LOOKUP_SIZE,
+    seeds = [b"namespace-lookup", namespace.key().as_ref(), hash_seed(&args.dynamic_seed).as_ref()],
+    bump,
+  )]
+  pub namespace_lookup: Box<Account<'info, NamespaceLookupV0>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = fee_destination @ ErrorCode::FeeDestinationMismatch,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: Only receives lamports, checked against the global config
+  #[account(mut)]
+  pub fee_destination: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Creates a parent entangler whose PDA is derived from the namespace and the dynamic seed, so
+/// that the seed can't be squatted by another deployer
+pub fn handler(
+  ctx: Context<InitializeNamespacedParentEntanglerV0>,
+  args: InitializeFungibleParentEntanglerV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  charge_creation_fee(
+    &ctx.accounts.global_config,
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.fee_destination.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
+  let namespace = ctx.accounts.namespace.key();
+  let seed_hash = hash_namespaced_seed(&namespace, &args.dynamic_seed);
+  let entangler_key = ctx.accounts.entangler.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.parent_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("parent_storage").unwrap()],
+    ],
+  )?;
+
+  let entangler = &mut ctx.accounts.entangler;
+  initialize_parent_entangler(
+    entangler,
+    ctx.accounts.parent_mint.key(),
+    ctx.accounts.parent_storage.key(),
+    ctx.accounts.payer.key(),
+    &ctx.bumps,
+    &clock,
+    args,
+  )?;
+  entangler.dynamic_seed_hash = seed_hash;
+  entangler.namespace = Some(namespace);
+
+  let namespace_lookup = &mut ctx.accounts.namespace_lookup;
+  namespace_lookup.namespace = namespace;
+  namespace_lookup.parent_entangler = entangler.key();
+  namespace_lookup.parent_mint = entangler.parent_mint;
+  namespace_lookup.bump_seed = *ctx.bumps.get("namespace_lookup").unwrap();
+
+  emit_noop(
+    &ctx.accounts.noop_program,
+    &ParentEntanglerCreatedV0 {
+      parent_entangler: entangler.key(),
+      parent_mint: entangler.parent_mint,
+      authority: entangler.authority,
+    },
+  )
+}
@@ -0,0 +1,114 @@
+use crate::{error::ErrorCode, state::*, util::create_pda_token_account};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+pub const REWARDS_POOL_SIZE: usize = 8 + // key
+32 + // child entangler
+32 + // reward mint
+32 + // reward storage
+8 + // reward per second
+8 + // start
+8 + // end
+16 + // reward per token stored
+8 + // last update
+8 + // total staked
+1 + // bump
+1 + // storage bump
+80; // padding
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InitializeRewardsPoolV0Args {
+  pub reward_per_second: u64,
+  pub start_unix_time: i64,
+  pub end_unix_time: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeRewardsPoolV0Args)]
+pub struct InitializeRewardsPoolV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.rewards_pool.is_none() @ ErrorCode::RewardsPoolExists,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    init,
+    payer = payer,
+    space = REWARDS_POOL_SIZE,
+    seeds = [b"rewards", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rewards_pool: Box<Account<'info, RewardsPoolV0>>,
+  /// CHECK: Created in the handler as a reward_mint token account owned by rewards_pool
+  #[account(
+    mut,
+    seeds = [b"reward-storage", rewards_pool.key().as_ref()],
+    bump,
+  )]
+  pub reward_storage: UncheckedAccount<'info>,
+  pub reward_mint: Box<Account<'info, Mint>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Rewards are funded by transferring reward tokens directly into reward storage
+pub fn handler(
+  ctx: Context<InitializeRewardsPoolV0>,
+  args: InitializeRewardsPoolV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(
+    args.end_unix_time > args.start_unix_time,
+    ErrorCode::InvalidRewardsSchedule
+  );
+
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  require!(
+    !child_entangler.is_expired(clock.unix_timestamp),
+    ErrorCode::Expired
+  );
+
+  let rewards_pool_key = ctx.accounts.rewards_pool.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.reward_storage.to_account_info(),
+    &ctx.accounts.reward_mint.to_account_info(),
+    &rewards_pool_key,
+    &[
+      b"reward-storage",
+      rewards_pool_key.as_ref(),
+      &[*ctx.bumps.get("reward_storage").unwrap()],
+    ],
+  )?;
+
+  let rewards_pool = &mut ctx.accounts.rewards_pool;
+  rewards_pool.child_entangler = child_entangler.key();
+  rewards_pool.reward_mint = ctx.accounts.reward_mint.key();
+  rewards_pool.reward_storage = ctx.accounts.reward_storage.key();
+  rewards_pool.reward_per_second = args.reward_per_second;
+  rewards_pool.start_unix_time = args.start_unix_time;
+  rewards_pool.end_unix_time = args.end_unix_time;
+  rewards_pool.reward_per_token_stored = 0;
+  rewards_pool.last_update_unix_time = clock.unix_timestamp;
+  // Deposits made before the pool existed earn from here on
+  rewards_pool.total_staked = child_entangler.total_top_off_amount;
+  rewards_pool.bump_seed = *ctx.bumps.get("rewards_pool").unwrap();
+  rewards_pool.storage_bump_seed = *ctx.bumps.get("reward_storage").unwrap();
+
+  child_entangler.rewards_pool = Some(rewards_pool.key());
+
+  Ok(())
+}
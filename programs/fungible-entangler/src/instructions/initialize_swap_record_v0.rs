@@ -0,0 +1,39 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::SWAP_RECORD_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeSwapRecordV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+    constraint = child_entangler.tracks_swap_records() @ ErrorCode::SwapRecordNotTracked,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// CHECK: Only used as a seed, the record tracks this wallet's swaps
+  pub owner: UncheckedAccount<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = SWAP_RECORD_SIZE,
+    seeds = [b"swap-record", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump,
+  )]
+  pub swap_record: Box<Account<'info, SwapRecordV0>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Creates owner's SwapRecordV0 on a child that tracks swaps for refunds, reverse swap rebates or
+/// fee distribution. Swaps through such a child fail until the record exists
+pub fn handler(ctx: Context<InitializeSwapRecordV0>) -> Result<()> {
+  let swap_record = &mut ctx.accounts.swap_record;
+  swap_record.child_entangler = ctx.accounts.child_entangler.key();
+  swap_record.owner = ctx.accounts.owner.key();
+  swap_record.bump_seed = *ctx.bumps.get("swap_record").unwrap();
+
+  Ok(())
+}
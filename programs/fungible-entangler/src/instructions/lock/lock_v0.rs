@@ -0,0 +1,94 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::SWAP_LOCK_SIZE, state::*,
+  util::init_pda_token_account_if_needed,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LockV0Args {
+  pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: LockV0Args)]
+pub struct LockV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.min_lock_seconds.is_some() @ ErrorCode::NoLock,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = SWAP_LOCK_SIZE,
+    seeds = [b"lock", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump,
+  )]
+  pub lock: Box<Account<'info, SwapLockV0>>,
+  /// CHECK: Created if missing in the handler as a parent_mint token account owned by lock
+  #[account(
+    mut,
+    seeds = [b"lock-storage", lock.key().as_ref()],
+    bump,
+  )]
+  pub lock_storage: UncheckedAccount<'info>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub owner: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<LockV0>, args: LockV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(args.amount > 0, ErrorCode::InvalidAmount);
+
+  let lock_key = ctx.accounts.lock.key();
+  init_pda_token_account_if_needed(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.lock_storage.to_account_info(),
+    &ctx.accounts.parent_mint.to_account_info(),
+    &lock_key,
+    &[
+      b"lock-storage",
+      lock_key.as_ref(),
+      &[*ctx.bumps.get("lock_storage").unwrap()],
+    ],
+  )?;
+
+  let lock = &mut ctx.accounts.lock;
+  lock.child_entangler = ctx.accounts.child_entangler.key();
+  lock.owner = ctx.accounts.owner.key();
+  lock.lock_storage = ctx.accounts.lock_storage.key();
+  lock.locked_at_unix_time = clock.unix_timestamp;
+  lock.bump_seed = *ctx.bumps.get("lock").unwrap();
+  lock.storage_bump_seed = *ctx.bumps.get("lock_storage").unwrap();
+
+  msg!("Locking {} parent tokens", args.amount);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info().clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.lock_storage.to_account_info().clone(),
+        authority: ctx.accounts.owner.to_account_info().clone(),
+      },
+    ),
+    args.amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod lock_v0;
+pub mod swap_locked_v0;
+pub mod unlock_v0;
+
+pub use lock_v0::*;
+pub use swap_locked_v0::*;
+pub use unlock_v0::*;
@@ -0,0 +1,159 @@
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  instructions::swap::{
+    account::SwapAccounts,
+    arg::SwapV0Args,
+    common::{return_fill, send_memo},
+    swap_parent_for_child_v0::{execute_parent_for_child, record_parent_for_child},
+  },
+  state::*,
+  util::{immediate_caller, is_frozen_token_account},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(args: SwapV0Args)]
+pub struct SwapLockedV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"lock", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump = lock.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = owner @ ErrorCode::InvalidOwner,
+    has_one = lock_storage @ ErrorCode::StorageMismatch,
+  )]
+  pub lock: Box<Account<'info, SwapLockV0>>,
+  #[account(mut,
+    constraint = !lock_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub lock_storage: Box<Account<'info, TokenAccount>>,
+  pub owner: Signer<'info>,
+  /// CHECK: Only receives tokens, the token program validates it in the transfer
+  #[account(mut,
+    constraint = !is_frozen_token_account(&destination) @ ErrorCode::DestinationAccountFrozen,
+  )]
+  pub destination: UncheckedAccount<'info>,
+  /// CHECK: The owner's SwapRecordV0, created via InitializeSwapRecordV0. Only read when the
+  /// child tracks swaps
+  #[account(
+    mut,
+    seeds = [b"swap-record", child_entangler.key().as_ref(), owner.key().as_ref()],
+    bump,
+  )]
+  pub swap_record: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The instructions sysvar, read to enforce the child's CPI policy
+  #[account(
+    address = sysvar::instructions::ID,
+    constraint = child_entangler.allows_caller(immediate_caller(&instructions)?) @ ErrorCode::CpiNotAllowed,
+  )]
+  pub instructions: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: The SPL memo program, only invoked when the swap carries a memo
+  #[account(address = spl_memo::id())]
+  pub memo_program: UncheckedAccount<'info>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+  /// CHECK: spl-noop, which swap events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapLockedV0<'info>>,
+  args: SwapV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  let min_lock_seconds = ctx
+    .accounts
+    .child_entangler
+    .min_lock_seconds
+    .ok_or(error!(ErrorCode::NoLock))?;
+  require!(
+    ctx
+      .accounts
+      .lock
+      .locked_at_unix_time
+      .checked_add(min_lock_seconds)
+      .map_or(false, |unlocked_at| unlocked_at <= clock.unix_timestamp),
+    ErrorCode::LockNotElapsed
+  );
+
+  let accounts = &mut *ctx.accounts;
+  let lock = accounts.lock.to_account_info();
+  let lock_seeds: &[&[&[u8]]] = &[&accounts.lock.signer_seeds()];
+  let outcome = execute_parent_for_child(
+    SwapAccounts {
+      parent_entangler: &mut accounts.parent_entangler,
+      parent_storage: &mut accounts.parent_storage,
+      parent_mint: &mut accounts.parent_mint,
+      child_entangler: &mut accounts.child_entangler,
+      child_storage: &mut accounts.child_storage,
+      child_mint: &mut accounts.child_mint,
+      source: &accounts.lock_storage,
+      source_authority: &lock,
+      source_signer_seeds: lock_seeds,
+      destination: &accounts.destination,
+      rate_schedule: &accounts.rate_schedule,
+      token_program: &accounts.token_program,
+      noop_program: &accounts.noop_program,
+      revealed_commit: false,
+      vested: false,
+      locked: true,
+      queued: false,
+    },
+    ctx.remaining_accounts,
+    &clock,
+    &args,
+  )?;
+
+  record_parent_for_child(
+    &accounts.swap_record,
+    &mut accounts.child_entangler,
+    &outcome,
+    clock.unix_timestamp,
+  )?;
+
+  return_fill(&args, &outcome)?;
+  send_memo(&accounts.memo_program, &args.memo)
+}
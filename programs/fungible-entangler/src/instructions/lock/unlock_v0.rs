@@ -0,0 +1,53 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::{close_token_account_signed, transfer_signed};
+
+#[derive(Accounts)]
+pub struct UnlockV0<'info> {
+  /// CHECK: Just used to get a refund of sol from closing the lock
+  #[account(mut)]
+  pub refund: AccountInfo<'info>,
+  #[account(
+    mut,
+    close = refund,
+    has_one = owner @ ErrorCode::InvalidOwner,
+    has_one = lock_storage @ ErrorCode::StorageMismatch,
+  )]
+  pub lock: Box<Account<'info, SwapLockV0>>,
+  #[account(mut)]
+  pub lock_storage: Box<Account<'info, TokenAccount>>,
+  pub owner: Signer<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Returns locked parent tokens without swapping and closes the lock
+pub fn handler(ctx: Context<UnlockV0>) -> Result<()> {
+  let lock = &ctx.accounts.lock;
+
+  msg!(
+    "Unlocking {} parent tokens",
+    ctx.accounts.lock_storage.amount
+  );
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.lock_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &lock.to_account_info(),
+    &lock.signer_seeds(),
+    ctx.accounts.lock_storage.amount,
+  )?;
+
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.lock_storage.to_account_info(),
+    &ctx.accounts.refund.to_account_info(),
+    &lock.to_account_info(),
+    &lock.signer_seeds(),
+  )?;
+
+  Ok(())
+}
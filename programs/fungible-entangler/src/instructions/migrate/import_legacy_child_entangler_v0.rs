@@ -0,0 +1,155 @@
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  instructions::initialize_fungible_child_entangler_v0::{
+    initialize_child_entangler, InitializeFungibleChildEntanglerV0Args, CHILD_ENTANGLER_SIZE,
+    ENTANGLER_LOOKUP_SIZE,
+  },
+  legacy_entangler::{self, TransferChildStorage},
+  state::*,
+  util::{create_pda_token_account, hash_seed},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ImportLegacyChildEntanglerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  /// CHECK: Deserialized as a FungibleParentEntanglerV0 of the legacy program in the handler
+  #[account(
+    mut,
+    owner = legacy_entangler::program::ID @ ErrorCode::InvalidLegacyEntangler,
+  )]
+  pub legacy_parent_entangler: UncheckedAccount<'info>,
+  /// CHECK: Deserialized as a FungibleChildEntanglerV0 of the legacy program in the handler
+  #[account(
+    owner = legacy_entangler::program::ID @ ErrorCode::InvalidLegacyEntangler,
+  )]
+  pub legacy_child_entangler: UncheckedAccount<'info>,
+  #[account(mut)]
+  pub legacy_child_storage: Box<Account<'info, TokenAccount>>,
+  // Imported with ImportLegacyEntanglerV0 first
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    init,
+    payer = payer,
+    space = CHILD_ENTANGLER_SIZE,
+    seeds = [b"entangler", parent_entangler.key().as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// CHECK: Created in the handler as a child_mint token account owned by entangler
+  #[account(
+    mut,
+    seeds = [b"storage", entangler.key().as_ref()],
+    bump,
+  )]
+  pub child_storage: UncheckedAccount<'info>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = ENTANGLER_LOOKUP_SIZE,
+    seeds = [b"lookup", parent_entangler.parent_mint.as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub lookup: Box<Account<'info, EntanglerLookupV0>>,
+  pub child_mint: Box<Account<'info, Mint>>,
+  /// CHECK: The original Strata fungible entangler
+  #[account(address = legacy_entangler::program::ID)]
+  pub legacy_program: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: spl-noop, which lifecycle events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Recreates a child of the original Strata program under its imported parent, with every newer
+/// feature disabled, and moves its storage balance over
+pub fn handler(ctx: Context<ImportLegacyChildEntanglerV0>) -> Result<()> {
+  require!(
+    crate::ID != legacy_entangler::program::ID,
+    ErrorCode::InvalidLegacyEntangler
+  );
+  let legacy_parent = {
+    let data = ctx.accounts.legacy_parent_entangler.try_borrow_data()?;
+    FungibleParentEntanglerV0::try_deserialize(&mut &data[..])?
+  };
+  let legacy = {
+    let data = ctx.accounts.legacy_child_entangler.try_borrow_data()?;
+    FungibleChildEntanglerV0::try_deserialize(&mut &data[..])?
+  };
+  require!(
+    legacy_parent
+      .authority
+      .ok_or(error!(ErrorCode::NoAuthority))?
+      == ctx.accounts.authority.key(),
+    ErrorCode::InvalidAuthority
+  );
+  require!(
+    legacy.parent_entangler == ctx.accounts.legacy_parent_entangler.key()
+      && legacy.child_mint == ctx.accounts.child_mint.key()
+      && legacy.child_storage == ctx.accounts.legacy_child_storage.key()
+      && legacy_parent.parent_mint == ctx.accounts.parent_entangler.parent_mint
+      && hash_seed(&legacy_parent.dynamic_seed) == ctx.accounts.parent_entangler.dynamic_seed_hash,
+    ErrorCode::InvalidLegacyEntangler
+  );
+
+  let entangler_key = ctx.accounts.entangler.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.child_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("child_storage").unwrap()],
+    ],
+  )?;
+
+  initialize_child_entangler(
+    &mut ctx.accounts.parent_entangler,
+    &mut ctx.accounts.entangler,
+    &mut ctx.accounts.lookup,
+    ctx.accounts.child_mint.key(),
+    ctx.accounts.child_storage.key(),
+    &ctx.accounts.noop_program,
+    ctx.accounts.authority.key(),
+    &ctx.bumps,
+    &Clock::get()?,
+    &InitializeFungibleChildEntanglerV0Args {
+      go_live_unix_time: legacy.go_live_unix_time,
+      freeze_swap_unix_time: legacy.freeze_swap_unix_time,
+      ..Default::default()
+    },
+  )?;
+  ctx.accounts.entangler.created_at_unix_time = legacy.created_at_unix_time;
+
+  let amount = ctx.accounts.legacy_child_storage.amount;
+  msg!("Importing {} child tokens from legacy storage", amount);
+  legacy_entangler::transfer_child_storage(
+    CpiContext::new(
+      ctx.accounts.legacy_program.to_account_info(),
+      TransferChildStorage {
+        authority: ctx.accounts.authority.to_account_info(),
+        parent_entangler: ctx.accounts.legacy_parent_entangler.to_account_info(),
+        entangler: ctx.accounts.legacy_child_entangler.to_account_info(),
+        child_storage: ctx.accounts.legacy_child_storage.to_account_info(),
+        destination: ctx.accounts.child_storage.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+      },
+    ),
+    amount,
+  )
+}
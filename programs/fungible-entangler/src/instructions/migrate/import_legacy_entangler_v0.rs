@@ -0,0 +1,127 @@
+use crate::{
+  error::ErrorCode,
+  instructions::initialize_fungible_parent_entangler_v0::PARENT_ENTANGLER_SIZE,
+  legacy_entangler::{self, TransferParentStorage},
+  state::*,
+  util::{create_pda_token_account, hash_seed, MAX_DYNAMIC_SEED_LEN},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ImportLegacyEntanglerV0Args {
+  // Must match the legacy parent's, which is only known once its data is read
+  pub dynamic_seed: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ImportLegacyEntanglerV0Args)]
+pub struct ImportLegacyEntanglerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  /// CHECK: Deserialized as a FungibleParentEntanglerV0 of the legacy program in the handler
+  #[account(
+    mut,
+    owner = legacy_entangler::program::ID @ ErrorCode::InvalidLegacyEntangler,
+  )]
+  pub legacy_parent_entangler: UncheckedAccount<'info>,
+  #[account(mut)]
+  pub legacy_parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    init,
+    payer = payer,
+    space = PARENT_ENTANGLER_SIZE,
+    seeds = [b"entangler", parent_mint.key().as_ref(), hash_seed(&args.dynamic_seed).as_ref()],
+    bump,
+  )]
+  pub entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  /// CHECK: Created in the handler as a parent_mint token account owned by entangler
+  #[account(
+    mut,
+    seeds = [b"storage", entangler.key().as_ref()],
+    bump,
+  )]
+  pub parent_storage: UncheckedAccount<'info>,
+  pub parent_mint: Box<Account<'info, Mint>>,
+  /// CHECK: The original Strata fungible entangler
+  #[account(address = legacy_entangler::program::ID)]
+  pub legacy_program: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Recreates a parent of the original Strata program here, under the same mint and dynamic seed,
+/// and moves its storage balance over. Children follow with ImportLegacyChildEntanglerV0. When
+/// this program is deployed at the original address, MigrateParentEntanglerV0ToV1 applies instead
+pub fn handler(
+  ctx: Context<ImportLegacyEntanglerV0>,
+  args: ImportLegacyEntanglerV0Args,
+) -> Result<()> {
+  require!(
+    crate::ID != legacy_entangler::program::ID,
+    ErrorCode::InvalidLegacyEntangler
+  );
+  let legacy = {
+    let data = ctx.accounts.legacy_parent_entangler.try_borrow_data()?;
+    FungibleParentEntanglerV0::try_deserialize(&mut &data[..])?
+  };
+  require!(
+    legacy.authority.ok_or(error!(ErrorCode::NoAuthority))? == ctx.accounts.authority.key(),
+    ErrorCode::InvalidAuthority
+  );
+  require!(
+    legacy.parent_mint == ctx.accounts.parent_mint.key()
+      && legacy.parent_storage == ctx.accounts.legacy_parent_storage.key()
+      && legacy.dynamic_seed == args.dynamic_seed
+      && args.dynamic_seed.len() <= MAX_DYNAMIC_SEED_LEN,
+    ErrorCode::InvalidLegacyEntangler
+  );
+
+  let entangler_key = ctx.accounts.entangler.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.parent_mint.to_account_info(),
+    &entangler_key,
+    &[
+      b"storage",
+      entangler_key.as_ref(),
+      &[*ctx.bumps.get("parent_storage").unwrap()],
+    ],
+  )?;
+
+  let entangler = &mut ctx.accounts.entangler;
+  entangler.version = ENTANGLER_VERSION;
+  entangler.authority = legacy.authority;
+  entangler.parent_mint = legacy.parent_mint;
+  entangler.parent_storage = ctx.accounts.parent_storage.key();
+  entangler.go_live_unix_time = legacy.go_live_unix_time;
+  entangler.freeze_swap_unix_time = legacy.freeze_swap_unix_time;
+  entangler.created_at_unix_time = legacy.created_at_unix_time;
+  entangler.dynamic_seed_hash = hash_seed(&legacy.dynamic_seed);
+  entangler.dynamic_seed = legacy.dynamic_seed;
+  entangler.rent_refund = ctx.accounts.authority.key();
+  entangler.default_rate = ExchangeRateV0::default();
+  entangler.bump_seed = *ctx.bumps.get("entangler").unwrap();
+  entangler.storage_bump_seed = *ctx.bumps.get("parent_storage").unwrap();
+
+  let amount = ctx.accounts.legacy_parent_storage.amount;
+  msg!("Importing {} parent tokens from legacy storage", amount);
+  legacy_entangler::transfer_parent_storage(
+    CpiContext::new(
+      ctx.accounts.legacy_program.to_account_info(),
+      TransferParentStorage {
+        authority: ctx.accounts.authority.to_account_info(),
+        parent_entangler: ctx.accounts.legacy_parent_entangler.to_account_info(),
+        parent_storage: ctx.accounts.legacy_parent_storage.to_account_info(),
+        destination: ctx.accounts.parent_storage.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+      },
+    ),
+    amount,
+  )
+}
@@ -0,0 +1,85 @@
+use crate::{
+  error::ErrorCode,
+  instructions::initialize_fungible_child_entangler_v0::{
+    CHILD_ENTANGLER_SIZE, ENTANGLER_LOOKUP_SIZE,
+  },
+  state::*,
+  util::realloc_with_rent,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct MigrateChildEntanglerV0ToV1<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  // Must be migrated first
+  #[account(
+    mut,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  /// CHECK: Deserialized as a FungibleChildEntanglerV0 and rewritten in place in the handler
+  #[account(mut)]
+  pub child_entangler: UncheckedAccount<'info>,
+  pub child_mint: Box<Account<'info, Mint>>,
+  // V0 children predate lookups, so claim the mint pair's lookup unless another child holds it
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = ENTANGLER_LOOKUP_SIZE,
+    seeds = [b"lookup", parent_entangler.parent_mint.as_ref(), child_mint.key().as_ref()],
+    bump,
+  )]
+  pub lookup: Box<Account<'info, EntanglerLookupV0>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a deployed FungibleChildEntanglerV0 to the V1 layout with every new feature disabled
+pub fn handler(ctx: Context<MigrateChildEntanglerV0ToV1>) -> Result<()> {
+  let info = ctx.accounts.child_entangler.to_account_info();
+  let legacy: Account<FungibleChildEntanglerV0> = Account::try_from(&info)?;
+  require!(
+    legacy.parent_entangler == ctx.accounts.parent_entangler.key()
+      && legacy.child_mint == ctx.accounts.child_mint.key(),
+    ErrorCode::InvalidMigration
+  );
+
+  let parent_entangler = &mut ctx.accounts.parent_entangler;
+  let entangler = FungibleChildEntanglerV1 {
+    version: ENTANGLER_VERSION,
+    parent_entangler: legacy.parent_entangler,
+    child_mint: legacy.child_mint,
+    child_storage: legacy.child_storage,
+    go_live_unix_time: legacy.go_live_unix_time,
+    freeze_swap_unix_time: legacy.freeze_swap_unix_time,
+    created_at_unix_time: legacy.created_at_unix_time,
+    index: parent_entangler.next_child_index,
+    rent_refund: parent_entangler.rent_refund,
+    bump_seed: legacy.bump_seed,
+    storage_bump_seed: legacy.storage_bump_seed,
+    ..Default::default()
+  };
+  // Already counted in num_children
+  parent_entangler.next_child_index += 1;
+
+  ctx.accounts.lookup.claim(
+    parent_entangler.key(),
+    info.key(),
+    *ctx.bumps.get("lookup").unwrap(),
+  );
+
+  msg!("Migrating child entangler to V1");
+  realloc_with_rent(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &info,
+    CHILD_ENTANGLER_SIZE,
+  )?;
+  let mut data = info.try_borrow_mut_data()?;
+  let mut writer: &mut [u8] = &mut data;
+  entangler.try_serialize(&mut writer)?;
+
+  Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::CHILD_ENTANGLER_SIZE,
+  state::*, util::realloc_with_rent,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateChildEntanglerV1ToV2<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  /// CHECK: Deserialized as a FungibleChildEntanglerV1Version1 and rewritten in place in the
+  /// handler
+  #[account(mut)]
+  pub child_entangler: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a child entangler written at layout version 1 to the current layout with every field
+/// added since disabled
+pub fn handler(ctx: Context<MigrateChildEntanglerV1ToV2>) -> Result<()> {
+  let info = ctx.accounts.child_entangler.to_account_info();
+  let legacy = {
+    let data = info.try_borrow_data()?;
+    require!(
+      info.owner == ctx.program_id
+        && data.len() >= 8
+        && data[..8] == FungibleChildEntanglerV1::discriminator(),
+      ErrorCode::NotVersion1
+    );
+    let legacy = FungibleChildEntanglerV1Version1::deserialize(&mut &data[8..])
+      .map_err(|_| error!(ErrorCode::NotVersion1))?;
+    require!(legacy.version == 1, ErrorCode::NotVersion1);
+    legacy
+  };
+
+  let entangler = FungibleChildEntanglerV1 {
+    version: ENTANGLER_VERSION,
+    parent_entangler: legacy.parent_entangler,
+    child_mint: legacy.child_mint,
+    child_storage: legacy.child_storage,
+    go_live_unix_time: legacy.go_live_unix_time,
+    freeze_swap_unix_time: legacy.freeze_swap_unix_time,
+    created_at_unix_time: legacy.created_at_unix_time,
+    index: legacy.index,
+    vesting: legacy.vesting,
+    refund_window_seconds: legacy.refund_window_seconds,
+    expiry_unix_time: legacy.expiry_unix_time,
+    total_top_off_amount: legacy.total_top_off_amount,
+    reclaim_snapshot_amount: legacy.reclaim_snapshot_amount,
+    rewards_pool: legacy.rewards_pool,
+    min_lock_seconds: legacy.min_lock_seconds,
+    burn: legacy.burn,
+    rent_refund: legacy.rent_refund,
+    rate: legacy.rate,
+    swap_fee_bps: legacy.swap_fee_bps,
+    max_parent_to_child_amount: legacy.max_parent_to_child_amount,
+    total_parent_to_child_amount: legacy.total_parent_to_child_amount,
+    bump_seed: legacy.bump_seed,
+    storage_bump_seed: legacy.storage_bump_seed,
+    ..Default::default()
+  };
+
+  msg!("Migrating child entangler to version 2");
+  realloc_with_rent(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &info,
+    CHILD_ENTANGLER_SIZE,
+  )?;
+  let mut data = info.try_borrow_mut_data()?;
+  let mut writer: &mut [u8] = &mut data;
+  entangler.try_serialize(&mut writer)?;
+
+  Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_child_registry_v0::CHILD_REGISTRY_SIZE, state::*,
+  util::realloc_with_rent,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateChildRegistryV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  /// CHECK: A ChildRegistryV0 page created before basket weights, checked in the handler
+  #[account(mut, owner = crate::ID)]
+  pub registry: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Grows a registry page created before basket weights to the current ChildRegistryV0 size. The
+/// added weights are zeroed, so the page has no basket until SetRegistryWeightsV0
+pub fn handler(ctx: Context<MigrateChildRegistryV0>) -> Result<()> {
+  let info = ctx.accounts.registry.to_account_info();
+  {
+    let data = info.try_borrow_data()?;
+    require!(
+      data.len() >= 8 && data[..8] == ChildRegistryV0::discriminator(),
+      ErrorCode::InvalidRegistry
+    );
+  }
+  require!(
+    info.data_len() < CHILD_REGISTRY_SIZE,
+    ErrorCode::RegistryAlreadyMigrated
+  );
+
+  msg!("Migrating child registry to basket weights");
+  realloc_with_rent(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &info,
+    CHILD_REGISTRY_SIZE,
+  )
+}
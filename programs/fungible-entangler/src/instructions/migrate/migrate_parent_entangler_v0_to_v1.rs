@@ -0,0 +1,59 @@
+use crate::{
+  instructions::initialize_fungible_parent_entangler_v0::PARENT_ENTANGLER_SIZE,
+  state::*,
+  util::{hash_seed, realloc_with_rent},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateParentEntanglerV0ToV1<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  /// CHECK: Deserialized as a FungibleParentEntanglerV0 and rewritten in place in the handler
+  #[account(mut)]
+  pub parent_entangler: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a deployed FungibleParentEntanglerV0 to the V1 layout. The PDA stays derived from
+/// the raw dynamic seed, so the migrated entangler signs with legacy seeds
+pub fn handler(ctx: Context<MigrateParentEntanglerV0ToV1>) -> Result<()> {
+  let info = ctx.accounts.parent_entangler.to_account_info();
+  let legacy: Account<FungibleParentEntanglerV0> = Account::try_from(&info)?;
+
+  let entangler = FungibleParentEntanglerV1 {
+    version: ENTANGLER_VERSION,
+    parent_mint: legacy.parent_mint,
+    parent_storage: legacy.parent_storage,
+    go_live_unix_time: legacy.go_live_unix_time,
+    freeze_swap_unix_time: legacy.freeze_swap_unix_time,
+    created_at_unix_time: legacy.created_at_unix_time,
+    num_children: legacy.num_children,
+    next_child_index: legacy.num_children,
+    authority: legacy.authority,
+    name: String::new(),
+    uri: String::new(),
+    dynamic_seed_hash: hash_seed(&legacy.dynamic_seed),
+    dynamic_seed: legacy.dynamic_seed.clone(),
+    // V0 entanglers without an authority can never be closed, so the payer is only a fallback
+    rent_refund: legacy.authority.unwrap_or_else(|| ctx.accounts.payer.key()),
+    legacy_seeds: true,
+    bump_seed: legacy.bump_seed,
+    storage_bump_seed: legacy.storage_bump_seed,
+    ..Default::default()
+  };
+
+  msg!("Migrating parent entangler to V1");
+  realloc_with_rent(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &info,
+    PARENT_ENTANGLER_SIZE,
+  )?;
+  let mut data = info.try_borrow_mut_data()?;
+  let mut writer: &mut [u8] = &mut data;
+  entangler.try_serialize(&mut writer)?;
+
+  Ok(())
+}
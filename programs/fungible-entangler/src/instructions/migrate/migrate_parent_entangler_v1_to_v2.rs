@@ -0,0 +1,70 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_parent_entangler_v0::PARENT_ENTANGLER_SIZE,
+  state::*, util::realloc_with_rent,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct MigrateParentEntanglerV1ToV2<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  /// CHECK: Deserialized as a FungibleParentEntanglerV1Version1 and rewritten in place in the
+  /// handler
+  #[account(mut)]
+  pub parent_entangler: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Upgrades a parent entangler written at layout version 1 to the current layout with every
+/// field added since disabled
+pub fn handler(ctx: Context<MigrateParentEntanglerV1ToV2>) -> Result<()> {
+  let info = ctx.accounts.parent_entangler.to_account_info();
+  let legacy = {
+    let data = info.try_borrow_data()?;
+    require!(
+      info.owner == ctx.program_id
+        && data.len() >= 8
+        && data[..8] == FungibleParentEntanglerV1::discriminator(),
+      ErrorCode::NotVersion1
+    );
+    let legacy = FungibleParentEntanglerV1Version1::deserialize(&mut &data[8..])
+      .map_err(|_| error!(ErrorCode::NotVersion1))?;
+    require!(legacy.version == 1, ErrorCode::NotVersion1);
+    legacy
+  };
+
+  let entangler = FungibleParentEntanglerV1 {
+    version: ENTANGLER_VERSION,
+    parent_mint: legacy.parent_mint,
+    parent_storage: legacy.parent_storage,
+    go_live_unix_time: legacy.go_live_unix_time,
+    freeze_swap_unix_time: legacy.freeze_swap_unix_time,
+    created_at_unix_time: legacy.created_at_unix_time,
+    num_children: legacy.num_children,
+    next_child_index: legacy.next_child_index,
+    authority: legacy.authority,
+    name: legacy.name,
+    uri: legacy.uri,
+    dynamic_seed: legacy.dynamic_seed,
+    dynamic_seed_hash: legacy.dynamic_seed_hash,
+    rent_refund: legacy.rent_refund,
+    legacy_seeds: legacy.legacy_seeds,
+    bump_seed: legacy.bump_seed,
+    storage_bump_seed: legacy.storage_bump_seed,
+    ..Default::default()
+  };
+
+  msg!("Migrating parent entangler to version 2");
+  realloc_with_rent(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &info,
+    PARENT_ENTANGLER_SIZE,
+  )?;
+  let mut data = info.try_borrow_mut_data()?;
+  let mut writer: &mut [u8] = &mut data;
+  entangler.try_serialize(&mut writer)?;
+
+  Ok(())
+}
@@ -0,0 +1,15 @@
+pub mod import_legacy_child_entangler_v0;
+pub mod import_legacy_entangler_v0;
+pub mod migrate_child_entangler_v0_to_v1;
+pub mod migrate_child_entangler_v1_to_v2;
+pub mod migrate_child_registry_v0;
+pub mod migrate_parent_entangler_v0_to_v1;
+pub mod migrate_parent_entangler_v1_to_v2;
+
+pub use import_legacy_child_entangler_v0::*;
+pub use import_legacy_entangler_v0::*;
+pub use migrate_child_entangler_v0_to_v1::*;
+pub use migrate_child_entangler_v1_to_v2::*;
+pub use migrate_child_registry_v0::*;
+pub use migrate_parent_entangler_v0_to_v1::*;
+pub use migrate_parent_entangler_v1_to_v2::*;
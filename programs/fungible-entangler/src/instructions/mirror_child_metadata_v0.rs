@@ -0,0 +1,110 @@
+use crate::{
+  error::ErrorCode,
+  state::*,
+  token_metadata::{
+    self, create_metadata_account_v2, update_metadata_account_v2, CreateMetadataAccount, Metadata,
+    MetadataArgs, UpdateMetadataAccount,
+  },
+};
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::token::Mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MirrorChildMetadataV0Args {
+  /// Appended to the parent name, ie " (Wrapped)"
+  pub name_suffix: String,
+  /// Appended to the parent symbol, ie "w"
+  pub symbol_suffix: String,
+}
+
+#[derive(Accounts)]
+#[instruction(args: MirrorChildMetadataV0Args)]
+pub struct MirrorChildMetadataV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(
+    constraint = child_mint.mint_authority == COption::Some(child_entangler.key()) @ ErrorCode::ChildMintNotControlled,
+  )]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"metadata", token_metadata::ID.as_ref(), parent_mint.key().as_ref()],
+    seeds::program = token_metadata::ID,
+    bump,
+  )]
+  pub parent_metadata: Box<Account<'info, Metadata>>,
+  /// CHECK: Created or updated by cpi
+  #[account(
+    mut,
+    seeds = [b"metadata", token_metadata::ID.as_ref(), child_mint.key().as_ref()],
+    seeds::program = token_metadata::ID,
+    bump,
+  )]
+  pub child_metadata: UncheckedAccount<'info>,
+
+  /// CHECK: Checked with constraint
+  #[account(address = token_metadata::ID)]
+  pub token_metadata_program: AccountInfo<'info>,
+  pub system_program: Program<'info, System>,
+  pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<MirrorChildMetadataV0>, args: MirrorChildMetadataV0Args) -> Result<()> {
+  let data = &ctx.accounts.parent_metadata.data;
+  let metadata = MetadataArgs {
+    name: str::replace(&data.name, "\u{0000}", "") + &args.name_suffix,
+    symbol: str::replace(&data.symbol, "\u{0000}", "") + &args.symbol_suffix,
+    uri: str::replace(&data.uri, "\u{0000}", ""),
+  };
+
+  let child_entangler = &ctx.accounts.child_entangler;
+  let child_entangler_seeds: &[&[&[u8]]] = &[&child_entangler.signer_seeds()];
+
+  if ctx.accounts.child_metadata.data_is_empty() {
+    msg!("Creating child metadata");
+    create_metadata_account_v2(
+      CpiContext::new_with_signer(
+        ctx.accounts.token_metadata_program.clone(),
+        CreateMetadataAccount {
+          token_metadata: ctx.accounts.child_metadata.to_account_info().clone(),
+          mint: ctx.accounts.child_mint.to_account_info().clone(),
+          mint_authority: child_entangler.to_account_info().clone(),
+          payer: ctx.accounts.payer.to_account_info().clone(),
+          update_authority: child_entangler.to_account_info().clone(),
+          system_program: ctx.accounts.system_program.to_account_info().clone(),
+          rent: ctx.accounts.rent.to_account_info().clone(),
+        },
+        child_entangler_seeds,
+      ),
+      metadata,
+    )?;
+  } else {
+    msg!("Updating child metadata");
+    update_metadata_account_v2(
+      CpiContext::new_with_signer(
+        ctx.accounts.token_metadata_program.clone(),
+        UpdateMetadataAccount {
+          token_metadata: ctx.accounts.child_metadata.to_account_info().clone(),
+          update_authority: child_entangler.to_account_info().clone(),
+        },
+        child_entangler_seeds,
+      ),
+      metadata,
+    )?;
+  }
+
+  Ok(())
+}
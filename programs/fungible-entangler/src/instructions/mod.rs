@@ -1,15 +1,115 @@
+pub mod append_child_registry_v0;
+pub mod authority_config;
+pub mod claim_fee_rebate_v0;
+pub mod claim_rewards_v0;
+pub mod claim_vested_v0;
 pub mod close_fungible_child_entangler_v0;
 pub mod close_fungible_parent_entangler_v0;
+pub mod commit_reveal;
+pub mod compressed;
+pub mod compressed_token;
+pub mod distribute_fees_v0;
+pub mod enforce_freeze_v0;
+pub mod ensure_child_entangler_v0;
+pub mod get_version_v0;
+pub mod initialize_child_mint_v0;
+pub mod initialize_child_registry_v0;
+pub mod initialize_entangler_with_children_v0;
 pub mod initialize_fungible_child_entangler_v0;
 pub mod initialize_fungible_parent_entangler_v0;
+pub mod initialize_global_config_v0;
+pub mod initialize_namespaced_parent_entangler_v0;
+pub mod initialize_rewards_pool_v0;
+pub mod initialize_swap_record_v0;
+pub mod lock;
+pub mod migrate;
+pub mod mirror_child_metadata_v0;
+pub mod nft_authority;
+pub mod otc;
+pub mod queue;
+pub mod rate_schedule;
+pub mod rebalance_between_entanglers_v0;
+pub mod reclaim_after_expiry_v0;
+pub mod remove_child_registry_v0;
+pub mod resize_entangler_v0;
+pub mod seal_entangler_v0;
+pub mod set_authority_v0;
+pub mod set_drawdown_limit_v0;
+pub mod set_fees_v0;
+pub mod set_freeze_time_v0;
+pub mod set_global_pause_v0;
+pub mod set_parent_freeze_time_v0;
+pub mod set_rate_band_v0;
+pub mod set_rate_v0;
+pub mod set_registry_weights_v0;
+pub mod set_withdrawals_frozen_until_v0;
+pub mod settlement;
+pub mod snapshot_v0;
+pub mod strategy;
 pub mod swap;
+pub mod sweep_dust_v0;
+pub mod top_off_child_storage_v0;
 pub mod transfer_child_storage_v0;
 pub mod transfer_parent_storage_v0;
+pub mod update_global_config_v0;
+pub mod update_metadata_v0;
+pub mod validate_init_params_v0;
+pub mod wrap;
 
+pub use append_child_registry_v0::*;
+pub use authority_config::*;
+pub use claim_fee_rebate_v0::*;
+pub use claim_rewards_v0::*;
+pub use claim_vested_v0::*;
 pub use close_fungible_child_entangler_v0::*;
 pub use close_fungible_parent_entangler_v0::*;
+pub use commit_reveal::*;
+pub use compressed::*;
+pub use compressed_token::*;
+pub use distribute_fees_v0::*;
+pub use enforce_freeze_v0::*;
+pub use ensure_child_entangler_v0::*;
+pub use get_version_v0::*;
+pub use initialize_child_mint_v0::*;
+pub use initialize_child_registry_v0::*;
+pub use initialize_entangler_with_children_v0::*;
 pub use initialize_fungible_child_entangler_v0::*;
 pub use initialize_fungible_parent_entangler_v0::*;
+pub use initialize_global_config_v0::*;
+pub use initialize_namespaced_parent_entangler_v0::*;
+pub use initialize_rewards_pool_v0::*;
+pub use initialize_swap_record_v0::*;
+pub use lock::*;
+pub use migrate::*;
+pub use mirror_child_metadata_v0::*;
+pub use nft_authority::*;
+pub use otc::*;
+pub use queue::*;
+pub use rate_schedule::*;
+pub use rebalance_between_entanglers_v0::*;
+pub use reclaim_after_expiry_v0::*;
+pub use remove_child_registry_v0::*;
+pub use resize_entangler_v0::*;
+pub use seal_entangler_v0::*;
+pub use set_authority_v0::*;
+pub use set_drawdown_limit_v0::*;
+pub use set_fees_v0::*;
+pub use set_freeze_time_v0::*;
+pub use set_global_pause_v0::*;
+pub use set_parent_freeze_time_v0::*;
+pub use set_rate_band_v0::*;
+pub use set_rate_v0::*;
+pub use set_registry_weights_v0::*;
+pub use set_withdrawals_frozen_until_v0::*;
+pub use settlement::*;
+pub use snapshot_v0::*;
+pub use strategy::*;
 pub use swap::*;
+pub use sweep_dust_v0::*;
+pub use top_off_child_storage_v0::*;
 pub use transfer_child_storage_v0::*;
 pub use transfer_parent_storage_v0::*;
+pub use update_global_config_v0::*;
+pub use update_metadata_v0::*;
+pub use validate_init_params_v0::*;
+pub use wrap::*;
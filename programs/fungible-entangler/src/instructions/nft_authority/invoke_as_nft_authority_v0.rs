@@ -0,0 +1,79 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+  },
+};
+use anchor_spl::token::TokenAccount;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InvokeAsNftAuthorityV0Args {
+  // Data of the authority gated instruction of this program to invoke
+  pub data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InvokeAsNftAuthorityV0Args)]
+pub struct InvokeAsNftAuthorityV0<'info> {
+  pub holder: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority_nft_mint.is_some() @ ErrorCode::NoNftAuthority,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    constraint = Some(holder_nft_account.mint) == parent_entangler.authority_nft_mint @ ErrorCode::NotNftHolder,
+    constraint = holder_nft_account.owner == holder.key() @ ErrorCode::NotNftHolder,
+    constraint = holder_nft_account.amount == 1 @ ErrorCode::NotNftHolder,
+  )]
+  pub holder_nft_account: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Signs the inner instruction as the parent's authority
+  #[account(
+    seeds = [b"nft-authority", parent_entangler.key().as_ref()],
+    bump,
+    constraint = parent_entangler.authority == Some(nft_authority.key()) @ ErrorCode::NoNftAuthority,
+  )]
+  pub nft_authority: UncheckedAccount<'info>,
+  /// CHECK: This program, invoked recursively
+  #[account(address = crate::ID)]
+  pub entangler_program: UncheckedAccount<'info>,
+}
+
+/// Invokes an authority gated instruction of this program with the nft_authority PDA signing.
+/// Remaining accounts are the inner instruction's accounts, with nft_authority in its authority
+/// slot
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, InvokeAsNftAuthorityV0<'info>>,
+  args: InvokeAsNftAuthorityV0Args,
+) -> Result<()> {
+  let nft_authority = ctx.accounts.nft_authority.key();
+  let parent_entangler = ctx.accounts.parent_entangler.key();
+  let accounts = ctx
+    .remaining_accounts
+    .iter()
+    .map(|account| AccountMeta {
+      pubkey: account.key(),
+      is_signer: account.is_signer || account.key() == nft_authority,
+      is_writable: account.is_writable,
+    })
+    .collect();
+
+  let mut account_infos = ctx.remaining_accounts.to_vec();
+  account_infos.push(ctx.accounts.entangler_program.to_account_info());
+  invoke_signed(
+    &Instruction {
+      program_id: crate::ID,
+      accounts,
+      data: args.data,
+    },
+    &account_infos,
+    &[&[
+      b"nft-authority",
+      parent_entangler.as_ref(),
+      &[*ctx.bumps.get("nft_authority").unwrap()],
+    ]],
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod invoke_as_nft_authority_v0;
+pub mod set_nft_authority_v0;
+
+pub use invoke_as_nft_authority_v0::*;
+pub use set_nft_authority_v0::*;
@@ -0,0 +1,38 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(Accounts)]
+pub struct SetNftAuthorityV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    constraint = nft_mint.decimals == 0 && nft_mint.supply == 1 @ ErrorCode::InvalidNftMint,
+  )]
+  pub nft_mint: Box<Account<'info, Mint>>,
+  /// CHECK: Only derived, becomes the parent's authority
+  #[account(
+    seeds = [b"nft-authority", parent_entangler.key().as_ref()],
+    bump,
+  )]
+  pub nft_authority: UncheckedAccount<'info>,
+}
+
+/// Hands the parent, and every child under it, to whoever holds nft_mint, so that control moves
+/// with the NFT. SetAuthorityV0 or SealEntanglerV0, invoked by the holder, leave NFT control
+pub fn handler(ctx: Context<SetNftAuthorityV0>) -> Result<()> {
+  msg!(
+    "Setting authority to the holder of {}",
+    ctx.accounts.nft_mint.key()
+  );
+  let parent_entangler = &mut ctx.accounts.parent_entangler;
+  parent_entangler.authority = Some(ctx.accounts.nft_authority.key());
+  parent_entangler.authority_nft_mint = Some(ctx.accounts.nft_mint.key());
+
+  Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use strata_spl_utils::{close_token_account_signed, transfer_signed};
+
+#[derive(Accounts)]
+pub struct AcceptOfferV0<'info> {
+  pub taker: Signer<'info>,
+  /// CHECK: Receives the rent of the offer and its escrow
+  #[account(mut)]
+  pub maker: UncheckedAccount<'info>,
+  #[account(
+    mut,
+    close = maker,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = maker @ ErrorCode::InvalidOwner,
+    has_one = taker @ ErrorCode::InvalidOwner,
+    has_one = escrow @ ErrorCode::StorageMismatch,
+  )]
+  pub offer: Box<Account<'info, OfferV0>>,
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  // Pays the ask
+  #[account(
+    mut,
+    constraint = source.mint == offer.ask_mint @ ErrorCode::InvalidOfferMints,
+  )]
+  pub source: Box<Account<'info, TokenAccount>>,
+  // Receives the ask
+  #[account(
+    mut,
+    constraint = maker_destination.mint == offer.ask_mint @ ErrorCode::InvalidOfferMints,
+    constraint = maker_destination.owner == offer.maker @ ErrorCode::InvalidOwner,
+  )]
+  pub maker_destination: Box<Account<'info, TokenAccount>>,
+  // Receives the escrow
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<AcceptOfferV0>) -> Result<()> {
+  let offer = &ctx.accounts.offer;
+  require!(
+    !offer.is_expired(Clock::get()?.unix_timestamp),
+    ErrorCode::OfferExpired
+  );
+
+  msg!("Paying {} to the maker", offer.ask_amount);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info().clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.maker_destination.to_account_info().clone(),
+        authority: ctx.accounts.taker.to_account_info().clone(),
+      },
+    ),
+    offer.ask_amount,
+  )?;
+
+  let child_entangler = &ctx.accounts.child_entangler;
+  msg!("Releasing {} from escrow", offer.offer_amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+    offer.offer_amount,
+  )?;
+
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.maker.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::{close_token_account_signed, transfer_signed};
+
+#[derive(Accounts)]
+pub struct CancelOfferV0<'info> {
+  #[account(mut)]
+  pub maker: Signer<'info>,
+  #[account(
+    mut,
+    close = maker,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = maker @ ErrorCode::InvalidOwner,
+    has_one = escrow @ ErrorCode::StorageMismatch,
+  )]
+  pub offer: Box<Account<'info, OfferV0>>,
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Returns the escrow to the maker and closes the offer
+pub fn handler(ctx: Context<CancelOfferV0>) -> Result<()> {
+  let child_entangler = &ctx.accounts.child_entangler;
+
+  msg!("Returning {} from escrow", ctx.accounts.escrow.amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+    ctx.accounts.escrow.amount,
+  )?;
+
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.maker.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+  )?;
+
+  Ok(())
+}
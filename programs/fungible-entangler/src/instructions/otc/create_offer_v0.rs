@@ -0,0 +1,119 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::OFFER_SIZE, state::*,
+  util::create_pda_token_account,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CreateOfferV0Args {
+  // Lets a maker hold several offers on one child at once
+  pub nonce: u64,
+  pub taker: Pubkey,
+  pub offer_amount: u64,
+  pub ask_amount: u64,
+  pub expiry_unix_time: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateOfferV0Args)]
+pub struct CreateOfferV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub maker: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    init,
+    payer = payer,
+    space = OFFER_SIZE,
+    seeds = [b"offer", child_entangler.key().as_ref(), maker.key().as_ref(), &args.nonce.to_le_bytes()],
+    bump,
+  )]
+  pub offer: Box<Account<'info, OfferV0>>,
+  /// CHECK: Created in the handler as an offer_mint token account owned by child_entangler
+  #[account(
+    mut,
+    seeds = [b"offer-escrow", offer.key().as_ref()],
+    bump,
+  )]
+  pub escrow: UncheckedAccount<'info>,
+  pub offer_mint: Box<Account<'info, Mint>>,
+  pub ask_mint: Box<Account<'info, Mint>>,
+  #[account(
+    mut,
+    constraint = source.mint == offer_mint.key() @ ErrorCode::InvalidOfferMints,
+  )]
+  pub source: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Escrows offer_amount of one of the child's mints for taker to claim with ask_amount of the
+/// other, at whatever rate maker and taker agreed instead of the child's rate
+pub fn handler(ctx: Context<CreateOfferV0>, args: CreateOfferV0Args) -> Result<()> {
+  require!(
+    args.offer_amount > 0 && args.ask_amount > 0,
+    ErrorCode::InvalidAmount
+  );
+  let parent_mint = ctx.accounts.parent_entangler.parent_mint;
+  let child_mint = ctx.accounts.child_entangler.child_mint;
+  let offer_mint = ctx.accounts.offer_mint.key();
+  let ask_mint = ctx.accounts.ask_mint.key();
+  require!(
+    (offer_mint == parent_mint && ask_mint == child_mint)
+      || (offer_mint == child_mint && ask_mint == parent_mint),
+    ErrorCode::InvalidOfferMints
+  );
+
+  let offer = &mut ctx.accounts.offer;
+  offer.child_entangler = ctx.accounts.child_entangler.key();
+  offer.maker = ctx.accounts.maker.key();
+  offer.taker = args.taker;
+  offer.offer_mint = offer_mint;
+  offer.offer_amount = args.offer_amount;
+  offer.ask_mint = ask_mint;
+  offer.ask_amount = args.ask_amount;
+  offer.escrow = ctx.accounts.escrow.key();
+  offer.expiry_unix_time = args.expiry_unix_time;
+  offer.nonce = args.nonce;
+  offer.bump_seed = *ctx.bumps.get("offer").unwrap();
+  offer.escrow_bump_seed = *ctx.bumps.get("escrow").unwrap();
+
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.offer_mint.to_account_info(),
+    &ctx.accounts.child_entangler.key(),
+    &[
+      b"offer-escrow",
+      ctx.accounts.offer.key().as_ref(),
+      &[*ctx.bumps.get("escrow").unwrap()],
+    ],
+  )?;
+
+  msg!("Escrowing {} for the offer", args.offer_amount);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info().clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.escrow.to_account_info().clone(),
+        authority: ctx.accounts.maker.to_account_info().clone(),
+      },
+    ),
+    args.offer_amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod accept_offer_v0;
+pub mod cancel_offer_v0;
+pub mod create_offer_v0;
+
+pub use accept_offer_v0::*;
+pub use cancel_offer_v0::*;
+pub use create_offer_v0::*;
@@ -0,0 +1,57 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::{close_token_account_signed, transfer_signed};
+
+#[derive(Accounts)]
+pub struct CancelQueuedSwapV0<'info> {
+  pub owner: Signer<'info>,
+  #[account(
+    mut,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = owner @ ErrorCode::InvalidOwner,
+    has_one = escrow @ ErrorCode::StorageMismatch,
+    has_one = refund_account @ ErrorCode::InvalidOwner,
+    has_one = payer @ ErrorCode::InvalidOwner,
+  )]
+  pub queued_swap: Box<Account<'info, QueuedSwapV0>>,
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Receives the escrow, the token program validates it in the transfer
+  #[account(mut)]
+  pub refund_account: UncheckedAccount<'info>,
+  /// CHECK: Receives the escrow's rent, checked against the ticket
+  #[account(mut)]
+  pub payer: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Returns the escrow of a ticket that hasn't executed. The ticket stays until the queue reaches
+/// it, so that later tickets keep their order
+pub fn handler(ctx: Context<CancelQueuedSwapV0>) -> Result<()> {
+  let child_entangler = &ctx.accounts.child_entangler;
+
+  msg!("Returning {} from escrow", ctx.accounts.escrow.amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.refund_account.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+    ctx.accounts.escrow.amount,
+  )?;
+
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.payer.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+  )?;
+
+  ctx.accounts.queued_swap.amount = 0;
+
+  Ok(())
+}
@@ -0,0 +1,270 @@
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  instructions::swap::{
+    account::SwapAccounts,
+    arg::SwapV0Args,
+    common::{
+      apply_rate_schedule, check_swaps_open, convert, deduct_fee, payable_storage_amount,
+      SwapDirection,
+    },
+    execute_child_for_parent, execute_parent_for_child,
+  },
+  math,
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use strata_spl_utils::{close_token_account_signed, transfer_signed};
+
+#[derive(Accounts)]
+pub struct ExecuteQueuedSwapV0<'info> {
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(
+    mut,
+    seeds = [b"swap-queue", child_entangler.key().as_ref()],
+    bump = swap_queue.bump_seed,
+  )]
+  pub swap_queue: Box<Account<'info, SwapQueueV0>>,
+  #[account(
+    mut,
+    close = payer,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = escrow @ ErrorCode::StorageMismatch,
+    has_one = destination @ ErrorCode::InvalidOwner,
+    has_one = refund_account @ ErrorCode::InvalidOwner,
+    has_one = payer @ ErrorCode::InvalidOwner,
+    constraint = queued_swap.ticket == swap_queue.next_execute @ ErrorCode::QueuedSwapOutOfOrder,
+  )]
+  pub queued_swap: Box<Account<'info, QueuedSwapV0>>,
+  /// CHECK: The ticket's escrow, already closed if the ticket was cancelled
+  #[account(mut)]
+  pub escrow: UncheckedAccount<'info>,
+  /// CHECK: Only receives tokens, the token program validates it in the transfer
+  #[account(mut)]
+  pub destination: UncheckedAccount<'info>,
+  /// CHECK: Only receives tokens, the token program validates it in the transfer
+  #[account(mut)]
+  pub refund_account: UncheckedAccount<'info>,
+  /// CHECK: Receives the rent of the ticket and escrow, checked against the ticket
+  #[account(mut)]
+  pub payer: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  /// CHECK: spl-noop, which swap events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Settles the next ticket of the child's queue once it is live. Anyone may crank it. Tickets
+/// the child can no longer swap, see fits, are refunded instead of blocking the queue, and
+/// cancelled tickets are skipped. Queued swaps may execute during a commit reveal
+/// window, and are not recorded to a SwapRecordV0. Swaps outside of the queue open once the last
+/// ticket has executed
+pub fn handler(ctx: Context<ExecuteQueuedSwapV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(
+    ctx.accounts.child_entangler.go_live_unix_time < clock.unix_timestamp,
+    ErrorCode::ChildNotLiveYet
+  );
+  ctx.accounts.swap_queue.next_execute += 1;
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.queued_swap_count = math::sub(child_entangler.queued_swap_count, 1)?;
+
+  let amount = ctx.accounts.queued_swap.amount;
+  if amount == 0 {
+    msg!(
+      "Skipping cancelled ticket {}",
+      ctx.accounts.queued_swap.ticket
+    );
+    return Ok(());
+  }
+
+  let accounts = &mut *ctx.accounts;
+  let escrow: Account<TokenAccount> = Account::try_from(&accounts.escrow)?;
+  let direction = if accounts.queued_swap.parent_to_child {
+    SwapDirection::ParentToChild
+  } else {
+    SwapDirection::ChildToParent
+  };
+  let parent_key = accounts.child_entangler.parent_entangler;
+  let child_mint_key = accounts.child_entangler.child_mint;
+  let bump_seed = [accounts.child_entangler.bump_seed];
+  let child_seeds: &[&[u8]] = &[
+    b"entangler",
+    parent_key.as_ref(),
+    child_mint_key.as_ref(),
+    &bump_seed,
+  ];
+  let child_info = accounts.child_entangler.to_account_info();
+
+  if fits(accounts, direction, amount, clock.unix_timestamp)? {
+    let swap_accounts = SwapAccounts {
+      parent_entangler: &mut accounts.parent_entangler,
+      parent_storage: &mut accounts.parent_storage,
+      parent_mint: &mut accounts.parent_mint,
+      child_entangler: &mut accounts.child_entangler,
+      child_storage: &mut accounts.child_storage,
+      child_mint: &mut accounts.child_mint,
+      source: &escrow,
+      source_authority: &child_info,
+      source_signer_seeds: &[child_seeds],
+      destination: &accounts.destination,
+      rate_schedule: &accounts.rate_schedule,
+      token_program: &accounts.token_program,
+      noop_program: &accounts.noop_program,
+      // Queueing already gave the swap a fair place, the commit reveal window has nothing to add
+      revealed_commit: true,
+      vested: false,
+      locked: false,
+      queued: true,
+    };
+    let args = SwapV0Args {
+      amount: Some(amount),
+      all: None,
+      output_splits_bps: vec![],
+      memo: None,
+      allow_partial: false,
+    };
+    match direction {
+      SwapDirection::ParentToChild => execute_parent_for_child(swap_accounts, &[], &clock, &args)?,
+      SwapDirection::ChildToParent => execute_child_for_parent(swap_accounts, &[], &clock, &args)?,
+    };
+  } else {
+    msg!(
+      "The child can't take ticket {}, refunding",
+      accounts.queued_swap.ticket
+    );
+    transfer_signed(
+      &accounts.token_program.to_account_info(),
+      &accounts.escrow.to_account_info(),
+      &accounts.refund_account.to_account_info(),
+      &child_info,
+      child_seeds,
+      amount,
+    )?;
+  }
+
+  close_token_account_signed(
+    &accounts.token_program.to_account_info(),
+    &accounts.escrow.to_account_info(),
+    &accounts.payer.to_account_info(),
+    &child_info,
+    child_seeds,
+  )
+}
+
+/// Whether the child still takes a swap of amount in direction, and storage, the child's caps and
+/// its drawdown limit cover it with a payout. Anything a swap could fail on after the ticket was
+/// queued is checked here, so that the ticket is refunded rather than blocking the queue
+fn fits(
+  accounts: &mut ExecuteQueuedSwapV0,
+  direction: SwapDirection,
+  amount: u64,
+  unix_time: i64,
+) -> Result<bool> {
+  let child_entangler = &accounts.child_entangler;
+  if check_swaps_open(
+    &accounts.parent_entangler,
+    child_entangler,
+    direction,
+    unix_time,
+  )
+  .is_err()
+  {
+    return Ok(false);
+  }
+  // The child may have started vesting or locking its output since the ticket was queued
+  if direction == SwapDirection::ParentToChild
+    && (child_entangler.vesting.is_some() || child_entangler.min_lock_seconds.is_some())
+  {
+    return Ok(false);
+  }
+
+  // A rate outside of the child's rate band halts swaps
+  let rate = match apply_rate_schedule(
+    &mut accounts.child_entangler,
+    &accounts.rate_schedule,
+    unix_time,
+  ) {
+    Ok(rate) => rate,
+    Err(_) => return Ok(false),
+  };
+  let child_entangler = &accounts.child_entangler;
+  let output_amount = deduct_fee(
+    convert(&rate, direction, amount)?,
+    child_entangler.fee_bps(amount),
+  )?;
+  let (target, total, max) = match direction {
+    SwapDirection::ParentToChild => (
+      &accounts.child_storage,
+      child_entangler.total_parent_to_child_amount,
+      child_entangler.max_parent_to_child_amount,
+    ),
+    SwapDirection::ChildToParent => (
+      &accounts.parent_storage,
+      child_entangler.total_child_to_parent_amount,
+      child_entangler.max_child_to_parent_amount,
+    ),
+  };
+
+  let payable = payable_storage_amount(
+    &accounts.parent_entangler,
+    child_entangler,
+    direction,
+    target.amount,
+  );
+
+  // Recording against a copy leaves the child's window untouched
+  let within_drawdown_limit = child_entangler.drawdown_limit.map_or(true, |mut limit| {
+    limit.record(direction, output_amount, unix_time).is_ok()
+  });
+
+  Ok(
+    output_amount > 0
+      && payable >= output_amount
+      && within_drawdown_limit
+      && max.map_or(true, |max| {
+        total
+          .checked_add(amount)
+          .map_or(false, |total| total <= max)
+      }),
+  )
+}
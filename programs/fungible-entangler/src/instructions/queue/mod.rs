@@ -0,0 +1,7 @@
+pub mod cancel_queued_swap_v0;
+pub mod execute_queued_swap_v0;
+pub mod queue_swap_v0;
+
+pub use cancel_queued_swap_v0::*;
+pub use execute_queued_swap_v0::*;
+pub use queue_swap_v0::*;
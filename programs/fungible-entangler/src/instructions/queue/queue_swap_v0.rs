@@ -0,0 +1,138 @@
+use crate::{
+  error::ErrorCode,
+  instructions::initialize_fungible_child_entangler_v0::{QUEUED_SWAP_SIZE, SWAP_QUEUE_SIZE},
+  math,
+  state::*,
+  util::create_pda_token_account,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct QueueSwapV0Args {
+  pub amount: u64,
+  pub parent_to_child: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: QueueSwapV0Args)]
+pub struct QueueSwapV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub owner: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = SWAP_QUEUE_SIZE,
+    seeds = [b"swap-queue", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub swap_queue: Box<Account<'info, SwapQueueV0>>,
+  #[account(
+    init,
+    payer = payer,
+    space = QUEUED_SWAP_SIZE,
+    seeds = [b"queued-swap", child_entangler.key().as_ref(), &swap_queue.next_ticket.to_le_bytes()],
+    bump,
+  )]
+  pub queued_swap: Box<Account<'info, QueuedSwapV0>>,
+  /// CHECK: Created in the handler as an input_mint token account owned by child_entangler
+  #[account(
+    mut,
+    seeds = [b"queued-swap-escrow", queued_swap.key().as_ref()],
+    bump,
+  )]
+  pub escrow: UncheckedAccount<'info>,
+  #[account(
+    constraint = input_mint.key() == if args.parent_to_child { parent_entangler.parent_mint } else { child_entangler.child_mint } @ ErrorCode::InvalidInputMint,
+  )]
+  pub input_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Only receives tokens, the token program validates it at execution
+  pub destination: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Escrows a swap before the child goes live and hands it the next ticket of the child's queue.
+/// Queued swaps settle in ticket order at go live, instead of racing for the first slot. Refunds
+/// go back to source. Swaps outside of the queue wait until every ticket has executed
+pub fn handler(ctx: Context<QueueSwapV0>, args: QueueSwapV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &ctx.accounts.child_entangler;
+  require!(args.amount > 0, ErrorCode::InvalidAmount);
+  require!(
+    clock.unix_timestamp < child_entangler.go_live_unix_time,
+    ErrorCode::QueueClosed
+  );
+  if args.parent_to_child {
+    require!(
+      child_entangler.vesting.is_none(),
+      ErrorCode::VestingRequired
+    );
+    require!(
+      child_entangler.min_lock_seconds.is_none(),
+      ErrorCode::LockRequired
+    );
+  }
+
+  let swap_queue = &mut ctx.accounts.swap_queue;
+  swap_queue.child_entangler = child_entangler.key();
+  swap_queue.bump_seed = *ctx.bumps.get("swap_queue").unwrap();
+  let ticket = swap_queue.next_ticket;
+  swap_queue.next_ticket += 1;
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.queued_swap_count = math::add(child_entangler.queued_swap_count, 1)?;
+
+  let queued_swap = &mut ctx.accounts.queued_swap;
+  queued_swap.child_entangler = child_entangler.key();
+  queued_swap.owner = ctx.accounts.owner.key();
+  queued_swap.ticket = ticket;
+  queued_swap.parent_to_child = args.parent_to_child;
+  queued_swap.amount = args.amount;
+  queued_swap.escrow = ctx.accounts.escrow.key();
+  queued_swap.destination = ctx.accounts.destination.key();
+  queued_swap.refund_account = ctx.accounts.source.key();
+  queued_swap.payer = ctx.accounts.payer.key();
+  queued_swap.bump_seed = *ctx.bumps.get("queued_swap").unwrap();
+  queued_swap.escrow_bump_seed = *ctx.bumps.get("escrow").unwrap();
+
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.input_mint.to_account_info(),
+    &ctx.accounts.child_entangler.key(),
+    &[
+      b"queued-swap-escrow",
+      ctx.accounts.queued_swap.key().as_ref(),
+      &[*ctx.bumps.get("escrow").unwrap()],
+    ],
+  )?;
+
+  msg!("Queueing {} as ticket {}", args.amount, ticket);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info(),
+        to: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+      },
+    ),
+    args.amount,
+  )
+}
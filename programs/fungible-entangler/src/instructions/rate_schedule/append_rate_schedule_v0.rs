@@ -0,0 +1,38 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AppendRateScheduleV0Args {
+  pub entry: RateScheduleEntryV0,
+}
+
+#[derive(Accounts)]
+#[instruction(args: AppendRateScheduleV0Args)]
+pub struct AppendRateScheduleV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    mut,
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump = rate_schedule.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+  )]
+  pub rate_schedule: Box<Account<'info, RateScheduleV0>>,
+}
+
+/// Adds an entry after the last one in the schedule
+pub fn handler(ctx: Context<AppendRateScheduleV0>, args: AppendRateScheduleV0Args) -> Result<()> {
+  let rate_schedule = &mut ctx.accounts.rate_schedule;
+  rate_schedule.entries.push(args.entry);
+  require!(rate_schedule.is_valid(), ErrorCode::InvalidRateSchedule);
+
+  Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod append_rate_schedule_v0;
+pub mod set_rate_schedule_v0;
+
+pub use append_rate_schedule_v0::*;
+pub use set_rate_schedule_v0::*;
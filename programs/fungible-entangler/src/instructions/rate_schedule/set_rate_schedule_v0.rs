@@ -0,0 +1,49 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::RATE_SCHEDULE_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetRateScheduleV0Args {
+  pub entries: Vec<RateScheduleEntryV0>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetRateScheduleV0Args)]
+pub struct SetRateScheduleV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = RATE_SCHEDULE_SIZE,
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: Box<Account<'info, RateScheduleV0>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Creates the child's rate schedule or replaces every entry of an existing one
+pub fn handler(ctx: Context<SetRateScheduleV0>, args: SetRateScheduleV0Args) -> Result<()> {
+  let rate_schedule = &mut ctx.accounts.rate_schedule;
+  rate_schedule.child_entangler = ctx.accounts.child_entangler.key();
+  rate_schedule.entries = args.entries;
+  rate_schedule.bump_seed = *ctx.bumps.get("rate_schedule").unwrap();
+  require!(rate_schedule.is_valid(), ErrorCode::InvalidRateSchedule);
+
+  Ok(())
+}
@@ -0,0 +1,133 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RebalanceBetweenEntanglersV0Args {
+  pub parent_amount: u64,
+  pub child_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RebalanceBetweenEntanglersV0Args)]
+pub struct RebalanceBetweenEntanglersV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = source_parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = source_parent_entangler.parent_storage == source_parent_storage.key() @ ErrorCode::StorageMismatch,
+    constraint = !source_parent_entangler.are_withdrawals_frozen(Clock::get()?.unix_timestamp) @ ErrorCode::WithdrawalsFrozen,
+    constraint = source_parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub source_parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub source_parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    constraint = source_child_entangler.parent_entangler == source_parent_entangler.key() @ ErrorCode::ParentEntanglerMismatch,
+    constraint = source_child_entangler.child_storage == source_child_storage.key() @ ErrorCode::StorageMismatch,
+    // Once expired, what is left in storage belongs to the TopOff depositors
+    constraint = source_child_entangler.total_top_off_amount == 0 || !source_child_entangler.is_expired(Clock::get()?.unix_timestamp) @ ErrorCode::Expired,
+    constraint = source_child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub source_child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub source_child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    constraint = destination_parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = destination_parent_entangler.parent_storage == destination_parent_storage.key() @ ErrorCode::StorageMismatch,
+    constraint = destination_parent_entangler.key() != source_parent_entangler.key() @ ErrorCode::InvalidRebalancePair,
+    constraint = destination_parent_entangler.parent_mint == source_parent_entangler.parent_mint @ ErrorCode::InvalidRebalancePair,
+    constraint = destination_parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub destination_parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub destination_parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    constraint = destination_child_entangler.parent_entangler == destination_parent_entangler.key() @ ErrorCode::ParentEntanglerMismatch,
+    constraint = destination_child_entangler.child_storage == destination_child_storage.key() @ ErrorCode::StorageMismatch,
+    constraint = destination_child_entangler.child_mint == source_child_entangler.child_mint @ ErrorCode::InvalidRebalancePair,
+    constraint = !destination_child_entangler.is_expired(Clock::get()?.unix_timestamp) @ ErrorCode::Expired,
+    constraint = destination_child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub destination_child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub destination_child_storage: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Moves parent and child storage from one entangler of a token pair to another under the same
+/// authority, e.g. between fee tiers, without the tokens passing through a wallet. Each source
+/// entangler signs for its own storage. Parent and child fees owed back to swappers and TopOff
+/// deposits stay behind
+pub fn handler(
+  ctx: Context<RebalanceBetweenEntanglersV0>,
+  args: RebalanceBetweenEntanglersV0Args,
+) -> Result<()> {
+  let source_child = &ctx.accounts.source_child_entangler;
+  require!(
+    ctx.accounts.source_parent_storage.amount >= args.parent_amount,
+    ErrorCode::InsufficientStorage
+  );
+  // Parent fees stay in storage for the swappers who paid them
+  require!(
+    args.parent_amount
+      <= ctx.accounts.source_parent_storage.amount.saturating_sub(
+        ctx
+          .accounts
+          .source_parent_entangler
+          .reserved_parent_fee_amount
+      ),
+    ErrorCode::FeeRebatesPending
+  );
+  require!(
+    ctx
+      .accounts
+      .source_child_storage
+      .amount
+      .checked_sub(args.child_amount)
+      .map_or(false, |left| left
+        >= source_child.reserved_child_fee_amount()),
+    ErrorCode::InsufficientStorage
+  );
+  // TopOff deposits stay behind for their depositors
+  require!(
+    args.child_amount
+      <= ctx
+        .accounts
+        .source_child_storage
+        .amount
+        .saturating_sub(source_child.reserved_child_fee_amount())
+        .saturating_sub(
+          source_child.unreclaimed_top_off_amount(ctx.accounts.source_child_storage.amount)
+        ),
+    ErrorCode::TopOffReclaimPending
+  );
+
+  if args.parent_amount > 0 {
+    let source_parent = &ctx.accounts.source_parent_entangler;
+    msg!("Rebalancing {} of parent storage", args.parent_amount);
+    transfer_signed(
+      &ctx.accounts.token_program.to_account_info(),
+      &ctx.accounts.source_parent_storage.to_account_info(),
+      &ctx.accounts.destination_parent_storage.to_account_info(),
+      &source_parent.to_account_info(),
+      &source_parent.signer_seeds(),
+      args.parent_amount,
+    )?;
+  }
+
+  if args.child_amount > 0 {
+    msg!("Rebalancing {} of child storage", args.child_amount);
+    transfer_signed(
+      &ctx.accounts.token_program.to_account_info(),
+      &ctx.accounts.source_child_storage.to_account_info(),
+      &ctx.accounts.destination_child_storage.to_account_info(),
+      &source_child.to_account_info(),
+      &source_child.signer_seeds(),
+      args.child_amount,
+    )?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::{error::ErrorCode, math, state::*, util::load_rewards_pool};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
+
+#[derive(Accounts)]
+pub struct ReclaimAfterExpiryV0<'info> {
+  #[account(
+    mut,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = [b"top-off", child_entangler.key().as_ref(), depositor.key().as_ref()],
+    bump = top_off_record.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+    has_one = depositor @ ErrorCode::InvalidOwner,
+  )]
+  pub top_off_record: Box<Account<'info, TopOffRecordV0>>,
+  pub depositor: Signer<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Pays the depositor their share of child storage at expiry, at most what they topped off
+pub fn handler(ctx: Context<ReclaimAfterExpiryV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  let top_off_record = &mut ctx.accounts.top_off_record;
+
+  require!(
+    child_entangler.is_expired(clock.unix_timestamp),
+    ErrorCode::NotExpired
+  );
+  require!(!top_off_record.reclaimed, ErrorCode::AlreadyReclaimed);
+
+  // Freeze the balance on first reclaim so every depositor's share is computed from the same basis.
+  // Fees owed back to swappers are not the depositors' to reclaim
+  let snapshot = match child_entangler.reclaim_snapshot_amount {
+    Some(snapshot) => snapshot,
+    None => {
+      let snapshot = ctx
+        .accounts
+        .child_storage
+        .amount
+        .saturating_sub(child_entangler.reserved_child_fee_amount());
+      child_entangler.reclaim_snapshot_amount = Some(snapshot);
+      snapshot
+    }
+  };
+
+  let amount = child_entangler.top_off_reclaim_amount(snapshot, top_off_record.amount)?;
+  child_entangler.reclaimed_top_off_amount =
+    math::add(child_entangler.reclaimed_top_off_amount, amount)?;
+
+  let rewards_pool = load_rewards_pool(child_entangler, ctx.remaining_accounts)?;
+  if let Some(mut rewards_pool) = rewards_pool {
+    rewards_pool.update(clock.unix_timestamp)?;
+    rewards_pool.settle(top_off_record)?;
+    rewards_pool.total_staked = rewards_pool
+      .total_staked
+      .saturating_sub(top_off_record.amount);
+    rewards_pool.exit(ctx.program_id)?;
+  }
+
+  top_off_record.reclaimed = true;
+
+  msg!("Reclaiming {} from child storage", amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+    amount,
+  )?;
+
+  Ok(())
+}
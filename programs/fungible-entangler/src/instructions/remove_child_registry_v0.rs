@@ -0,0 +1,46 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RemoveChildRegistryV0Args {
+  pub child_entangler: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RemoveChildRegistryV0Args)]
+pub struct RemoveChildRegistryV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub registry: AccountLoader<'info, ChildRegistryV0>,
+}
+
+pub fn handler(ctx: Context<RemoveChildRegistryV0>, args: RemoveChildRegistryV0Args) -> Result<()> {
+  let mut registry = ctx.accounts.registry.load_mut()?;
+
+  require!(
+    registry.parent_entangler == ctx.accounts.parent_entangler.key(),
+    ErrorCode::InvalidRegistry
+  );
+
+  // Child entanglers may already be closed, so removal goes by key rather than account.
+  let index = registry
+    .entries()
+    .iter()
+    .position(|key| *key == args.child_entangler)
+    .ok_or(error!(ErrorCode::ChildNotInRegistry))?;
+
+  // Swap remove, order within a page is not meaningful
+  let last = registry.num_entries as usize - 1;
+  registry.children[index] = registry.children[last];
+  registry.children[last] = Pubkey::default();
+  registry.weights_bps[index] = registry.weights_bps[last];
+  registry.weights_bps[last] = 0;
+  registry.num_entries -= 1;
+
+  Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::{error::ErrorCode, state::*, util::realloc_with_rent};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ResizeEntanglerV0Args {
+  pub new_size: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ResizeEntanglerV0Args)]
+pub struct ResizeEntanglerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  /// CHECK: Either the parent entangler itself or one of its children, checked in the handler
+  #[account(mut)]
+  pub entangler: UncheckedAccount<'info>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Grows a parent or child entangler so that fields added in later program versions fit
+pub fn handler(ctx: Context<ResizeEntanglerV0>, args: ResizeEntanglerV0Args) -> Result<()> {
+  let info = ctx.accounts.entangler.to_account_info();
+  if info.key() != ctx.accounts.parent_entangler.key() {
+    let child: Account<FungibleChildEntanglerV1> = Account::try_from(&info)?;
+    require!(
+      child.parent_entangler == ctx.accounts.parent_entangler.key(),
+      ErrorCode::InvalidAuthority
+    );
+    require!(child.is_supported_version(), ErrorCode::UnsupportedVersion);
+  }
+
+  let new_size = args.new_size as usize;
+  require!(new_size >= info.data_len(), ErrorCode::InvalidResize);
+
+  realloc_with_rent(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &info,
+    new_size,
+  )
+}
@@ -0,0 +1,25 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SealEntanglerV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+}
+
+/// Permanently drops the parent's authority. Every authority gated instruction (updates, new
+/// children, storage transfers and closes) fails with NoAuthority from then on, leaving expiry
+/// reclaims and fee rebates as the only way tokens leave storage other than swaps. Children that
+/// track swaps may distribute their fees via DistributeFeesV0 from then on
+pub fn handler(ctx: Context<SealEntanglerV0>) -> Result<()> {
+  msg!("Sealing parent entangler");
+  ctx.accounts.parent_entangler.authority = None;
+  ctx.accounts.parent_entangler.authority_nft_mint = None;
+
+  Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetAuthorityV0Args {
+  pub new_authority: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetAuthorityV0Args)]
+pub struct SetAuthorityV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+}
+
+/// Hands the parent, and every child under it, to new_authority. Use SealEntanglerV0 to drop the
+/// authority instead
+pub fn handler(ctx: Context<SetAuthorityV0>, args: SetAuthorityV0Args) -> Result<()> {
+  msg!("Setting authority to {}", args.new_authority);
+  ctx.accounts.parent_entangler.authority = Some(args.new_authority);
+  ctx.accounts.parent_entangler.authority_nft_mint = None;
+
+  Ok(())
+}
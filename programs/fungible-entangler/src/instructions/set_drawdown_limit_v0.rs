@@ -0,0 +1,53 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DrawdownLimitArgsV0 {
+  pub window_seconds: i64,
+  pub max_child_amount: u64,
+  pub max_parent_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetDrawdownLimitV0Args {
+  pub drawdown_limit: Option<DrawdownLimitArgsV0>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetDrawdownLimitV0Args)]
+pub struct SetDrawdownLimitV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+}
+
+/// Caps what swaps through the child may take out of child and parent storage per window. The
+/// current window's counts carry over, so tightening a limit applies immediately. None removes it
+pub fn handler(ctx: Context<SetDrawdownLimitV0>, args: SetDrawdownLimitV0Args) -> Result<()> {
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.drawdown_limit = match args.drawdown_limit {
+    Some(limit) => {
+      let current = child_entangler.drawdown_limit.unwrap_or_default();
+      let drawdown_limit = DrawdownLimitV0 {
+        window_seconds: limit.window_seconds,
+        max_child_amount: limit.max_child_amount,
+        max_parent_amount: limit.max_parent_amount,
+        ..current
+      };
+      require!(drawdown_limit.is_valid(), ErrorCode::InvalidDrawdownLimit);
+      Some(drawdown_limit)
+    }
+    None => None,
+  };
+
+  Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::{error::ErrorCode, state::*, util::validate_fees};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetFeesV0Args {
+  pub swap_fee_bps: u16,
+  pub fee_tiers: Vec<FeeTierV0>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetFeesV0Args)]
+pub struct SetFeesV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+}
+
+pub fn handler(ctx: Context<SetFeesV0>, args: SetFeesV0Args) -> Result<()> {
+  validate_fees(args.swap_fee_bps, &args.fee_tiers)?;
+
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.swap_fee_bps = args.swap_fee_bps;
+  child_entangler.fee_tiers = args.fee_tiers;
+
+  Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetFreezeTimeV0Args {
+  pub freeze_swap_unix_time: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetFreezeTimeV0Args)]
+pub struct SetFreezeTimeV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+}
+
+/// Replaces the child's freeze times. Does not lift a freeze already applied by EnforceFreezeV0
+pub fn handler(ctx: Context<SetFreezeTimeV0>, args: SetFreezeTimeV0Args) -> Result<()> {
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.freeze_swap_unix_time = args.freeze_swap_unix_time;
+  child_entangler.freeze_parent_to_child_unix_time = args.freeze_parent_to_child_unix_time;
+  child_entangler.freeze_child_to_parent_unix_time = args.freeze_child_to_parent_unix_time;
+
+  Ok(())
+}
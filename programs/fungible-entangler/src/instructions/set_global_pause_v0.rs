@@ -0,0 +1,28 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetGlobalPauseV0Args {
+  pub paused: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetGlobalPauseV0Args)]
+pub struct SetGlobalPauseV0<'info> {
+  pub admin: Signer<'info>,
+  #[account(
+    mut,
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = admin @ ErrorCode::InvalidAuthority,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+}
+
+/// Halts or resumes every swap, refund and locked or vested swap across all entanglers
+pub fn handler(ctx: Context<SetGlobalPauseV0>, args: SetGlobalPauseV0Args) -> Result<()> {
+  msg!("Setting global pause to {}", args.paused);
+  ctx.accounts.global_config.paused = args.paused;
+
+  Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetParentFreezeTimeV0Args {
+  pub freeze_swap_unix_time: Option<i64>,
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetParentFreezeTimeV0Args)]
+pub struct SetParentFreezeTimeV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+}
+
+/// Replaces the parent's freeze times, which apply to every child
+pub fn handler(ctx: Context<SetParentFreezeTimeV0>, args: SetParentFreezeTimeV0Args) -> Result<()> {
+  let parent_entangler = &mut ctx.accounts.parent_entangler;
+  parent_entangler.freeze_swap_unix_time = args.freeze_swap_unix_time;
+  parent_entangler.freeze_parent_to_child_unix_time = args.freeze_parent_to_child_unix_time;
+  parent_entangler.freeze_child_to_parent_unix_time = args.freeze_child_to_parent_unix_time;
+
+  Ok(())
+}
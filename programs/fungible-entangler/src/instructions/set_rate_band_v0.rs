@@ -0,0 +1,37 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetRateBandV0Args {
+  pub rate_band: Option<RateBandV0>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetRateBandV0Args)]
+pub struct SetRateBandV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+}
+
+/// Bounds the rates the child's rate schedule may swap at, so an entry at an extreme price can't
+/// drain storage. None removes the band
+pub fn handler(ctx: Context<SetRateBandV0>, args: SetRateBandV0Args) -> Result<()> {
+  require!(
+    args.rate_band.map_or(true, |band| band.is_valid()),
+    ErrorCode::InvalidRateBand
+  );
+
+  ctx.accounts.child_entangler.rate_band = args.rate_band;
+
+  Ok(())
+}
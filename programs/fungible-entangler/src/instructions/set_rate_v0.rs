@@ -0,0 +1,39 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetRateV0Args {
+  pub rate: ExchangeRateV0,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetRateV0Args)]
+pub struct SetRateV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+}
+
+/// Replaces the child's rate. Children with a RateScheduleV0 move back onto the schedule at
+/// their next swap once one of its entries is in effect
+pub fn handler(ctx: Context<SetRateV0>, args: SetRateV0Args) -> Result<()> {
+  require!(args.rate.is_valid(), ErrorCode::InvalidExchangeRate);
+
+  msg!(
+    "Setting rate to {} parent : {} child",
+    args.rate.parent_units,
+    args.rate.child_units
+  );
+  ctx.accounts.child_entangler.rate = args.rate;
+
+  Ok(())
+}
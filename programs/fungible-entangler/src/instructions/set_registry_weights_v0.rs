@@ -0,0 +1,41 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetRegistryWeightsV0Args {
+  // One per registry entry, in registry order
+  pub weights_bps: Vec<u16>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetRegistryWeightsV0Args)]
+pub struct SetRegistryWeightsV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub registry: AccountLoader<'info, ChildRegistryV0>,
+}
+
+/// Sets the page's basket. Weights summing to 10000 make SwapParentForBasketV0 split its input
+/// across the weighted children, all zero weights disable the basket
+pub fn handler(ctx: Context<SetRegistryWeightsV0>, args: SetRegistryWeightsV0Args) -> Result<()> {
+  let mut registry = ctx.accounts.registry.load_mut()?;
+  require!(
+    registry.parent_entangler == ctx.accounts.parent_entangler.key(),
+    ErrorCode::InvalidRegistry
+  );
+
+  let total: u32 = args.weights_bps.iter().map(|weight| *weight as u32).sum();
+  require!(
+    args.weights_bps.len() == registry.num_entries as usize && (total == 10000 || total == 0),
+    ErrorCode::InvalidBasketWeights
+  );
+  registry.weights_bps = [0; CHILD_REGISTRY_PAGE_SIZE];
+  registry.weights_bps[..args.weights_bps.len()].copy_from_slice(&args.weights_bps);
+
+  Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetWithdrawalsFrozenUntilV0Args {
+  pub withdrawals_frozen_until: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetWithdrawalsFrozenUntilV0Args)]
+pub struct SetWithdrawalsFrozenUntilV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+}
+
+/// Stops the authority withdrawing from or closing the parent's and its children's storage until
+/// the given time, while swaps carry on. The freeze can be extended but never shortened, so
+/// those topping off storage can rely on it
+pub fn handler(
+  ctx: Context<SetWithdrawalsFrozenUntilV0>,
+  args: SetWithdrawalsFrozenUntilV0Args,
+) -> Result<()> {
+  let parent_entangler = &mut ctx.accounts.parent_entangler;
+  require!(
+    parent_entangler
+      .withdrawals_frozen_until
+      .map_or(true, |until| args.withdrawals_frozen_until >= until),
+    ErrorCode::InvalidWithdrawalFreeze
+  );
+
+  msg!(
+    "Freezing withdrawals until {}",
+    args.withdrawals_frozen_until
+  );
+  parent_entangler.withdrawals_frozen_until = Some(args.withdrawals_frozen_until);
+
+  Ok(())
+}
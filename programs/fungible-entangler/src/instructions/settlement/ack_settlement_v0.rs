@@ -0,0 +1,167 @@
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  instructions::swap::{
+    account::SwapAccounts,
+    arg::SwapV0Args,
+    common::{send_memo, SwapDirection},
+    execute_child_for_parent, execute_parent_for_child,
+  },
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use strata_spl_utils::close_token_account_signed;
+
+#[derive(Accounts)]
+pub struct AckSettlementV0<'info> {
+  pub settler: Signer<'info>,
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [b"pending-settlement", child_entangler.key().as_ref()],
+    bump = pending_settlement.bump_seed,
+    has_one = settler @ ErrorCode::InvalidAuthority,
+  )]
+  pub pending_settlement: Box<Account<'info, PendingSettlementV0>>,
+  #[account(
+    mut,
+    close = payer,
+    has_one = pending_settlement @ ErrorCode::PendingSettlementMismatch,
+    has_one = escrow @ ErrorCode::StorageMismatch,
+    has_one = payer @ ErrorCode::InvalidOwner,
+  )]
+  pub settlement_entry: Box<Account<'info, SettlementEntryV0>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Only receives tokens, the token program validates it in the transfer
+  #[account(mut)]
+  pub destination: UncheckedAccount<'info>,
+  /// CHECK: Receives the rent of the entry and escrow, checked against the entry
+  #[account(mut)]
+  pub payer: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  /// CHECK: The SPL memo program, logs the entry's reference
+  #[account(address = spl_memo::id())]
+  pub memo_program: UncheckedAccount<'info>,
+  /// CHECK: spl-noop, which swap events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Acknowledges that the settler credited an entry off chain, swapping its escrow into
+/// destination, usually the settler's own account. Entries are not recorded to a SwapRecordV0,
+/// and must be acknowledged before they time out
+pub fn handler(ctx: Context<AckSettlementV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  let accounts = &mut *ctx.accounts;
+  let settlement_entry = &accounts.settlement_entry;
+  require!(
+    !settlement_entry.is_timed_out(
+      accounts.pending_settlement.timeout_seconds,
+      clock.unix_timestamp
+    ),
+    ErrorCode::SettlementTimedOut
+  );
+
+  let direction = if settlement_entry.parent_to_child {
+    SwapDirection::ParentToChild
+  } else {
+    SwapDirection::ChildToParent
+  };
+  let args = SwapV0Args {
+    amount: Some(settlement_entry.amount),
+    all: None,
+    output_splits_bps: vec![],
+    memo: Some(hex_reference(&settlement_entry.reference)),
+    allow_partial: false,
+  };
+  let parent_key = accounts.child_entangler.parent_entangler;
+  let child_mint_key = accounts.child_entangler.child_mint;
+  let bump_seed = [accounts.child_entangler.bump_seed];
+  let child_seeds: &[&[u8]] = &[
+    b"entangler",
+    parent_key.as_ref(),
+    child_mint_key.as_ref(),
+    &bump_seed,
+  ];
+  let child_info = accounts.child_entangler.to_account_info();
+
+  msg!("Settling entry {}", settlement_entry.ticket);
+  let swap_accounts = SwapAccounts {
+    parent_entangler: &mut accounts.parent_entangler,
+    parent_storage: &mut accounts.parent_storage,
+    parent_mint: &mut accounts.parent_mint,
+    child_entangler: &mut accounts.child_entangler,
+    child_storage: &mut accounts.child_storage,
+    child_mint: &mut accounts.child_mint,
+    source: &accounts.escrow,
+    source_authority: &child_info,
+    source_signer_seeds: &[child_seeds],
+    destination: &accounts.destination,
+    rate_schedule: &accounts.rate_schedule,
+    token_program: &accounts.token_program,
+    noop_program: &accounts.noop_program,
+    revealed_commit: false,
+    vested: false,
+    locked: false,
+    queued: false,
+  };
+  match direction {
+    SwapDirection::ParentToChild => execute_parent_for_child(swap_accounts, &[], &clock, &args)?,
+    SwapDirection::ChildToParent => execute_child_for_parent(swap_accounts, &[], &clock, &args)?,
+  };
+  send_memo(&accounts.memo_program, &args.memo)?;
+
+  close_token_account_signed(
+    &accounts.token_program.to_account_info(),
+    &accounts.escrow.to_account_info(),
+    &accounts.payer.to_account_info(),
+    &child_info,
+    child_seeds,
+  )
+}
+
+fn hex_reference(reference: &[u8; 32]) -> String {
+  reference
+    .iter()
+    .map(|byte| format!("{:02x}", byte))
+    .collect()
+}
@@ -0,0 +1,113 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::SETTLEMENT_ENTRY_SIZE,
+  state::*, util::create_pda_token_account,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DeferSwapV0Args {
+  pub amount: u64,
+  pub parent_to_child: bool,
+  pub reference: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(args: DeferSwapV0Args)]
+pub struct DeferSwapV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub owner: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    mut,
+    seeds = [b"pending-settlement", child_entangler.key().as_ref()],
+    bump = pending_settlement.bump_seed,
+  )]
+  pub pending_settlement: Box<Account<'info, PendingSettlementV0>>,
+  #[account(
+    init,
+    payer = payer,
+    space = SETTLEMENT_ENTRY_SIZE,
+    seeds = [b"settlement-entry", pending_settlement.key().as_ref(), &pending_settlement.next_ticket.to_le_bytes()],
+    bump,
+  )]
+  pub settlement_entry: Box<Account<'info, SettlementEntryV0>>,
+  /// CHECK: Created in the handler as an input_mint token account owned by child_entangler
+  #[account(
+    mut,
+    seeds = [b"settlement-escrow", settlement_entry.key().as_ref()],
+    bump,
+  )]
+  pub escrow: UncheckedAccount<'info>,
+  #[account(
+    constraint = input_mint.key() == if args.parent_to_child { parent_entangler.parent_mint } else { child_entangler.child_mint } @ ErrorCode::InvalidInputMint,
+  )]
+  pub input_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Escrows a swap for deferred delivery. The settler credits reference off chain and then
+/// acknowledges the entry, which performs the swap. Entries the settler doesn't acknowledge in
+/// time are refunded to source
+pub fn handler(ctx: Context<DeferSwapV0>, args: DeferSwapV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(args.amount > 0, ErrorCode::InvalidAmount);
+
+  let pending_settlement = &mut ctx.accounts.pending_settlement;
+  let ticket = pending_settlement.next_ticket;
+  pending_settlement.next_ticket += 1;
+
+  let settlement_entry = &mut ctx.accounts.settlement_entry;
+  settlement_entry.pending_settlement = pending_settlement.key();
+  settlement_entry.owner = ctx.accounts.owner.key();
+  settlement_entry.ticket = ticket;
+  settlement_entry.parent_to_child = args.parent_to_child;
+  settlement_entry.amount = args.amount;
+  settlement_entry.reference = args.reference;
+  settlement_entry.escrow = ctx.accounts.escrow.key();
+  settlement_entry.refund_account = ctx.accounts.source.key();
+  settlement_entry.payer = ctx.accounts.payer.key();
+  settlement_entry.created_at_unix_time = clock.unix_timestamp;
+  settlement_entry.bump_seed = *ctx.bumps.get("settlement_entry").unwrap();
+  settlement_entry.escrow_bump_seed = *ctx.bumps.get("escrow").unwrap();
+
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.input_mint.to_account_info(),
+    &ctx.accounts.child_entangler.key(),
+    &[
+      b"settlement-escrow",
+      ctx.accounts.settlement_entry.key().as_ref(),
+      &[*ctx.bumps.get("escrow").unwrap()],
+    ],
+  )?;
+
+  msg!("Deferring {} as settlement {}", args.amount, ticket);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info(),
+        to: ctx.accounts.escrow.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+      },
+    ),
+    args.amount,
+  )
+}
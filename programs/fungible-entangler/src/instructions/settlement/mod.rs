@@ -0,0 +1,9 @@
+pub mod ack_settlement_v0;
+pub mod defer_swap_v0;
+pub mod refund_settlement_v0;
+pub mod set_pending_settlement_v0;
+
+pub use ack_settlement_v0::*;
+pub use defer_swap_v0::*;
+pub use refund_settlement_v0::*;
+pub use set_pending_settlement_v0::*;
@@ -0,0 +1,65 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::{close_token_account_signed, transfer_signed};
+
+#[derive(Accounts)]
+pub struct RefundSettlementV0<'info> {
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    seeds = [b"pending-settlement", child_entangler.key().as_ref()],
+    bump = pending_settlement.bump_seed,
+  )]
+  pub pending_settlement: Box<Account<'info, PendingSettlementV0>>,
+  #[account(
+    mut,
+    close = payer,
+    has_one = pending_settlement @ ErrorCode::PendingSettlementMismatch,
+    has_one = escrow @ ErrorCode::StorageMismatch,
+    has_one = refund_account @ ErrorCode::InvalidOwner,
+    has_one = payer @ ErrorCode::InvalidOwner,
+  )]
+  pub settlement_entry: Box<Account<'info, SettlementEntryV0>>,
+  #[account(mut)]
+  pub escrow: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Receives the escrow, the token program validates it in the transfer
+  #[account(mut)]
+  pub refund_account: UncheckedAccount<'info>,
+  /// CHECK: Receives the rent of the entry and escrow, checked against the entry
+  #[account(mut)]
+  pub payer: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Returns the escrow of an entry the settler didn't acknowledge within its timeout. Anyone may
+/// crank it
+pub fn handler(ctx: Context<RefundSettlementV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  require!(
+    ctx.accounts.settlement_entry.is_timed_out(
+      ctx.accounts.pending_settlement.timeout_seconds,
+      clock.unix_timestamp
+    ),
+    ErrorCode::SettlementNotTimedOut
+  );
+
+  let child_entangler = &ctx.accounts.child_entangler;
+  msg!("Refunding {} from escrow", ctx.accounts.escrow.amount);
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.refund_account.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+    ctx.accounts.escrow.amount,
+  )?;
+
+  close_token_account_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.escrow.to_account_info(),
+    &ctx.accounts.payer.to_account_info(),
+    &child_entangler.to_account_info(),
+    &child_entangler.signer_seeds(),
+  )
+}
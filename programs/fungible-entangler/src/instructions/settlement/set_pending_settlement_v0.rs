@@ -0,0 +1,59 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::PENDING_SETTLEMENT_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetPendingSettlementV0Args {
+  pub settler: Pubkey,
+  pub timeout_seconds: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetPendingSettlementV0Args)]
+pub struct SetPendingSettlementV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = PENDING_SETTLEMENT_SIZE,
+    seeds = [b"pending-settlement", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub pending_settlement: Box<Account<'info, PendingSettlementV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Opens the child to deferred delivery through settler, or hands it to a new settler. Entries
+/// already pending move to the new settler and timeout
+pub fn handler(
+  ctx: Context<SetPendingSettlementV0>,
+  args: SetPendingSettlementV0Args,
+) -> Result<()> {
+  require!(
+    args.timeout_seconds > 0,
+    ErrorCode::InvalidSettlementTimeout
+  );
+
+  msg!("Setting settler to {}", args.settler);
+  let pending_settlement = &mut ctx.accounts.pending_settlement;
+  pending_settlement.child_entangler = ctx.accounts.child_entangler.key();
+  pending_settlement.settler = args.settler;
+  pending_settlement.timeout_seconds = args.timeout_seconds;
+  pending_settlement.bump_seed = *ctx.bumps.get("pending_settlement").unwrap();
+
+  Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::SNAPSHOT_HISTORY_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct SnapshotV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = SNAPSHOT_HISTORY_SIZE,
+    seeds = [b"snapshot-history", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub snapshot_history: Box<Account<'info, SnapshotHistoryV0>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Permissionless, at most once per epoch. Records storage balances and cumulative volumes so that
+/// treasury reports can be built from onchain checkpoints
+pub fn handler(ctx: Context<SnapshotV0>) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &ctx.accounts.child_entangler;
+  let snapshot_history = &mut ctx.accounts.snapshot_history;
+  snapshot_history.child_entangler = child_entangler.key();
+  snapshot_history.bump_seed = *ctx.bumps.get("snapshot_history").unwrap();
+  snapshot_history.push(SnapshotEntryV0 {
+    epoch: clock.epoch,
+    unix_time: clock.unix_timestamp,
+    parent_storage_balance: ctx.accounts.parent_storage.amount,
+    child_storage_balance: ctx.accounts.child_storage.amount,
+    total_parent_to_child_amount: child_entangler.total_parent_to_child_amount,
+    total_child_to_parent_amount: child_entangler.total_child_to_parent_amount,
+  })
+}
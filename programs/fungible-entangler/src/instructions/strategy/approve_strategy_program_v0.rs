@@ -0,0 +1,41 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::STRATEGY_PROGRAM_SIZE,
+  state::*,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApproveStrategyProgramV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub admin: Signer<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = admin @ ErrorCode::InvalidAuthority,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The lending program to approve
+  #[account(executable)]
+  pub program: UncheckedAccount<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = STRATEGY_PROGRAM_SIZE,
+    seeds = [b"strategy-program", program.key().as_ref()],
+    bump,
+  )]
+  pub strategy_program: Box<Account<'info, StrategyProgramV0>>,
+
+  pub system_program: Program<'info, System>,
+}
+
+/// Lets children lend storage through program. Approvals are permanent, so that deposits can
+/// always be withdrawn through the program they went into
+pub fn handler(ctx: Context<ApproveStrategyProgramV0>) -> Result<()> {
+  let strategy_program = &mut ctx.accounts.strategy_program;
+  strategy_program.program = ctx.accounts.program.key();
+  strategy_program.bump_seed = *ctx.bumps.get("strategy_program").unwrap();
+
+  Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod approve_strategy_program_v0;
+pub mod rebalance_strategy_v0;
+pub mod set_yield_strategy_v0;
+
+pub use approve_strategy_program_v0::*;
+pub use rebalance_strategy_v0::*;
+pub use set_yield_strategy_v0::*;
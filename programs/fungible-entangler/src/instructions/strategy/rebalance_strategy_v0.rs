@@ -0,0 +1,124 @@
+use crate::{error::ErrorCode, math, state::*, util::is_token_account_held_by};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+  },
+};
+use anchor_spl::token::TokenAccount;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RebalanceStrategyV0Args {
+  // Deposit amount from child storage into the strategy, or withdraw at least amount back
+  pub deposit: bool,
+  pub amount: u64,
+  // Instruction data for the strategy program
+  pub data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RebalanceStrategyV0Args)]
+pub struct RebalanceStrategyV0<'info> {
+  pub keeper: Signer<'info>,
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.yield_strategy.map(|strategy| strategy.keeper) == Some(keeper.key())
+      || parent_entangler.authority == Some(keeper.key()) @ ErrorCode::InvalidAuthority,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Must be the child's strategy program. Invoked with the remaining accounts
+  #[account(
+    executable,
+    constraint = child_entangler.yield_strategy.map(|strategy| strategy.program) == Some(strategy_program.key()) @ ErrorCode::InvalidStrategy,
+  )]
+  pub strategy_program: UncheckedAccount<'info>,
+}
+
+/// Invokes the strategy program with the remaining accounts, the child entangler signing, and
+/// checks what moved in or out of child storage. Deposits must take exactly amount and leave the
+/// strategy's liquid share in storage, so swaps keep paying out between rebalances. The child
+/// entangler also holds the OTC, queue, settlement and compressed token escrows, so child storage
+/// is the only one of its token accounts the strategy program is handed
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, RebalanceStrategyV0<'info>>,
+  args: RebalanceStrategyV0Args,
+) -> Result<()> {
+  let child_entangler = &ctx.accounts.child_entangler;
+  let child_key = child_entangler.key();
+  let child_storage = ctx.accounts.child_storage.key();
+  for account in ctx.remaining_accounts {
+    require!(
+      account.key() == child_storage || !is_token_account_held_by(account, &child_key),
+      ErrorCode::StrategyAccountNotAllowed
+    );
+  }
+  let metas = ctx
+    .remaining_accounts
+    .iter()
+    .map(|account| AccountMeta {
+      pubkey: account.key(),
+      is_signer: account.is_signer || account.key() == child_key,
+      is_writable: account.is_writable,
+    })
+    .collect();
+
+  let before = ctx.accounts.child_storage.amount;
+  let mut account_infos = ctx.remaining_accounts.to_vec();
+  account_infos.push(ctx.accounts.strategy_program.to_account_info());
+  invoke_signed(
+    &Instruction {
+      program_id: ctx.accounts.strategy_program.key(),
+      accounts: metas,
+      data: args.data,
+    },
+    &account_infos,
+    &[&child_entangler.signer_seeds()],
+  )?;
+  ctx.accounts.child_storage.reload()?;
+  let after = ctx.accounts.child_storage.amount;
+
+  let strategy = ctx
+    .accounts
+    .child_entangler
+    .yield_strategy
+    .as_mut()
+    .ok_or(error!(ErrorCode::InvalidStrategy))?;
+  if args.deposit {
+    require!(
+      !ctx
+        .accounts
+        .parent_entangler
+        .are_withdrawals_frozen(Clock::get()?.unix_timestamp),
+      ErrorCode::WithdrawalsFrozen
+    );
+    require!(
+      before.checked_sub(after) == Some(args.amount),
+      ErrorCode::InvalidStrategyTransfer
+    );
+    strategy.deposited_amount = math::add(strategy.deposited_amount, args.amount)?;
+    let min_liquid = math::apply_bps(
+      math::add(after, strategy.deposited_amount)?,
+      strategy.min_liquid_bps,
+      math::Rounding::Up,
+    )?;
+    require!(after >= min_liquid, ErrorCode::StrategyLiquidityBuffer);
+    msg!("Deposited {} into the strategy", args.amount);
+  } else {
+    let received = after.saturating_sub(before);
+    require!(
+      after >= before && received >= args.amount,
+      ErrorCode::InvalidStrategyTransfer
+    );
+    strategy.deposited_amount = strategy.deposited_amount.saturating_sub(received);
+    msg!("Withdrew {} from the strategy", received);
+  }
+
+  Ok(())
+}
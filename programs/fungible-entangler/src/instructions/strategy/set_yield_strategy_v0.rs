@@ -0,0 +1,58 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetYieldStrategyV0Args {
+  pub keeper: Pubkey,
+  pub min_liquid_bps: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetYieldStrategyV0Args)]
+pub struct SetYieldStrategyV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(
+    seeds = [b"strategy-program", strategy_program.program.as_ref()],
+    bump = strategy_program.bump_seed,
+  )]
+  pub strategy_program: Box<Account<'info, StrategyProgramV0>>,
+}
+
+/// Points the child's yield strategy at an approved program. The program can only change while
+/// nothing is deposited, so deposits are always withdrawn through the program holding them.
+/// A min_liquid_bps of 10000 stops further deposits
+pub fn handler(ctx: Context<SetYieldStrategyV0>, args: SetYieldStrategyV0Args) -> Result<()> {
+  require!(args.min_liquid_bps <= 10000, ErrorCode::InvalidArgs);
+  let program = ctx.accounts.strategy_program.program;
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  let deposited_amount = match child_entangler.yield_strategy {
+    Some(strategy) => {
+      require!(
+        strategy.deposited_amount == 0 || strategy.program == program,
+        ErrorCode::InvalidStrategy
+      );
+      strategy.deposited_amount
+    }
+    None => 0,
+  };
+
+  child_entangler.yield_strategy = Some(YieldStrategyV0 {
+    program,
+    keeper: args.keeper,
+    min_liquid_bps: args.min_liquid_bps,
+    deposited_amount,
+  });
+
+  Ok(())
+}
@@ -1,28 +1,137 @@
-use crate::state::*;
-use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  state::*,
+  util::{immediate_caller, is_frozen_token_account},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// The accounts a swap moves tokens between, borrowed from whichever instruction performs it so
+/// that swaps recording to a SwapRecordV0 and to a merkle tree share one implementation
+pub struct SwapAccounts<'a, 'info> {
+  pub parent_entangler: &'a mut Account<'info, FungibleParentEntanglerV1>,
+  pub parent_storage: &'a mut Account<'info, TokenAccount>,
+  pub parent_mint: &'a mut Account<'info, Mint>,
+  pub child_entangler: &'a mut Account<'info, FungibleChildEntanglerV1>,
+  pub child_storage: &'a mut Account<'info, TokenAccount>,
+  pub child_mint: &'a mut Account<'info, Mint>,
+  pub source: &'a Account<'info, TokenAccount>,
+  pub source_authority: &'a AccountInfo<'info>,
+  // Seeds the program signs the source transfer with, for sources held by a PDA
+  pub source_signer_seeds: &'a [&'a [&'a [u8]]],
+  pub destination: &'a AccountInfo<'info>,
+  pub rate_schedule: &'a AccountInfo<'info>,
+  pub token_program: &'a AccountInfo<'info>,
+  // spl-noop, which the swap event is logged through
+  pub noop_program: &'a AccountInfo<'info>,
+  // Set by RevealAndSwapV0, the only way to swap during the child's commit reveal window
+  pub revealed_commit: bool,
+  // Set by SwapParentForChildVestedV0, the only way to swap into a child that vests its output
+  pub vested: bool,
+  // Set by SwapLockedV0, the only way to swap into a child with a minimum lock
+  pub locked: bool,
+  // Set by ExecuteQueuedSwapV0, the only way to swap while the child's queue has tickets left
+  pub queued: bool,
+}
 
 #[derive(Accounts)]
 pub struct SwapCommonV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
   #[account(mut,
-    has_one = parent_storage
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
   )]
-  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
-  #[account(mut)]
   pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
   #[account(mut,
-    has_one = parent_entangler,
-    has_one = child_storage
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
   )]
-  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV0>>,
-  #[account(mut)]
   pub child_storage: Box<Account<'info, TokenAccount>>,
   #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    constraint = !source.is_frozen() @ ErrorCode::SourceAccountFrozen,
+  )]
   pub source: Box<Account<'info, TokenAccount>>,
   pub source_authority: Signer<'info>,
-  #[account(mut)]
-  pub destination: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Only receives tokens, the token program validates it in the transfer
+  #[account(mut,
+    constraint = !is_frozen_token_account(&destination) @ ErrorCode::DestinationAccountFrozen,
+  )]
+  pub destination: UncheckedAccount<'info>,
+  /// CHECK: The source authority's SwapRecordV0, created via InitializeSwapRecordV0. Only read when
+  /// the child tracks swaps
+  #[account(
+    mut,
+    seeds = [b"swap-record", child_entangler.key().as_ref(), source_authority.key().as_ref()],
+    bump,
+  )]
+  pub swap_record: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The instructions sysvar, read to enforce the child's CPI policy
+  #[account(
+    address = sysvar::instructions::ID,
+    constraint = child_entangler.allows_caller(immediate_caller(&instructions)?) @ ErrorCode::CpiNotAllowed,
+  )]
+  pub instructions: UncheckedAccount<'info>,
 
   pub token_program: Program<'info, Token>,
-  pub clock: Sysvar<'info, Clock>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: The SPL memo program, only invoked when the swap carries a memo
+  #[account(address = spl_memo::id())]
+  pub memo_program: UncheckedAccount<'info>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+  /// CHECK: spl-noop, which swap events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+impl<'info> SwapCommonV0<'info> {
+  pub fn swap_accounts(&mut self) -> SwapAccounts<'_, 'info> {
+    SwapAccounts {
+      parent_entangler: &mut self.parent_entangler,
+      parent_storage: &mut self.parent_storage,
+      parent_mint: &mut self.parent_mint,
+      child_entangler: &mut self.child_entangler,
+      child_storage: &mut self.child_storage,
+      child_mint: &mut self.child_mint,
+      source: &self.source,
+      source_authority: &self.source_authority,
+      source_signer_seeds: &[],
+      destination: &self.destination,
+      rate_schedule: &self.rate_schedule,
+      token_program: &self.token_program,
+      noop_program: &self.noop_program,
+      revealed_commit: false,
+      vested: false,
+      locked: false,
+      queued: false,
+    }
+  }
 }
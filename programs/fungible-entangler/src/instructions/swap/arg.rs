@@ -4,4 +4,10 @@ use anchor_lang::prelude::*;
 pub struct SwapV0Args {
   pub amount: Option<u64>,
   pub all: Option<bool>, // if true swap all and closes tokenacct
+  // Basis points of the output sent to each remaining account, the rest goes to destination
+  pub output_splits_bps: Vec<u16>,
+  // Logged through the SPL memo program after the swap, for deposit attribution
+  pub memo: Option<String>,
+  // Swap what output storage can cover rather than failing, and return a SwapFillV0
+  pub allow_partial: bool,
 }
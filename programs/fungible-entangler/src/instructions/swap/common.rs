@@ -1,29 +1,310 @@
 use super::arg::SwapV0Args;
 use crate::error::ErrorCode;
+use crate::math::{self, Rounding};
 use crate::state::*;
-use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    instruction::Instruction,
+    program::{invoke, set_return_data},
+    program_option::COption,
+  },
+};
+use anchor_spl::token::{self, Burn, TokenAccount, Transfer};
+
+pub const MAX_OUTPUT_SPLITS: usize = 4;
+pub const MAX_MEMO_LEN: usize = 256;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SwapDirection {
+  ParentToChild,
+  ChildToParent,
+}
 
 pub struct SwapAmount {
+  // Taken from the source
+  pub amount: u64,
+  // Leaves output storage, after the exchange rate and swap fee
+  pub output_amount: u64,
+}
+
+/// Return data of swaps with allow_partial set, what was actually swapped
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct SwapFillV0 {
   pub amount: u64,
+  pub output_amount: u64,
+}
+
+/// What a swap moved, recorded once its transfers have settled
+pub struct SwapOutcome {
+  pub amount: u64,
+  // Paid out to the destination and splits, net of burns
+  pub output_amount: u64,
+  pub fee_amount: u64,
+  // Burned out of the input storage after amount arrived
+  pub input_burn_amount: u64,
+}
+
+impl SwapOutcome {
+  /// What the input storage kept of amount, and so all a refund can pay back
+  pub fn retained_amount(&self) -> Result<u64> {
+    math::sub(self.amount, self.input_burn_amount)
+  }
+}
+
+pub struct BurnAmounts {
+  // Burned out of the input storage after the swapped in tokens arrive
+  pub input: u64,
+  // Burned out of the output storage instead of being paid out
+  pub output: u64,
+}
+
+impl SwapDirection {
+  pub fn reverse(self) -> Self {
+    match self {
+      SwapDirection::ParentToChild => SwapDirection::ChildToParent,
+      SwapDirection::ChildToParent => SwapDirection::ParentToChild,
+    }
+  }
+}
+
+/// Moves the child onto the latest entry of rate_schedule in effect at unix_time, then returns the
+/// rate to swap at, held to the child's rate band. The band only bounds the current swap, the
+/// child keeps its rate. Children without a schedule keep their rate
+pub fn apply_rate_schedule<'info>(
+  child_entangler: &mut FungibleChildEntanglerV1,
+  rate_schedule: &AccountInfo<'info>,
+  unix_time: i64,
+) -> Result<ExchangeRateV0> {
+  if rate_schedule.owner == &crate::ID && !rate_schedule.data_is_empty() {
+    let rate_schedule: Account<RateScheduleV0> = Account::try_from(rate_schedule)?;
+    if let Some(rate) = rate_schedule.active_rate(unix_time) {
+      child_entangler.rate = rate;
+    }
+  }
+
+  match child_entangler.rate_band {
+    Some(band) => band.guard(child_entangler.rate),
+    None => Ok(child_entangler.rate),
+  }
 }
 
+fn is_due(unix_time: Option<i64>, now: i64) -> bool {
+  unix_time.map_or(false, |time| now >= time)
+}
+
+/// Converts amount of the input token to the output token at rate, rounding down
+pub fn convert(rate: &ExchangeRateV0, direction: SwapDirection, amount: u64) -> Result<u64> {
+  match direction {
+    SwapDirection::ParentToChild => rate.child_for_parent(amount),
+    SwapDirection::ChildToParent => rate.parent_for_child(amount),
+  }
+}
+
+/// Takes the swap fee out of converted. The fee rounds up so that it never undercharges
+pub fn deduct_fee(converted: u64, swap_fee_bps: u16) -> Result<u64> {
+  math::sub(
+    converted,
+    math::apply_bps(converted, swap_fee_bps, Rounding::Up)?,
+  )
+}
+
+/// Output of swapping amount in direction, after the exchange rate and swap fee but before burns
+pub fn quote(
+  rate: &ExchangeRateV0,
+  swap_fee_bps: u16,
+  direction: SwapDirection,
+  amount: u64,
+) -> Result<u64> {
+  deduct_fee(convert(rate, direction, amount)?, swap_fee_bps)
+}
+
+/// Smallest output a single swap in direction through child can pay at its current rate. Output
+/// storage below it can't be reached by swapping. None when no input pays out anything
+pub fn min_swap_output(child: &FungibleChildEntanglerV1, direction: SwapDirection) -> Option<u64> {
+  // Quotes too large for a u64 still pay out
+  let output =
+    |amount: u64| quote(&child.rate, child.fee_bps(amount), direction, amount).unwrap_or(u64::MAX);
+  if output(u64::MAX) == 0 {
+    return None;
+  }
+
+  // Outputs only grow with the input, so search for the smallest input that pays anything
+  let (mut low, mut high) = (1u64, u64::MAX);
+  while low < high {
+    let mid = low + (high - low) / 2;
+    if output(mid) > 0 {
+      high = mid;
+    } else {
+      low = mid + 1;
+    }
+  }
+
+  Some(output(low))
+}
+
+/// Swap fee kept in output storage by swap_amount
+pub fn fee_amount(
+  rate: &ExchangeRateV0,
+  direction: SwapDirection,
+  swap_amount: &SwapAmount,
+) -> Result<u64> {
+  math::sub(
+    convert(rate, direction, swap_amount.amount)?,
+    swap_amount.output_amount,
+  )
+}
+
+pub fn burn_amounts(burn: &Option<BurnConfigV0>, swap_amount: &SwapAmount) -> Result<BurnAmounts> {
+  Ok(match burn {
+    None => BurnAmounts {
+      input: 0,
+      output: 0,
+    },
+    Some(BurnConfigV0 {
+      bps,
+      side: BurnSideV0::Input,
+    }) => BurnAmounts {
+      input: math::apply_bps(swap_amount.amount, *bps, Rounding::Down)?,
+      output: 0,
+    },
+    Some(BurnConfigV0 {
+      bps,
+      side: BurnSideV0::Output,
+    }) => BurnAmounts {
+      input: 0,
+      output: math::apply_bps(swap_amount.output_amount, *bps, Rounding::Down)?,
+    },
+  })
+}
+
+pub fn burn_from_storage<'info>(
+  ctx: CpiContext<'_, '_, '_, 'info, Burn<'info>>,
+  amount: u64,
+) -> Result<()> {
+  if amount == 0 {
+    return Ok(());
+  }
+
+  msg!("Burning {} from storage", amount);
+  token::burn(ctx, amount)
+}
+
+/// How much of source authority may swap, as either its owner or its SPL token delegate
+pub fn swappable_amount(source: &TokenAccount, authority: &Pubkey) -> Result<u64> {
+  if source.owner == *authority {
+    return Ok(source.amount);
+  }
+
+  require!(
+    source.delegate == COption::Some(*authority),
+    ErrorCode::InvalidDelegate
+  );
+  Ok(std::cmp::min(source.amount, source.delegated_amount))
+}
+
+/// What storage_amount of the output storage of a swap in direction may pay out, leaving behind
+/// the fees owed to swappers as rebates
+pub fn payable_storage_amount(
+  parent_entangler: &FungibleParentEntanglerV1,
+  child_entangler: &FungibleChildEntanglerV1,
+  direction: SwapDirection,
+  storage_amount: u64,
+) -> u64 {
+  let reserved = match direction {
+    SwapDirection::ParentToChild => child_entangler.reserved_child_fee_amount(),
+    SwapDirection::ChildToParent => parent_entangler.reserved_parent_fee_amount,
+  };
+
+  storage_amount.saturating_sub(reserved)
+}
+
+/// Fails unless the freezes, expiry and fee distribution of the child and its parent leave swaps in
+/// direction open at unix_time
+pub fn check_swaps_open(
+  parent_entangler: &FungibleParentEntanglerV1,
+  child_entangler: &FungibleChildEntanglerV1,
+  direction: SwapDirection,
+  unix_time: i64,
+) -> Result<()> {
+  require!(
+    parent_entangler.freeze_swap_unix_time.is_none()
+      || (parent_entangler.freeze_swap_unix_time > Some(unix_time)),
+    ErrorCode::ParentSwapFrozen
+  );
+
+  require!(
+    child_entangler.freeze_swap_unix_time.is_none()
+      || (child_entangler.freeze_swap_unix_time > Some(unix_time)),
+    ErrorCode::ChildSwapFrozen
+  );
+
+  require!(!child_entangler.frozen, ErrorCode::ChildSwapFrozen);
+
+  let (parent_freeze, child_freeze) = match direction {
+    SwapDirection::ParentToChild => (
+      parent_entangler.freeze_parent_to_child_unix_time,
+      child_entangler.freeze_parent_to_child_unix_time,
+    ),
+    SwapDirection::ChildToParent => (
+      parent_entangler.freeze_child_to_parent_unix_time,
+      child_entangler.freeze_child_to_parent_unix_time,
+    ),
+  };
+  require!(
+    !is_due(parent_freeze, unix_time),
+    ErrorCode::ParentSwapFrozen
+  );
+  require!(!is_due(child_freeze, unix_time), ErrorCode::ChildSwapFrozen);
+
+  require!(!child_entangler.is_expired(unix_time), ErrorCode::Expired);
+  require!(
+    child_entangler.fee_distribution.is_none(),
+    ErrorCode::FeesAlreadyDistributing
+  );
+
+  Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn swap_shared_logic(
-  parent_entangler: &Account<FungibleParentEntanglerV0>,
-  child_entangler: &Account<FungibleChildEntanglerV0>,
+  parent_entangler: &Account<FungibleParentEntanglerV1>,
+  child_entangler: &Account<FungibleChildEntanglerV1>,
+  // The rate in effect for this swap, see apply_rate_schedule
+  rate: &ExchangeRateV0,
+  direction: SwapDirection,
   target: &Account<TokenAccount>,
-  source: &Account<TokenAccount>,
-  clock: &Sysvar<Clock>,
+  // What the source authority may spend from source, see swappable_amount
+  source_amount: u64,
+  revealed_commit: bool,
+  queued: bool,
+  clock: &Clock,
   args: &SwapV0Args,
 ) -> Result<SwapAmount> {
-  let amount: u64;
-  let clock = clock;
-
   require!(
     (args.all.is_some() && args.all == Some(true)) || args.amount.is_some(),
     ErrorCode::InvalidArgs
   );
 
+  require!(
+    args.output_splits_bps.len() <= MAX_OUTPUT_SPLITS
+      && args
+        .output_splits_bps
+        .iter()
+        .map(|bps| *bps as u32)
+        .sum::<u32>()
+        <= 10000,
+    ErrorCode::InvalidOutputSplits
+  );
+
+  require!(
+    args
+      .memo
+      .as_ref()
+      .map_or(true, |memo| memo.len() <= MAX_MEMO_LEN),
+    ErrorCode::InvalidStringLength
+  );
+
   require!(
     parent_entangler.go_live_unix_time < clock.unix_timestamp,
     ErrorCode::ParentNotLiveYet
@@ -35,28 +316,184 @@ pub fn swap_shared_logic(
   );
 
   require!(
-    parent_entangler.freeze_swap_unix_time.is_none()
-      || (parent_entangler.freeze_swap_unix_time > Some(clock.unix_timestamp)),
-    ErrorCode::ParentSwapFrozen
+    queued || child_entangler.queued_swap_count == 0,
+    ErrorCode::QueuedSwapsPending
   );
 
   require!(
-    child_entangler.freeze_swap_unix_time.is_none()
-      || (child_entangler.freeze_swap_unix_time > Some(clock.unix_timestamp)),
-    ErrorCode::ChildSwapFrozen
+    revealed_commit || !child_entangler.is_commit_reveal_only(clock.unix_timestamp),
+    ErrorCode::CommitRevealOnly
   );
 
-  if args.all == Some(true) {
-    amount = if source.amount > target.amount {
-      target.amount
-    } else {
-      source.amount
-    };
+  check_swaps_open(
+    parent_entangler,
+    child_entangler,
+    direction,
+    clock.unix_timestamp,
+  )?;
+
+  let all = args.all == Some(true);
+  let mut amount = if all {
+    source_amount
   } else {
-    amount = args.amount.unwrap();
+    let amount = args.amount.unwrap();
+    require!(amount <= source_amount, ErrorCode::TokenAccountAmountTooLow);
+    amount
+  };
+  let payable = payable_storage_amount(parent_entangler, child_entangler, direction, target.amount);
+  let mut converted = convert(rate, direction, amount)?;
+  // Fill only what the target storage may pay out, charging the matching input
+  if (all || args.allow_partial) && converted > payable {
+    amount = convert(rate, direction.reverse(), payable)?;
+    converted = convert(rate, direction, amount)?;
+  }
+
+  let output_amount = deduct_fee(converted, child_entangler.fee_bps(amount))?;
+  // Empty storage clamps partial fills to nothing, which must not pass as a swap
+  require!(amount > 0 && output_amount > 0, ErrorCode::InvalidAmount);
+  require!(payable >= output_amount, ErrorCode::InsufficientStorage);
+
+  match direction {
+    SwapDirection::ParentToChild => {
+      if let Some(max) = child_entangler.max_parent_to_child_amount {
+        require!(
+          child_entangler
+            .total_parent_to_child_amount
+            .checked_add(amount)
+            .map_or(false, |total| total <= max),
+          ErrorCode::SwapCapExceeded
+        );
+      }
+    }
+    SwapDirection::ChildToParent => {
+      if let Some(max) = child_entangler.max_child_to_parent_amount {
+        require!(
+          child_entangler
+            .total_child_to_parent_amount
+            .checked_add(amount)
+            .map_or(false, |total| total <= max),
+          ErrorCode::ConversionCapExceeded
+        );
+      }
+    }
+  }
+
+  Ok(SwapAmount {
+    amount,
+    output_amount,
+  })
+}
 
-    require!(target.amount >= amount, ErrorCode::TokenAccountAmountTooLow);
+/// Tells the caller of a partial fill swap how much was filled
+pub fn return_fill(args: &SwapV0Args, outcome: &SwapOutcome) -> Result<()> {
+  if args.allow_partial {
+    let fill = SwapFillV0 {
+      amount: outcome.amount,
+      output_amount: outcome.output_amount,
+    };
+    set_return_data(&fill.try_to_vec()?);
   }
 
-  Ok(SwapAmount { amount })
+  Ok(())
+}
+
+/// Loads the swapper's SwapRecordV0 if the child tracks swaps, which requires it to exist. Callers
+/// persist their changes with exit. Children that don't track swaps never touch the record
+pub fn load_swap_record<'info>(
+  swap_record: &AccountInfo<'info>,
+  child_entangler: &FungibleChildEntanglerV1,
+) -> Result<Option<Account<'info, SwapRecordV0>>> {
+  if !child_entangler.tracks_swap_records() {
+    return Ok(None);
+  }
+  require!(
+    swap_record.owner == &crate::ID && !swap_record.data_is_empty(),
+    ErrorCode::SwapRecordRequired
+  );
+
+  Ok(Some(Account::try_from(swap_record)?))
+}
+
+/// Pays out swap output, sending output_splits_bps[i] of amount to split_accounts[i] and the
+/// remainder to the context's destination
+pub fn transfer_output<'info>(
+  ctx: CpiContext<'_, '_, '_, 'info, Transfer<'info>>,
+  split_accounts: &[AccountInfo<'info>],
+  output_splits_bps: &[u16],
+  amount: u64,
+) -> Result<()> {
+  require!(
+    split_accounts.len() >= output_splits_bps.len(),
+    ErrorCode::InvalidOutputSplits
+  );
+
+  let mut remaining = amount;
+  for (split_account, bps) in split_accounts.iter().zip(output_splits_bps) {
+    let split_amount = math::apply_bps(amount, *bps, Rounding::Down)?;
+    if split_amount == 0 {
+      continue;
+    }
+    remaining = math::sub(remaining, split_amount)?;
+
+    msg!(
+      "Sending {} of the output to {}",
+      split_amount,
+      split_account.key
+    );
+    token::transfer(
+      CpiContext::new_with_signer(
+        ctx.program.clone(),
+        Transfer {
+          from: ctx.accounts.from.clone(),
+          to: split_account.clone(),
+          authority: ctx.accounts.authority.clone(),
+        },
+        ctx.signer_seeds,
+      ),
+      split_amount,
+    )?;
+  }
+
+  token::transfer(ctx, remaining)
+}
+
+/// Logs memo through the SPL memo program, if one was given
+pub fn send_memo<'info>(memo_program: &AccountInfo<'info>, memo: &Option<String>) -> Result<()> {
+  if let Some(memo) = memo {
+    invoke(
+      &spl_memo::build_memo(memo.as_bytes(), &[]),
+      std::slice::from_ref(memo_program),
+    )?;
+  }
+
+  Ok(())
+}
+
+/// Invokes program with accounts as given, for composing swaps with another program's deposit.
+/// Only the transaction's own signers carry over, never the entanglers
+pub fn invoke_with_accounts<'info>(
+  program: &AccountInfo<'info>,
+  accounts: &[AccountInfo<'info>],
+  data: Vec<u8>,
+) -> Result<()> {
+  let metas = accounts
+    .iter()
+    .map(|account| {
+      if account.is_writable {
+        AccountMeta::new(account.key(), account.is_signer)
+      } else {
+        AccountMeta::new_readonly(account.key(), account.is_signer)
+      }
+    })
+    .collect();
+  invoke(
+    &Instruction {
+      program_id: program.key(),
+      accounts: metas,
+      data,
+    },
+    accounts,
+  )?;
+
+  Ok(())
 }
@@ -1,10 +1,20 @@
 pub mod account;
 pub mod arg;
 pub mod common;
+pub mod refund_swap_v0;
+pub mod swap_child_for_parent_and_provide_liquidity_v0;
 pub mod swap_child_for_parent_v0;
+pub mod swap_parent_for_basket_v0;
+pub mod swap_parent_for_child_and_stake_v0;
 pub mod swap_parent_for_child_v0;
+pub mod swap_parent_for_child_vested_v0;
 
 pub use account::*;
 pub use arg::*;
+pub use refund_swap_v0::*;
+pub use swap_child_for_parent_and_provide_liquidity_v0::*;
 pub use swap_child_for_parent_v0::*;
+pub use swap_parent_for_basket_v0::*;
+pub use swap_parent_for_child_and_stake_v0::*;
 pub use swap_parent_for_child_v0::*;
+pub use swap_parent_for_child_vested_v0::*;
@@ -0,0 +1,121 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use strata_spl_utils::transfer_signed;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RefundSwapV0Args {
+  // Amount of child tokens to return
+  pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RefundSwapV0Args)]
+pub struct RefundSwapV0<'info> {
+  #[account(
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut)]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = [b"swap-record", child_entangler.key().as_ref(), source_authority.key().as_ref()],
+    bump = swap_record.bump_seed,
+    has_one = child_entangler @ ErrorCode::ChildEntanglerMismatch,
+  )]
+  pub swap_record: Box<Account<'info, SwapRecordV0>>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub source_authority: Signer<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+/// Reverses a recent parent to child swap at the rate it was made, regardless of swap freezes.
+/// Refunds close with swaps once the child expires or distributes its fees, whose snapshots must
+/// not change
+pub fn handler(ctx: Context<RefundSwapV0>, args: RefundSwapV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &ctx.accounts.child_entangler;
+  let window = child_entangler
+    .refund_window_seconds
+    .ok_or(error!(ErrorCode::NoRefundWindow))?;
+  require!(
+    !child_entangler.is_expired(clock.unix_timestamp),
+    ErrorCode::Expired
+  );
+  require!(
+    child_entangler.fee_distribution.is_none(),
+    ErrorCode::FeesAlreadyDistributing
+  );
+  let swap_record = &mut ctx.accounts.swap_record;
+
+  require!(
+    swap_record.in_refund_window(window, clock.unix_timestamp),
+    ErrorCode::RefundWindowClosed
+  );
+  require!(
+    args.amount > 0 && args.amount <= swap_record.refundable_child_amount,
+    ErrorCode::InvalidAmount
+  );
+
+  let parent_amount = swap_record.refund(args.amount)?;
+
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  child_entangler.total_parent_to_child_amount = child_entangler
+    .total_parent_to_child_amount
+    .saturating_sub(parent_amount);
+  child_entangler.recorded_parent_to_child_amount = child_entangler
+    .recorded_parent_to_child_amount
+    .saturating_sub(parent_amount);
+
+  let token_program = ctx.accounts.token_program.to_account_info();
+  let parent_entangler = &ctx.accounts.parent_entangler;
+
+  msg!("Refunding {} from source to child storage", args.amount);
+  token::transfer(
+    CpiContext::new(
+      token_program.clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.child_storage.to_account_info().clone(),
+        authority: ctx.accounts.source_authority.to_account_info().clone(),
+      },
+    ),
+    args.amount,
+  )?;
+
+  msg!(
+    "Refunding {} from parent storage to destination",
+    parent_amount
+  );
+  transfer_signed(
+    &token_program,
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &parent_entangler.to_account_info(),
+    &parent_entangler.signer_seeds(),
+    parent_amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,52 @@
+use super::{
+  account::*,
+  arg::SwapV0Args,
+  common::{invoke_with_accounts, send_memo},
+  swap_child_for_parent_v0::swap_child_for_parent,
+};
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SwapAndProvideLiquidityV0Args {
+  pub swap: SwapV0Args,
+  // Instruction data for the liquidity program's deposit, which pulls the swap output from the
+  // destination into the pool
+  pub deposit_data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SwapAndProvideLiquidityV0Args)]
+pub struct SwapChildForParentAndProvideLiquidityV0<'info> {
+  pub common: SwapCommonV0<'info>,
+  /// CHECK: Must be the child's liquidity program. Invoked with the remaining accounts
+  #[account(
+    executable,
+    constraint = common.child_entangler.liquidity_program == Some(liquidity_program.key()) @ ErrorCode::InvalidLiquidityProgram,
+  )]
+  pub liquidity_program: UncheckedAccount<'info>,
+}
+
+/// Swaps child tokens for parent tokens, then invokes the child's liquidity program to deposit
+/// them into a pool, so relaunches can convert old tokens and seed the new token's liquidity in
+/// one instruction. The remaining accounts are the pool accounts, passed to the deposit as is
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapChildForParentAndProvideLiquidityV0<'info>>,
+  args: SwapAndProvideLiquidityV0Args,
+) -> Result<()> {
+  require!(
+    args.swap.output_splits_bps.is_empty(),
+    ErrorCode::OutputSplitsNotSupported
+  );
+
+  swap_child_for_parent(&mut ctx.accounts.common, &[], false, &args.swap)?;
+
+  msg!("Depositing swap output into the pool");
+  invoke_with_accounts(
+    &ctx.accounts.liquidity_program.to_account_info(),
+    ctx.remaining_accounts,
+    args.deposit_data,
+  )?;
+
+  send_memo(&ctx.accounts.common.memo_program, &args.swap.memo)
+}
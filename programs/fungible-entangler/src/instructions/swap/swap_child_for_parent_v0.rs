@@ -1,10 +1,20 @@
 use super::{
   account::*,
   arg::SwapV0Args,
-  common::{swap_shared_logic, SwapAmount},
+  common::{
+    apply_rate_schedule, burn_amounts, burn_from_storage, fee_amount, load_swap_record,
+    return_fill, send_memo, swap_shared_logic, swappable_amount, transfer_output, SwapDirection,
+    SwapOutcome,
+  },
+};
+#[cfg(feature = "strict-invariants")]
+use crate::invariants::SwapSnapshot;
+use crate::{
+  events::{emit_noop, SwapEventV0},
+  math,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{self, Burn, Transfer};
 
 #[derive(Accounts)]
 #[instruction(args: SwapV0Args)]
@@ -12,46 +22,173 @@ pub struct SwapChildForParentV0<'info> {
   pub common: SwapCommonV0<'info>,
 }
 
-pub fn handler(ctx: Context<SwapChildForParentV0>, args: SwapV0Args) -> Result<()> {
-  let SwapAmount { amount } = swap_shared_logic(
-    &ctx.accounts.common.parent_entangler,
-    &ctx.accounts.common.child_entangler,
-    &ctx.accounts.common.parent_storage,
-    &ctx.accounts.common.source,
-    &ctx.accounts.common.clock,
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapChildForParentV0<'info>>,
+  args: SwapV0Args,
+) -> Result<()> {
+  let outcome = swap_child_for_parent(
+    &mut ctx.accounts.common,
+    ctx.remaining_accounts,
+    false,
     &args,
   )?;
+  return_fill(&args, &outcome)?;
+  send_memo(&ctx.accounts.common.memo_program, &args.memo)
+}
+
+/// Swaps child tokens from source for parent tokens paid out to destination and split_accounts,
+/// recording the swap and accruing its fee in the source authority's SwapRecordV0
+pub fn swap_child_for_parent<'info>(
+  common: &mut SwapCommonV0<'info>,
+  split_accounts: &[AccountInfo<'info>],
+  revealed_commit: bool,
+  args: &SwapV0Args,
+) -> Result<SwapOutcome> {
+  let clock = Clock::get()?;
+  let mut accounts = common.swap_accounts();
+  accounts.revealed_commit = revealed_commit;
+  let outcome = execute_child_for_parent(accounts, split_accounts, &clock, args)?;
+
+  let mut swap_record = match load_swap_record(&common.swap_record, &common.child_entangler)? {
+    Some(swap_record) => swap_record,
+    None => return Ok(outcome),
+  };
+  swap_record.record_child_to_parent(outcome.amount)?;
+  let child_entangler = &mut common.child_entangler;
+  child_entangler.accrued_parent_fee_amount = math::add(
+    child_entangler.accrued_parent_fee_amount,
+    outcome.fee_amount,
+  )?;
+  child_entangler.recorded_child_to_parent_amount = math::add(
+    child_entangler.recorded_child_to_parent_amount,
+    outcome.amount,
+  )?;
+  common.parent_entangler.reserved_parent_fee_amount = math::add(
+    common.parent_entangler.reserved_parent_fee_amount,
+    outcome.fee_amount,
+  )?;
 
-  let parent_entangler = &mut ctx.accounts.common.parent_entangler;
-  let source = ctx.accounts.common.source.to_account_info();
-  let destination = ctx.accounts.common.destination.to_account_info();
-  let parent_storage = ctx.accounts.common.parent_storage.to_account_info();
-  let child_storage = ctx.accounts.common.child_storage.to_account_info();
-  let source_authority = ctx.accounts.common.source_authority.to_account_info();
-  let token_program = ctx.accounts.common.token_program.to_account_info();
+  if let Some(window) = common.child_entangler.reverse_rebate_seconds {
+    let rebate = swap_record.take_rebate(outcome.amount, window, clock.unix_timestamp);
+    if rebate > 0 {
+      common.child_entangler.accrued_child_fee_amount = common
+        .child_entangler
+        .accrued_child_fee_amount
+        .saturating_sub(rebate);
+      msg!("Rebating {} child tokens of fees to source", rebate);
+      token::transfer(
+        CpiContext::new_with_signer(
+          common.token_program.to_account_info(),
+          Transfer {
+            from: common.child_storage.to_account_info(),
+            to: common.source.to_account_info(),
+            authority: common.child_entangler.to_account_info(),
+          },
+          &[&common.child_entangler.signer_seeds()],
+        ),
+        rebate,
+      )?;
+    }
+  }
+  swap_record.exit(&crate::ID)?;
+
+  Ok(outcome)
+}
+
+/// Moves the tokens of a child to parent swap and updates the child's totals. Recording the swap,
+/// and with it accruing the fee, is left to the caller
+pub fn execute_child_for_parent<'info>(
+  accounts: SwapAccounts<'_, 'info>,
+  split_accounts: &[AccountInfo<'info>],
+  clock: &Clock,
+  args: &SwapV0Args,
+) -> Result<SwapOutcome> {
+  let rate = apply_rate_schedule(
+    accounts.child_entangler,
+    accounts.rate_schedule,
+    clock.unix_timestamp,
+  )?;
+  let swap_amount = swap_shared_logic(
+    accounts.parent_entangler,
+    accounts.child_entangler,
+    &rate,
+    SwapDirection::ChildToParent,
+    accounts.parent_storage,
+    swappable_amount(accounts.source, accounts.source_authority.key)?,
+    accounts.revealed_commit,
+    accounts.queued,
+    clock,
+    args,
+  )?;
+  accounts.child_entangler.record_drawdown(
+    SwapDirection::ChildToParent,
+    swap_amount.output_amount,
+    clock.unix_timestamp,
+  )?;
+  let burn = burn_amounts(&accounts.child_entangler.burn, &swap_amount)?;
+  #[cfg(feature = "strict-invariants")]
+  let snapshot = SwapSnapshot::take(
+    accounts.parent_storage,
+    accounts.child_storage,
+    accounts.parent_mint,
+    accounts.child_mint,
+  );
+  let amount = swap_amount.amount;
+  let output_amount = math::sub(swap_amount.output_amount, burn.output)?;
+
+  let parent_entangler = &*accounts.parent_entangler;
+  let child_entangler = &*accounts.child_entangler;
+  let source = accounts.source.to_account_info();
+  let destination = accounts.destination.clone();
+  let parent_storage = accounts.parent_storage.to_account_info();
+  let child_storage = accounts.child_storage.to_account_info();
+  let source_authority = accounts.source_authority.clone();
+  let token_program = accounts.token_program.clone();
 
   msg!("Swapping out from source to child storage");
   token::transfer(
-    CpiContext::new(
+    CpiContext::new_with_signer(
       token_program.clone(),
       Transfer {
         from: source.clone(),
         to: child_storage.clone(),
         authority: source_authority.clone(),
       },
+      accounts.source_signer_seeds,
     ),
     amount,
   )?;
 
-  let parent_entangler_seeds: &[&[&[u8]]] = &[&[
-    b"entangler",
-    parent_entangler.parent_mint.as_ref(),
-    &parent_entangler.dynamic_seed,
-    &[parent_entangler.bump_seed],
-  ]];
+  let parent_entangler_seeds: &[&[&[u8]]] = &[&parent_entangler.signer_seeds()];
+  let child_entangler_seeds: &[&[&[u8]]] = &[&child_entangler.signer_seeds()];
+
+  burn_from_storage(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      Burn {
+        mint: accounts.child_mint.to_account_info().clone(),
+        from: child_storage.clone(),
+        authority: child_entangler.to_account_info().clone(),
+      },
+      child_entangler_seeds,
+    ),
+    burn.input,
+  )?;
+  burn_from_storage(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      Burn {
+        mint: accounts.parent_mint.to_account_info().clone(),
+        from: parent_storage.clone(),
+        authority: parent_entangler.to_account_info().clone(),
+      },
+      parent_entangler_seeds,
+    ),
+    burn.output,
+  )?;
 
   msg!("Swapping out from parent storage to source");
-  token::transfer(
+  transfer_output(
     CpiContext::new_with_signer(
       token_program.clone(),
       Transfer {
@@ -61,8 +198,51 @@ pub fn handler(ctx: Context<SwapChildForParentV0>, args: SwapV0Args) -> Result<(
       },
       parent_entangler_seeds,
     ),
+    split_accounts,
+    &args.output_splits_bps,
+    output_amount,
+  )?;
+
+  #[cfg(feature = "strict-invariants")]
+  snapshot.check(
+    &rate,
+    accounts.parent_storage,
+    accounts.child_storage,
+    accounts.parent_mint,
+    accounts.child_mint,
+    SwapDirection::ChildToParent,
+    &swap_amount,
+    &burn,
+  )?;
+
+  let fee = fee_amount(&rate, SwapDirection::ChildToParent, &swap_amount)?;
+  accounts.child_entangler.total_child_to_parent_amount = math::add(
+    accounts.child_entangler.total_child_to_parent_amount,
     amount,
   )?;
+  accounts
+    .child_entangler
+    .child_to_parent_volume
+    .record(amount, clock.unix_timestamp);
+
+  emit_noop(
+    accounts.noop_program,
+    &SwapEventV0 {
+      parent_entangler: accounts.parent_entangler.key(),
+      child_entangler: accounts.child_entangler.key(),
+      source_authority: accounts.source_authority.key(),
+      parent_to_child: false,
+      amount,
+      output_amount,
+      fee_amount: fee,
+      unix_time: clock.unix_timestamp,
+    },
+  )?;
 
-  Ok(())
+  Ok(SwapOutcome {
+    amount,
+    output_amount,
+    fee_amount: fee,
+    input_burn_amount: burn.input,
+  })
 }
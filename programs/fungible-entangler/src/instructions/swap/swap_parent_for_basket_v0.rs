@@ -0,0 +1,213 @@
+use super::{
+  account::SwapAccounts,
+  arg::SwapV0Args,
+  swap_parent_for_child_v0::{execute_parent_for_child, record_parent_for_child},
+};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  math,
+  state::*,
+  util::{immediate_caller, is_frozen_token_account},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+// Child entangler, child storage, child mint, destination, rate schedule and swap record
+const ACCOUNTS_PER_LEG: usize = 6;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SwapParentForBasketV0Args {
+  // Parent tokens to swap, split across the basket by weight
+  pub amount: u64,
+  // Either empty or the least output accepted from each leg, in basket order
+  pub min_output_amounts: Vec<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SwapParentForBasketV0Args)]
+pub struct SwapParentForBasketV0<'info> {
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    constraint = !source.is_frozen() @ ErrorCode::SourceAccountFrozen,
+  )]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub source_authority: Signer<'info>,
+  #[account(
+    constraint = registry.load()?.parent_entangler == parent_entangler.key() @ ErrorCode::InvalidRegistry,
+  )]
+  pub registry: AccountLoader<'info, ChildRegistryV0>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The instructions sysvar, read to enforce each child's CPI policy
+  #[account(address = sysvar::instructions::ID)]
+  pub instructions: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  /// CHECK: spl-noop, which swap events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+/// Swaps parent tokens for the registry page's weighted basket of children. Remaining accounts
+/// hold six accounts per weighted child in registry order: the child entangler, its storage, its
+/// mint, the destination, its rate schedule PDA and the source authority's SwapRecordV0 PDA for it.
+/// Each leg into a child that tracks swaps is recorded like a single swap. The last leg takes the
+/// rounding remainder
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapParentForBasketV0<'info>>,
+  args: SwapParentForBasketV0Args,
+) -> Result<()> {
+  let basket = ctx.accounts.registry.load()?.basket();
+  require!(!basket.is_empty(), ErrorCode::NoBasket);
+  require!(
+    basket.iter().map(|(_, weight)| *weight as u32).sum::<u32>() == 10000,
+    ErrorCode::InvalidBasketWeights
+  );
+  require!(
+    ctx.remaining_accounts.len() == basket.len() * ACCOUNTS_PER_LEG
+      && (args.min_output_amounts.is_empty() || args.min_output_amounts.len() == basket.len()),
+    ErrorCode::InvalidArgs
+  );
+
+  let clock = Clock::get()?;
+  let caller = immediate_caller(&ctx.accounts.instructions)?;
+  let accounts = &mut *ctx.accounts;
+  let mut remaining = args.amount;
+  for (index, ((child_key, weight), leg)) in basket
+    .iter()
+    .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_LEG))
+    .enumerate()
+  {
+    let leg_amount = if index + 1 == basket.len() {
+      remaining
+    } else {
+      math::apply_bps(args.amount, *weight, math::Rounding::Down)?
+    };
+    remaining = math::sub(remaining, leg_amount)?;
+    if leg_amount == 0 {
+      continue;
+    }
+
+    let (child_info, child_storage_info, child_mint_info, destination, rate_schedule, swap_record) =
+      (&leg[0], &leg[1], &leg[2], &leg[3], &leg[4], &leg[5]);
+    require!(
+      child_info.key() == *child_key,
+      ErrorCode::ChildEntanglerMismatch
+    );
+    let mut child_entangler: Account<FungibleChildEntanglerV1> = Account::try_from(child_info)?;
+    let mut child_storage: Account<TokenAccount> = Account::try_from(child_storage_info)?;
+    let mut child_mint: Account<Mint> = Account::try_from(child_mint_info)?;
+    require!(
+      child_entangler.is_supported_version(),
+      ErrorCode::UnsupportedVersion
+    );
+    require!(
+      child_entangler.parent_entangler == accounts.parent_entangler.key(),
+      ErrorCode::ParentEntanglerMismatch
+    );
+    require!(
+      child_entangler.child_storage == child_storage.key(),
+      ErrorCode::StorageMismatch
+    );
+    require!(
+      child_entangler.child_mint == child_mint.key(),
+      ErrorCode::ChildMintMismatch
+    );
+    require!(
+      child_entangler.allows_caller(caller),
+      ErrorCode::CpiNotAllowed
+    );
+    require!(
+      rate_schedule.key()
+        == Pubkey::find_program_address(&[b"rate-schedule", child_key.as_ref()], &crate::ID).0,
+      ErrorCode::InvalidRateSchedule
+    );
+    require!(
+      swap_record.key()
+        == Pubkey::find_program_address(
+          &[
+            b"swap-record",
+            child_key.as_ref(),
+            accounts.source_authority.key().as_ref(),
+          ],
+          &crate::ID
+        )
+        .0,
+      ErrorCode::InvalidSwapRecord
+    );
+    require!(!child_storage.is_frozen(), ErrorCode::StorageAccountFrozen);
+    require!(
+      !is_frozen_token_account(destination),
+      ErrorCode::DestinationAccountFrozen
+    );
+
+    let outcome = execute_parent_for_child(
+      SwapAccounts {
+        parent_entangler: &mut accounts.parent_entangler,
+        parent_storage: &mut accounts.parent_storage,
+        parent_mint: &mut accounts.parent_mint,
+        child_entangler: &mut child_entangler,
+        child_storage: &mut child_storage,
+        child_mint: &mut child_mint,
+        source: &accounts.source,
+        source_authority: &accounts.source_authority,
+        source_signer_seeds: &[],
+        destination,
+        rate_schedule,
+        token_program: &accounts.token_program,
+        noop_program: &accounts.noop_program,
+        revealed_commit: false,
+        vested: false,
+        locked: false,
+        queued: false,
+      },
+      &[],
+      &clock,
+      &SwapV0Args {
+        amount: Some(leg_amount),
+        all: None,
+        output_splits_bps: vec![],
+        memo: None,
+        allow_partial: false,
+      },
+    )?;
+    if let Some(min_output_amount) = args.min_output_amounts.get(index) {
+      require!(
+        outcome.output_amount >= *min_output_amount,
+        ErrorCode::SlippageExceeded
+      );
+    }
+
+    record_parent_for_child(
+      swap_record,
+      &mut child_entangler,
+      &outcome,
+      clock.unix_timestamp,
+    )?;
+
+    // Persist the child's totals, volume and scheduled rate, and refresh what later legs read
+    child_entangler.exit(&crate::ID)?;
+    accounts.source.reload()?;
+    accounts.parent_storage.reload()?;
+    accounts.parent_mint.reload()?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,50 @@
+use super::{
+  account::*,
+  arg::SwapV0Args,
+  common::{invoke_with_accounts, send_memo},
+  swap_parent_for_child_v0::swap_parent_for_child,
+};
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SwapAndStakeV0Args {
+  pub swap: SwapV0Args,
+  // Instruction data for the stake program, which deposits the swap output on the wallet's behalf
+  pub stake_data: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SwapAndStakeV0Args)]
+pub struct SwapParentForChildAndStakeV0<'info> {
+  pub common: SwapCommonV0<'info>,
+  /// CHECK: Must be the child's stake program. Invoked with the remaining accounts
+  #[account(
+    executable,
+    constraint = common.child_entangler.stake_program == Some(stake_program.key()) @ ErrorCode::InvalidStakeProgram,
+  )]
+  pub stake_program: UncheckedAccount<'info>,
+}
+
+/// Swaps parent tokens for child tokens, then invokes the child's stake program to deposit them.
+/// The remaining accounts are passed to the stake program as is
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapParentForChildAndStakeV0<'info>>,
+  args: SwapAndStakeV0Args,
+) -> Result<()> {
+  require!(
+    args.swap.output_splits_bps.is_empty(),
+    ErrorCode::OutputSplitsNotSupported
+  );
+
+  swap_parent_for_child(&mut ctx.accounts.common, &[], false, &args.swap)?;
+
+  msg!("Staking swap output");
+  invoke_with_accounts(
+    &ctx.accounts.stake_program.to_account_info(),
+    ctx.remaining_accounts,
+    args.stake_data,
+  )?;
+
+  send_memo(&ctx.accounts.common.memo_program, &args.swap.memo)
+}
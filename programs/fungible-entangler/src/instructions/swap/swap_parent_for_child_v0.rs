@@ -1,10 +1,22 @@
 use super::{
   account::*,
   arg::SwapV0Args,
-  common::{swap_shared_logic, SwapAmount},
+  common::{
+    apply_rate_schedule, burn_amounts, burn_from_storage, fee_amount, load_swap_record,
+    return_fill, send_memo, swap_shared_logic, swappable_amount, transfer_output, SwapDirection,
+    SwapOutcome,
+  },
+};
+#[cfg(feature = "strict-invariants")]
+use crate::invariants::SwapSnapshot;
+use crate::{
+  error::ErrorCode,
+  events::{emit_noop, SwapEventV0},
+  math,
+  state::*,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{self, Burn, Transfer};
 
 #[derive(Accounts)]
 #[instruction(args: SwapV0Args)]
@@ -12,47 +24,194 @@ pub struct SwapParentForChildV0<'info> {
   pub common: SwapCommonV0<'info>,
 }
 
-pub fn handler(ctx: Context<SwapParentForChildV0>, args: SwapV0Args) -> Result<()> {
-  let SwapAmount { amount } = swap_shared_logic(
-    &ctx.accounts.common.parent_entangler,
-    &ctx.accounts.common.child_entangler,
-    &ctx.accounts.common.child_storage,
-    &ctx.accounts.common.source,
-    &ctx.accounts.common.clock,
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapParentForChildV0<'info>>,
+  args: SwapV0Args,
+) -> Result<()> {
+  let outcome = swap_parent_for_child(
+    &mut ctx.accounts.common,
+    ctx.remaining_accounts,
+    false,
     &args,
   )?;
+  return_fill(&args, &outcome)?;
+  send_memo(&ctx.accounts.common.memo_program, &args.memo)
+}
+
+/// Swaps parent tokens from source for child tokens paid out to destination and split_accounts,
+/// recording the swap in the source authority's SwapRecordV0
+pub fn swap_parent_for_child<'info>(
+  common: &mut SwapCommonV0<'info>,
+  split_accounts: &[AccountInfo<'info>],
+  revealed_commit: bool,
+  args: &SwapV0Args,
+) -> Result<SwapOutcome> {
+  let clock = Clock::get()?;
+  let mut accounts = common.swap_accounts();
+  accounts.revealed_commit = revealed_commit;
+  let outcome = execute_parent_for_child(accounts, split_accounts, &clock, args)?;
+  record_parent_for_child(
+    &common.swap_record,
+    &mut common.child_entangler,
+    &outcome,
+    clock.unix_timestamp,
+  )?;
+
+  Ok(outcome)
+}
+
+/// Records a parent to child swap in the swapper's SwapRecordV0 if the child tracks swaps, accruing
+/// its fee for the fee distribution
+pub fn record_parent_for_child<'info>(
+  swap_record: &AccountInfo<'info>,
+  child_entangler: &mut FungibleChildEntanglerV1,
+  outcome: &SwapOutcome,
+  unix_time: i64,
+) -> Result<()> {
+  let mut swap_record = match load_swap_record(swap_record, child_entangler)? {
+    Some(swap_record) => swap_record,
+    None => return Ok(()),
+  };
+  if let Some(window) = child_entangler.reverse_rebate_seconds {
+    swap_record.record_rebatable_fee(outcome.fee_amount, window, unix_time)?;
+  }
+  swap_record.record_parent_to_child(
+    outcome.amount,
+    outcome.retained_amount()?,
+    outcome.output_amount,
+    child_entangler.refund_window_seconds,
+    unix_time,
+  )?;
+  child_entangler.accrued_child_fee_amount =
+    math::add(child_entangler.accrued_child_fee_amount, outcome.fee_amount)?;
+  child_entangler.recorded_parent_to_child_amount = math::add(
+    child_entangler.recorded_parent_to_child_amount,
+    outcome.amount,
+  )?;
+
+  swap_record.exit(&crate::ID)
+}
+
+/// Moves the tokens of a parent to child swap and updates the child's totals. Recording the swap,
+/// and with it accruing the fee, is left to the caller
+pub fn execute_parent_for_child<'info>(
+  accounts: SwapAccounts<'_, 'info>,
+  split_accounts: &[AccountInfo<'info>],
+  clock: &Clock,
+  args: &SwapV0Args,
+) -> Result<SwapOutcome> {
+  if accounts.vested {
+    require!(
+      accounts.child_entangler.vesting.is_some(),
+      ErrorCode::NoVesting
+    );
+  } else {
+    require!(
+      accounts.child_entangler.vesting.is_none(),
+      ErrorCode::VestingRequired
+    );
+  }
+  if accounts.locked {
+    require!(
+      accounts.child_entangler.min_lock_seconds.is_some(),
+      ErrorCode::NoLock
+    );
+  } else {
+    require!(
+      accounts.child_entangler.min_lock_seconds.is_none(),
+      ErrorCode::LockRequired
+    );
+  }
+
+  let rate = apply_rate_schedule(
+    accounts.child_entangler,
+    accounts.rate_schedule,
+    clock.unix_timestamp,
+  )?;
+  let swap_amount = swap_shared_logic(
+    accounts.parent_entangler,
+    accounts.child_entangler,
+    &rate,
+    SwapDirection::ParentToChild,
+    accounts.child_storage,
+    swappable_amount(accounts.source, accounts.source_authority.key)?,
+    accounts.revealed_commit,
+    accounts.queued,
+    clock,
+    args,
+  )?;
+  accounts.child_entangler.record_drawdown(
+    SwapDirection::ParentToChild,
+    swap_amount.output_amount,
+    clock.unix_timestamp,
+  )?;
+  let burn = burn_amounts(&accounts.child_entangler.burn, &swap_amount)?;
+  #[cfg(feature = "strict-invariants")]
+  let snapshot = SwapSnapshot::take(
+    accounts.parent_storage,
+    accounts.child_storage,
+    accounts.parent_mint,
+    accounts.child_mint,
+  );
+  let amount = swap_amount.amount;
+  let output_amount = math::sub(swap_amount.output_amount, burn.output)?;
 
-  let parent_entangler = ctx.accounts.common.parent_entangler.to_account_info();
-  let child_entangler = &mut ctx.accounts.common.child_entangler;
-  let source = ctx.accounts.common.source.to_account_info();
-  let destination = ctx.accounts.common.destination.to_account_info();
-  let parent_storage = ctx.accounts.common.parent_storage.to_account_info();
-  let child_storage = ctx.accounts.common.child_storage.to_account_info();
-  let source_authority = ctx.accounts.common.source_authority.to_account_info();
-  let token_program = ctx.accounts.common.token_program.to_account_info();
+  let parent_entangler = &*accounts.parent_entangler;
+  let child_entangler = &*accounts.child_entangler;
+  let source = accounts.source.to_account_info();
+  let destination = accounts.destination.clone();
+  let parent_storage = accounts.parent_storage.to_account_info();
+  let child_storage = accounts.child_storage.to_account_info();
+  let source_authority = accounts.source_authority.clone();
+  let token_program = accounts.token_program.clone();
 
   msg!("Swapping out {} from source to parent storage", amount);
   token::transfer(
-    CpiContext::new(
+    CpiContext::new_with_signer(
       token_program.clone(),
       Transfer {
         from: source.clone(),
         to: parent_storage.clone(),
         authority: source_authority.clone(),
       },
+      accounts.source_signer_seeds,
     ),
     amount,
   )?;
 
-  let child_entangler_seeds: &[&[&[u8]]] = &[&[
-    b"entangler",
-    parent_entangler.key.as_ref(),
-    child_entangler.child_mint.as_ref(),
-    &[child_entangler.bump_seed],
-  ]];
+  let parent_entangler_seeds: &[&[&[u8]]] = &[&parent_entangler.signer_seeds()];
+  let child_entangler_seeds: &[&[&[u8]]] = &[&child_entangler.signer_seeds()];
 
-  msg!("Swapping out {} from child storage to source", amount);
-  token::transfer(
+  burn_from_storage(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      Burn {
+        mint: accounts.parent_mint.to_account_info().clone(),
+        from: parent_storage.clone(),
+        authority: parent_entangler.to_account_info().clone(),
+      },
+      parent_entangler_seeds,
+    ),
+    burn.input,
+  )?;
+  burn_from_storage(
+    CpiContext::new_with_signer(
+      token_program.clone(),
+      Burn {
+        mint: accounts.child_mint.to_account_info().clone(),
+        from: child_storage.clone(),
+        authority: child_entangler.to_account_info().clone(),
+      },
+      child_entangler_seeds,
+    ),
+    burn.output,
+  )?;
+
+  msg!(
+    "Swapping out {} from child storage to source",
+    output_amount
+  );
+  transfer_output(
     CpiContext::new_with_signer(
       token_program.clone(),
       Transfer {
@@ -62,8 +221,51 @@ pub fn handler(ctx: Context<SwapParentForChildV0>, args: SwapV0Args) -> Result<(
       },
       child_entangler_seeds,
     ),
+    split_accounts,
+    &args.output_splits_bps,
+    output_amount,
+  )?;
+
+  #[cfg(feature = "strict-invariants")]
+  snapshot.check(
+    &rate,
+    accounts.parent_storage,
+    accounts.child_storage,
+    accounts.parent_mint,
+    accounts.child_mint,
+    SwapDirection::ParentToChild,
+    &swap_amount,
+    &burn,
+  )?;
+
+  let fee = fee_amount(&rate, SwapDirection::ParentToChild, &swap_amount)?;
+  accounts.child_entangler.total_parent_to_child_amount = math::add(
+    accounts.child_entangler.total_parent_to_child_amount,
     amount,
   )?;
+  accounts
+    .child_entangler
+    .parent_to_child_volume
+    .record(amount, clock.unix_timestamp);
+
+  emit_noop(
+    accounts.noop_program,
+    &SwapEventV0 {
+      parent_entangler: accounts.parent_entangler.key(),
+      child_entangler: accounts.child_entangler.key(),
+      source_authority: accounts.source_authority.key(),
+      parent_to_child: true,
+      amount,
+      output_amount,
+      fee_amount: fee,
+      unix_time: clock.unix_timestamp,
+    },
+  )?;
 
-  Ok(())
+  Ok(SwapOutcome {
+    amount,
+    output_amount,
+    fee_amount: fee,
+    input_burn_amount: burn.input,
+  })
 }
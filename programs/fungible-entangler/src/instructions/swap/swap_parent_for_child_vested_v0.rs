@@ -0,0 +1,173 @@
+use super::{
+  account::SwapAccounts,
+  arg::SwapV0Args,
+  common::{return_fill, send_memo},
+  swap_parent_for_child_v0::{execute_parent_for_child, record_parent_for_child},
+};
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  instructions::initialize_fungible_child_entangler_v0::VESTING_SIZE,
+  math,
+  state::*,
+  util::{immediate_caller, init_pda_token_account_if_needed},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(args: SwapV0Args)]
+pub struct SwapParentForChildVestedV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    has_one = parent_mint @ ErrorCode::ParentMintMismatch,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(mut,
+    constraint = !parent_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub parent_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub parent_mint: Box<Account<'info, Mint>>,
+  #[account(mut,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    has_one = child_mint @ ErrorCode::ChildMintMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut,
+    constraint = !child_storage.is_frozen() @ ErrorCode::StorageAccountFrozen,
+  )]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub child_mint: Box<Account<'info, Mint>>,
+  /// CHECK: Created if missing in the handler as a child_mint token account owned by
+  /// child_entangler
+  #[account(
+    mut,
+    seeds = [b"vesting-storage", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub vesting_storage: UncheckedAccount<'info>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = VESTING_SIZE,
+    seeds = [b"vesting", child_entangler.key().as_ref(), source_authority.key().as_ref()],
+    bump,
+  )]
+  pub vesting: Box<Account<'info, VestingV0>>,
+  #[account(mut,
+    constraint = !source.is_frozen() @ ErrorCode::SourceAccountFrozen,
+  )]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub source_authority: Signer<'info>,
+  /// CHECK: The source authority's SwapRecordV0, created via InitializeSwapRecordV0. Only read when the
+  /// child tracks swaps
+  #[account(
+    mut,
+    seeds = [b"swap-record", child_entangler.key().as_ref(), source_authority.key().as_ref()],
+    bump,
+  )]
+  pub swap_record: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    constraint = !global_config.paused @ ErrorCode::GloballyPaused,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  /// CHECK: The instructions sysvar, read to enforce the child's CPI policy
+  #[account(
+    address = sysvar::instructions::ID,
+    constraint = child_entangler.allows_caller(immediate_caller(&instructions)?) @ ErrorCode::CpiNotAllowed,
+  )]
+  pub instructions: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  /// CHECK: The SPL memo program, only invoked when the swap carries a memo
+  #[account(address = spl_memo::id())]
+  pub memo_program: UncheckedAccount<'info>,
+  /// CHECK: The child's RateScheduleV0, left uninitialized when it has no schedule
+  #[account(
+    seeds = [b"rate-schedule", child_entangler.key().as_ref()],
+    bump,
+  )]
+  pub rate_schedule: UncheckedAccount<'info>,
+  /// CHECK: spl-noop, which swap events are logged through
+  #[account(address = noop::ID)]
+  pub noop_program: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, SwapParentForChildVestedV0<'info>>,
+  args: SwapV0Args,
+) -> Result<()> {
+  require!(
+    args.output_splits_bps.is_empty(),
+    ErrorCode::OutputSplitsNotSupported
+  );
+
+  let clock = Clock::get()?;
+  let accounts = &mut *ctx.accounts;
+  let vesting_storage = accounts.vesting_storage.to_account_info();
+  let child_entangler_key = accounts.child_entangler.key();
+  init_pda_token_account_if_needed(
+    &accounts.payer.to_account_info(),
+    &accounts.system_program.to_account_info(),
+    &accounts.token_program.to_account_info(),
+    &vesting_storage,
+    &accounts.child_mint.to_account_info(),
+    &child_entangler_key,
+    &[
+      b"vesting-storage",
+      child_entangler_key.as_ref(),
+      &[*ctx.bumps.get("vesting_storage").unwrap()],
+    ],
+  )?;
+  let outcome = execute_parent_for_child(
+    SwapAccounts {
+      parent_entangler: &mut accounts.parent_entangler,
+      parent_storage: &mut accounts.parent_storage,
+      parent_mint: &mut accounts.parent_mint,
+      child_entangler: &mut accounts.child_entangler,
+      child_storage: &mut accounts.child_storage,
+      child_mint: &mut accounts.child_mint,
+      source: &accounts.source,
+      source_authority: &accounts.source_authority,
+      source_signer_seeds: &[],
+      destination: &vesting_storage,
+      rate_schedule: &accounts.rate_schedule,
+      token_program: &accounts.token_program,
+      noop_program: &accounts.noop_program,
+      revealed_commit: false,
+      vested: true,
+      locked: false,
+      queued: false,
+    },
+    &[],
+    &clock,
+    &args,
+  )?;
+
+  let vesting = &mut accounts.vesting;
+  vesting.child_entangler = accounts.child_entangler.key();
+  vesting.owner = accounts.source_authority.key();
+  vesting.total_amount = math::add(vesting.total_amount, outcome.output_amount)?;
+  vesting.bump_seed = *ctx.bumps.get("vesting").unwrap();
+
+  record_parent_for_child(
+    &accounts.swap_record,
+    &mut accounts.child_entangler,
+    &outcome,
+    clock.unix_timestamp,
+  )?;
+
+  return_fill(&args, &outcome)?;
+  send_memo(&accounts.memo_program, &args.memo)
+}
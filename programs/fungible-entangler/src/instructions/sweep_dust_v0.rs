@@ -0,0 +1,117 @@
+use crate::{
+  error::ErrorCode,
+  instructions::swap::common::{min_swap_output, SwapDirection},
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
+
+#[derive(Accounts)]
+pub struct SweepDustV0<'info> {
+  pub sweeper: Signer<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+  #[account(
+    constraint = parent_entangler.authority.map_or(true, |authority| authority == sweeper.key()) @ ErrorCode::InvalidAuthority,
+    constraint = !parent_entangler.are_withdrawals_frozen(Clock::get()?.unix_timestamp) @ ErrorCode::WithdrawalsFrozen,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+  #[account(
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  /// Either the parent's or the child's storage
+  #[account(
+    mut,
+    constraint = storage.key() == parent_entangler.parent_storage || storage.key() == child_entangler.child_storage @ ErrorCode::StorageMismatch,
+  )]
+  pub storage: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+  pub token_program: Program<'info, Token>,
+}
+
+/// What swaps can't take out of amount, given the smallest output one swap pays
+fn unreachable_amount(amount: u64, min_output: Option<u64>) -> u64 {
+  match min_output {
+    Some(min_output) if amount >= min_output => 0,
+    _ => amount,
+  }
+}
+
+/// Sweeps a storage balance smaller than anything a swap through the child pays out at its current
+/// rate. Only the parent's authority may sweep, or anyone once the parent is sealed, in which case
+/// the dust goes to the global config's fee destination
+pub fn handler(ctx: Context<SweepDustV0>) -> Result<()> {
+  let parent_entangler = &ctx.accounts.parent_entangler;
+  let child_entangler = &ctx.accounts.child_entangler;
+  let is_authority = parent_entangler.authority == Some(ctx.accounts.sweeper.key());
+  require!(
+    is_authority || ctx.accounts.destination.owner == ctx.accounts.global_config.fee_destination,
+    ErrorCode::InvalidDustDestination
+  );
+
+  let token_program = ctx.accounts.token_program.to_account_info();
+  let storage = ctx.accounts.storage.to_account_info();
+  let destination = ctx.accounts.destination.to_account_info();
+  if storage.key() == parent_entangler.parent_storage {
+    // Other children swap out of the same parent storage
+    let dust = if parent_entangler.num_children == 1 {
+      unreachable_amount(
+        ctx
+          .accounts
+          .storage
+          .amount
+          .saturating_sub(parent_entangler.reserved_parent_fee_amount),
+        min_swap_output(child_entangler, SwapDirection::ChildToParent),
+      )
+    } else {
+      0
+    };
+    require!(dust > 0, ErrorCode::NoDust);
+
+    msg!("Sweeping {} of dust from parent storage", dust);
+    transfer_signed(
+      &token_program,
+      &storage,
+      &destination,
+      &parent_entangler.to_account_info(),
+      &parent_entangler.signer_seeds(),
+      dust,
+    )?;
+  } else {
+    // Once expired, what is left in storage belongs to the TopOff depositors
+    require!(
+      child_entangler.total_top_off_amount == 0
+        || !child_entangler.is_expired(Clock::get()?.unix_timestamp),
+      ErrorCode::Expired
+    );
+    let dust = unreachable_amount(
+      ctx
+        .accounts
+        .storage
+        .amount
+        .saturating_sub(child_entangler.reserved_child_fee_amount()),
+      min_swap_output(child_entangler, SwapDirection::ParentToChild),
+    );
+    require!(dust > 0, ErrorCode::NoDust);
+
+    msg!("Sweeping {} of dust from child storage", dust);
+    transfer_signed(
+      &token_program,
+      &storage,
+      &destination,
+      &child_entangler.to_account_info(),
+      &child_entangler.signer_seeds(),
+      dust,
+    )?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::{
+  error::ErrorCode, instructions::initialize_fungible_child_entangler_v0::TOP_OFF_RECORD_SIZE,
+  math, state::*, util::load_rewards_pool,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TopOffChildStorageV0Args {
+  pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: TopOffChildStorageV0Args)]
+pub struct TopOffChildStorageV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = child_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub child_entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
+  #[account(mut)]
+  pub child_storage: Box<Account<'info, TokenAccount>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = TOP_OFF_RECORD_SIZE,
+    seeds = [b"top-off", child_entangler.key().as_ref(), depositor.key().as_ref()],
+    bump,
+  )]
+  pub top_off_record: Box<Account<'info, TopOffRecordV0>>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub depositor: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TopOffChildStorageV0>, args: TopOffChildStorageV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  let child_entangler = &mut ctx.accounts.child_entangler;
+  let top_off_record = &mut ctx.accounts.top_off_record;
+
+  require!(
+    !child_entangler.is_expired(clock.unix_timestamp),
+    ErrorCode::Expired
+  );
+  require!(args.amount > 0, ErrorCode::InvalidAmount);
+
+  top_off_record.child_entangler = child_entangler.key();
+  top_off_record.depositor = ctx.accounts.depositor.key();
+
+  let rewards_pool = load_rewards_pool(child_entangler, ctx.remaining_accounts)?;
+  if let Some(mut rewards_pool) = rewards_pool {
+    rewards_pool.update(clock.unix_timestamp)?;
+    rewards_pool.settle(top_off_record)?;
+    rewards_pool.total_staked = math::add(rewards_pool.total_staked, args.amount)?;
+    rewards_pool.exit(ctx.program_id)?;
+  }
+
+  top_off_record.amount = math::add(top_off_record.amount, args.amount)?;
+  top_off_record.bump_seed = *ctx.bumps.get("top_off_record").unwrap();
+  child_entangler.total_top_off_amount =
+    math::add(child_entangler.total_top_off_amount, args.amount)?;
+
+  msg!("Topping off child storage with {}", args.amount);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info().clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.child_storage.to_account_info().clone(),
+        authority: ctx.accounts.depositor.to_account_info().clone(),
+      },
+    ),
+    args.amount,
+  )?;
+
+  Ok(())
+}
@@ -1,20 +1,24 @@
 use crate::{error::ErrorCode, state::*};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
 
 #[derive(Accounts)]
 pub struct TransferChildStorageV0<'info> {
   pub authority: Signer<'info>,
   #[account(
     mut,
-    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key(),
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = !parent_entangler.are_withdrawals_frozen(Clock::get()?.unix_timestamp) @ ErrorCode::WithdrawalsFrozen,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
   #[account(
-    has_one = parent_entangler,
-    has_one = child_storage,
+    has_one = parent_entangler @ ErrorCode::ParentEntanglerMismatch,
+    has_one = child_storage @ ErrorCode::StorageMismatch,
+    constraint = entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub entangler: Box<Account<'info, FungibleChildEntanglerV0>>,
+  pub entangler: Box<Account<'info, FungibleChildEntanglerV1>>,
   #[account(mut)]
   pub child_storage: Box<Account<'info, TokenAccount>>,
   #[account(mut)]
@@ -32,24 +36,34 @@ pub fn handler(
   args: TransferChildStorageArgsV0,
 ) -> Result<()> {
   let entangler = &mut ctx.accounts.entangler;
-  let child_entangler_seeds: &[&[&[u8]]] = &[&[
-    b"entangler",
-    entangler.parent_entangler.as_ref(),
-    entangler.child_mint.as_ref(),
-    &[entangler.bump_seed],
-  ]];
+  let storage_amount = ctx.accounts.child_storage.amount;
+
+  // Fees stay in storage for the swappers who paid them
+  require!(
+    args.amount <= storage_amount.saturating_sub(entangler.reserved_child_fee_amount()),
+    ErrorCode::FeeRebatesPending
+  );
+  // Storage up to what they deposited belongs to the TopOff depositors until they reclaim it
+  if entangler.is_expired(Clock::get()?.unix_timestamp)
+    || entangler.total_top_off_amount > entangler.reclaimed_top_off_amount
+  {
+    let owed = entangler.unreclaimed_top_off_amount(storage_amount);
+    require!(
+      args.amount
+        <= storage_amount
+          .saturating_sub(entangler.reserved_child_fee_amount())
+          .saturating_sub(owed),
+      ErrorCode::TopOffReclaimPending
+    );
+  }
 
   msg!("Transfering child storage {}", args.amount);
-  token::transfer(
-    CpiContext::new_with_signer(
-      ctx.accounts.token_program.to_account_info().clone(),
-      Transfer {
-        from: ctx.accounts.child_storage.to_account_info().clone(),
-        to: ctx.accounts.destination.to_account_info().clone(),
-        authority: entangler.to_account_info().clone(),
-      },
-      child_entangler_seeds,
-    ),
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.child_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &entangler.to_account_info(),
+    &entangler.signer_seeds(),
     args.amount,
   )?;
 
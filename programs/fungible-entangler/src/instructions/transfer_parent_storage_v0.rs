@@ -1,16 +1,19 @@
 use crate::{error::ErrorCode, state::*};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::transfer_signed;
 
 #[derive(Accounts)]
 pub struct TransferParentStorageV0<'info> {
   pub authority: Signer<'info>,
   #[account(
     mut,
-    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key(),
-    has_one = parent_storage,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    has_one = parent_storage @ ErrorCode::StorageMismatch,
+    constraint = !parent_entangler.are_withdrawals_frozen(Clock::get()?.unix_timestamp) @ ErrorCode::WithdrawalsFrozen,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
   )]
-  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV0>>,
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
   #[account(mut)]
   pub parent_storage: Box<Account<'info, TokenAccount>>,
   #[account(mut)]
@@ -28,24 +31,25 @@ pub fn handler(
   args: TransferParentStorageArgsV0,
 ) -> Result<()> {
   let entangler = &mut ctx.accounts.parent_entangler;
-  let parent_entangler_seeds: &[&[&[u8]]] = &[&[
-    b"entangler",
-    entangler.parent_mint.as_ref(),
-    &entangler.dynamic_seed,
-    &[entangler.bump_seed],
-  ]];
+
+  // Fees stay in storage for the swappers who paid them
+  require!(
+    args.amount
+      <= ctx
+        .accounts
+        .parent_storage
+        .amount
+        .saturating_sub(entangler.reserved_parent_fee_amount),
+    ErrorCode::FeeRebatesPending
+  );
 
   msg!("Transfering parent storage {}", args.amount);
-  token::transfer(
-    CpiContext::new_with_signer(
-      ctx.accounts.token_program.to_account_info().clone(),
-      Transfer {
-        from: ctx.accounts.parent_storage.to_account_info().clone(),
-        to: ctx.accounts.destination.to_account_info().clone(),
-        authority: entangler.to_account_info().clone(),
-      },
-      parent_entangler_seeds,
-    ),
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.parent_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &entangler.to_account_info(),
+    &entangler.signer_seeds(),
     args.amount,
   )?;
 
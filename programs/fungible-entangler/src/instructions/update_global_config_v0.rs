@@ -0,0 +1,38 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateGlobalConfigV0Args {
+  pub admin: Option<Pubkey>,
+  pub fee_destination: Option<Pubkey>,
+  pub creation_fee_lamports: Option<u64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateGlobalConfigV0Args)]
+pub struct UpdateGlobalConfigV0<'info> {
+  pub admin: Signer<'info>,
+  #[account(
+    mut,
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+    has_one = admin @ ErrorCode::InvalidAuthority,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+}
+
+pub fn handler(ctx: Context<UpdateGlobalConfigV0>, args: UpdateGlobalConfigV0Args) -> Result<()> {
+  let global_config = &mut ctx.accounts.global_config;
+
+  if let Some(admin) = args.admin {
+    global_config.admin = admin;
+  }
+  if let Some(fee_destination) = args.fee_destination {
+    global_config.fee_destination = fee_destination;
+  }
+  if let Some(creation_fee_lamports) = args.creation_fee_lamports {
+    global_config.creation_fee_lamports = creation_fee_lamports;
+  }
+
+  Ok(())
+}
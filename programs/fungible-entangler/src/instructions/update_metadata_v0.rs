@@ -0,0 +1,30 @@
+use crate::{error::ErrorCode, state::*, util::validate_metadata};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateMetadataV0Args {
+  pub name: String,
+  pub uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateMetadataV0Args)]
+pub struct UpdateMetadataV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    mut,
+    constraint = parent_entangler.authority.ok_or(error!(ErrorCode::NoAuthority))? == authority.key() @ ErrorCode::InvalidAuthority,
+    constraint = parent_entangler.is_supported_version() @ ErrorCode::UnsupportedVersion,
+  )]
+  pub parent_entangler: Box<Account<'info, FungibleParentEntanglerV1>>,
+}
+
+pub fn handler(ctx: Context<UpdateMetadataV0>, args: UpdateMetadataV0Args) -> Result<()> {
+  validate_metadata(&args.name, &args.uri)?;
+
+  let parent_entangler = &mut ctx.accounts.parent_entangler;
+  parent_entangler.name = args.name;
+  parent_entangler.uri = args.uri;
+
+  Ok(())
+}
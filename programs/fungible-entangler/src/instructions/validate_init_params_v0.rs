@@ -0,0 +1,239 @@
+use super::{
+  initialize_entangler_with_children_v0::{
+    InitializeEntanglerWithChildrenV0Args, ACCOUNTS_PER_CHILD, MAX_CHILDREN_PER_INIT,
+  },
+  initialize_fungible_child_entangler_v0::{
+    validate_child_entangler_args, InitializeFungibleChildEntanglerV0Args, CHILD_ENTANGLER_SIZE,
+    ENTANGLER_LOOKUP_SIZE,
+  },
+  initialize_fungible_parent_entangler_v0::{
+    validate_parent_entangler_args, InitializeFungibleParentEntanglerV0Args, PARENT_ENTANGLER_SIZE,
+  },
+};
+use crate::{error::ErrorCode, state::*, util::hash_seed};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::set_return_data, program_pack::Pack},
+};
+use anchor_spl::token::{self, Mint};
+
+/// Return data of ValidateInitParamsV0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
+pub struct InitValidationV0 {
+  // Error codes initializing with these args would fail with, or that flag a likely
+  // misconfiguration. Empty when the parent is good to go
+  pub parent_issues: Vec<u32>,
+  // Issues of each entry of child_args, in order
+  pub child_issues: Vec<Vec<u32>>,
+  pub parent_decimals: u8,
+  pub child_decimals: Vec<u8>,
+  // What the payer would spend, in creation fees and in rent for the accounts created
+  pub creation_fee_lamports: u64,
+  pub rent_lamports: u64,
+}
+
+/// Remaining accounts are, for each entry of `child_args` in order:
+/// child mint, child entangler, child storage, lookup. The same as InitializeEntanglerWithChildrenV0
+#[derive(Accounts)]
+#[instruction(args: InitializeEntanglerWithChildrenV0Args)]
+pub struct ValidateInitParamsV0<'info> {
+  /// CHECK: Inspected as a mint, problems are reported rather than failing
+  pub parent_mint: UncheckedAccount<'info>,
+  /// CHECK: Only checked for already existing
+  #[account(
+    seeds = [b"entangler", parent_mint.key().as_ref(), hash_seed(&args.parent_args.dynamic_seed).as_ref()],
+    bump,
+  )]
+  pub entangler: UncheckedAccount<'info>,
+  #[account(
+    seeds = [b"global-config"],
+    bump = global_config.bump_seed,
+  )]
+  pub global_config: Box<Account<'info, GlobalConfigV0>>,
+}
+
+/// Checks what InitializeEntanglerWithChildrenV0, or the parent and child initializations it
+/// combines, would do with args without creating anything. Every problem found is returned
+/// through InitValidationV0 instead of failing, so deployment tooling can simulate it once
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, ValidateInitParamsV0<'info>>,
+  args: InitializeEntanglerWithChildrenV0Args,
+) -> Result<()> {
+  let clock = Clock::get()?;
+  let rent = Rent::get()?;
+  require!(
+    args.child_args.len() <= MAX_CHILDREN_PER_INIT
+      && ctx.remaining_accounts.len() == args.child_args.len() * ACCOUNTS_PER_CHILD,
+    ErrorCode::InvalidChildAccounts
+  );
+
+  let parent_args = &args.parent_args;
+  let mut parent_issues = vec![];
+  check(
+    &mut parent_issues,
+    validate_parent_entangler_args(parent_args),
+  );
+  expect(
+    &mut parent_issues,
+    ctx.accounts.entangler.data_is_empty(),
+    ErrorCode::AccountAlreadyInitialized,
+  );
+  let parent_mint = load_mint(&ctx.accounts.parent_mint, &mut parent_issues);
+  if let Some(parent_mint) = &parent_mint {
+    expect(
+      &mut parent_issues,
+      !parent_args.reject_freeze_authority || parent_mint.freeze_authority.is_none(),
+      ErrorCode::MintHasFreezeAuthority,
+    );
+  }
+  let go_live = parent_args.go_live_unix_time.max(clock.unix_timestamp);
+  expect(
+    &mut parent_issues,
+    parent_times_follow(parent_args, go_live),
+    ErrorCode::InvalidTimestampOrder,
+  );
+
+  let parent_key = ctx.accounts.entangler.key();
+  let parent_mint_key = ctx.accounts.parent_mint.key();
+  let mut child_issues = vec![];
+  let mut child_decimals = vec![];
+  let mut lookup_rent = 0;
+  for (child_args, accounts) in args
+    .child_args
+    .iter()
+    .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_CHILD))
+  {
+    let child_mint_key = accounts[0].key();
+    let (entangler_key, _) = Pubkey::find_program_address(
+      &[b"entangler", parent_key.as_ref(), child_mint_key.as_ref()],
+      ctx.program_id,
+    );
+    let (storage_key, _) =
+      Pubkey::find_program_address(&[b"storage", entangler_key.as_ref()], ctx.program_id);
+    let (lookup_key, _) = Pubkey::find_program_address(
+      &[b"lookup", parent_mint_key.as_ref(), child_mint_key.as_ref()],
+      ctx.program_id,
+    );
+    require!(
+      accounts[1].key() == entangler_key
+        && accounts[2].key() == storage_key
+        && accounts[3].key() == lookup_key,
+      ErrorCode::InvalidChildAccounts
+    );
+
+    let mut issues = vec![];
+    check(&mut issues, validate_child_entangler_args(child_args));
+    expect(
+      &mut issues,
+      accounts[1].data_is_empty(),
+      ErrorCode::AccountAlreadyInitialized,
+    );
+    // Another parent's child of the same mints may already hold the lookup
+    if accounts[3].data_is_empty() {
+      lookup_rent += rent.minimum_balance(ENTANGLER_LOOKUP_SIZE);
+    }
+    expect(
+      &mut issues,
+      child_mint_key != parent_mint_key,
+      ErrorCode::SameMint,
+    );
+    let child_mint = load_mint(&accounts[0], &mut issues);
+    if let Some(child_mint) = &child_mint {
+      expect(
+        &mut issues,
+        !child_args.reject_freeze_authority || child_mint.freeze_authority.is_none(),
+        ErrorCode::MintHasFreezeAuthority,
+      );
+    }
+    if let (Some(parent_mint), Some(child_mint)) = (&parent_mint, &child_mint) {
+      let rate = child_args
+        .rate
+        .or(parent_args.default_rate)
+        .unwrap_or_default();
+      expect(
+        &mut issues,
+        parent_mint.decimals == child_mint.decimals || rate.parent_units != rate.child_units,
+        ErrorCode::DecimalsMismatch,
+      );
+    }
+    expect(
+      &mut issues,
+      child_times_follow(
+        child_args,
+        child_args.go_live_unix_time.max(clock.unix_timestamp),
+      ),
+      ErrorCode::InvalidTimestampOrder,
+    );
+
+    child_issues.push(issues);
+    child_decimals.push(child_mint.map_or(0, |mint| mint.decimals));
+  }
+
+  let storage_rent = rent.minimum_balance(spl_token::state::Account::LEN);
+  let child_rent = rent.minimum_balance(CHILD_ENTANGLER_SIZE) + storage_rent;
+  let entanglers = args.child_args.len() as u64 + 1;
+  let validation = InitValidationV0 {
+    parent_issues,
+    child_issues,
+    parent_decimals: parent_mint.map_or(0, |mint| mint.decimals),
+    child_decimals,
+    creation_fee_lamports: ctx
+      .accounts
+      .global_config
+      .creation_fee_lamports
+      .saturating_mul(entanglers),
+    rent_lamports: rent.minimum_balance(PARENT_ENTANGLER_SIZE)
+      + storage_rent
+      + child_rent * args.child_args.len() as u64
+      + lookup_rent,
+  };
+  set_return_data(&validation.try_to_vec()?);
+
+  Ok(())
+}
+
+fn check(issues: &mut Vec<u32>, result: Result<()>) {
+  if let Err(error) = result {
+    issues.push(match error {
+      Error::AnchorError(error) => error.error_code_number,
+      Error::ProgramError(_) => ErrorCode::InvalidArgs.into(),
+    });
+  }
+}
+
+fn expect(issues: &mut Vec<u32>, ok: bool, error: ErrorCode) {
+  if !ok {
+    issues.push(error.into());
+  }
+}
+
+/// The mint, if it is an initialized SPL token mint. Token extension mints can't back storage
+fn load_mint(mint: &AccountInfo, issues: &mut Vec<u32>) -> Option<Mint> {
+  if mint.owner != &token::ID {
+    issues.push(ErrorCode::UnsupportedMint.into());
+    return None;
+  }
+
+  let loaded = Mint::try_deserialize(&mut &mint.data.borrow()[..]).ok();
+  if loaded.is_none() {
+    issues.push(ErrorCode::MintNotInitialized.into());
+  }
+  loaded
+}
+
+fn after(unix_time: Option<i64>, go_live: i64) -> bool {
+  unix_time.map_or(true, |time| time > go_live)
+}
+
+fn parent_times_follow(args: &InitializeFungibleParentEntanglerV0Args, go_live: i64) -> bool {
+  after(args.freeze_swap_unix_time, go_live)
+    && after(args.freeze_parent_to_child_unix_time, go_live)
+    && after(args.freeze_child_to_parent_unix_time, go_live)
+}
+
+fn child_times_follow(args: &InitializeFungibleChildEntanglerV0Args, go_live: i64) -> bool {
+  after(args.freeze_swap_unix_time, go_live)
+    && after(args.freeze_parent_to_child_unix_time, go_live)
+    && after(args.freeze_child_to_parent_unix_time, go_live)
+    && after(args.expiry_unix_time, go_live)
+}
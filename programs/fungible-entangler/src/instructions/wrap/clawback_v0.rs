@@ -0,0 +1,52 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use strata_spl_utils::token_2022::{self, Token2022};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ClawbackV0Args {
+  pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClawbackV0Args)]
+pub struct ClawbackV0<'info> {
+  pub clawback_authority: Signer<'info>,
+  #[account(
+    has_one = wrapped_mint @ ErrorCode::WrappedMintMismatch,
+    constraint = wrapper.clawback_authority.ok_or(error!(ErrorCode::NoAuthority))? == clawback_authority.key() @ ErrorCode::InvalidAuthority,
+  )]
+  pub wrapper: Box<Account<'info, WrappedMintV0>>,
+  /// CHECK: Checked by has_one on wrapper, and for the wrapper as its permanent delegate
+  #[account(owner = token_2022::ID)]
+  pub wrapped_mint: UncheckedAccount<'info>,
+  /// CHECK: Token-2022 account of the wrapped mint, checked in cpi
+  #[account(mut)]
+  pub source: UncheckedAccount<'info>,
+  /// CHECK: Token-2022 account of the wrapped mint, checked in cpi
+  #[account(mut)]
+  pub destination: UncheckedAccount<'info>,
+
+  pub token_2022_program: Program<'info, Token2022>,
+}
+
+/// Moves wrapped tokens from any holder to destination, with the wrapper signing as the wrapped
+/// mint's permanent delegate. The underlying tokens stay in storage backing them
+pub fn handler(ctx: Context<ClawbackV0>, args: ClawbackV0Args) -> Result<()> {
+  let wrapper = &ctx.accounts.wrapper;
+  require!(
+    token_2022::permanent_delegate(&ctx.accounts.wrapped_mint.try_borrow_data()?)
+      == Some(wrapper.key()),
+    ErrorCode::NotPermanentDelegate
+  );
+
+  msg!("Clawing back {} wrapped tokens", args.amount);
+  token_2022::transfer_checked_signed(
+    &ctx.accounts.token_2022_program.to_account_info(),
+    &ctx.accounts.source.to_account_info(),
+    &ctx.accounts.wrapped_mint.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &wrapper.to_account_info(),
+    &wrapper.signer_seeds(),
+    args.amount,
+  )
+}
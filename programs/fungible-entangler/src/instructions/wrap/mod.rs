@@ -0,0 +1,9 @@
+pub mod clawback_v0;
+pub mod unwrap_v0;
+pub mod wrap_mint_v0;
+pub mod wrap_v0;
+
+pub use clawback_v0::*;
+pub use unwrap_v0::*;
+pub use wrap_mint_v0::*;
+pub use wrap_v0::*;
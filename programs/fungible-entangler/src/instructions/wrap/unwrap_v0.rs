@@ -0,0 +1,68 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use strata_spl_utils::{
+  token_2022::{self, Burn, Token2022},
+  transfer_signed,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UnwrapV0Args {
+  pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: UnwrapV0Args)]
+pub struct UnwrapV0<'info> {
+  #[account(
+    has_one = underlying_storage @ ErrorCode::StorageMismatch,
+    has_one = wrapped_mint @ ErrorCode::WrappedMintMismatch,
+  )]
+  pub wrapper: Box<Account<'info, WrappedMintV0>>,
+  #[account(mut)]
+  pub underlying_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Checked by has_one on wrapper
+  #[account(mut)]
+  pub wrapped_mint: UncheckedAccount<'info>,
+  /// CHECK: Token-2022 account of the wrapped mint, checked in cpi
+  #[account(mut)]
+  pub source: UncheckedAccount<'info>,
+  pub source_authority: Signer<'info>,
+  #[account(mut)]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<UnwrapV0>, args: UnwrapV0Args) -> Result<()> {
+  let wrapper = &ctx.accounts.wrapper;
+
+  msg!("Burning {} wrapped tokens from source", args.amount);
+  token_2022::burn(
+    CpiContext::new(
+      ctx.accounts.token_2022_program.to_account_info().clone(),
+      Burn {
+        mint: ctx.accounts.wrapped_mint.to_account_info().clone(),
+        from: ctx.accounts.source.to_account_info().clone(),
+        authority: ctx.accounts.source_authority.to_account_info().clone(),
+      },
+    ),
+    args.amount,
+  )?;
+
+  msg!(
+    "Unwrapping {} from underlying storage to destination",
+    args.amount
+  );
+  transfer_signed(
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.underlying_storage.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &wrapper.to_account_info(),
+    &wrapper.signer_seeds(),
+    args.amount,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,167 @@
+use crate::{error::ErrorCode, state::*, util::create_pda_token_account};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{Mint, Token};
+use spl_token_2022::extension::{transfer_fee, ExtensionType};
+use strata_spl_utils::token_2022::{self, Token2022};
+
+pub const WRAPPED_MINT_SIZE: usize = 8 + // key
+32 + // underlying mint
+32 + // wrapped mint
+32 + // underlying storage
+3 + // transfer fee bps
+8 + // created
+1 + // bump
+1 + // storage bump
+33 + // clawback authority
+47; // padding
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TransferFeeArgsV0 {
+  pub basis_points: u16,
+  pub maximum_fee: u64,
+  // Authority that may update the fee and withdraw withheld fees
+  pub fee_authority: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct WrapMintV0Args {
+  pub transfer_fee: Option<TransferFeeArgsV0>,
+  // Compliance mode. Makes the wrapper the wrapped mint's permanent delegate, so that this
+  // authority can claw wrapped tokens back from any holder
+  pub clawback_authority: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: WrapMintV0Args)]
+pub struct WrapMintV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    init,
+    payer = payer,
+    space = WRAPPED_MINT_SIZE,
+    seeds = [b"wrapper", underlying_mint.key().as_ref()],
+    bump,
+  )]
+  pub wrapper: Box<Account<'info, WrappedMintV0>>,
+  /// CHECK: Created in the handler as an underlying_mint token account owned by wrapper
+  #[account(
+    mut,
+    seeds = [b"storage", wrapper.key().as_ref()],
+    bump,
+  )]
+  pub underlying_storage: UncheckedAccount<'info>,
+  #[account(constraint = underlying_mint.is_initialized @ ErrorCode::MintNotInitialized)]
+  pub underlying_mint: Box<Account<'info, Mint>>,
+  // Created and initialized as a token-2022 mint in this instruction
+  #[account(mut)]
+  pub wrapped_mint: Signer<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub token_2022_program: Program<'info, Token2022>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<WrapMintV0>, args: WrapMintV0Args) -> Result<()> {
+  let clock = Clock::get()?;
+  let wrapper = &mut ctx.accounts.wrapper;
+  let wrapped_mint = &ctx.accounts.wrapped_mint;
+  let token_2022_program = &ctx.accounts.token_2022_program;
+
+  let extensions = if args.transfer_fee.is_some() {
+    vec![ExtensionType::TransferFeeConfig]
+  } else {
+    vec![]
+  };
+  let space = token_2022::mint_len(&extensions, args.clawback_authority.is_some());
+
+  msg!("Creating wrapped mint");
+  invoke(
+    &system_instruction::create_account(
+      ctx.accounts.payer.key,
+      wrapped_mint.key,
+      Rent::get()?.minimum_balance(space),
+      space as u64,
+      &spl_token_2022::ID,
+    ),
+    &[
+      ctx.accounts.payer.to_account_info().clone(),
+      wrapped_mint.to_account_info().clone(),
+      ctx.accounts.system_program.to_account_info().clone(),
+    ],
+  )?;
+
+  if let Some(transfer_fee) = &args.transfer_fee {
+    require!(
+      transfer_fee.basis_points <= 10000,
+      ErrorCode::InvalidTransferFee
+    );
+    invoke(
+      &transfer_fee::instruction::initialize_transfer_fee_config(
+        &spl_token_2022::ID,
+        wrapped_mint.key,
+        Some(&transfer_fee.fee_authority),
+        Some(&transfer_fee.fee_authority),
+        transfer_fee.basis_points,
+        transfer_fee.maximum_fee,
+      )?,
+      &[
+        wrapped_mint.to_account_info().clone(),
+        token_2022_program.to_account_info().clone(),
+      ],
+    )?;
+  }
+
+  if args.clawback_authority.is_some() {
+    invoke(
+      &token_2022::initialize_permanent_delegate(wrapped_mint.key, &wrapper.key()),
+      &[
+        wrapped_mint.to_account_info().clone(),
+        token_2022_program.to_account_info().clone(),
+      ],
+    )?;
+  }
+
+  invoke(
+    &spl_token_2022::instruction::initialize_mint2(
+      &spl_token_2022::ID,
+      wrapped_mint.key,
+      &wrapper.key(),
+      None,
+      ctx.accounts.underlying_mint.decimals,
+    )?,
+    &[
+      wrapped_mint.to_account_info().clone(),
+      token_2022_program.to_account_info().clone(),
+    ],
+  )?;
+
+  let wrapper_key = wrapper.key();
+  create_pda_token_account(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.token_program.to_account_info(),
+    &ctx.accounts.underlying_storage.to_account_info(),
+    &ctx.accounts.underlying_mint.to_account_info(),
+    &wrapper_key,
+    &[
+      b"storage",
+      wrapper_key.as_ref(),
+      &[*ctx.bumps.get("underlying_storage").unwrap()],
+    ],
+  )?;
+
+  wrapper.underlying_mint = ctx.accounts.underlying_mint.key();
+  wrapper.wrapped_mint = wrapped_mint.key();
+  wrapper.underlying_storage = ctx.accounts.underlying_storage.key();
+  wrapper.transfer_fee_basis_points = args.transfer_fee.map(|fee| fee.basis_points);
+  wrapper.created_at_unix_time = clock.unix_timestamp;
+  wrapper.bump_seed = *ctx.bumps.get("wrapper").unwrap();
+  wrapper.storage_bump_seed = *ctx.bumps.get("underlying_storage").unwrap();
+  wrapper.clawback_authority = args.clawback_authority;
+
+  Ok(())
+}
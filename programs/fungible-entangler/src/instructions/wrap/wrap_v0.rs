@@ -0,0 +1,62 @@
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use strata_spl_utils::token_2022::{self, Token2022};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct WrapV0Args {
+  pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: WrapV0Args)]
+pub struct WrapV0<'info> {
+  #[account(
+    has_one = underlying_storage @ ErrorCode::StorageMismatch,
+    has_one = wrapped_mint @ ErrorCode::WrappedMintMismatch,
+  )]
+  pub wrapper: Box<Account<'info, WrappedMintV0>>,
+  #[account(mut)]
+  pub underlying_storage: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Checked by has_one on wrapper
+  #[account(mut)]
+  pub wrapped_mint: UncheckedAccount<'info>,
+  #[account(mut)]
+  pub source: Box<Account<'info, TokenAccount>>,
+  pub source_authority: Signer<'info>,
+  /// CHECK: Token-2022 account of the wrapped mint, checked in cpi
+  #[account(mut)]
+  pub destination: UncheckedAccount<'info>,
+
+  pub token_program: Program<'info, Token>,
+  pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<WrapV0>, args: WrapV0Args) -> Result<()> {
+  let wrapper = &ctx.accounts.wrapper;
+
+  msg!("Wrapping {} from source to underlying storage", args.amount);
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info().clone(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info().clone(),
+        to: ctx.accounts.underlying_storage.to_account_info().clone(),
+        authority: ctx.accounts.source_authority.to_account_info().clone(),
+      },
+    ),
+    args.amount,
+  )?;
+
+  msg!("Minting {} wrapped tokens to destination", args.amount);
+  token_2022::mint_to_signed(
+    &ctx.accounts.token_2022_program.to_account_info(),
+    &ctx.accounts.wrapped_mint.to_account_info(),
+    &ctx.accounts.destination.to_account_info(),
+    &wrapper.to_account_info(),
+    &wrapper.signer_seeds(),
+    args.amount,
+  )?;
+
+  Ok(())
+}
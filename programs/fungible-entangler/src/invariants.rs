@@ -0,0 +1,108 @@
+//! Conservation checks run after every swap when built with the `strict-invariants` feature.
+//! They reload the storage accounts and mints, so they are left out of release builds.
+
+use crate::{
+  instructions::swap::common::{BurnAmounts, SwapAmount, SwapDirection},
+  state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+/// Balances and supplies captured before any tokens move
+pub struct SwapSnapshot {
+  pub parent_storage: u64,
+  pub child_storage: u64,
+  pub parent_supply: u64,
+  pub child_supply: u64,
+}
+
+impl SwapSnapshot {
+  pub fn take(
+    parent_storage: &Account<TokenAccount>,
+    child_storage: &Account<TokenAccount>,
+    parent_mint: &Account<Mint>,
+    child_mint: &Account<Mint>,
+  ) -> Self {
+    Self {
+      parent_storage: parent_storage.amount,
+      child_storage: child_storage.amount,
+      parent_supply: parent_mint.supply,
+      child_supply: child_mint.supply,
+    }
+  }
+
+  /// Panics unless storage moved by exactly the swapped amounts and only the configured burns
+  /// changed supply
+  #[allow(clippy::too_many_arguments)]
+  pub fn check<'info>(
+    &self,
+    rate: &ExchangeRateV0,
+    parent_storage: &mut Account<'info, TokenAccount>,
+    child_storage: &mut Account<'info, TokenAccount>,
+    parent_mint: &mut Account<'info, Mint>,
+    child_mint: &mut Account<'info, Mint>,
+    direction: SwapDirection,
+    swap_amount: &SwapAmount,
+    burn: &BurnAmounts,
+  ) -> Result<()> {
+    parent_storage.reload()?;
+    child_storage.reload()?;
+    parent_mint.reload()?;
+    child_mint.reload()?;
+
+    let converted = match direction {
+      SwapDirection::ParentToChild => rate.child_for_parent(swap_amount.amount)?,
+      SwapDirection::ChildToParent => rate.parent_for_child(swap_amount.amount)?,
+    };
+    assert!(
+      swap_amount.output_amount <= converted,
+      "Swap output {} exceeds the converted amount {}",
+      swap_amount.output_amount,
+      converted
+    );
+
+    let (input_storage, input_before, output_storage, output_before) = match direction {
+      SwapDirection::ParentToChild => (
+        &*parent_storage,
+        self.parent_storage,
+        &*child_storage,
+        self.child_storage,
+      ),
+      SwapDirection::ChildToParent => (
+        &*child_storage,
+        self.child_storage,
+        &*parent_storage,
+        self.parent_storage,
+      ),
+    };
+    assert_eq!(
+      input_storage.amount as u128,
+      input_before as u128 + swap_amount.amount as u128 - burn.input as u128,
+      "Input storage does not match the swapped in amount"
+    );
+    // Output side burns come out of output_amount, the fee stays in storage
+    assert_eq!(
+      output_storage.amount as u128,
+      output_before as u128 - swap_amount.output_amount as u128,
+      "Output storage does not match the swapped out amount"
+    );
+
+    let (input_burned, output_burned) = (burn.input as u128, burn.output as u128);
+    let (parent_burned, child_burned) = match direction {
+      SwapDirection::ParentToChild => (input_burned, output_burned),
+      SwapDirection::ChildToParent => (output_burned, input_burned),
+    };
+    assert_eq!(
+      parent_mint.supply as u128,
+      self.parent_supply as u128 - parent_burned,
+      "Parent supply changed by more than the burn"
+    );
+    assert_eq!(
+      child_mint.supply as u128,
+      self.child_supply as u128 - child_burned,
+      "Child supply changed by more than the burn"
+    );
+
+    Ok(())
+  }
+}
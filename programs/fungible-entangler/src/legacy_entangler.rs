@@ -0,0 +1,127 @@
+//! CPIs into the original Strata fungible entangler, for importing its entanglers into a fork
+//! deployed under another program id. Built by hand from its Anchor discriminators and borsh
+//! layout, since it is not available as a crate
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+  self,
+  instruction::{AccountMeta, Instruction},
+};
+use anchor_lang::Accounts;
+
+pub mod program {
+  anchor_lang::declare_id!("fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB");
+}
+
+// sha256("global:<instruction name>")[..8]
+const TRANSFER_PARENT_STORAGE_V0: [u8; 8] = [140, 6, 181, 183, 35, 156, 177, 92];
+const TRANSFER_CHILD_STORAGE_V0: [u8; 8] = [73, 255, 139, 252, 48, 76, 110, 6];
+
+#[derive(Accounts)]
+pub struct TransferParentStorage<'info> {
+  /// CHECK: Checked with cpi
+  pub authority: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub parent_entangler: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub parent_storage: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub destination: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub token_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferChildStorage<'info> {
+  /// CHECK: Checked with cpi
+  pub authority: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub parent_entangler: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub entangler: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub child_storage: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub destination: AccountInfo<'info>,
+  /// CHECK: Checked with cpi
+  pub token_program: AccountInfo<'info>,
+}
+
+fn invoke_legacy<'info>(
+  program: AccountInfo<'info>,
+  accounts: Vec<AccountMeta>,
+  mut account_infos: Vec<AccountInfo<'info>>,
+  mut data: Vec<u8>,
+  amount: u64,
+) -> Result<()> {
+  data.extend_from_slice(&amount.to_le_bytes());
+  account_infos.push(program);
+
+  solana_program::program::invoke(
+    &Instruction {
+      program_id: program::ID,
+      accounts,
+      data,
+    },
+    &account_infos,
+  )
+  .map_err(|e| e.into())
+}
+
+/// Moves amount out of a legacy parent's storage. The legacy parent's authority must have signed
+/// the transaction
+pub fn transfer_parent_storage<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, TransferParentStorage<'info>>,
+  amount: u64,
+) -> Result<()> {
+  let a = &ctx.accounts;
+  invoke_legacy(
+    ctx.program.clone(),
+    vec![
+      AccountMeta::new_readonly(*a.authority.key, true),
+      AccountMeta::new(*a.parent_entangler.key, false),
+      AccountMeta::new(*a.parent_storage.key, false),
+      AccountMeta::new(*a.destination.key, false),
+      AccountMeta::new_readonly(*a.token_program.key, false),
+    ],
+    vec![
+      a.authority.clone(),
+      a.parent_entangler.clone(),
+      a.parent_storage.clone(),
+      a.destination.clone(),
+      a.token_program.clone(),
+    ],
+    TRANSFER_PARENT_STORAGE_V0.to_vec(),
+    amount,
+  )
+}
+
+/// Moves amount out of a legacy child's storage. The legacy parent's authority must have signed
+/// the transaction
+pub fn transfer_child_storage<'a, 'b, 'c, 'info>(
+  ctx: CpiContext<'a, 'b, 'c, 'info, TransferChildStorage<'info>>,
+  amount: u64,
+) -> Result<()> {
+  let a = &ctx.accounts;
+  invoke_legacy(
+    ctx.program.clone(),
+    vec![
+      AccountMeta::new_readonly(*a.authority.key, true),
+      AccountMeta::new(*a.parent_entangler.key, false),
+      AccountMeta::new_readonly(*a.entangler.key, false),
+      AccountMeta::new(*a.child_storage.key, false),
+      AccountMeta::new(*a.destination.key, false),
+      AccountMeta::new_readonly(*a.token_program.key, false),
+    ],
+    vec![
+      a.authority.clone(),
+      a.parent_entangler.clone(),
+      a.entangler.clone(),
+      a.child_storage.clone(),
+      a.destination.clone(),
+      a.token_program.clone(),
+    ],
+    TRANSFER_CHILD_STORAGE_V0.to_vec(),
+    amount,
+  )
+}
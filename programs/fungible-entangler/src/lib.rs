@@ -1,16 +1,39 @@
 #![allow(clippy::or_fun_call)]
 
 use anchor_lang::prelude::*;
+#[cfg(not(feature = "no-entrypoint"))]
+use solana_security_txt::security_txt;
 
+pub mod account_compression;
+pub mod compressed_token;
 pub mod error;
+pub mod events;
 pub mod instructions;
+#[cfg(feature = "strict-invariants")]
+pub mod invariants;
+pub mod legacy_entangler;
+pub mod math;
 pub mod state;
+pub mod token_metadata;
+pub mod token_swap;
 pub mod util;
 
 use instructions::*;
 
 declare_id!("fent99TYZcj9PGbeooaZXEMQzMd7rz8vYFiudd8HevB");
 
+#[cfg(not(feature = "no-entrypoint"))]
+security_txt! {
+  name: "Fungible Entangler",
+  project_url: "https://strataprotocol.com",
+  contacts: "link:https://github.com/StrataFoundation/strata/security",
+  policy: "https://github.com/StrataFoundation/strata/security/policy",
+  source_code: "https://github.com/StrataFoundation/strata",
+  source_revision: env!("GIT_COMMIT")
+}
+
+// Swap and lifecycle events are logged through spl-noop, see events.rs. Self-CPI events
+// (#[event_cpi]) need Anchor 0.28 or later, and this program is on 0.24
 #[program]
 pub mod fungible_entangler {
   use super::*;
@@ -28,15 +51,15 @@ pub mod fungible_entangler {
     instructions::initialize_fungible_child_entangler_v0::handler(ctx, args)
   }
 
-  pub fn swap_parent_for_child_v0(
-    ctx: Context<SwapParentForChildV0>,
+  pub fn swap_parent_for_child_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapParentForChildV0<'info>>,
     args: SwapV0Args,
   ) -> Result<()> {
     instructions::swap_parent_for_child_v0::handler(ctx, args)
   }
 
-  pub fn swap_child_for_parent_v0(
-    ctx: Context<SwapChildForParentV0>,
+  pub fn swap_child_for_parent_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapChildForParentV0<'info>>,
     args: SwapV0Args,
   ) -> Result<()> {
     instructions::swap_child_for_parent_v0::handler(ctx, args)
@@ -67,4 +90,473 @@ pub mod fungible_entangler {
   ) -> Result<()> {
     instructions::transfer_parent_storage_v0::handler(ctx, args)
   }
+
+  pub fn initialize_child_registry_v0(
+    ctx: Context<InitializeChildRegistryV0>,
+    args: InitializeChildRegistryV0Args,
+  ) -> Result<()> {
+    instructions::initialize_child_registry_v0::handler(ctx, args)
+  }
+
+  pub fn append_child_registry_v0(ctx: Context<AppendChildRegistryV0>) -> Result<()> {
+    instructions::append_child_registry_v0::handler(ctx)
+  }
+
+  pub fn remove_child_registry_v0(
+    ctx: Context<RemoveChildRegistryV0>,
+    args: RemoveChildRegistryV0Args,
+  ) -> Result<()> {
+    instructions::remove_child_registry_v0::handler(ctx, args)
+  }
+
+  pub fn update_metadata_v0(
+    ctx: Context<UpdateMetadataV0>,
+    args: UpdateMetadataV0Args,
+  ) -> Result<()> {
+    instructions::update_metadata_v0::handler(ctx, args)
+  }
+
+  pub fn mirror_child_metadata_v0(
+    ctx: Context<MirrorChildMetadataV0>,
+    args: MirrorChildMetadataV0Args,
+  ) -> Result<()> {
+    instructions::mirror_child_metadata_v0::handler(ctx, args)
+  }
+
+  pub fn initialize_child_mint_v0(
+    ctx: Context<InitializeChildMintV0>,
+    args: InitializeChildMintV0Args,
+  ) -> Result<()> {
+    instructions::initialize_child_mint_v0::handler(ctx, args)
+  }
+
+  pub fn wrap_mint_v0(ctx: Context<WrapMintV0>, args: WrapMintV0Args) -> Result<()> {
+    instructions::wrap::wrap_mint_v0::handler(ctx, args)
+  }
+
+  pub fn wrap_v0(ctx: Context<WrapV0>, args: WrapV0Args) -> Result<()> {
+    instructions::wrap::wrap_v0::handler(ctx, args)
+  }
+
+  pub fn unwrap_v0(ctx: Context<UnwrapV0>, args: UnwrapV0Args) -> Result<()> {
+    instructions::wrap::unwrap_v0::handler(ctx, args)
+  }
+
+  pub fn clawback_v0(ctx: Context<ClawbackV0>, args: ClawbackV0Args) -> Result<()> {
+    instructions::wrap::clawback_v0::handler(ctx, args)
+  }
+
+  pub fn swap_parent_for_child_vested_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapParentForChildVestedV0<'info>>,
+    args: SwapV0Args,
+  ) -> Result<()> {
+    instructions::swap_parent_for_child_vested_v0::handler(ctx, args)
+  }
+
+  pub fn claim_vested_v0(ctx: Context<ClaimVestedV0>) -> Result<()> {
+    instructions::claim_vested_v0::handler(ctx)
+  }
+
+  pub fn refund_swap_v0(ctx: Context<RefundSwapV0>, args: RefundSwapV0Args) -> Result<()> {
+    instructions::refund_swap_v0::handler(ctx, args)
+  }
+
+  pub fn top_off_child_storage_v0(
+    ctx: Context<TopOffChildStorageV0>,
+    args: TopOffChildStorageV0Args,
+  ) -> Result<()> {
+    instructions::top_off_child_storage_v0::handler(ctx, args)
+  }
+
+  pub fn reclaim_after_expiry_v0(ctx: Context<ReclaimAfterExpiryV0>) -> Result<()> {
+    instructions::reclaim_after_expiry_v0::handler(ctx)
+  }
+
+  pub fn initialize_rewards_pool_v0(
+    ctx: Context<InitializeRewardsPoolV0>,
+    args: InitializeRewardsPoolV0Args,
+  ) -> Result<()> {
+    instructions::initialize_rewards_pool_v0::handler(ctx, args)
+  }
+
+  pub fn claim_rewards_v0(ctx: Context<ClaimRewardsV0>) -> Result<()> {
+    instructions::claim_rewards_v0::handler(ctx)
+  }
+
+  pub fn lock_v0(ctx: Context<LockV0>, args: LockV0Args) -> Result<()> {
+    instructions::lock::lock_v0::handler(ctx, args)
+  }
+
+  pub fn swap_locked_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapLockedV0<'info>>,
+    args: SwapV0Args,
+  ) -> Result<()> {
+    instructions::lock::swap_locked_v0::handler(ctx, args)
+  }
+
+  pub fn unlock_v0(ctx: Context<UnlockV0>) -> Result<()> {
+    instructions::lock::unlock_v0::handler(ctx)
+  }
+
+  pub fn initialize_global_config_v0(
+    ctx: Context<InitializeGlobalConfigV0>,
+    args: InitializeGlobalConfigV0Args,
+  ) -> Result<()> {
+    instructions::initialize_global_config_v0::handler(ctx, args)
+  }
+
+  pub fn update_global_config_v0(
+    ctx: Context<UpdateGlobalConfigV0>,
+    args: UpdateGlobalConfigV0Args,
+  ) -> Result<()> {
+    instructions::update_global_config_v0::handler(ctx, args)
+  }
+
+  pub fn migrate_parent_entangler_v0_to_v1(
+    ctx: Context<MigrateParentEntanglerV0ToV1>,
+  ) -> Result<()> {
+    instructions::migrate::migrate_parent_entangler_v0_to_v1::handler(ctx)
+  }
+
+  pub fn migrate_child_entangler_v0_to_v1(ctx: Context<MigrateChildEntanglerV0ToV1>) -> Result<()> {
+    instructions::migrate::migrate_child_entangler_v0_to_v1::handler(ctx)
+  }
+
+  pub fn resize_entangler_v0(
+    ctx: Context<ResizeEntanglerV0>,
+    args: ResizeEntanglerV0Args,
+  ) -> Result<()> {
+    instructions::resize_entangler_v0::handler(ctx, args)
+  }
+
+  pub fn enforce_freeze_v0(ctx: Context<EnforceFreezeV0>) -> Result<()> {
+    instructions::enforce_freeze_v0::handler(ctx)
+  }
+
+  pub fn set_global_pause_v0(
+    ctx: Context<SetGlobalPauseV0>,
+    args: SetGlobalPauseV0Args,
+  ) -> Result<()> {
+    instructions::set_global_pause_v0::handler(ctx, args)
+  }
+
+  pub fn sweep_dust_v0(ctx: Context<SweepDustV0>) -> Result<()> {
+    instructions::sweep_dust_v0::handler(ctx)
+  }
+
+  pub fn seal_entangler_v0(ctx: Context<SealEntanglerV0>) -> Result<()> {
+    instructions::seal_entangler_v0::handler(ctx)
+  }
+
+  pub fn create_offer_v0(ctx: Context<CreateOfferV0>, args: CreateOfferV0Args) -> Result<()> {
+    instructions::otc::create_offer_v0::handler(ctx, args)
+  }
+
+  pub fn accept_offer_v0(ctx: Context<AcceptOfferV0>) -> Result<()> {
+    instructions::otc::accept_offer_v0::handler(ctx)
+  }
+
+  pub fn cancel_offer_v0(ctx: Context<CancelOfferV0>) -> Result<()> {
+    instructions::otc::cancel_offer_v0::handler(ctx)
+  }
+
+  pub fn swap_parent_for_child_and_stake_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapParentForChildAndStakeV0<'info>>,
+    args: SwapAndStakeV0Args,
+  ) -> Result<()> {
+    instructions::swap_parent_for_child_and_stake_v0::handler(ctx, args)
+  }
+
+  pub fn swap_child_for_parent_and_provide_liquidity_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapChildForParentAndProvideLiquidityV0<'info>>,
+    args: SwapAndProvideLiquidityV0Args,
+  ) -> Result<()> {
+    instructions::swap_child_for_parent_and_provide_liquidity_v0::handler(ctx, args)
+  }
+
+  pub fn set_rate_schedule_v0(
+    ctx: Context<SetRateScheduleV0>,
+    args: SetRateScheduleV0Args,
+  ) -> Result<()> {
+    instructions::rate_schedule::set_rate_schedule_v0::handler(ctx, args)
+  }
+
+  pub fn append_rate_schedule_v0(
+    ctx: Context<AppendRateScheduleV0>,
+    args: AppendRateScheduleV0Args,
+  ) -> Result<()> {
+    instructions::rate_schedule::append_rate_schedule_v0::handler(ctx, args)
+  }
+
+  pub fn set_freeze_time_v0(
+    ctx: Context<SetFreezeTimeV0>,
+    args: SetFreezeTimeV0Args,
+  ) -> Result<()> {
+    instructions::set_freeze_time_v0::handler(ctx, args)
+  }
+
+  pub fn set_parent_freeze_time_v0(
+    ctx: Context<SetParentFreezeTimeV0>,
+    args: SetParentFreezeTimeV0Args,
+  ) -> Result<()> {
+    instructions::set_parent_freeze_time_v0::handler(ctx, args)
+  }
+
+  pub fn set_fees_v0(ctx: Context<SetFeesV0>, args: SetFeesV0Args) -> Result<()> {
+    instructions::set_fees_v0::handler(ctx, args)
+  }
+
+  pub fn set_rate_v0(ctx: Context<SetRateV0>, args: SetRateV0Args) -> Result<()> {
+    instructions::set_rate_v0::handler(ctx, args)
+  }
+
+  pub fn set_authority_v0(ctx: Context<SetAuthorityV0>, args: SetAuthorityV0Args) -> Result<()> {
+    instructions::set_authority_v0::handler(ctx, args)
+  }
+
+  pub fn get_version_v0(ctx: Context<GetVersionV0>) -> Result<()> {
+    instructions::get_version_v0::handler(ctx)
+  }
+
+  pub fn initialize_swap_record_tree_v0(
+    ctx: Context<InitializeSwapRecordTreeV0>,
+    args: InitializeSwapRecordTreeV0Args,
+  ) -> Result<()> {
+    instructions::compressed::initialize_swap_record_tree_v0::handler(ctx, args)
+  }
+
+  pub fn swap_parent_for_child_compressed_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapParentForChildCompressedV0<'info>>,
+    args: SwapV0Args,
+  ) -> Result<()> {
+    instructions::compressed::swap_parent_for_child_compressed_v0::handler(ctx, args)
+  }
+
+  pub fn swap_child_for_parent_compressed_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapChildForParentCompressedV0<'info>>,
+    args: SwapV0Args,
+  ) -> Result<()> {
+    instructions::compressed::swap_child_for_parent_compressed_v0::handler(ctx, args)
+  }
+
+  pub fn refund_compressed_swap_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundCompressedSwapV0<'info>>,
+    args: RefundCompressedSwapV0Args,
+  ) -> Result<()> {
+    instructions::compressed::refund_compressed_swap_v0::handler(ctx, args)
+  }
+
+  pub fn swap_parent_for_child_compressed_token_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapParentForChildCompressedTokenV0<'info>>,
+    args: SwapCompressedTokenV0Args,
+  ) -> Result<()> {
+    instructions::compressed_token::swap_parent_for_child_compressed_token_v0::handler(ctx, args)
+  }
+
+  pub fn swap_child_for_parent_compressed_token_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapChildForParentCompressedTokenV0<'info>>,
+    args: SwapCompressedTokenV0Args,
+  ) -> Result<()> {
+    instructions::compressed_token::swap_child_for_parent_compressed_token_v0::handler(ctx, args)
+  }
+
+  pub fn initialize_entangler_with_children_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeEntanglerWithChildrenV0<'info>>,
+    args: InitializeEntanglerWithChildrenV0Args,
+  ) -> Result<()> {
+    instructions::initialize_entangler_with_children_v0::handler(ctx, args)
+  }
+
+  pub fn ensure_child_entangler_v0(
+    ctx: Context<EnsureChildEntanglerV0>,
+    args: InitializeFungibleChildEntanglerV0Args,
+  ) -> Result<()> {
+    instructions::ensure_child_entangler_v0::handler(ctx, args)
+  }
+
+  pub fn snapshot_v0(ctx: Context<SnapshotV0>) -> Result<()> {
+    instructions::snapshot_v0::handler(ctx)
+  }
+
+  pub fn commit_swap_v0(ctx: Context<CommitSwapV0>, args: CommitSwapV0Args) -> Result<()> {
+    instructions::commit_reveal::commit_swap_v0::handler(ctx, args)
+  }
+
+  pub fn reveal_and_swap_v0(
+    ctx: Context<RevealAndSwapV0>,
+    args: RevealAndSwapV0Args,
+  ) -> Result<()> {
+    instructions::commit_reveal::reveal_and_swap_v0::handler(ctx, args)
+  }
+
+  pub fn set_nft_authority_v0(ctx: Context<SetNftAuthorityV0>) -> Result<()> {
+    instructions::nft_authority::set_nft_authority_v0::handler(ctx)
+  }
+
+  pub fn invoke_as_nft_authority_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, InvokeAsNftAuthorityV0<'info>>,
+    args: InvokeAsNftAuthorityV0Args,
+  ) -> Result<()> {
+    instructions::nft_authority::invoke_as_nft_authority_v0::handler(ctx, args)
+  }
+
+  pub fn import_legacy_entangler_v0(
+    ctx: Context<ImportLegacyEntanglerV0>,
+    args: ImportLegacyEntanglerV0Args,
+  ) -> Result<()> {
+    instructions::migrate::import_legacy_entangler_v0::handler(ctx, args)
+  }
+
+  pub fn import_legacy_child_entangler_v0(
+    ctx: Context<ImportLegacyChildEntanglerV0>,
+  ) -> Result<()> {
+    instructions::migrate::import_legacy_child_entangler_v0::handler(ctx)
+  }
+
+  /// Instructions without an Anchor discriminator, the SPL Token Swap adapter
+  pub fn fallback(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> Result<()> {
+    token_swap::process(accounts, data)
+  }
+
+  pub fn approve_strategy_program_v0(ctx: Context<ApproveStrategyProgramV0>) -> Result<()> {
+    instructions::strategy::approve_strategy_program_v0::handler(ctx)
+  }
+
+  pub fn set_yield_strategy_v0(
+    ctx: Context<SetYieldStrategyV0>,
+    args: SetYieldStrategyV0Args,
+  ) -> Result<()> {
+    instructions::strategy::set_yield_strategy_v0::handler(ctx, args)
+  }
+
+  pub fn rebalance_strategy_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, RebalanceStrategyV0<'info>>,
+    args: RebalanceStrategyV0Args,
+  ) -> Result<()> {
+    instructions::strategy::rebalance_strategy_v0::handler(ctx, args)
+  }
+
+  pub fn set_registry_weights_v0(
+    ctx: Context<SetRegistryWeightsV0>,
+    args: SetRegistryWeightsV0Args,
+  ) -> Result<()> {
+    instructions::set_registry_weights_v0::handler(ctx, args)
+  }
+
+  pub fn swap_parent_for_basket_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapParentForBasketV0<'info>>,
+    args: SwapParentForBasketV0Args,
+  ) -> Result<()> {
+    instructions::swap_parent_for_basket_v0::handler(ctx, args)
+  }
+
+  pub fn set_rate_band_v0(ctx: Context<SetRateBandV0>, args: SetRateBandV0Args) -> Result<()> {
+    instructions::set_rate_band_v0::handler(ctx, args)
+  }
+
+  pub fn queue_swap_v0(ctx: Context<QueueSwapV0>, args: QueueSwapV0Args) -> Result<()> {
+    instructions::queue::queue_swap_v0::handler(ctx, args)
+  }
+
+  pub fn execute_queued_swap_v0(ctx: Context<ExecuteQueuedSwapV0>) -> Result<()> {
+    instructions::queue::execute_queued_swap_v0::handler(ctx)
+  }
+
+  pub fn cancel_queued_swap_v0(ctx: Context<CancelQueuedSwapV0>) -> Result<()> {
+    instructions::queue::cancel_queued_swap_v0::handler(ctx)
+  }
+
+  pub fn initialize_namespaced_parent_entangler_v0(
+    ctx: Context<InitializeNamespacedParentEntanglerV0>,
+    args: InitializeFungibleParentEntanglerV0Args,
+  ) -> Result<()> {
+    instructions::initialize_namespaced_parent_entangler_v0::handler(ctx, args)
+  }
+
+  pub fn set_withdrawals_frozen_until_v0(
+    ctx: Context<SetWithdrawalsFrozenUntilV0>,
+    args: SetWithdrawalsFrozenUntilV0Args,
+  ) -> Result<()> {
+    instructions::set_withdrawals_frozen_until_v0::handler(ctx, args)
+  }
+
+  pub fn distribute_fees_v0(ctx: Context<DistributeFeesV0>) -> Result<()> {
+    instructions::distribute_fees_v0::handler(ctx)
+  }
+
+  pub fn claim_fee_rebate_v0(ctx: Context<ClaimFeeRebateV0>) -> Result<()> {
+    instructions::claim_fee_rebate_v0::handler(ctx)
+  }
+
+  pub fn set_authority_config_v0(
+    ctx: Context<SetAuthorityConfigV0>,
+    args: SetAuthorityConfigV0Args,
+  ) -> Result<()> {
+    instructions::authority_config::set_authority_config_v0::handler(ctx, args)
+  }
+
+  pub fn invoke_as_authority_config_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, InvokeAsAuthorityConfigV0<'info>>,
+    args: InvokeAsAuthorityConfigV0Args,
+  ) -> Result<()> {
+    instructions::authority_config::invoke_as_authority_config_v0::handler(ctx, args)
+  }
+
+  pub fn set_pending_settlement_v0(
+    ctx: Context<SetPendingSettlementV0>,
+    args: SetPendingSettlementV0Args,
+  ) -> Result<()> {
+    instructions::settlement::set_pending_settlement_v0::handler(ctx, args)
+  }
+
+  pub fn defer_swap_v0(ctx: Context<DeferSwapV0>, args: DeferSwapV0Args) -> Result<()> {
+    instructions::settlement::defer_swap_v0::handler(ctx, args)
+  }
+
+  pub fn ack_settlement_v0(ctx: Context<AckSettlementV0>) -> Result<()> {
+    instructions::settlement::ack_settlement_v0::handler(ctx)
+  }
+
+  pub fn refund_settlement_v0(ctx: Context<RefundSettlementV0>) -> Result<()> {
+    instructions::settlement::refund_settlement_v0::handler(ctx)
+  }
+
+  pub fn set_drawdown_limit_v0(
+    ctx: Context<SetDrawdownLimitV0>,
+    args: SetDrawdownLimitV0Args,
+  ) -> Result<()> {
+    instructions::set_drawdown_limit_v0::handler(ctx, args)
+  }
+
+  pub fn validate_init_params_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, ValidateInitParamsV0<'info>>,
+    args: InitializeEntanglerWithChildrenV0Args,
+  ) -> Result<()> {
+    instructions::validate_init_params_v0::handler(ctx, args)
+  }
+
+  pub fn rebalance_between_entanglers_v0(
+    ctx: Context<RebalanceBetweenEntanglersV0>,
+    args: RebalanceBetweenEntanglersV0Args,
+  ) -> Result<()> {
+    instructions::rebalance_between_entanglers_v0::handler(ctx, args)
+  }
+
+  pub fn migrate_parent_entangler_v1_to_v2(
+    ctx: Context<MigrateParentEntanglerV1ToV2>,
+  ) -> Result<()> {
+    instructions::migrate::migrate_parent_entangler_v1_to_v2::handler(ctx)
+  }
+
+  pub fn migrate_child_entangler_v1_to_v2(ctx: Context<MigrateChildEntanglerV1ToV2>) -> Result<()> {
+    instructions::migrate::migrate_child_entangler_v1_to_v2::handler(ctx)
+  }
+
+  pub fn initialize_swap_record_v0(ctx: Context<InitializeSwapRecordV0>) -> Result<()> {
+    instructions::initialize_swap_record_v0::handler(ctx)
+  }
+
+  pub fn migrate_child_registry_v0(ctx: Context<MigrateChildRegistryV0>) -> Result<()> {
+    instructions::migrate::migrate_child_registry_v0::handler(ctx)
+  }
 }
@@ -0,0 +1,60 @@
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+pub const BPS_DENOMINATOR: u64 = 10000;
+
+/// Which way a division rounds. Pick the direction that favors the entangler's storage, e.g. round
+/// payouts down and fees up.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rounding {
+  Down,
+  Up,
+}
+
+/// a * b / c with the product taken in u128
+pub fn mul_div_u128(a: u128, b: u128, c: u128, rounding: Rounding) -> Result<u128> {
+  require!(c != 0, ErrorCode::DivideByZero);
+  let product = a.checked_mul(b).ok_or(error!(ErrorCode::MathOverflow))?;
+  let quotient = product / c;
+
+  match rounding {
+    Rounding::Up if product % c != 0 => quotient
+      .checked_add(1)
+      .ok_or(error!(ErrorCode::MathOverflow)),
+    _ => Ok(quotient),
+  }
+}
+
+pub fn mul_div(a: u64, b: u64, c: u64, rounding: Rounding) -> Result<u64> {
+  to_u64(mul_div_u128(a as u128, b as u128, c as u128, rounding)?)
+}
+
+/// bps basis points of amount
+pub fn apply_bps(amount: u64, bps: u16, rounding: Rounding) -> Result<u64> {
+  mul_div(amount, bps as u64, BPS_DENOMINATOR, rounding)
+}
+
+pub fn to_u64(value: u128) -> Result<u64> {
+  u64::try_from(value).map_err(|_| error!(ErrorCode::CastOverflow))
+}
+
+pub fn add(a: u64, b: u64) -> Result<u64> {
+  a.checked_add(b).ok_or(error!(ErrorCode::MathOverflow))
+}
+
+pub fn sub(a: u64, b: u64) -> Result<u64> {
+  a.checked_sub(b).ok_or(error!(ErrorCode::MathUnderflow))
+}
+
+pub fn add_u128(a: u128, b: u128) -> Result<u128> {
+  a.checked_add(b).ok_or(error!(ErrorCode::MathOverflow))
+}
+
+pub fn sub_u128(a: u128, b: u128) -> Result<u128> {
+  a.checked_sub(b).ok_or(error!(ErrorCode::MathUnderflow))
+}
+
+pub fn sub_u32(a: u32, b: u32) -> Result<u32> {
+  a.checked_sub(b).ok_or(error!(ErrorCode::MathUnderflow))
+}
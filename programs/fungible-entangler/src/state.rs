@@ -1,5 +1,1326 @@
-use anchor_lang::prelude::*;
+use crate::{
+  error::ErrorCode,
+  instructions::swap::common::SwapDirection,
+  math::{self, Rounding},
+};
+use anchor_lang::{prelude::*, solana_program::keccak};
 
+/// Protocol wide settings, a single PDA at [b"global-config"]
+#[account]
+#[derive(Default)]
+pub struct GlobalConfigV0 {
+  pub admin: Pubkey,
+  // Receives creation fees for parent and child entanglers
+  pub fee_destination: Pubkey,
+  // Lamports charged to the payer whenever an entangler is created. Zero disables the fee
+  pub creation_fee_lamports: u64,
+  pub bump_seed: u8,
+  // Set by the admin via SetGlobalPauseV0 to halt every swap program wide
+  pub paused: bool,
+}
+
+/// Layout version written to new entanglers
+pub const ENTANGLER_VERSION: u16 = 2;
+/// Oldest entangler layout this program can interpret. Version 1 entanglers are upgraded through
+/// MigrateParentEntanglerV1ToV2 and MigrateChildEntanglerV1ToV2
+pub const MIN_SUPPORTED_ENTANGLER_VERSION: u16 = 2;
+
+pub fn is_supported_version(version: u16) -> bool {
+  (MIN_SUPPORTED_ENTANGLER_VERSION..=ENTANGLER_VERSION).contains(&version)
+}
+
+/// Words reserved at the end of each entangler so that new fields can be carved out of them
+/// without reallocating deployed accounts. Account fields spell array lengths out, as Anchor 0.24's
+/// IDL parser only reads literal lengths
+pub const ENTANGLER_RESERVED_WORDS: usize = 16;
+
+/// What is left of the child's reserved words after reclaimed_top_off_amount, the recorded swap
+/// totals and queued_swap_count
+pub const CHILD_ENTANGLER_RESERVED_WORDS: usize = ENTANGLER_RESERVED_WORDS - 4;
+
+/// What is left of the parent's reserved words after reserved_parent_fee_amount
+pub const PARENT_ENTANGLER_RESERVED_WORDS: usize = ENTANGLER_RESERVED_WORDS - 1;
+
+#[account]
+#[derive(Default)]
+pub struct FungibleParentEntanglerV1 {
+  pub version: u16,
+  pub parent_mint: Pubkey,
+  pub parent_storage: Pubkey,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub created_at_unix_time: i64,
+  pub num_children: u32,
+  // Monotonic counter used to assign each child a stable index. Never decremented,
+  // so indices of closed children are not reused.
+  pub next_child_index: u32,
+  pub authority: Option<Pubkey>,
+  // Human readable label for explorers and wallets, at most MAX_NAME_LEN bytes
+  pub name: String,
+  // Points at offchain json describing the entangler, at most MAX_URI_LEN bytes
+  pub uri: String,
+
+  // The original seed, at most MAX_DYNAMIC_SEED_LEN bytes. The PDA is derived from its sha256 hash
+  pub dynamic_seed: Vec<u8>,
+  pub dynamic_seed_hash: [u8; 32],
+  // Receives the rent back when the entangler is closed. Defaults to the payer at init
+  pub rent_refund: Pubkey,
+  // Migrated from FungibleParentEntanglerV0, whose PDA is derived from the raw dynamic seed
+  pub legacy_seeds: bool,
+  // Rate given to children initialized without one of their own
+  pub default_rate: ExchangeRateV0,
+  // Stop a single direction for every child, e.g. closing new conversions while exits stay open
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+  // When set, authority is the [b"nft-authority", parent] PDA and whoever holds this NFT acts
+  // through InvokeAsNftAuthorityV0
+  pub authority_nft_mint: Option<Pubkey>,
+  // Deployer whose namespace the dynamic seed was claimed in, see hash_namespaced_seed
+  pub namespace: Option<Pubkey>,
+  // Storage can't be withdrawn from or closed before this time, which only ever moves forward
+  pub withdrawals_frozen_until: Option<i64>,
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+
+  // Parent tokens in storage owed to swappers as fee rebates across every child, see
+  // FungibleChildEntanglerV1::reserved_parent_fee_amount
+  pub reserved_parent_fee_amount: u64,
+
+  pub reserved: [u64; 15], // PARENT_ENTANGLER_RESERVED_WORDS
+}
+
+impl FungibleParentEntanglerV1 {
+  pub fn is_supported_version(&self) -> bool {
+    is_supported_version(self.version)
+  }
+
+  pub fn is_freeze_due(&self, unix_time: i64) -> bool {
+    self
+      .freeze_swap_unix_time
+      .map_or(false, |freeze| unix_time >= freeze)
+  }
+
+  pub fn are_withdrawals_frozen(&self, unix_time: i64) -> bool {
+    self
+      .withdrawals_frozen_until
+      .map_or(false, |until| unix_time < until)
+  }
+
+  /// Sealed parents have no authority left to update them or take tokens out of storage, see
+  /// SealEntanglerV0
+  pub fn is_sealed(&self) -> bool {
+    self.authority.is_none()
+  }
+
+  /// The dynamic seed component of this entangler's PDA
+  pub fn seed(&self) -> &[u8] {
+    if self.legacy_seeds {
+      &self.dynamic_seed
+    } else {
+      &self.dynamic_seed_hash
+    }
+  }
+
+  pub fn signer_seeds(&self) -> [&[u8]; 4] {
+    [
+      b"entangler",
+      self.parent_mint.as_ref(),
+      self.seed(),
+      std::slice::from_ref(&self.bump_seed),
+    ]
+  }
+}
+
+#[account]
+#[derive(Default)]
+pub struct FungibleChildEntanglerV1 {
+  pub version: u16,
+  pub parent_entangler: Pubkey,
+  pub child_mint: Pubkey,
+  pub child_storage: Pubkey,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub created_at_unix_time: i64,
+  pub index: u32,
+  // When set, parent to child swaps vest through a VestingV0 instead of paying out immediately
+  pub vesting: Option<VestingConfigV0>,
+  // Seconds after a parent to child swap during which the wallet may reverse it via RefundSwapV0
+  pub refund_window_seconds: Option<i64>,
+  // After this time swaps close and TopOff depositors may reclaim their deposits from child storage
+  pub expiry_unix_time: Option<i64>,
+  // Sum of all deposits made through TopOffChildStorageV0
+  pub total_top_off_amount: u64,
+  // Child storage balance when the first reclaim happened, the basis for every depositor's share
+  pub reclaim_snapshot_amount: Option<u64>,
+  // When set, TopOff depositors earn rewards from this RewardsPoolV0
+  pub rewards_pool: Option<Pubkey>,
+  // When set, parent tokens must sit in a SwapLockV0 this long before swapping via SwapLockedV0
+  pub min_lock_seconds: Option<i64>,
+  // Deflationary burn applied to every swap through this child
+  pub burn: Option<BurnConfigV0>,
+  // Receives the rent back when the entangler is closed. Defaults to the payer at init
+  pub rent_refund: Pubkey,
+  pub rate: ExchangeRateV0,
+  // Kept in output storage on every swap
+  pub swap_fee_bps: u16,
+  // Lifetime cap on parent tokens swapped into this child
+  pub max_parent_to_child_amount: Option<u64>,
+  // Parent tokens swapped in, net of refunds
+  pub total_parent_to_child_amount: u64,
+  // Set by EnforceFreezeV0 once this child or its parent passes its freeze time
+  pub frozen: bool,
+  // Which programs may invoke swaps through this child via CPI
+  pub cpi_policy: CpiPolicyV0,
+  // Programs allowed to CPI into swaps when cpi_policy is Allowlist
+  pub cpi_allowlist: Vec<Pubkey>,
+  // Lifetime cap on child tokens converted into the parent, e.g. the audited supply of a bridged
+  // variant being consolidated
+  pub max_child_to_parent_amount: Option<u64>,
+  // Child tokens swapped in
+  pub total_child_to_parent_amount: u64,
+  // Program SwapParentForChildAndStakeV0 deposits swap output into
+  pub stake_program: Option<Pubkey>,
+  // AMM SwapChildForParentAndProvideLiquidityV0 deposits swap output into
+  pub liquidity_program: Option<Pubkey>,
+  // Size based fees, sorted by min_amount. Swaps below every tier pay swap_fee_bps
+  pub fee_tiers: Vec<FeeTierV0>,
+  // Seconds after a parent to child swap during which a child to parent swap rebates its fee
+  pub reverse_rebate_seconds: Option<i64>,
+  // Stop a single direction through this child, on top of freeze_swap_unix_time
+  pub freeze_parent_to_child_unix_time: Option<i64>,
+  pub freeze_child_to_parent_unix_time: Option<i64>,
+  // Concurrent merkle tree that compressed swaps append CompressedSwapRecordV0 leaves to
+  pub swap_record_tree: Option<Pubkey>,
+  // Leaves appended so far, the index of the next one
+  pub compressed_record_count: u64,
+  // Activity for risk systems and frontends, in parent tokens in and child tokens in respectively
+  pub parent_to_child_volume: VolumeStatsV0,
+  pub child_to_parent_volume: VolumeStatsV0,
+  // Seconds after go live during which swaps must be committed beforehand via CommitSwapV0 and
+  // executed via RevealAndSwapV0
+  pub commit_reveal_seconds: Option<i64>,
+  // Lends idle child storage out through an approved program, see RebalanceStrategyV0
+  pub yield_strategy: Option<YieldStrategyV0>,
+  // Bounds on the rates the child and its rate schedule set at swap time, see SetRateBandV0
+  pub rate_band: Option<RateBandV0>,
+  // Fees of swaps recorded in a SwapRecordV0 kept in storage, child tokens on parent to child swaps
+  // net of reverse swap rebates and parent tokens on child to parent swaps. Only recorded swaps
+  // can claim fees back, so the fees of other swaps are left to the authority
+  pub accrued_child_fee_amount: u64,
+  pub accrued_parent_fee_amount: u64,
+  // Set by DistributeFeesV0, after which swaps close and swappers claim the fees back
+  pub fee_distribution: Option<FeeDistributionV0>,
+  // Caps on what swaps through this child take out of storage per window, see SetDrawdownLimitV0
+  pub drawdown_limit: Option<DrawdownLimitV0>,
+
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+
+  // Child tokens ReclaimAfterExpiryV0 paid out to TopOff depositors so far
+  pub reclaimed_top_off_amount: u64,
+  // The part of the swap totals recorded in a SwapRecordV0, net of refunds. DistributeFeesV0 shares
+  // the fees out by these
+  pub recorded_parent_to_child_amount: u64,
+  pub recorded_child_to_parent_amount: u64,
+  // Tickets of the child's SwapQueueV0 not yet executed. Swaps outside of the queue wait until it
+  // drains, so that queued swaps settle first
+  pub queued_swap_count: u64,
+
+  pub reserved: [u64; 12], // CHILD_ENTANGLER_RESERVED_WORDS
+}
+
+pub const VOLUME_HALF_LIFE_SECONDS: i64 = 24 * 60 * 60;
+pub const VOLUME_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Rolling swap volume through one direction of a child, updated on every swap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct VolumeStatsV0 {
+  // Sum of swap amounts, each decaying by half every VOLUME_HALF_LIFE_SECONDS
+  pub ema_amount: u64,
+  pub ema_updated_unix_time: i64,
+  // Amount and count of swaps since window_start_unix_time, reset every VOLUME_WINDOW_SECONDS
+  pub window_amount: u64,
+  pub window_swap_count: u32,
+  pub window_start_unix_time: i64,
+}
+
+impl VolumeStatsV0 {
+  /// ema_amount decayed to unix_time. Whole half lives shift, and the remainder interpolates
+  /// linearly between 1 and 1/2
+  pub fn decayed_ema_amount(&self, unix_time: i64) -> u64 {
+    let elapsed = unix_time.saturating_sub(self.ema_updated_unix_time).max(0);
+    let halvings = elapsed / VOLUME_HALF_LIFE_SECONDS;
+    if halvings >= 64 {
+      return 0;
+    }
+    let shifted = self.ema_amount >> halvings;
+    let remainder = (elapsed % VOLUME_HALF_LIFE_SECONDS) as u128;
+    let decay = shifted as u128 * remainder / (2 * VOLUME_HALF_LIFE_SECONDS as u128);
+
+    shifted - decay as u64
+  }
+
+  /// Saturates rather than erroring, so that statistics can never block a swap
+  pub fn record(&mut self, amount: u64, unix_time: i64) {
+    self.ema_amount = self.decayed_ema_amount(unix_time).saturating_add(amount);
+    self.ema_updated_unix_time = unix_time;
+
+    if unix_time.saturating_sub(self.window_start_unix_time) >= VOLUME_WINDOW_SECONDS {
+      self.window_amount = 0;
+      self.window_swap_count = 0;
+      self.window_start_unix_time = unix_time;
+    }
+    self.window_amount = self.window_amount.saturating_add(amount);
+    self.window_swap_count = self.window_swap_count.saturating_add(1);
+  }
+}
+
+/// Caps on the tokens swaps through a child pay out of child storage and parent storage within
+/// each window_seconds, bounding the loss to a mispriced rate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct DrawdownLimitV0 {
+  pub window_seconds: i64,
+  pub max_child_amount: u64,
+  pub max_parent_amount: u64,
+  // Paid out of each storage since window_start_unix_time
+  pub window_start_unix_time: i64,
+  pub child_amount: u64,
+  pub parent_amount: u64,
+}
+
+impl DrawdownLimitV0 {
+  pub fn is_valid(&self) -> bool {
+    self.window_seconds > 0
+  }
+
+  /// Counts amount leaving the output storage of a swap in direction at unix_time, starting a
+  /// new window once the current one is over
+  pub fn record(&mut self, direction: SwapDirection, amount: u64, unix_time: i64) -> Result<()> {
+    if unix_time.saturating_sub(self.window_start_unix_time) >= self.window_seconds {
+      self.window_start_unix_time = unix_time;
+      self.child_amount = 0;
+      self.parent_amount = 0;
+    }
+
+    let (drawn, max) = match direction {
+      SwapDirection::ParentToChild => (&mut self.child_amount, self.max_child_amount),
+      SwapDirection::ChildToParent => (&mut self.parent_amount, self.max_parent_amount),
+    };
+    *drawn = math::add(*drawn, amount)?;
+    require!(*drawn <= max, ErrorCode::DrawdownLimitExceeded);
+
+    Ok(())
+  }
+}
+
+/// The accrued fees of a child and the swap totals they are shared out by, as of DistributeFeesV0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct FeeDistributionV0 {
+  pub child_fee_amount: u64,
+  pub parent_fee_amount: u64,
+  pub parent_to_child_amount: u64,
+  pub child_to_parent_amount: u64,
+  pub claimed_child_fee_amount: u64,
+  pub claimed_parent_fee_amount: u64,
+}
+
+impl FeeDistributionV0 {
+  /// Child and parent fees owed to a wallet that swapped the given amounts, pro rata to the
+  /// totals and capped at what is left unclaimed
+  pub fn rebate(
+    &self,
+    parent_to_child_amount: u64,
+    child_to_parent_amount: u64,
+  ) -> Result<(u64, u64)> {
+    let child_rebate = share(
+      self.child_fee_amount,
+      parent_to_child_amount,
+      self.parent_to_child_amount,
+    )?;
+    let parent_rebate = share(
+      self.parent_fee_amount,
+      child_to_parent_amount,
+      self.child_to_parent_amount,
+    )?;
+
+    Ok((
+      std::cmp::min(
+        child_rebate,
+        self.child_fee_amount - self.claimed_child_fee_amount,
+      ),
+      std::cmp::min(
+        parent_rebate,
+        self.parent_fee_amount - self.claimed_parent_fee_amount,
+      ),
+    ))
+  }
+}
+
+fn share(amount: u64, part: u64, total: u64) -> Result<u64> {
+  if total == 0 {
+    return Ok(0);
+  }
+
+  math::mul_div(amount, std::cmp::min(part, total), total, Rounding::Down)
+}
+
+/// Where a child lends its idle storage, and how much of it is out
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct YieldStrategyV0 {
+  pub program: Pubkey,
+  // May rebalance alongside the parent's authority
+  pub keeper: Pubkey,
+  // Share of storage plus deposits that deposits must leave in storage for swaps to pay from
+  pub min_liquid_bps: u16,
+  // Principal deposited and not yet withdrawn. Yield shows up as withdrawals beyond it
+  pub deposited_amount: u64,
+}
+
+/// Swapping parent_units parent tokens yields child_units child tokens, and the reverse
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct ExchangeRateV0 {
+  pub parent_units: u64,
+  pub child_units: u64,
+}
+
+impl Default for ExchangeRateV0 {
+  fn default() -> Self {
+    ExchangeRateV0 {
+      parent_units: 1,
+      child_units: 1,
+    }
+  }
+}
+
+impl ExchangeRateV0 {
+  pub fn is_valid(&self) -> bool {
+    self.parent_units > 0 && self.child_units > 0
+  }
+
+  /// Child tokens for parent_amount parent tokens, rounding down
+  pub fn child_for_parent(&self, parent_amount: u64) -> Result<u64> {
+    math::mul_div(
+      parent_amount,
+      self.child_units,
+      self.parent_units,
+      Rounding::Down,
+    )
+  }
+
+  /// Parent tokens for child_amount child tokens, rounding down
+  pub fn parent_for_child(&self, child_amount: u64) -> Result<u64> {
+    math::mul_div(
+      child_amount,
+      self.parent_units,
+      self.child_units,
+      Rounding::Down,
+    )
+  }
+
+  /// Orders rates by child tokens per parent token
+  pub fn cmp_child_per_parent(&self, other: &ExchangeRateV0) -> std::cmp::Ordering {
+    let own = self.child_units as u128 * other.parent_units as u128;
+    let others = other.child_units as u128 * self.parent_units as u128;
+    own.cmp(&others)
+  }
+}
+
+/// Acceptable rates for a child, in child tokens per parent token. Swaps at a rate outside the
+/// band use fallback_rate, or fail when there is none. The band bounds the rates a RateScheduleV0
+/// steps through, so a mistyped or stale entry can't price storage away. Rates only come from the
+/// child and its schedule, there is no oracle rate mode
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct RateBandV0 {
+  pub min_rate: ExchangeRateV0,
+  pub max_rate: ExchangeRateV0,
+  pub fallback_rate: Option<ExchangeRateV0>,
+}
+
+impl RateBandV0 {
+  pub fn contains(&self, rate: &ExchangeRateV0) -> bool {
+    rate.cmp_child_per_parent(&self.min_rate) != std::cmp::Ordering::Less
+      && rate.cmp_child_per_parent(&self.max_rate) != std::cmp::Ordering::Greater
+  }
+
+  pub fn is_valid(&self) -> bool {
+    self.min_rate.is_valid()
+      && self.max_rate.is_valid()
+      && self.min_rate.cmp_child_per_parent(&self.max_rate) != std::cmp::Ordering::Greater
+      && self
+        .fallback_rate
+        .map_or(true, |rate| rate.is_valid() && self.contains(&rate))
+  }
+
+  /// The rate swaps use when rate is in effect
+  pub fn guard(&self, rate: ExchangeRateV0) -> Result<ExchangeRateV0> {
+    if self.contains(&rate) {
+      return Ok(rate);
+    }
+
+    self.fallback_rate.ok_or(error!(ErrorCode::RateOutOfBand))
+  }
+}
+
+pub const MAX_CPI_ALLOWLIST: usize = 4;
+pub const MAX_FEE_TIERS: usize = 4;
+
+/// Swaps of at least min_amount input tokens pay bps instead of the child's swap_fee_bps
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct FeeTierV0 {
+  pub min_amount: u64,
+  pub bps: u16,
+}
+
+/// Checked against the program that directly invoked the swap. Swaps nested deeper than one CPI
+/// have no known caller, so only Any allows them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum CpiPolicyV0 {
+  // Any program may CPI into swaps
+  Any,
+  // Swaps must be top level instructions
+  Deny,
+  // Only programs in cpi_allowlist may CPI into swaps
+  Allowlist,
+}
+
+impl Default for CpiPolicyV0 {
+  fn default() -> Self {
+    CpiPolicyV0::Any
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum BurnSideV0 {
+  // Burn from the tokens swapped in, after they reach storage
+  Input,
+  // Burn from storage instead of paying out, the wallet receives the rest
+  Output,
+}
+
+impl Default for BurnSideV0 {
+  fn default() -> Self {
+    BurnSideV0::Output
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct BurnConfigV0 {
+  pub bps: u16,
+  pub side: BurnSideV0,
+}
+
+/// Swap output unlocks linearly between start and end
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct VestingConfigV0 {
+  pub start_unix_time: i64,
+  pub end_unix_time: i64,
+}
+
+impl VestingConfigV0 {
+  pub fn unlocked_amount(&self, total_amount: u64, unix_time: i64) -> Result<u64> {
+    if unix_time <= self.start_unix_time {
+      return Ok(0);
+    }
+    if unix_time >= self.end_unix_time {
+      return Ok(total_amount);
+    }
+
+    // Both are positive since start < unix_time < end
+    let elapsed = (unix_time - self.start_unix_time) as u64;
+    let duration = (self.end_unix_time - self.start_unix_time) as u64;
+    math::mul_div(total_amount, elapsed, duration, Rounding::Down)
+  }
+}
+
+/// Per wallet vesting record for a child entangler.
+/// PDA of [b"vesting", child_entangler, owner]
+#[account]
+#[derive(Default)]
+pub struct VestingV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub total_amount: u64,
+  pub claimed_amount: u64,
+  pub bump_seed: u8,
+}
+
+/// Per wallet swap history for a child entangler.
+/// PDA of [b"swap-record", child_entangler, owner]
+#[account]
+#[derive(Default)]
+pub struct SwapRecordV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  // Total parent tokens swapped in for child tokens
+  pub parent_to_child_amount: u64,
+  // Total child tokens swapped in for parent tokens
+  pub child_to_parent_amount: u64,
+  pub last_parent_to_child_unix_time: i64,
+  // Amounts swapped parent to child within the current refund window
+  pub refundable_parent_amount: u64,
+  pub refundable_child_amount: u64,
+  // Child tokens paid as fees on parent to child swaps within the current rebate window
+  pub rebatable_fee_amount: u64,
+  pub bump_seed: u8,
+}
+
+impl FungibleChildEntanglerV1 {
+  pub fn is_supported_version(&self) -> bool {
+    is_supported_version(self.version)
+  }
+
+  /// Fee for swapping amount input tokens, from the largest tier amount reaches
+  pub fn fee_bps(&self, amount: u64) -> u16 {
+    self
+      .fee_tiers
+      .iter()
+      .rev()
+      .find(|tier| amount >= tier.min_amount)
+      .map_or(self.swap_fee_bps, |tier| tier.bps)
+  }
+
+  pub fn is_commit_reveal_only(&self, unix_time: i64) -> bool {
+    self.commit_reveal_seconds.map_or(false, |seconds| {
+      unix_time < self.go_live_unix_time.saturating_add(seconds)
+    })
+  }
+
+  /// Whether a swap directly invoked by caller is allowed, None being a caller below a nested CPI
+  pub fn allows_caller(&self, caller: Option<Pubkey>) -> bool {
+    if caller == Some(crate::ID) {
+      return true;
+    }
+
+    match (self.cpi_policy, caller) {
+      (CpiPolicyV0::Any, _) => true,
+      (CpiPolicyV0::Deny, _) => false,
+      (CpiPolicyV0::Allowlist, Some(caller)) => self.cpi_allowlist.contains(&caller),
+      (CpiPolicyV0::Allowlist, None) => false,
+    }
+  }
+
+  pub fn signer_seeds(&self) -> [&[u8]; 4] {
+    [
+      b"entangler",
+      self.parent_entangler.as_ref(),
+      self.child_mint.as_ref(),
+      std::slice::from_ref(&self.bump_seed),
+    ]
+  }
+
+  pub fn is_freeze_due(&self, unix_time: i64) -> bool {
+    self
+      .freeze_swap_unix_time
+      .map_or(false, |freeze| unix_time >= freeze)
+  }
+
+  pub fn is_expired(&self, unix_time: i64) -> bool {
+    self
+      .expiry_unix_time
+      .map_or(false, |expiry| unix_time >= expiry)
+  }
+
+  /// Whether DistributeFeesV0 may snapshot the accrued fees: once the child has expired, or once
+  /// its parent is sealed and swaps through the child have stopped for good. Only children that
+  /// track swaps have swappers to distribute to. Distributing closes swaps for good, so it must
+  /// never be what shuts a live child
+  pub fn is_fee_distributable(
+    &self,
+    parent_entangler: &FungibleParentEntanglerV1,
+    unix_time: i64,
+  ) -> bool {
+    self.is_expired(unix_time)
+      || (parent_entangler.is_sealed()
+        && self.tracks_swap_records()
+        && (self.frozen
+          || self.is_freeze_due(unix_time)
+          || parent_entangler.is_freeze_due(unix_time)))
+  }
+
+  /// Whether swaps must be recorded in the swapper's SwapRecordV0, for refunds, reverse swap
+  /// rebates, or the fee distribution that follows expiry
+  pub fn tracks_swap_records(&self) -> bool {
+    self.refund_window_seconds.is_some()
+      || self.reverse_rebate_seconds.is_some()
+      || self.expiry_unix_time.is_some()
+  }
+
+  /// The part of a reclaim snapshot owed to TopOff depositors. They share a shortfall pro rata but
+  /// never take out more than they deposited, the rest of storage stays with the authority
+  pub fn reclaimable_top_off_amount(&self, snapshot: u64) -> u64 {
+    std::cmp::min(snapshot, self.total_top_off_amount)
+  }
+
+  /// A depositor's share of a reclaim snapshot, pro rata to their deposit and at most the deposit
+  pub fn top_off_reclaim_amount(&self, snapshot: u64, deposit: u64) -> Result<u64> {
+    let share = math::mul_div(snapshot, deposit, self.total_top_off_amount, Rounding::Down)?;
+
+    Ok(std::cmp::min(share, deposit))
+  }
+
+  /// Child tokens in storage still owed to TopOff depositors, before the first reclaim every
+  /// deposit storage still covers
+  pub fn unreclaimed_top_off_amount(&self, storage_amount: u64) -> u64 {
+    let snapshot = self
+      .reclaim_snapshot_amount
+      .unwrap_or_else(|| storage_amount.saturating_sub(self.reserved_child_fee_amount()));
+
+    self
+      .reclaimable_top_off_amount(snapshot)
+      .saturating_sub(self.reclaimed_top_off_amount)
+  }
+
+  /// Counts a swap's output against the drawdown limit, if the child has one
+  pub fn record_drawdown(
+    &mut self,
+    direction: SwapDirection,
+    amount: u64,
+    unix_time: i64,
+  ) -> Result<()> {
+    match &mut self.drawdown_limit {
+      Some(limit) => limit.record(direction, amount, unix_time),
+      None => Ok(()),
+    }
+  }
+
+  /// Child tokens in storage owed to swappers as fee rebates, which expiry reclaims leave behind
+  pub fn reserved_child_fee_amount(&self) -> u64 {
+    match &self.fee_distribution {
+      Some(distribution) => distribution.child_fee_amount - distribution.claimed_child_fee_amount,
+      None => self.accrued_child_fee_amount,
+    }
+  }
+
+  /// Parent tokens in parent storage owed to this child's swappers as fee rebates, counted towards
+  /// the parent's reserved_parent_fee_amount. Only children that track swaps have swappers to owe
+  pub fn reserved_parent_fee_amount(&self) -> u64 {
+    if !self.tracks_swap_records() {
+      return 0;
+    }
+
+    match &self.fee_distribution {
+      Some(distribution) => distribution.parent_fee_amount - distribution.claimed_parent_fee_amount,
+      None => self.accrued_parent_fee_amount,
+    }
+  }
+}
+
+impl SwapRecordV0 {
+  /// Records parent_amount swapped in for child_amount, of which refundable_parent_amount stayed in
+  /// parent storage after burns and can be refunded. What was burned stays swapped in
+  pub fn record_parent_to_child(
+    &mut self,
+    parent_amount: u64,
+    refundable_parent_amount: u64,
+    child_amount: u64,
+    refund_window_seconds: Option<i64>,
+    unix_time: i64,
+  ) -> Result<()> {
+    self.parent_to_child_amount = math::add(self.parent_to_child_amount, parent_amount)?;
+
+    if let Some(window) = refund_window_seconds {
+      // Each swap extends the window, everything swapped inside of it stays refundable
+      if !self.in_refund_window(window, unix_time) {
+        self.refundable_parent_amount = 0;
+        self.refundable_child_amount = 0;
+      }
+      self.refundable_parent_amount =
+        math::add(self.refundable_parent_amount, refundable_parent_amount)?;
+      self.refundable_child_amount = math::add(self.refundable_child_amount, child_amount)?;
+    }
+    self.last_parent_to_child_unix_time = unix_time;
+
+    Ok(())
+  }
+
+  /// Returns child_amount of the refundable child tokens and pays back parent tokens pro rata,
+  /// rounding down. The refunded parent tokens no longer count towards fee rebates. Returns the
+  /// parent amount owed
+  pub fn refund(&mut self, child_amount: u64) -> Result<u64> {
+    let parent_amount = math::mul_div(
+      child_amount,
+      self.refundable_parent_amount,
+      self.refundable_child_amount,
+      Rounding::Down,
+    )?;
+
+    self.refundable_child_amount = math::sub(self.refundable_child_amount, child_amount)?;
+    self.refundable_parent_amount = math::sub(self.refundable_parent_amount, parent_amount)?;
+    self.parent_to_child_amount = math::sub(self.parent_to_child_amount, parent_amount)?;
+
+    Ok(parent_amount)
+  }
+
+  /// Tracks the fee of a parent to child swap. Must be called before record_parent_to_child so
+  /// the window is measured from the previous swap
+  pub fn record_rebatable_fee(
+    &mut self,
+    fee_amount: u64,
+    rebate_window_seconds: i64,
+    unix_time: i64,
+  ) -> Result<()> {
+    if !self.in_refund_window(rebate_window_seconds, unix_time) {
+      self.rebatable_fee_amount = 0;
+    }
+    self.rebatable_fee_amount = math::add(self.rebatable_fee_amount, fee_amount)?;
+
+    Ok(())
+  }
+
+  /// Takes up to child_amount of the fees rebatable for a reverse swap at unix_time
+  pub fn take_rebate(
+    &mut self,
+    child_amount: u64,
+    rebate_window_seconds: i64,
+    unix_time: i64,
+  ) -> u64 {
+    if !self.in_refund_window(rebate_window_seconds, unix_time) {
+      self.rebatable_fee_amount = 0;
+    }
+    let rebate = std::cmp::min(self.rebatable_fee_amount, child_amount);
+    self.rebatable_fee_amount -= rebate;
+
+    rebate
+  }
+
+  pub fn record_child_to_parent(&mut self, child_amount: u64) -> Result<()> {
+    self.child_to_parent_amount = math::add(self.child_to_parent_amount, child_amount)?;
+
+    Ok(())
+  }
+
+  pub fn in_refund_window(&self, refund_window_seconds: i64, unix_time: i64) -> bool {
+    self
+      .last_parent_to_child_unix_time
+      .checked_add(refund_window_seconds)
+      .map_or(false, |end| unix_time <= end)
+  }
+}
+
+/// Marks that the owner of swap_record claimed its fee rebate. PDA of [b"fee-rebate", swap_record]
+#[account]
+#[derive(Default)]
+pub struct FeeRebateClaimV0 {
+  pub swap_record: Pubkey,
+  pub bump_seed: u8,
+}
+
+/// A lending program the global admin approved for yield strategies. Its existence is the
+/// approval. PDA of [b"strategy-program", program]
+#[account]
+#[derive(Default)]
+pub struct StrategyProgramV0 {
+  pub program: Pubkey,
+  pub bump_seed: u8,
+}
+
+/// A swap committed to before go live, executable by RevealAndSwapV0 once the child is live.
+/// PDA of [b"swap-commit", child_entangler, owner]
+#[account]
+#[derive(Default)]
+pub struct SwapCommitV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub commitment: [u8; 32],
+  pub committed_at_unix_time: i64,
+  // Receives the rent of the commitment
+  pub payer: Pubkey,
+  pub bump_seed: u8,
+}
+
+impl SwapCommitV0 {
+  /// Binding the child and owner stops others from copying a commitment and racing its reveal
+  pub fn commitment(
+    child_entangler: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    parent_to_child: bool,
+    salt: &[u8; 32],
+  ) -> [u8; 32] {
+    keccak::hashv(&[
+      child_entangler.as_ref(),
+      owner.as_ref(),
+      &amount.to_le_bytes(),
+      &[parent_to_child as u8],
+      salt,
+    ])
+    .to_bytes()
+  }
+}
+
+/// Tickets of a child's swap queue. Tickets are issued and executed in order.
+/// PDA of [b"swap-queue", child_entangler]
+#[account]
+#[derive(Default)]
+pub struct SwapQueueV0 {
+  pub child_entangler: Pubkey,
+  pub next_ticket: u64,
+  // The only ticket ExecuteQueuedSwapV0 may settle
+  pub next_execute: u64,
+  pub bump_seed: u8,
+}
+
+/// A swap escrowed before go live, settled by ExecuteQueuedSwapV0 in ticket order.
+/// PDA of [b"queued-swap", child_entangler, ticket]
+#[account]
+#[derive(Default)]
+pub struct QueuedSwapV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub ticket: u64,
+  pub parent_to_child: bool,
+  // Zero once cancelled
+  pub amount: u64,
+  pub escrow: Pubkey,
+  // Receives the output
+  pub destination: Pubkey,
+  // Receives the escrow back on cancellation, or when storage can't cover the swap
+  pub refund_account: Pubkey,
+  // Receives the rent of the ticket and escrow
+  pub payer: Pubkey,
+  pub bump_seed: u8,
+  pub escrow_bump_seed: u8,
+}
+
+/// Deferred delivery for custodial venues. Swaps are escrowed as SettlementEntryV0 tickets that
+/// settler acknowledges via AckSettlementV0 once it has credited the owner off chain.
+/// PDA of [b"pending-settlement", child_entangler]
+#[account]
+#[derive(Default)]
+pub struct PendingSettlementV0 {
+  pub child_entangler: Pubkey,
+  pub settler: Pubkey,
+  // Seconds after which unacknowledged entries may be refunded
+  pub timeout_seconds: i64,
+  pub next_ticket: u64,
+  pub bump_seed: u8,
+}
+
+/// A swap awaiting acknowledgement by the settler. Holds the escrow at
+/// [b"settlement-escrow", settlement_entry]. PDA of [b"settlement-entry", pending_settlement, ticket]
+#[account]
+#[derive(Default)]
+pub struct SettlementEntryV0 {
+  pub pending_settlement: Pubkey,
+  pub owner: Pubkey,
+  pub ticket: u64,
+  pub parent_to_child: bool,
+  pub amount: u64,
+  // Identifies what the settler credits off chain, e.g. a hashed exchange account id
+  pub reference: [u8; 32],
+  pub escrow: Pubkey,
+  // Receives the escrow back once the entry times out
+  pub refund_account: Pubkey,
+  // Receives the rent of the entry and escrow
+  pub payer: Pubkey,
+  pub created_at_unix_time: i64,
+  pub bump_seed: u8,
+  pub escrow_bump_seed: u8,
+}
+
+impl SettlementEntryV0 {
+  pub fn is_timed_out(&self, timeout_seconds: i64, unix_time: i64) -> bool {
+    self
+      .created_at_unix_time
+      .checked_add(timeout_seconds)
+      .map_or(false, |timeout| unix_time >= timeout)
+  }
+}
+
+/// A single swap, stored as the keccak hash of its borsh encoding in the child's swap_record_tree
+/// instead of a SwapRecordV0 PDA. Clients keep the leaf data and fetch proofs from an indexer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct CompressedSwapRecordV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub parent_to_child: bool,
+  // Net of input burns on parent to child swaps, so that refunds only pay back what storage kept
+  pub parent_amount: u64,
+  pub child_amount: u64,
+  pub unix_time: i64,
+  // Set when RefundCompressedSwapV0 replaces the leaf, so it can't be refunded twice
+  pub refunded: bool,
+}
+
+impl CompressedSwapRecordV0 {
+  pub fn leaf_hash(&self) -> Result<[u8; 32]> {
+    Ok(keccak::hash(&self.try_to_vec()?).to_bytes())
+  }
+}
+
+/// Parent tokens a wallet has locked ahead of swapping. Holds the escrow at
+/// [b"lock-storage", lock]. PDA of [b"lock", child_entangler, owner]
+#[account]
+#[derive(Default)]
+pub struct SwapLockV0 {
+  pub child_entangler: Pubkey,
+  pub owner: Pubkey,
+  pub lock_storage: Pubkey,
+  // Reset whenever more tokens are locked
+  pub locked_at_unix_time: i64,
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+}
+
+impl SwapLockV0 {
+  pub fn signer_seeds(&self) -> [&[u8]; 4] {
+    [
+      b"lock",
+      self.child_entangler.as_ref(),
+      self.owner.as_ref(),
+      std::slice::from_ref(&self.bump_seed),
+    ]
+  }
+}
+
+/// A fixed size bilateral swap between maker and taker of a child's parent and child mints.
+/// The maker's side sits in escrow at [b"offer-escrow", offer], owned by the child entangler.
+/// PDA of [b"offer", child_entangler, maker, nonce]
+#[account]
+#[derive(Default)]
+pub struct OfferV0 {
+  pub child_entangler: Pubkey,
+  pub maker: Pubkey,
+  // The only wallet that may accept
+  pub taker: Pubkey,
+  // What the maker escrows, either the parent or the child mint
+  pub offer_mint: Pubkey,
+  pub offer_amount: u64,
+  // What the maker wants from the taker, the other mint
+  pub ask_mint: Pubkey,
+  pub ask_amount: u64,
+  pub escrow: Pubkey,
+  pub expiry_unix_time: Option<i64>,
+  pub nonce: u64,
+  pub bump_seed: u8,
+  pub escrow_bump_seed: u8,
+}
+
+impl OfferV0 {
+  pub fn is_expired(&self, unix_time: i64) -> bool {
+    self
+      .expiry_unix_time
+      .map_or(false, |expiry| unix_time >= expiry)
+  }
+}
+
+pub const MAX_RATE_SCHEDULE_ENTRIES: usize = 8;
+
+/// Rate a child moves onto once effective_unix_time passes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct RateScheduleEntryV0 {
+  pub effective_unix_time: i64,
+  pub rate: ExchangeRateV0,
+}
+
+/// Pre-announced rate changes for a child. Swaps move the child onto the latest entry in effect
+/// before pricing. PDA of [b"rate-schedule", child_entangler]
+#[account]
+#[derive(Default)]
+pub struct RateScheduleV0 {
+  pub child_entangler: Pubkey,
+  // Sorted by strictly increasing effective_unix_time
+  pub entries: Vec<RateScheduleEntryV0>,
+  pub bump_seed: u8,
+}
+
+impl RateScheduleV0 {
+  pub fn active_rate(&self, unix_time: i64) -> Option<ExchangeRateV0> {
+    self
+      .entries
+      .iter()
+      .rev()
+      .find(|entry| unix_time >= entry.effective_unix_time)
+      .map(|entry| entry.rate)
+  }
+
+  pub fn is_valid(&self) -> bool {
+    self.entries.len() <= MAX_RATE_SCHEDULE_ENTRIES
+      && self.entries.iter().all(|entry| entry.rate.is_valid())
+      && self
+        .entries
+        .windows(2)
+        .all(|pair| pair[0].effective_unix_time < pair[1].effective_unix_time)
+  }
+}
+
+pub const MAX_SNAPSHOT_ENTRIES: usize = 64;
+
+/// Storage balances and cumulative swap volumes of a child as of one epoch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub struct SnapshotEntryV0 {
+  pub epoch: u64,
+  pub unix_time: i64,
+  pub parent_storage_balance: u64,
+  pub child_storage_balance: u64,
+  pub total_parent_to_child_amount: u64,
+  pub total_child_to_parent_amount: u64,
+}
+
+/// Most recent epoch checkpoints of a child, oldest first. Once full, each new snapshot drops the
+/// oldest. PDA of [b"snapshot-history", child_entangler]
+#[account]
+#[derive(Default)]
+pub struct SnapshotHistoryV0 {
+  pub child_entangler: Pubkey,
+  pub entries: Vec<SnapshotEntryV0>,
+  pub bump_seed: u8,
+}
+
+impl SnapshotHistoryV0 {
+  /// Anyone may snapshot, so at most one entry per epoch keeps the history from being flushed
+  pub fn push(&mut self, entry: SnapshotEntryV0) -> Result<()> {
+    if let Some(last) = self.entries.last() {
+      require!(entry.epoch > last.epoch, ErrorCode::SnapshotTooSoon);
+    }
+    if self.entries.len() >= MAX_SNAPSHOT_ENTRIES {
+      self.entries.remove(0);
+    }
+    self.entries.push(entry);
+
+    Ok(())
+  }
+}
+
+/// Deposits a wallet made into child storage.
+/// PDA of [b"top-off", child_entangler, depositor]
+#[account]
+#[derive(Default)]
+pub struct TopOffRecordV0 {
+  pub child_entangler: Pubkey,
+  pub depositor: Pubkey,
+  pub amount: u64,
+  pub reclaimed: bool,
+  pub bump_seed: u8,
+  // Rewards accounting, see RewardsPoolV0
+  pub reward_per_token_paid: u128,
+  pub rewards_owed: u64,
+}
+
+impl TopOffRecordV0 {
+  pub fn staked_amount(&self) -> u64 {
+    if self.reclaimed {
+      0
+    } else {
+      self.amount
+    }
+  }
+}
+
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Streams reward_per_second of the reward mint to TopOff depositors of a child entangler,
+/// pro rata to their outstanding deposits. PDA of [b"rewards", child_entangler]
+#[account]
+#[derive(Default)]
+pub struct RewardsPoolV0 {
+  pub child_entangler: Pubkey,
+  pub reward_mint: Pubkey,
+  pub reward_storage: Pubkey,
+  pub reward_per_second: u64,
+  pub start_unix_time: i64,
+  pub end_unix_time: i64,
+  // Rewards earned per staked token since start, scaled by REWARD_PRECISION
+  pub reward_per_token_stored: u128,
+  pub last_update_unix_time: i64,
+  pub total_staked: u64,
+
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+}
+
+impl RewardsPoolV0 {
+  pub fn signer_seeds(&self) -> [&[u8]; 3] {
+    [
+      b"rewards",
+      self.child_entangler.as_ref(),
+      std::slice::from_ref(&self.bump_seed),
+    ]
+  }
+
+  /// Accrues rewards up to unix_time. Must be called before total_staked changes
+  pub fn update(&mut self, unix_time: i64) -> Result<()> {
+    let now = unix_time.min(self.end_unix_time);
+    let from = self.last_update_unix_time.max(self.start_unix_time);
+
+    if now > from && self.total_staked > 0 {
+      // Positive since now > from
+      let elapsed = (now - from) as u128;
+      let emitted = elapsed
+        .checked_mul(self.reward_per_second as u128)
+        .ok_or(error!(ErrorCode::MathOverflow))?;
+      let accrued = math::mul_div_u128(
+        emitted,
+        REWARD_PRECISION,
+        self.total_staked as u128,
+        Rounding::Down,
+      )?;
+      self.reward_per_token_stored = math::add_u128(self.reward_per_token_stored, accrued)?;
+    }
+    self.last_update_unix_time = self.last_update_unix_time.max(now);
+
+    Ok(())
+  }
+
+  /// Moves rewards earned by a record into rewards_owed. Must be called after update
+  pub fn settle(&self, record: &mut TopOffRecordV0) -> Result<()> {
+    let earned = math::mul_div_u128(
+      record.staked_amount() as u128,
+      math::sub_u128(self.reward_per_token_stored, record.reward_per_token_paid)?,
+      REWARD_PRECISION,
+      Rounding::Down,
+    )?;
+    record.rewards_owed = math::add(record.rewards_owed, math::to_u64(earned)?)?;
+    record.reward_per_token_paid = self.reward_per_token_stored;
+
+    Ok(())
+  }
+}
+
+/// Reverse lookup from a parent and a child mint to the entangler between them, so that wallets
+/// that only know the two mints find it with a single derivation. Parents of the same mint under
+/// other dynamic seeds may have children of the same mint too, the lookup names the first one
+/// created and is freed when that child closes. Check the parent's authority before trusting it.
+/// PDA of [b"lookup", parent_mint, child_mint]
+#[account]
+#[derive(Default)]
+pub struct EntanglerLookupV0 {
+  pub parent_entangler: Pubkey,
+  pub child_entangler: Pubkey,
+  pub bump_seed: u8,
+}
+
+impl EntanglerLookupV0 {
+  /// Points the lookup at child_entangler, unless another child of the mint pair already holds it
+  pub fn claim(&mut self, parent_entangler: Pubkey, child_entangler: Pubkey, bump_seed: u8) {
+    if self.child_entangler != Pubkey::default() {
+      return;
+    }
+
+    self.parent_entangler = parent_entangler;
+    self.child_entangler = child_entangler;
+    self.bump_seed = bump_seed;
+  }
+}
+
+/// Finds a namespaced parent entangler from its deployer and dynamic seed. A seed names a single
+/// parent within a namespace. PDA of [b"namespace-lookup", namespace, sha256(dynamic_seed)]
+#[account]
+#[derive(Default)]
+pub struct NamespaceLookupV0 {
+  pub namespace: Pubkey,
+  pub parent_entangler: Pubkey,
+  pub parent_mint: Pubkey,
+  pub bump_seed: u8,
+}
+
+pub const MAX_AUTHORITY_SIGNERS: usize = 10;
+
+/// Native multisig control of a parent. Made the parent's authority by SetAuthorityConfigV0, and
+/// exercised through InvokeAsAuthorityConfigV0. PDA of [b"authority-config", parent_entangler]
+#[account]
+#[derive(Default)]
+pub struct AuthorityConfigV0 {
+  pub parent_entangler: Pubkey,
+  pub signers: Vec<Pubkey>,
+  pub threshold: u8,
+  pub bump_seed: u8,
+}
+
+impl AuthorityConfigV0 {
+  pub fn signer_seeds(&self) -> [&[u8]; 3] {
+    [
+      b"authority-config",
+      self.parent_entangler.as_ref(),
+      std::slice::from_ref(&self.bump_seed),
+    ]
+  }
+
+  pub fn is_valid(&self) -> bool {
+    self.threshold > 0
+      && self.threshold as usize <= self.signers.len()
+      && self.signers.len() <= MAX_AUTHORITY_SIGNERS
+      && self
+        .signers
+        .iter()
+        .enumerate()
+        .all(|(i, signer)| !self.signers[..i].contains(signer))
+  }
+
+  /// How many distinct configured signers are among signed
+  pub fn approvals(&self, signed: &[Pubkey]) -> usize {
+    self
+      .signers
+      .iter()
+      .filter(|signer| signed.contains(signer))
+      .count()
+  }
+}
+
+pub const CHILD_REGISTRY_PAGE_SIZE: usize = 128;
+
+/// A page of child entangler keys for a parent entangler, so clients can list children
+/// with a single account fetch. Pages are PDAs of [b"registry", parent_entangler, page].
+/// Pages created before basket weights must be grown by MigrateChildRegistryV0 before use
+#[account(zero_copy)]
+pub struct ChildRegistryV0 {
+  pub parent_entangler: Pubkey,
+  pub page: u16,
+  pub num_entries: u16,
+  pub bump_seed: u8,
+  pub _padding: [u8; 3],
+  pub children: [Pubkey; 128], // CHILD_REGISTRY_PAGE_SIZE
+  // Share of a SwapParentForBasketV0 input each child receives, indexed like children
+  pub weights_bps: [u16; 128], // CHILD_REGISTRY_PAGE_SIZE
+}
+
+impl ChildRegistryV0 {
+  pub fn entries(&self) -> &[Pubkey] {
+    &self.children[..self.num_entries as usize]
+  }
+
+  /// The weighted children of the page's basket, in registry order
+  pub fn basket(&self) -> Vec<(Pubkey, u16)> {
+    self
+      .entries()
+      .iter()
+      .zip(self.weights_bps.iter())
+      .filter(|(_, weight)| **weight > 0)
+      .map(|(child, weight)| (*child, *weight))
+      .collect()
+  }
+}
+
+/// A Token-2022 mint backed 1:1 by an underlying spl mint held in storage.
+/// PDA of [b"wrapper", underlying_mint]
+#[account]
+#[derive(Default)]
+pub struct WrappedMintV0 {
+  pub underlying_mint: Pubkey,
+  pub wrapped_mint: Pubkey,
+  pub underlying_storage: Pubkey,
+  pub transfer_fee_basis_points: Option<u16>,
+  pub created_at_unix_time: i64,
+
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+
+  // When set, the wrapper is the wrapped mint's permanent delegate and this authority may claw
+  // wrapped tokens back through ClawbackV0
+  pub clawback_authority: Option<Pubkey>,
+}
+
+impl WrappedMintV0 {
+  pub fn signer_seeds(&self) -> [&[u8]; 3] {
+    [
+      b"wrapper",
+      self.underlying_mint.as_ref(),
+      std::slice::from_ref(&self.bump_seed),
+    ]
+  }
+}
+
+/// Layout deployed before V1. Only read by the migration instructions
 #[account]
 #[derive(Default)]
 pub struct FungibleParentEntanglerV0 {
@@ -16,6 +1337,7 @@ pub struct FungibleParentEntanglerV0 {
   pub storage_bump_seed: u8,
 }
 
+/// Layout deployed before V1. Only read by the migration instructions
 #[account]
 #[derive(Default)]
 pub struct FungibleChildEntanglerV0 {
@@ -29,3 +1351,61 @@ pub struct FungibleChildEntanglerV0 {
   pub bump_seed: u8,
   pub storage_bump_seed: u8,
 }
+
+/// FungibleParentEntanglerV1 as written at version 1. It shares the V1 discriminator, so it is
+/// decoded from the bytes after it. Only read by MigrateParentEntanglerV1ToV2
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FungibleParentEntanglerV1Version1 {
+  pub version: u16,
+  pub parent_mint: Pubkey,
+  pub parent_storage: Pubkey,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub created_at_unix_time: i64,
+  pub num_children: u32,
+  pub next_child_index: u32,
+  pub authority: Option<Pubkey>,
+  pub name: String,
+  pub uri: String,
+
+  pub dynamic_seed: Vec<u8>,
+  pub dynamic_seed_hash: [u8; 32],
+  pub rent_refund: Pubkey,
+  pub legacy_seeds: bool,
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+
+  pub reserved: [u64; 16], // ENTANGLER_RESERVED_WORDS
+}
+
+/// FungibleChildEntanglerV1 as written at version 1. It shares the V1 discriminator, so it is
+/// decoded from the bytes after it. Only read by MigrateChildEntanglerV1ToV2
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FungibleChildEntanglerV1Version1 {
+  pub version: u16,
+  pub parent_entangler: Pubkey,
+  pub child_mint: Pubkey,
+  pub child_storage: Pubkey,
+  pub go_live_unix_time: i64,
+  pub freeze_swap_unix_time: Option<i64>,
+  pub created_at_unix_time: i64,
+  pub index: u32,
+  pub vesting: Option<VestingConfigV0>,
+  pub refund_window_seconds: Option<i64>,
+  pub expiry_unix_time: Option<i64>,
+  pub total_top_off_amount: u64,
+  pub reclaim_snapshot_amount: Option<u64>,
+  pub rewards_pool: Option<Pubkey>,
+  pub min_lock_seconds: Option<i64>,
+  pub burn: Option<BurnConfigV0>,
+  pub rent_refund: Pubkey,
+  pub rate: ExchangeRateV0,
+  pub swap_fee_bps: u16,
+  pub max_parent_to_child_amount: Option<u64>,
+  pub total_parent_to_child_amount: u64,
+
+  pub bump_seed: u8,
+  pub storage_bump_seed: u8,
+
+  pub reserved: [u64; 16], // ENTANGLER_RESERVED_WORDS
+}
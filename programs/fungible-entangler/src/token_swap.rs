@@ -0,0 +1,185 @@
+//! Accepts the SPL Token Swap `Swap` instruction, so that routers built for token-swap pools can
+//! swap through a child entangler. Token-swap instructions carry a one byte tag rather than an
+//! Anchor discriminator, so they reach the program through its fallback.
+//!
+//! Accounts follow token-swap's layout, with entangler accounts in the pool's slots:
+//!
+//! 0. `[writable]` Child entangler, the swap
+//! 1. `[]` Parent entangler, the swap authority
+//! 2. `[signer]` User transfer authority
+//! 3. `[writable]` Source
+//! 4. `[writable]` Storage of the input mint, the swap source
+//! 5. `[writable]` Storage of the output mint, the swap destination
+//! 6. `[writable]` Destination
+//! 7. `[writable]` Parent mint, in the pool mint slot
+//! 8. `[writable]` Child mint, in the pool fee account slot
+//! 9. `[]` Token program
+//!
+//! Followed by what every entangler swap checks, in place of token-swap's host fee account:
+//!
+//! 10. `[]` Global config
+//! 11. `[]` Instructions sysvar
+//! 12. `[]` Rate schedule PDA of the child
+//! 13. `[]` spl-noop, which the swap event is logged through
+//!
+//! Token-swap's layout has no slot for the swapper's SwapRecordV0, so children that track swaps,
+//! whose refunds and fee rebates depend on every swap being recorded, can't be swapped through it.
+
+use crate::{
+  account_compression::noop,
+  error::ErrorCode,
+  instructions::swap::{
+    arg::SwapV0Args, execute_child_for_parent, execute_parent_for_child, SwapAccounts,
+  },
+  state::*,
+  util::{immediate_caller, is_frozen_token_account},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar};
+use anchor_spl::token::{self, Mint, TokenAccount};
+use std::convert::TryInto;
+
+// token-swap's SwapInstruction::Swap
+const SWAP_TAG: u8 = 1;
+const NUM_ACCOUNTS: usize = 14;
+
+pub struct TokenSwapArgs {
+  pub amount_in: u64,
+  pub minimum_amount_out: u64,
+}
+
+impl TokenSwapArgs {
+  pub fn unpack(data: &[u8]) -> Option<Self> {
+    match data {
+      [SWAP_TAG, rest @ ..] if rest.len() == 16 => Some(TokenSwapArgs {
+        amount_in: u64::from_le_bytes(rest[..8].try_into().ok()?),
+        minimum_amount_out: u64::from_le_bytes(rest[8..].try_into().ok()?),
+      }),
+      _ => None,
+    }
+  }
+}
+
+pub fn process<'info>(accounts: &[AccountInfo<'info>], data: &[u8]) -> Result<()> {
+  let args = TokenSwapArgs::unpack(data)
+    .ok_or(anchor_lang::error::ErrorCode::InstructionFallbackNotFound)?;
+  require!(accounts.len() >= NUM_ACCOUNTS, ErrorCode::InvalidArgs);
+  let [child_info, parent_info, authority, source_info, swap_source, swap_destination, destination, parent_mint_info, child_mint_info, token_program, global_config_info, instructions, rate_schedule, noop_program]: &[AccountInfo<'info>; NUM_ACCOUNTS] =
+    accounts[..NUM_ACCOUNTS].try_into().unwrap();
+
+  let mut parent_entangler: Account<FungibleParentEntanglerV1> = Account::try_from(parent_info)?;
+  let mut child_entangler: Account<FungibleChildEntanglerV1> = Account::try_from(child_info)?;
+  let global_config: Account<GlobalConfigV0> = Account::try_from(global_config_info)?;
+  let source: Account<TokenAccount> = Account::try_from(source_info)?;
+  let mut parent_mint: Account<Mint> = Account::try_from(parent_mint_info)?;
+  let mut child_mint: Account<Mint> = Account::try_from(child_mint_info)?;
+
+  require!(
+    child_info.is_writable && authority.is_signer,
+    ErrorCode::InvalidArgs
+  );
+  require!(
+    parent_entangler.is_supported_version() && child_entangler.is_supported_version(),
+    ErrorCode::UnsupportedVersion
+  );
+  require!(
+    !child_entangler.tracks_swap_records(),
+    ErrorCode::SwapRecordRequired
+  );
+  require!(
+    child_entangler.parent_entangler == parent_entangler.key(),
+    ErrorCode::ParentEntanglerMismatch
+  );
+  require!(
+    parent_entangler.parent_mint == parent_mint.key(),
+    ErrorCode::ParentMintMismatch
+  );
+  require!(
+    child_entangler.child_mint == child_mint.key(),
+    ErrorCode::ChildMintMismatch
+  );
+  require!(
+    global_config.key() == Pubkey::find_program_address(&[b"global-config"], &crate::ID).0,
+    ErrorCode::InvalidArgs
+  );
+  require!(!global_config.paused, ErrorCode::GloballyPaused);
+  require!(
+    instructions.key() == sysvar::instructions::ID
+      && child_entangler.allows_caller(immediate_caller(instructions)?),
+    ErrorCode::CpiNotAllowed
+  );
+  require!(
+    rate_schedule.key()
+      == Pubkey::find_program_address(
+        &[b"rate-schedule", child_entangler.key().as_ref()],
+        &crate::ID
+      )
+      .0,
+    ErrorCode::InvalidRateSchedule
+  );
+  require!(token_program.key() == token::ID, ErrorCode::InvalidArgs);
+  require!(noop_program.key() == noop::ID, ErrorCode::InvalidArgs);
+  require!(!source.is_frozen(), ErrorCode::SourceAccountFrozen);
+  require!(
+    !is_frozen_token_account(destination),
+    ErrorCode::DestinationAccountFrozen
+  );
+
+  let parent_to_child = swap_source.key() == parent_entangler.parent_storage;
+  let (parent_storage_info, child_storage_info) = if parent_to_child {
+    (swap_source, swap_destination)
+  } else {
+    (swap_destination, swap_source)
+  };
+  require!(
+    parent_storage_info.key() == parent_entangler.parent_storage
+      && child_storage_info.key() == child_entangler.child_storage,
+    ErrorCode::StorageMismatch
+  );
+  let mut parent_storage: Account<TokenAccount> = Account::try_from(parent_storage_info)?;
+  let mut child_storage: Account<TokenAccount> = Account::try_from(child_storage_info)?;
+  require!(
+    !parent_storage.is_frozen() && !child_storage.is_frozen(),
+    ErrorCode::StorageAccountFrozen
+  );
+
+  let clock = Clock::get()?;
+  let swap_args = SwapV0Args {
+    amount: Some(args.amount_in),
+    all: None,
+    output_splits_bps: vec![],
+    memo: None,
+    allow_partial: false,
+  };
+  let swap_accounts = SwapAccounts {
+    parent_entangler: &mut parent_entangler,
+    parent_storage: &mut parent_storage,
+    parent_mint: &mut parent_mint,
+    child_entangler: &mut child_entangler,
+    child_storage: &mut child_storage,
+    child_mint: &mut child_mint,
+    source: &source,
+    source_authority: authority,
+    source_signer_seeds: &[],
+    destination,
+    rate_schedule,
+    token_program,
+    noop_program,
+    revealed_commit: false,
+    vested: false,
+    locked: false,
+    queued: false,
+  };
+  let outcome = if parent_to_child {
+    execute_parent_for_child(swap_accounts, &[], &clock, &swap_args)?
+  } else {
+    execute_child_for_parent(swap_accounts, &[], &clock, &swap_args)?
+  };
+  require!(
+    outcome.output_amount >= args.minimum_amount_out,
+    ErrorCode::SlippageExceeded
+  );
+
+  // Persist the child's totals, volume and scheduled rate. The parent sits in token-swap's readonly
+  // authority slot and is not written back
+  child_entangler.exit(&crate::ID)
+}
@@ -1,35 +1,394 @@
-use anchor_lang::{prelude::*, solana_program};
+use crate::{error::ErrorCode, state::*};
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    hash::{hash, hashv},
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    program::{invoke, invoke_signed},
+    program_option::COption,
+    program_pack::Pack,
+    system_instruction, system_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+  },
+};
+use anchor_spl::token::TokenAccount;
+use spl_token_2022::extension::StateWithExtensions;
 use std::convert::*;
 
-#[derive(Accounts)]
-pub struct CloseTokenAccount<'info> {
-  /// CHECK: Used in cpi
-  pub from: AccountInfo<'info>,
-  /// CHECK: Used in cpi
-  pub to: AccountInfo<'info>,
-  /// CHECK: Used in cpi
-  pub authority: AccountInfo<'info>,
+pub const MAX_DYNAMIC_SEED_LEN: usize = 64;
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_URI_LEN: usize = 200;
+
+/// Parent entanglers are derived from the sha256 of their dynamic seed, so seeds of any length up
+/// to MAX_DYNAMIC_SEED_LEN (including human readable names) fit within the 32 byte seed limit.
+pub fn hash_seed(seed: &[u8]) -> [u8; 32] {
+  hash(seed).to_bytes()
+}
+
+pub const NAMESPACED_SEED_DOMAIN: &[u8] = b"namespaced-entangler";
+
+/// Seed hash of a parent entangler in namespace's namespace, so that deployers can't claim each
+/// other's seeds. The preimage is the domain tag, the namespace and the seed's own hash, which at
+/// 84 bytes is longer than any dynamic seed hash_seed accepts, so a plain parent can never land on
+/// a namespaced parent's address.
+pub fn hash_namespaced_seed(namespace: &Pubkey, seed: &[u8]) -> [u8; 32] {
+  hashv(&[NAMESPACED_SEED_DOMAIN, namespace.as_ref(), &hash_seed(seed)]).to_bytes()
+}
+
+pub fn validate_metadata(name: &str, uri: &str) -> Result<()> {
+  require!(
+    name.len() <= MAX_NAME_LEN && uri.len() <= MAX_URI_LEN,
+    ErrorCode::InvalidStringLength
+  );
+
+  Ok(())
+}
+
+/// Swap fee and fee tiers, with tiers sorted by strictly increasing min amount
+pub fn validate_fees(swap_fee_bps: u16, fee_tiers: &[FeeTierV0]) -> Result<()> {
+  require!(swap_fee_bps <= 10000, ErrorCode::InvalidSwapFee);
+  require!(
+    fee_tiers.len() <= MAX_FEE_TIERS
+      && fee_tiers.iter().all(|tier| tier.bps <= 10000)
+      && fee_tiers
+        .windows(2)
+        .all(|tiers| tiers[0].min_amount < tiers[1].min_amount),
+    ErrorCode::InvalidFeeTiers
+  );
+
+  Ok(())
+}
+
+pub fn charge_creation_fee<'info>(
+  global_config: &GlobalConfigV0,
+  payer: &AccountInfo<'info>,
+  fee_destination: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+) -> Result<()> {
+  if global_config.creation_fee_lamports == 0 {
+    return Ok(());
+  }
+
+  msg!(
+    "Charging creation fee of {} lamports",
+    global_config.creation_fee_lamports
+  );
+  invoke(
+    &system_instruction::transfer(
+      payer.key,
+      fee_destination.key,
+      global_config.creation_fee_lamports,
+    ),
+    &[
+      payer.clone(),
+      fee_destination.clone(),
+      system_program.clone(),
+    ],
+  )?;
+
+  Ok(())
+}
+
+/// Creates a rent exempt account at a program derived address. Used where the set of accounts to
+/// create is only known at runtime, so anchor's `init` cannot be used.
+pub fn create_pda_account<'info>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  account: &AccountInfo<'info>,
+  space: usize,
+  owner: &Pubkey,
+  seeds: &[&[u8]],
+) -> Result<()> {
+  require!(
+    account.lamports() == 0 && account.data_is_empty(),
+    ErrorCode::AccountAlreadyInitialized
+  );
+  invoke_signed(
+    &system_instruction::create_account(
+      payer.key,
+      account.key,
+      Rent::get()?.minimum_balance(space),
+      space as u64,
+      owner,
+    ),
+    &[payer.clone(), account.clone(), system_program.clone()],
+    &[seeds],
+  )?;
+
+  Ok(())
+}
+
+/// Closes the mint pair lookup to destination if child_entangler holds it. The lookup may belong to
+/// another parent's child of the same mints, or be gone with the child that held it
+pub fn close_lookup_if_held<'info>(
+  lookup: &AccountInfo<'info>,
+  child_entangler: &Pubkey,
+  destination: &AccountInfo<'info>,
+) -> Result<()> {
+  if lookup.owner != &crate::ID || lookup.data_is_empty() {
+    return Ok(());
+  }
+  let held = Account::<EntanglerLookupV0>::try_from(lookup)?.child_entangler == *child_entangler;
+  if !held {
+    return Ok(());
+  }
+
+  let lamports = lookup.lamports();
+  **destination.try_borrow_mut_lamports()? = destination
+    .lamports()
+    .checked_add(lamports)
+    .ok_or(error!(ErrorCode::MathOverflow))?;
+  **lookup.try_borrow_mut_lamports()? = 0;
+  lookup.try_borrow_mut_data()?.fill(0);
+
+  Ok(())
+}
+
+/// Creates a rent exempt account, also when someone has already sent lamports to its address
+fn create_rent_exempt_account<'info>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  account: &AccountInfo<'info>,
+  space: usize,
+  owner: &Pubkey,
+  signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+  let rent = Rent::get()?;
+  let lamports = account.lamports();
+  if lamports == 0 {
+    invoke_signed(
+      &system_instruction::create_account(
+        payer.key,
+        account.key,
+        rent.minimum_balance(space),
+        space as u64,
+        owner,
+      ),
+      &[payer.clone(), account.clone(), system_program.clone()],
+      signer_seeds,
+    )?;
+    return Ok(());
+  }
+
+  let top_up = rent.minimum_balance(space).saturating_sub(lamports);
+  if top_up > 0 {
+    invoke(
+      &system_instruction::transfer(payer.key, account.key, top_up),
+      &[payer.clone(), account.clone(), system_program.clone()],
+    )?;
+  }
+  invoke_signed(
+    &system_instruction::allocate(account.key, space as u64),
+    &[account.clone(), system_program.clone()],
+    signer_seeds,
+  )?;
+  invoke_signed(
+    &system_instruction::assign(account.key, owner),
+    &[account.clone(), system_program.clone()],
+    signer_seeds,
+  )?;
+
+  Ok(())
 }
 
-pub fn close_token_account<'a, 'b, 'c, 'info>(
-  ctx: CpiContext<'a, 'b, 'c, 'info, CloseTokenAccount<'info>>,
+/// Creates a token account for mint owned by authority at a program derived address. Unlike
+/// anchor's `token::` init, InitializeAccount3 needs no rent sysvar account. spl-token 3.2 has no
+/// builder for it, but Token-2022 packs the base token instructions identically
+pub fn create_pda_token_account<'info>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  token_program: &AccountInfo<'info>,
+  account: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  authority: &Pubkey,
+  seeds: &[&[u8]],
 ) -> Result<()> {
-  let ix = spl_token::instruction::close_account(
+  create_rent_exempt_account(
+    payer,
+    system_program,
+    account,
+    spl_token::state::Account::LEN,
+    &spl_token::ID,
+    &[seeds],
+  )?;
+
+  let mut ix = spl_token_2022::instruction::initialize_account3(
+    &spl_token_2022::ID,
+    account.key,
+    mint.key,
+    authority,
+  )?;
+  ix.program_id = spl_token::ID;
+  invoke(&ix, &[account.clone(), mint.clone(), token_program.clone()])?;
+
+  Ok(())
+}
+
+/// create_pda_token_account unless account already exists, in which case it must already be
+/// authority's token account for mint
+pub fn init_pda_token_account_if_needed<'info>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  token_program: &AccountInfo<'info>,
+  account: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  authority: &Pubkey,
+  seeds: &[&[u8]],
+) -> Result<()> {
+  if account.owner == &system_program::ID {
+    return create_pda_token_account(
+      payer,
+      system_program,
+      token_program,
+      account,
+      mint,
+      authority,
+      seeds,
+    );
+  }
+
+  let existing: Account<TokenAccount> = Account::try_from(account)?;
+  require!(
+    existing.mint == mint.key(),
+    anchor_lang::error::ErrorCode::ConstraintTokenMint
+  );
+  require!(
+    existing.owner == *authority,
+    anchor_lang::error::ErrorCode::ConstraintTokenOwner
+  );
+
+  Ok(())
+}
+
+/// Creates mint, which signs the transaction, with InitializeMint2 rather than anchor's `mint::`
+/// init, which needs the rent sysvar account
+pub fn create_mint_account<'info>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  token_program: &AccountInfo<'info>,
+  mint: &AccountInfo<'info>,
+  decimals: u8,
+  authority: &Pubkey,
+  freeze_authority: Option<&Pubkey>,
+) -> Result<()> {
+  create_rent_exempt_account(
+    payer,
+    system_program,
+    mint,
+    spl_token::state::Mint::LEN,
     &spl_token::ID,
-    ctx.accounts.from.key,
-    ctx.accounts.to.key,
-    ctx.accounts.authority.key,
     &[],
   )?;
-  solana_program::program::invoke_signed(
-    &ix,
-    &[
-      ctx.accounts.from.clone(),
-      ctx.accounts.to.clone(),
-      ctx.accounts.authority.clone(),
-      ctx.program.clone(),
-    ],
-    ctx.signer_seeds,
-  )
-  .map_err(|e| e.into())
+
+  let mut ix = spl_token_2022::instruction::initialize_mint2(
+    &spl_token_2022::ID,
+    mint.key,
+    authority,
+    freeze_authority,
+    decimals,
+  )?;
+  ix.program_id = spl_token::ID;
+  invoke(&ix, &[mint.clone(), token_program.clone()])?;
+
+  Ok(())
+}
+
+/// Grows or shrinks account to new_size, topping up rent from payer when it grows
+pub fn realloc_with_rent<'info>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  account: &AccountInfo<'info>,
+  new_size: usize,
+) -> Result<()> {
+  let rent = Rent::get()?;
+  let old_size = account.data_len();
+  if new_size > old_size {
+    require!(
+      new_size - old_size <= MAX_PERMITTED_DATA_INCREASE,
+      ErrorCode::InvalidDataIncrease
+    );
+  }
+
+  let lamports_diff = rent
+    .minimum_balance(new_size)
+    .saturating_sub(account.lamports());
+  if lamports_diff > 0 {
+    invoke(
+      &system_instruction::transfer(payer.key, account.key, lamports_diff),
+      &[payer.clone(), account.clone(), system_program.clone()],
+    )?;
+  }
+
+  msg!("Resizing to {} with lamports {}", new_size, lamports_diff);
+  account.realloc(new_size, true)?;
+
+  Ok(())
+}
+
+/// Child entanglers with rewards expect their RewardsPoolV0 as the first remaining account
+pub fn load_rewards_pool<'info>(
+  child_entangler: &FungibleChildEntanglerV1,
+  remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Option<Account<'info, RewardsPoolV0>>> {
+  match child_entangler.rewards_pool {
+    None => Ok(None),
+    Some(rewards_pool) => {
+      let info = remaining_accounts
+        .first()
+        .ok_or(error!(ErrorCode::MissingRewardsPool))?;
+      require!(info.key() == rewards_pool, ErrorCode::MissingRewardsPool);
+
+      Ok(Some(Account::try_from(info)?))
+    }
+  }
+}
+
+/// The program that invoked this one: this program itself for a top level instruction, and the
+/// top level instruction's program for a direct CPI. The runtime doesn't expose the caller of a
+/// nested CPI, so deeper calls return None
+pub fn immediate_caller(instructions: &AccountInfo) -> Result<Option<Pubkey>> {
+  let stack_height = get_stack_height();
+  if stack_height <= TRANSACTION_LEVEL_STACK_HEIGHT {
+    return Ok(Some(crate::ID));
+  }
+  if stack_height > TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+    return Ok(None);
+  }
+
+  let index = load_current_index_checked(instructions)?;
+  Ok(Some(
+    load_instruction_at_checked(index as usize, instructions)?.program_id,
+  ))
+}
+
+/// Whether account is a token account of either token program that holder owns or is the delegate
+/// of, so that holder's signature can move its tokens
+pub fn is_token_account_held_by(account: &AccountInfo, holder: &Pubkey) -> bool {
+  if *account.owner != spl_token::ID && *account.owner != spl_token_2022::ID {
+    return false;
+  }
+
+  account
+    .try_borrow_data()
+    .ok()
+    .and_then(|data| {
+      StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)
+        .ok()
+        .map(|token_account| {
+          token_account.base.owner == *holder
+            || token_account.base.delegate == COption::Some(*holder)
+        })
+    })
+    .unwrap_or(false)
+}
+
+/// Whether account is an initialized token account that its mint's freeze authority has frozen.
+/// Anything else is left to the token program to reject
+pub fn is_frozen_token_account(account: &AccountInfo) -> bool {
+  account
+    .try_borrow_data()
+    .ok()
+    .and_then(|data| spl_token::state::Account::unpack(&data).ok())
+    .map_or(false, |token_account| token_account.is_frozen())
 }
@@ -0,0 +1,165 @@
+//! Runs SwapParentForBasketV0 against the program in solana-program-test.
+
+use anchor_lang::prelude::Pubkey;
+use fungible_entangler::{
+  instructions::SwapParentForBasketV0Args,
+  state::{FungibleChildEntanglerV1, SwapRecordV0},
+};
+use fungible_entangler_test_utils::{
+  add_child_entangler, add_child_registry, add_swap_record, create_mint, create_token_account,
+  get_program_account, instructions, mint_to, pda, process_instructions, refresh_blockhash,
+  swap_fixture::{DECIMALS, SOURCE_AMOUNT, STORAGE_AMOUNT},
+  EntanglerKeys, SwapFixture,
+};
+
+/// A fixture whose parent has a second child, both funded, adjusted by configure and listed in
+/// registry page 0 at 60/40
+async fn basket_fixture(
+  configure: fn(&mut FungibleChildEntanglerV1),
+) -> (SwapFixture, EntanglerKeys, Pubkey) {
+  let mut fixture = SwapFixture::new(configure).await;
+  let payer = fixture.payer();
+  let keys = fixture.keys;
+  let context = &mut fixture.context;
+
+  let child_mint = create_mint(context, DECIMALS).await.unwrap();
+  let second = add_child_entangler(context, &keys, &child_mint, configure)
+    .await
+    .unwrap();
+  mint_to(
+    context,
+    &child_mint,
+    &second.child_storage(),
+    STORAGE_AMOUNT,
+  )
+  .await
+  .unwrap();
+  let second_account = create_token_account(context, &child_mint, &payer)
+    .await
+    .unwrap();
+  add_child_registry(
+    context,
+    &keys.parent_entangler,
+    &[
+      (keys.child_entangler, 6_000),
+      (second.child_entangler, 4_000),
+    ],
+  );
+
+  (fixture, second, second_account)
+}
+
+#[tokio::test]
+async fn test_basket_splits_by_weight() {
+  let (mut fixture, second, second_account) = basket_fixture(|_| {}).await;
+  let keys = fixture.keys;
+  let payer = fixture.payer();
+  let registry = pda::child_registry(&keys.parent_entangler, 0);
+
+  let ix = instructions::swap_parent_for_basket(
+    &keys.parent_mint,
+    &keys.parent_entangler,
+    &registry,
+    &fixture.parent_account,
+    &payer,
+    &[(keys, fixture.child_account), (second, second_account)],
+    SwapParentForBasketV0Args {
+      amount: 10_001,
+      min_output_amounts: vec![],
+    },
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  // The last leg takes the rounding remainder
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - 10_001
+  );
+  assert_eq!(
+    fixture.balance(keys.parent_storage()).await,
+    STORAGE_AMOUNT + 10_001
+  );
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 6_000
+  );
+  assert_eq!(fixture.balance(second_account).await, 4_001);
+  assert_eq!(
+    fixture.balance(second.child_storage()).await,
+    STORAGE_AMOUNT - 4_001
+  );
+}
+
+#[tokio::test]
+async fn test_basket_enforces_min_outputs() {
+  let (mut fixture, second, second_account) = basket_fixture(|_| {}).await;
+  let keys = fixture.keys;
+  let payer = fixture.payer();
+  let registry = pda::child_registry(&keys.parent_entangler, 0);
+
+  let ix = instructions::swap_parent_for_basket(
+    &keys.parent_mint,
+    &keys.parent_entangler,
+    &registry,
+    &fixture.parent_account,
+    &payer,
+    &[(keys, fixture.child_account), (second, second_account)],
+    SwapParentForBasketV0Args {
+      amount: 10_000,
+      min_output_amounts: vec![6_000, 4_001],
+    },
+  );
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+  assert_eq!(fixture.balance(fixture.parent_account).await, SOURCE_AMOUNT);
+}
+
+#[tokio::test]
+async fn test_basket_records_legs_into_tracking_children() {
+  let (mut fixture, second, second_account) =
+    basket_fixture(|child| child.refund_window_seconds = Some(3_600)).await;
+  let keys = fixture.keys;
+  let payer = fixture.payer();
+  let registry = pda::child_registry(&keys.parent_entangler, 0);
+  let ix = instructions::swap_parent_for_basket(
+    &keys.parent_mint,
+    &keys.parent_entangler,
+    &registry,
+    &fixture.parent_account,
+    &payer,
+    &[(keys, fixture.child_account), (second, second_account)],
+    SwapParentForBasketV0Args {
+      amount: 10_000,
+      min_output_amounts: vec![],
+    },
+  );
+
+  // Legs into children that track swaps need the swapper's record for each
+  assert!(
+    process_instructions(&mut fixture.context, std::slice::from_ref(&ix), &[])
+      .await
+      .is_err()
+  );
+  add_swap_record(&mut fixture.context, &second, &payer);
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  for (leg, amount) in [(keys, 6_000), (second, 4_000)] {
+    let address = pda::swap_record(&leg.child_entangler, &payer);
+    let record: SwapRecordV0 = get_program_account(&mut fixture.context, &address)
+      .await
+      .unwrap();
+    assert_eq!(record.parent_to_child_amount, amount);
+    assert_eq!(record.refundable_child_amount, amount);
+    let child: FungibleChildEntanglerV1 =
+      get_program_account(&mut fixture.context, &leg.child_entangler)
+        .await
+        .unwrap();
+    assert_eq!(child.recorded_parent_to_child_amount, amount);
+  }
+}
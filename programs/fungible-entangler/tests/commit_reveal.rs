@@ -0,0 +1,147 @@
+//! Runs CommitSwapV0 and RevealAndSwapV0 against the program in solana-program-test.
+
+use anchor_lang::{
+  prelude::{Pubkey, Rent},
+  solana_program::instruction::Instruction,
+};
+use fungible_entangler::{
+  instructions::{initialize_fungible_child_entangler_v0::SWAP_COMMIT_SIZE, RevealAndSwapV0Args},
+  state::SwapCommitV0,
+};
+use fungible_entangler_test_utils::{
+  add_swap_commit, instructions, pda, process_instructions, swap_fixture::SOURCE_AMOUNT,
+  SwapFixture,
+};
+
+const COMMIT_REVEAL_SECONDS: i64 = 3_600;
+const SALT: [u8; 32] = [7; 32];
+
+fn reveal_args(amount: u64, salt: [u8; 32]) -> RevealAndSwapV0Args {
+  RevealAndSwapV0Args {
+    amount,
+    parent_to_child: true,
+    salt,
+    memo: None,
+  }
+}
+
+fn reveal(fixture: &SwapFixture, commit_payer: &Pubkey, args: RevealAndSwapV0Args) -> Instruction {
+  instructions::reveal_and_swap(
+    &fixture.payer(),
+    commit_payer,
+    &fixture.keys,
+    &fixture.parent_account,
+    &fixture.payer(),
+    &fixture.child_account,
+    args,
+  )
+}
+
+/// A child in its commit reveal window, with the payer committed to swapping 10_000 parent tokens
+/// in a commitment paid for by the returned wallet
+async fn committed_fixture() -> (SwapFixture, Pubkey) {
+  let mut fixture = SwapFixture::new(|child| {
+    child.commit_reveal_seconds = Some(COMMIT_REVEAL_SECONDS);
+  })
+  .await;
+  let payer = fixture.payer();
+  let commitment =
+    SwapCommitV0::commitment(&fixture.keys.child_entangler, &payer, 10_000, true, &SALT);
+  let commit_payer = Pubkey::new_unique();
+  add_swap_commit(
+    &mut fixture.context,
+    &fixture.keys,
+    &payer,
+    &commit_payer,
+    commitment,
+  );
+
+  (fixture, commit_payer)
+}
+
+#[tokio::test]
+async fn test_reveal_swaps_and_refunds_the_commit_rent() {
+  let (mut fixture, commit_payer) = committed_fixture().await;
+  let swap_commit = pda::swap_commit(&fixture.keys.child_entangler, &fixture.payer());
+
+  // Only committed swaps run inside the window
+  assert!(fixture.swap_parent_for_child(10_000).await.is_err());
+
+  // The rent goes back to whoever paid for the commitment, not to the revealing payer
+  let payer = fixture.payer();
+  let ix = reveal(&fixture, &payer, reveal_args(10_000, SALT));
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+
+  let ix = reveal(&fixture, &commit_payer, reveal_args(10_000, SALT));
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 10_000
+  );
+  let banks_client = &mut fixture.context.banks_client;
+  assert!(banks_client
+    .get_account(swap_commit)
+    .await
+    .unwrap()
+    .is_none());
+  assert_eq!(
+    banks_client.get_balance(commit_payer).await.unwrap(),
+    Rent::default().minimum_balance(SWAP_COMMIT_SIZE)
+  );
+}
+
+#[tokio::test]
+async fn test_reveal_must_match_the_commitment() {
+  let (mut fixture, commit_payer) = committed_fixture().await;
+
+  for args in [reveal_args(10_000, [8; 32]), reveal_args(9_999, SALT)] {
+    let ix = reveal(&fixture, &commit_payer, args);
+    assert!(process_instructions(&mut fixture.context, &[ix], &[])
+      .await
+      .is_err());
+  }
+  assert_eq!(fixture.balance(fixture.child_account).await, SOURCE_AMOUNT);
+}
+
+// CommitSwapV0 creates the SwapCommitV0
+#[cfg(feature = "test-bpf")]
+#[tokio::test]
+async fn test_commit_before_go_live_then_reveal() {
+  use fungible_entangler::instructions::CommitSwapV0Args;
+  use fungible_entangler_test_utils::advance_clock;
+
+  let mut fixture = SwapFixture::new(|child| {
+    child.go_live_unix_time += COMMIT_REVEAL_SECONDS;
+    child.commit_reveal_seconds = Some(COMMIT_REVEAL_SECONDS);
+  })
+  .await;
+  let payer = fixture.payer();
+  let commitment =
+    SwapCommitV0::commitment(&fixture.keys.child_entangler, &payer, 10_000, true, &SALT);
+
+  let ix = instructions::commit_swap(
+    &payer,
+    &payer,
+    &fixture.keys,
+    CommitSwapV0Args { commitment },
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  advance_clock(&mut fixture.context, COMMIT_REVEAL_SECONDS + 1)
+    .await
+    .unwrap();
+  let ix = reveal(&fixture, &payer, reveal_args(10_000, SALT));
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 10_000
+  );
+}
@@ -0,0 +1,75 @@
+//! Checks the hand built compressed-token transfers of compressed token swaps against Light's
+//! instruction layout, and how the change of their inputs is worked out.
+
+use anchor_lang::{prelude::Pubkey, solana_program::hash::hash, AnchorDeserialize};
+use fungible_entangler::compressed_token::{
+  change_output, InputTokenDataWithContext, PackedTokenTransferOutputData, TransferData, TRANSFER,
+};
+
+fn input(amount: u64, lamports: Option<u64>) -> InputTokenDataWithContext {
+  InputTokenDataWithContext {
+    amount,
+    lamports,
+    ..Default::default()
+  }
+}
+
+#[test]
+fn test_change_returns_the_rest_of_the_inputs() {
+  let owner = Pubkey::new_unique();
+  let inputs = [input(600, None), input(500, None)];
+
+  assert_eq!(
+    change_output(owner, &inputs, 1_000, 3).unwrap(),
+    Some(PackedTokenTransferOutputData {
+      owner,
+      amount: 100,
+      lamports: None,
+      merkle_tree_index: 3,
+      tlv: None,
+    })
+  );
+  assert_eq!(change_output(owner, &inputs, 1_100, 3).unwrap(), None);
+  assert!(change_output(owner, &inputs, 1_101, 3).is_err());
+}
+
+#[test]
+fn test_change_keeps_the_inputs_lamports() {
+  let owner = Pubkey::new_unique();
+  let inputs = [input(600, Some(1_000)), input(500, None), input(0, Some(5))];
+
+  let change = change_output(owner, &inputs, 1_100, 0).unwrap().unwrap();
+  assert_eq!(change.amount, 0);
+  assert_eq!(change.lamports, Some(1_005));
+}
+
+#[test]
+fn test_transfers_wrap_their_inputs_in_a_byte_vector() {
+  assert_eq!(TRANSFER, hash(b"global:transfer").to_bytes()[..8]);
+
+  let mint = Pubkey::new_unique();
+  let transfer = TransferData {
+    proof: None,
+    mint,
+    delegated_transfer: None,
+    input_token_data_with_context: vec![input(7, None)],
+    output_compressed_accounts: vec![],
+    is_compress: false,
+    compress_or_decompress_amount: Some(7),
+    cpi_context: None,
+    lamports_change_account_merkle_tree_index: None,
+  };
+  let data = transfer.instruction_data().unwrap();
+  assert_eq!(data[..8], TRANSFER);
+
+  let inputs = Vec::<u8>::deserialize(&mut &data[8..]).unwrap();
+  assert_eq!(inputs.len(), data.len() - 12);
+  let decoded = TransferData::deserialize(&mut inputs.as_slice()).unwrap();
+  assert_eq!(decoded.mint, mint);
+  assert_eq!(decoded.input_token_data_with_context, vec![input(7, None)]);
+  assert_eq!(decoded.compress_or_decompress_amount, Some(7));
+  // proof, mint and delegated_transfer come first
+  assert_eq!(inputs[0], 0);
+  assert_eq!(inputs[1..33], mint.to_bytes());
+  assert_eq!(inputs[33], 0);
+}
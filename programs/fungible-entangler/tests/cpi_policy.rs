@@ -0,0 +1,36 @@
+//! Checks which callers each CPI policy lets swap through a child.
+
+use anchor_lang::prelude::Pubkey;
+use fungible_entangler::state::{CpiPolicyV0, FungibleChildEntanglerV1};
+
+fn child(cpi_policy: CpiPolicyV0, cpi_allowlist: Vec<Pubkey>) -> FungibleChildEntanglerV1 {
+  FungibleChildEntanglerV1 {
+    cpi_policy,
+    cpi_allowlist,
+    ..Default::default()
+  }
+}
+
+#[test]
+fn test_top_level_swaps_are_always_allowed() {
+  let child = child(CpiPolicyV0::Deny, vec![]);
+  assert!(child.allows_caller(Some(fungible_entangler::ID)));
+  assert!(!child.allows_caller(Some(Pubkey::new_unique())));
+}
+
+#[test]
+fn test_allowlist_checks_the_immediate_caller() {
+  let allowed = Pubkey::new_unique();
+  let child = child(CpiPolicyV0::Allowlist, vec![allowed]);
+  assert!(child.allows_caller(Some(allowed)));
+  assert!(!child.allows_caller(Some(Pubkey::new_unique())));
+}
+
+#[test]
+fn test_nested_callers_are_only_allowed_by_any() {
+  // An allowlisted top level program can't launder another program's call through itself
+  let child_with_allowlist = child(CpiPolicyV0::Allowlist, vec![Pubkey::new_unique()]);
+  assert!(!child_with_allowlist.allows_caller(None));
+  assert!(!child(CpiPolicyV0::Deny, vec![]).allows_caller(None));
+  assert!(child(CpiPolicyV0::Any, vec![]).allows_caller(None));
+}
@@ -0,0 +1,163 @@
+//! Checks when accrued fees can be distributed and what each swap record can claim back.
+
+use anchor_lang::prelude::Pubkey;
+use fungible_entangler::state::{
+  FeeDistributionV0, FungibleChildEntanglerV1, FungibleParentEntanglerV1, SwapRecordV0,
+};
+
+const NOW: i64 = 1_000_000;
+const REFUND_WINDOW_SECONDS: i64 = 3600;
+
+fn parent(authority: Option<Pubkey>) -> FungibleParentEntanglerV1 {
+  FungibleParentEntanglerV1 {
+    authority,
+    ..Default::default()
+  }
+}
+
+#[test]
+fn test_live_entangler_is_not_distributable() {
+  let parent = parent(Some(Pubkey::new_unique()));
+  let child = FungibleChildEntanglerV1 {
+    refund_window_seconds: Some(REFUND_WINDOW_SECONDS),
+    ..Default::default()
+  };
+  assert!(!child.is_fee_distributable(&parent, NOW));
+
+  let child = FungibleChildEntanglerV1 {
+    expiry_unix_time: Some(NOW + 1),
+    ..Default::default()
+  };
+  assert!(!child.is_fee_distributable(&parent, NOW));
+}
+
+#[test]
+fn test_expired_entangler_is_distributable() {
+  let child = FungibleChildEntanglerV1 {
+    expiry_unix_time: Some(NOW),
+    ..Default::default()
+  };
+  assert!(child.is_fee_distributable(&parent(Some(Pubkey::new_unique())), NOW));
+}
+
+#[test]
+fn test_sealed_entangler_is_distributable_once_frozen() {
+  let mut sealed = parent(None);
+  assert!(sealed.is_sealed());
+
+  // Sealing alone leaves the child live, and distributing would close it
+  let mut child = FungibleChildEntanglerV1 {
+    refund_window_seconds: Some(REFUND_WINDOW_SECONDS),
+    ..Default::default()
+  };
+  assert!(!child.is_fee_distributable(&sealed, NOW));
+
+  child.freeze_swap_unix_time = Some(NOW);
+  assert!(child.is_fee_distributable(&sealed, NOW));
+  child.freeze_swap_unix_time = None;
+
+  child.frozen = true;
+  assert!(child.is_fee_distributable(&sealed, NOW));
+  child.frozen = false;
+
+  sealed.freeze_swap_unix_time = Some(NOW);
+  assert!(child.is_fee_distributable(&sealed, NOW));
+
+  // Frozen children of unsealed parents wait for expiry
+  child.frozen = true;
+  assert!(!child.is_fee_distributable(&parent(Some(Pubkey::new_unique())), NOW));
+
+  // Without swap records there is nobody to distribute to, so nobody gets to close swaps
+  let untracked = FungibleChildEntanglerV1 {
+    frozen: true,
+    ..Default::default()
+  };
+  assert!(!untracked.is_fee_distributable(&sealed, NOW));
+}
+
+#[test]
+fn test_only_entanglers_with_claims_track_swap_records() {
+  // Swappers only pay for a swap record when a refund, rebate or fee distribution reads it
+  assert!(!FungibleChildEntanglerV1::default().tracks_swap_records());
+
+  for child in [
+    FungibleChildEntanglerV1 {
+      refund_window_seconds: Some(REFUND_WINDOW_SECONDS),
+      ..Default::default()
+    },
+    FungibleChildEntanglerV1 {
+      reverse_rebate_seconds: Some(REFUND_WINDOW_SECONDS),
+      ..Default::default()
+    },
+    FungibleChildEntanglerV1 {
+      expiry_unix_time: Some(NOW),
+      ..Default::default()
+    },
+  ] {
+    assert!(child.tracks_swap_records());
+  }
+}
+
+#[test]
+fn test_refunded_swap_claims_nothing() {
+  // Two wallets swap 1000 parent tokens for 990 child tokens each, paying 10 in fees
+  let mut refunded = SwapRecordV0::default();
+  let mut kept = SwapRecordV0::default();
+  for record in [&mut refunded, &mut kept] {
+    record
+      .record_parent_to_child(1000, 1000, 990, Some(REFUND_WINDOW_SECONDS), NOW)
+      .unwrap();
+  }
+  let mut total_parent_to_child_amount = 2000;
+
+  // Refunds take the parent tokens back out of the child's total, as RefundSwapV0 does
+  let parent_amount = refunded.refund(990).unwrap();
+  assert_eq!(parent_amount, 1000);
+  total_parent_to_child_amount -= parent_amount;
+
+  let distribution = FeeDistributionV0 {
+    child_fee_amount: 20,
+    parent_to_child_amount: total_parent_to_child_amount,
+    ..Default::default()
+  };
+  assert_eq!(
+    distribution
+      .rebate(
+        refunded.parent_to_child_amount,
+        refunded.child_to_parent_amount
+      )
+      .unwrap(),
+    (0, 0)
+  );
+  assert_eq!(
+    distribution
+      .rebate(kept.parent_to_child_amount, kept.child_to_parent_amount)
+      .unwrap(),
+    (20, 0)
+  );
+}
+
+#[test]
+fn test_parent_fees_stay_reserved_until_claimed() {
+  // Fees of children without swap records have nobody to go back to
+  let untracked = FungibleChildEntanglerV1 {
+    accrued_parent_fee_amount: 30,
+    ..Default::default()
+  };
+  assert_eq!(untracked.reserved_parent_fee_amount(), 0);
+
+  let mut child = FungibleChildEntanglerV1 {
+    expiry_unix_time: Some(NOW),
+    accrued_parent_fee_amount: 30,
+    ..Default::default()
+  };
+  assert_eq!(child.reserved_parent_fee_amount(), 30);
+
+  // DistributeFeesV0 snapshots what accrued, claims release it
+  child.fee_distribution = Some(FeeDistributionV0 {
+    parent_fee_amount: 30,
+    claimed_parent_fee_amount: 12,
+    ..Default::default()
+  });
+  assert_eq!(child.reserved_parent_fee_amount(), 18);
+}
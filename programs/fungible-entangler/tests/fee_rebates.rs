@@ -0,0 +1,236 @@
+//! Runs DistributeFeesV0 and ClaimFeeRebateV0 against the program in solana-program-test.
+
+use fungible_entangler::{
+  instructions::initialize_fungible_child_entangler_v0::CHILD_ENTANGLER_SIZE,
+  state::FeeDistributionV0,
+};
+use fungible_entangler_test_utils::{
+  advance_clock, instructions, process_instructions, refresh_blockhash, set_program_account,
+  SwapFixture,
+};
+
+const EXPIRY_SECONDS: i64 = 3_600;
+
+/// Swaps 10_000 parent tokens in and 5_000 child tokens back out at a 1% fee, then expires the child
+async fn expired_fixture() -> SwapFixture {
+  let mut fixture = SwapFixture::new(|child| {
+    child.swap_fee_bps = 100;
+    child.expiry_unix_time = Some(child.go_live_unix_time + EXPIRY_SECONDS);
+  })
+  .await;
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  fixture.swap_child_for_parent(5_000).await.unwrap();
+  advance_clock(&mut fixture.context, EXPIRY_SECONDS + 1)
+    .await
+    .unwrap();
+
+  fixture
+}
+
+#[tokio::test]
+async fn test_fees_are_not_distributable_before_expiry() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.swap_fee_bps = 100;
+    child.expiry_unix_time = Some(child.go_live_unix_time + EXPIRY_SECONDS);
+  })
+  .await;
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+
+  let ix = instructions::distribute_fees(&fixture.keys);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+  fixture.swap_child_for_parent(5_000).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_sealed_parent_leaves_live_child_swapping() {
+  let mut fixture = SwapFixture::with_parent(|parent, child| {
+    parent.authority = None;
+    child.swap_fee_bps = 100;
+    child.refund_window_seconds = Some(EXPIRY_SECONDS);
+  })
+  .await;
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+
+  let ix = instructions::distribute_fees(&fixture.keys);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+  fixture.swap_child_for_parent(5_000).await.unwrap();
+  assert!(fixture.child_entangler().await.fee_distribution.is_none());
+}
+
+#[tokio::test]
+async fn test_unrecorded_swaps_are_left_out_of_the_distribution() {
+  let mut fixture = SwapFixture::new(|child| child.swap_fee_bps = 100).await;
+
+  // Nothing records swaps until the child expires, so this fee goes to nobody
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  let mut child = fixture.child_entangler().await;
+  assert_eq!(child.accrued_child_fee_amount, 0);
+  child.expiry_unix_time = Some(child.go_live_unix_time + EXPIRY_SECONDS);
+  let address = fixture.keys.child_entangler;
+  set_program_account(&mut fixture.context, &address, &child, CHILD_ENTANGLER_SIZE);
+
+  fixture.swap_parent_for_child(20_000).await.unwrap();
+  advance_clock(&mut fixture.context, EXPIRY_SECONDS + 1)
+    .await
+    .unwrap();
+  let ix = instructions::distribute_fees(&fixture.keys);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  let child = fixture.child_entangler().await;
+  assert_eq!(child.total_parent_to_child_amount, 30_000);
+  let distribution = child.fee_distribution.unwrap();
+  assert_eq!(distribution.child_fee_amount, 200);
+  assert_eq!(distribution.parent_to_child_amount, 20_000);
+  // The one recorded swap claims every fee it paid
+  assert_eq!(distribution.rebate(20_000, 0).unwrap(), (200, 0));
+}
+
+#[tokio::test]
+async fn test_distribution_snapshots_fees_and_closes_swaps() {
+  let mut fixture = expired_fixture().await;
+  let ix = instructions::distribute_fees(&fixture.keys);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  let distribution = fixture.child_entangler().await.fee_distribution.unwrap();
+  assert!(
+    distribution
+      == FeeDistributionV0 {
+        child_fee_amount: 100,
+        parent_fee_amount: 50,
+        parent_to_child_amount: 10_000,
+        child_to_parent_amount: 5_000,
+        claimed_child_fee_amount: 0,
+        claimed_parent_fee_amount: 0,
+      }
+  );
+
+  // The snapshot is final
+  assert!(fixture.swap_parent_for_child(1_000).await.is_err());
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  let ix = instructions::distribute_fees(&fixture.keys);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+}
+
+// ClaimFeeRebateV0 creates the FeeRebateClaimV0 that stops a second claim
+#[cfg(feature = "test-bpf")]
+#[tokio::test]
+async fn test_sole_swapper_claims_every_fee_once() {
+  let mut fixture = expired_fixture().await;
+  let payer = fixture.payer();
+  let child_balance = fixture.balance(fixture.child_account).await;
+  let parent_balance = fixture.balance(fixture.parent_account).await;
+
+  let ix = instructions::distribute_fees(&fixture.keys);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  let ix = instructions::claim_fee_rebate(
+    &payer,
+    &fixture.keys,
+    &fixture.child_account,
+    &fixture.parent_account,
+  );
+  process_instructions(&mut fixture.context, std::slice::from_ref(&ix), &[])
+    .await
+    .unwrap();
+
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    child_balance + 100
+  );
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    parent_balance + 50
+  );
+  let distribution = fixture.child_entangler().await.fee_distribution.unwrap();
+  assert_eq!(distribution.claimed_child_fee_amount, 100);
+  assert_eq!(distribution.claimed_parent_fee_amount, 50);
+
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+}
+
+/// A live child that took 100 child tokens of fees on a 10_000 parent token swap, with the rest
+/// of its storage withdrawn by the authority
+async fn withdrawn_fixture() -> SwapFixture {
+  let mut fixture = SwapFixture::new(|child| {
+    child.swap_fee_bps = 100;
+    child.expiry_unix_time = Some(child.go_live_unix_time + EXPIRY_SECONDS);
+  })
+  .await;
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  let (payer, keys) = (fixture.payer(), fixture.keys);
+  let storage_amount = fixture.balance(keys.child_storage()).await;
+  assert_eq!(
+    fixture.child_entangler().await.reserved_child_fee_amount(),
+    100
+  );
+
+  let ix =
+    instructions::transfer_child_storage(&payer, &keys, &fixture.child_account, storage_amount);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+  let ix = instructions::transfer_child_storage(
+    &payer,
+    &keys,
+    &fixture.child_account,
+    storage_amount - 100,
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  fixture
+}
+
+#[tokio::test]
+async fn test_withdrawals_before_expiry_leave_accrued_fees() {
+  let mut fixture = withdrawn_fixture().await;
+  let child_storage = fixture.keys.child_storage();
+  assert_eq!(fixture.balance(child_storage).await, 100);
+}
+
+// ClaimFeeRebateV0 creates the FeeRebateClaimV0 that stops a second claim
+#[cfg(feature = "test-bpf")]
+#[tokio::test]
+async fn test_fees_are_claimable_after_withdrawals_before_expiry() {
+  let mut fixture = withdrawn_fixture().await;
+  let payer = fixture.payer();
+  let child_balance = fixture.balance(fixture.child_account).await;
+  advance_clock(&mut fixture.context, EXPIRY_SECONDS + 1)
+    .await
+    .unwrap();
+
+  let ix = instructions::distribute_fees(&fixture.keys);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  let ix = instructions::claim_fee_rebate(
+    &payer,
+    &fixture.keys,
+    &fixture.child_account,
+    &fixture.parent_account,
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    child_balance + 100
+  );
+  assert_eq!(fixture.balance(fixture.keys.child_storage()).await, 0);
+}
@@ -0,0 +1,71 @@
+//! Checks that the mint pair lookup names the first child of the pair and is freed with it.
+
+use fungible_entangler::state::EntanglerLookupV0;
+use fungible_entangler_test_utils::{
+  add_entangler, add_lookup, create_mint, get_program_account, instructions, process_instructions,
+  program_test,
+};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn test_lookup_is_freed_by_the_child_holding_it() {
+  let mut context = program_test().start_with_context().await;
+  let payer = context.payer.pubkey();
+  let parent_mint = create_mint(&mut context, 9).await.unwrap();
+  let child_mint = create_mint(&mut context, 9).await.unwrap();
+  let first = add_entangler(&mut context, &parent_mint, &child_mint, b"first", |_, _| {})
+    .await
+    .unwrap();
+  let second = add_entangler(
+    &mut context,
+    &parent_mint,
+    &child_mint,
+    b"second",
+    |_, _| {},
+  )
+  .await
+  .unwrap();
+  let lookup = add_lookup(&mut context, &first);
+
+  // Another parent's child of the same mints leaves the lookup alone
+  let ix = instructions::close_child_entangler(&payer, &payer, &second);
+  process_instructions(&mut context, &[ix], &[])
+    .await
+    .unwrap();
+  let held: EntanglerLookupV0 = get_program_account(&mut context, &lookup).await.unwrap();
+  assert_eq!(held.child_entangler, first.child_entangler);
+
+  let ix = instructions::close_child_entangler(&payer, &payer, &first);
+  process_instructions(&mut context, &[ix], &[])
+    .await
+    .unwrap();
+  assert!(context
+    .banks_client
+    .get_account(lookup)
+    .await
+    .unwrap()
+    .is_none());
+}
+
+#[cfg(feature = "test-bpf")]
+#[tokio::test]
+async fn test_first_child_of_a_mint_pair_holds_the_lookup() {
+  use fungible_entangler_test_utils::{create_entangler, pda};
+
+  let mut context = program_test().start_with_context().await;
+  let parent_mint = create_mint(&mut context, 9).await.unwrap();
+  let child_mint = create_mint(&mut context, 9).await.unwrap();
+  let first = create_entangler(&mut context, &parent_mint, &child_mint, b"first")
+    .await
+    .unwrap();
+  create_entangler(&mut context, &parent_mint, &child_mint, b"second")
+    .await
+    .unwrap();
+
+  let lookup: EntanglerLookupV0 =
+    get_program_account(&mut context, &pda::lookup(&parent_mint, &child_mint))
+      .await
+      .unwrap();
+  assert_eq!(lookup.parent_entangler, first.parent_entangler);
+  assert_eq!(lookup.child_entangler, first.child_entangler);
+}
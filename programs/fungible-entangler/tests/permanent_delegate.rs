@@ -0,0 +1,65 @@
+//! Checks how wrapped mints in clawback mode are sized, and that ClawbackV0 finds the permanent
+//! delegate among the mint's extensions.
+
+use anchor_lang::{prelude::Pubkey, solana_program::program_pack::Pack};
+use spl_token_2022::{
+  extension::{AccountType, ExtensionType},
+  state::{Account, Mint},
+};
+use strata_spl_utils::token_2022::{mint_len, permanent_delegate, PERMANENT_DELEGATE_EXTENSION};
+
+/// Mint data with each (extension type, value) as a TLV entry, in order
+fn mint_data(extensions: &[(u16, &[u8])]) -> Vec<u8> {
+  let mut data = vec![0; Account::LEN];
+  data.push(AccountType::Mint as u8);
+  for (extension_type, value) in extensions {
+    data.extend_from_slice(&extension_type.to_le_bytes());
+    data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    data.extend_from_slice(value);
+  }
+
+  data
+}
+
+#[test]
+fn test_clawback_mints_make_room_for_the_delegate() {
+  assert_eq!(mint_len(&[], false), Mint::LEN);
+  assert_eq!(
+    mint_len(&[], true),
+    mint_data(&[(PERMANENT_DELEGATE_EXTENSION, &[0; 32])]).len()
+  );
+
+  let transfer_fee = vec![0; ExtensionType::TransferFeeConfig.get_type_len()];
+  assert_eq!(
+    mint_len(&[ExtensionType::TransferFeeConfig], true),
+    mint_data(&[
+      (ExtensionType::TransferFeeConfig as u16, &transfer_fee),
+      (PERMANENT_DELEGATE_EXTENSION, &[0; 32]),
+    ])
+    .len()
+  );
+}
+
+#[test]
+fn test_permanent_delegate_is_found_after_other_extensions() {
+  let delegate = Pubkey::new_unique();
+  let transfer_fee = vec![7; ExtensionType::TransferFeeConfig.get_type_len()];
+  let data = mint_data(&[
+    (ExtensionType::TransferFeeConfig as u16, &transfer_fee),
+    (PERMANENT_DELEGATE_EXTENSION, delegate.as_ref()),
+  ]);
+  assert_eq!(permanent_delegate(&data), Some(delegate));
+}
+
+#[test]
+fn test_mints_without_a_delegate_have_none() {
+  assert_eq!(permanent_delegate(&[0; Mint::LEN]), None);
+
+  let transfer_fee = vec![7; ExtensionType::TransferFeeConfig.get_type_len()];
+  let data = mint_data(&[(ExtensionType::TransferFeeConfig as u16, &transfer_fee)]);
+  assert_eq!(permanent_delegate(&data), None);
+
+  // An unset delegate is stored as zeros
+  let data = mint_data(&[(PERMANENT_DELEGATE_EXTENSION, &[0; 32])]);
+  assert_eq!(permanent_delegate(&data), None);
+}
@@ -0,0 +1,95 @@
+//! Runs RebalanceBetweenEntanglersV0 against the program in solana-program-test, checking that
+//! fees owed back to swappers stay in the source entangler's storage.
+
+use fungible_entangler_test_utils::{
+  add_entangler, instructions, mint_to, process_instructions, refresh_blockhash,
+  swap_fixture::STORAGE_AMOUNT, EntanglerKeys, SwapFixture,
+};
+
+const RESERVED_FEE_AMOUNT: u64 = 1_000;
+
+/// A second funded entangler of the fixture's mints under the same authority
+async fn add_destination(fixture: &mut SwapFixture) -> EntanglerKeys {
+  let keys = fixture.keys;
+  let destination = add_entangler(
+    &mut fixture.context,
+    &keys.parent_mint,
+    &keys.child_mint,
+    b"rebalance-destination",
+    |_, _| {},
+  )
+  .await
+  .unwrap();
+  for (mint, storage) in [
+    (keys.parent_mint, destination.parent_storage()),
+    (keys.child_mint, destination.child_storage()),
+  ] {
+    mint_to(&mut fixture.context, &mint, &storage, STORAGE_AMOUNT)
+      .await
+      .unwrap();
+  }
+
+  destination
+}
+
+#[tokio::test]
+async fn test_rebalance_leaves_reserved_parent_fees() {
+  let mut fixture = SwapFixture::with_parent(|parent, _| {
+    parent.reserved_parent_fee_amount = RESERVED_FEE_AMOUNT;
+  })
+  .await;
+  let destination = add_destination(&mut fixture).await;
+  let (payer, source) = (fixture.payer(), fixture.keys);
+
+  let ix =
+    instructions::rebalance_between_entanglers(&payer, &source, &destination, STORAGE_AMOUNT, 0);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  let movable = STORAGE_AMOUNT - RESERVED_FEE_AMOUNT;
+  let ix = instructions::rebalance_between_entanglers(&payer, &source, &destination, movable, 0);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(source.parent_storage()).await,
+    RESERVED_FEE_AMOUNT
+  );
+  assert_eq!(
+    fixture.balance(destination.parent_storage()).await,
+    STORAGE_AMOUNT + movable
+  );
+}
+
+#[tokio::test]
+async fn test_rebalance_leaves_reserved_child_fees() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.accrued_child_fee_amount = RESERVED_FEE_AMOUNT;
+  })
+  .await;
+  let destination = add_destination(&mut fixture).await;
+  let (payer, source) = (fixture.payer(), fixture.keys);
+
+  let ix =
+    instructions::rebalance_between_entanglers(&payer, &source, &destination, 0, STORAGE_AMOUNT);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  let movable = STORAGE_AMOUNT - RESERVED_FEE_AMOUNT;
+  let ix = instructions::rebalance_between_entanglers(&payer, &source, &destination, 0, movable);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(source.child_storage()).await,
+    RESERVED_FEE_AMOUNT
+  );
+  assert_eq!(
+    fixture.balance(destination.child_storage()).await,
+    STORAGE_AMOUNT + movable
+  );
+}
@@ -0,0 +1,98 @@
+//! Runs RefundSettlementV0 against the program in solana-program-test, checking that deferred
+//! swaps the settler never acknowledges go back to the swapper once they time out.
+
+use anchor_lang::prelude::{Clock, Pubkey};
+use fungible_entangler::state::SettlementEntryV0;
+use fungible_entangler_test_utils::{
+  add_pending_settlement, add_settlement_entry, advance_clock, instructions, mint_to, pda,
+  process_instructions, refresh_blockhash, swap_fixture::SOURCE_AMOUNT, SwapFixture,
+};
+
+const TIMEOUT_SECONDS: i64 = 3_600;
+const DEFERRED_AMOUNT: u64 = 10_000;
+
+/// A child with a settler and a parent to child entry of DEFERRED_AMOUNT from the payer, deferred
+/// just now and not yet acknowledged
+async fn deferred_fixture() -> (SwapFixture, SettlementEntryV0) {
+  let mut fixture = SwapFixture::new(|_| {}).await;
+  let (payer, keys) = (fixture.payer(), fixture.keys);
+  let clock: Clock = fixture.context.banks_client.get_sysvar().await.unwrap();
+  add_pending_settlement(
+    &mut fixture.context,
+    &keys,
+    &Pubkey::new_unique(),
+    TIMEOUT_SECONDS,
+    1,
+  );
+  let settlement_entry = add_settlement_entry(
+    &mut fixture.context,
+    &keys,
+    SettlementEntryV0 {
+      owner: payer,
+      ticket: 0,
+      parent_to_child: true,
+      amount: DEFERRED_AMOUNT,
+      refund_account: fixture.parent_account,
+      payer,
+      created_at_unix_time: clock.unix_timestamp,
+      ..Default::default()
+    },
+  );
+  mint_to(
+    &mut fixture.context,
+    &keys.parent_mint,
+    &settlement_entry.escrow,
+    DEFERRED_AMOUNT,
+  )
+  .await
+  .unwrap();
+
+  (fixture, settlement_entry)
+}
+
+#[tokio::test]
+async fn test_refund_waits_for_the_timeout() {
+  let (mut fixture, settlement_entry) = deferred_fixture().await;
+
+  let ix = instructions::refund_settlement(&fixture.keys, &settlement_entry);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+  assert_eq!(
+    fixture.balance(settlement_entry.escrow).await,
+    DEFERRED_AMOUNT
+  );
+}
+
+#[tokio::test]
+async fn test_refund_after_timeout_returns_the_escrow() {
+  let (mut fixture, settlement_entry) = deferred_fixture().await;
+  advance_clock(&mut fixture.context, TIMEOUT_SECONDS)
+    .await
+    .unwrap();
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+
+  let ix = instructions::refund_settlement(&fixture.keys, &settlement_entry);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT + DEFERRED_AMOUNT
+  );
+
+  // The entry and its escrow close, so the swap can't be refunded twice
+  let pending_settlement = pda::pending_settlement(&fixture.keys.child_entangler);
+  for address in [
+    pda::settlement_entry(&pending_settlement, settlement_entry.ticket),
+    settlement_entry.escrow,
+  ] {
+    let account = fixture
+      .context
+      .banks_client
+      .get_account(address)
+      .await
+      .unwrap();
+    assert!(account.is_none());
+  }
+}
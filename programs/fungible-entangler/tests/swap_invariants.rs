@@ -0,0 +1,318 @@
+//! Fuzzes sequences of swaps and refunds through the program's own quote, burn and refund math
+//! against a model of the token balances, across random decimals, rates, fees and burns.
+
+use fungible_entangler::{
+  instructions::swap::common::{burn_amounts, quote, SwapAmount, SwapDirection},
+  state::{BurnConfigV0, BurnSideV0, ExchangeRateV0, SwapRecordV0},
+};
+use proptest::prelude::*;
+
+const REFUND_WINDOW_SECONDS: i64 = 3600;
+
+#[derive(Clone, Debug)]
+enum Op {
+  ParentToChild(u64),
+  ChildToParent(u64),
+  Refund(u64),
+}
+
+#[derive(Clone)]
+struct Config {
+  rate: ExchangeRateV0,
+  swap_fee_bps: u16,
+  burn: Option<BurnConfigV0>,
+  parent_decimals: u32,
+  child_decimals: u32,
+}
+
+// The onchain types don't derive Debug, which proptest needs to report failing cases
+impl std::fmt::Debug for Config {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "rate {}:{}, fee {} bps, burn {:?}, decimals {}/{}",
+      self.rate.parent_units,
+      self.rate.child_units,
+      self.swap_fee_bps,
+      self
+        .burn
+        .as_ref()
+        .map(|burn| (burn.bps, matches!(burn.side, BurnSideV0::Input))),
+      self.parent_decimals,
+      self.child_decimals
+    )
+  }
+}
+
+/// Token balances of one wallet and the entangler's storage, plus everything burned
+struct Model {
+  config: Config,
+  user_parent: u64,
+  user_child: u64,
+  parent_storage: u64,
+  child_storage: u64,
+  parent_burned: u64,
+  child_burned: u64,
+  parent_refunded: u64,
+  record: SwapRecordV0,
+}
+
+impl Model {
+  fn new(config: Config) -> Self {
+    let parent_supply = 10u64.pow(config.parent_decimals + 9);
+    let child_supply = 10u64.pow(config.child_decimals + 9);
+    Self {
+      config,
+      user_parent: parent_supply,
+      user_child: child_supply,
+      parent_storage: parent_supply,
+      child_storage: child_supply,
+      parent_burned: 0,
+      child_burned: 0,
+      parent_refunded: 0,
+      record: SwapRecordV0::default(),
+    }
+  }
+
+  fn parent_total(&self) -> u128 {
+    self.user_parent as u128 + self.parent_storage as u128 + self.parent_burned as u128
+  }
+
+  fn child_total(&self) -> u128 {
+    self.user_child as u128 + self.child_storage as u128 + self.child_burned as u128
+  }
+
+  /// Applies a swap the way the handlers do. Returns the output paid, or None where the program
+  /// would fail the transaction
+  fn swap(&mut self, direction: SwapDirection, amount: u64) -> Option<u64> {
+    let (source, target) = match direction {
+      SwapDirection::ParentToChild => (self.user_parent, self.child_storage),
+      SwapDirection::ChildToParent => (self.user_child, self.parent_storage),
+    };
+    if amount == 0 || amount > source {
+      return None;
+    }
+
+    let output_amount = quote(
+      &self.config.rate,
+      self.config.swap_fee_bps,
+      direction,
+      amount,
+    )
+    .ok()?;
+    if output_amount > target {
+      return None;
+    }
+    let swap_amount = SwapAmount {
+      amount,
+      output_amount,
+    };
+    let burn = burn_amounts(&self.config.burn, &swap_amount).ok()?;
+    let paid = output_amount - burn.output;
+
+    match direction {
+      SwapDirection::ParentToChild => {
+        self.user_parent -= amount;
+        self.parent_storage += amount - burn.input;
+        self.parent_burned += burn.input;
+        self.child_storage -= output_amount;
+        self.child_burned += burn.output;
+        self.user_child += paid;
+        self
+          .record
+          .record_parent_to_child(
+            amount,
+            amount - burn.input,
+            paid,
+            Some(REFUND_WINDOW_SECONDS),
+            0,
+          )
+          .ok()?;
+      }
+      SwapDirection::ChildToParent => {
+        self.user_child -= amount;
+        self.child_storage += amount - burn.input;
+        self.child_burned += burn.input;
+        self.parent_storage -= output_amount;
+        self.parent_burned += burn.output;
+        self.user_parent += paid;
+        self.record.record_child_to_parent(amount).ok()?;
+      }
+    }
+
+    Some(paid)
+  }
+
+  fn refund(&mut self, amount: u64) -> Option<u64> {
+    if amount == 0 || amount > self.record.refundable_child_amount || amount > self.user_child {
+      return None;
+    }
+
+    let mut record = self.record.clone();
+    let parent_amount = record.refund(amount).ok()?;
+    if parent_amount > self.parent_storage {
+      return None;
+    }
+
+    self.record = record;
+    self.user_child -= amount;
+    self.child_storage += amount;
+    self.parent_storage -= parent_amount;
+    self.user_parent += parent_amount;
+    self.parent_refunded += parent_amount;
+
+    Some(parent_amount)
+  }
+
+  fn apply(&mut self, op: &Op) {
+    match op {
+      Op::ParentToChild(amount) => {
+        self.swap(SwapDirection::ParentToChild, *amount);
+      }
+      Op::ChildToParent(amount) => {
+        self.swap(SwapDirection::ChildToParent, *amount);
+      }
+      Op::Refund(amount) => {
+        self.refund(*amount);
+      }
+    }
+  }
+}
+
+fn config_strategy() -> impl Strategy<Value = Config> {
+  (
+    0u32..=9,
+    0u32..=9,
+    1u64..1000,
+    1u64..1000,
+    0u16..=10000,
+    prop::option::of((0u16..=10000, any::<bool>())),
+  )
+    .prop_map(
+      |(parent_decimals, child_decimals, parent_ratio, child_ratio, swap_fee_bps, burn)| Config {
+        rate: ExchangeRateV0 {
+          parent_units: parent_ratio * 10u64.pow(parent_decimals),
+          child_units: child_ratio * 10u64.pow(child_decimals),
+        },
+        swap_fee_bps,
+        burn: burn.map(|(bps, input)| BurnConfigV0 {
+          bps,
+          side: if input {
+            BurnSideV0::Input
+          } else {
+            BurnSideV0::Output
+          },
+        }),
+        parent_decimals,
+        child_decimals,
+      },
+    )
+}
+
+// Spreads amounts across many orders of magnitude so both dust and whale swaps are covered
+fn amount_strategy() -> impl Strategy<Value = u64> {
+  (0u32..19).prop_flat_map(|exponent| 0u64..=10u64.pow(exponent))
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+  prop_oneof![
+    amount_strategy().prop_map(Op::ParentToChild),
+    amount_strategy().prop_map(Op::ChildToParent),
+    amount_strategy().prop_map(Op::Refund),
+  ]
+}
+
+proptest! {
+  #[test]
+  fn swaps_conserve_tokens_and_keep_totals_monotone(
+    config in config_strategy(),
+    ops in prop::collection::vec(op_strategy(), 1..50),
+  ) {
+    let mut model = Model::new(config);
+    let parent_total = model.parent_total();
+    let child_total = model.child_total();
+
+    for op in &ops {
+      let parent_to_child = model.record.parent_to_child_amount;
+      let parent_refunded = model.parent_refunded;
+      let child_to_parent = model.record.child_to_parent_amount;
+      let parent_burned = model.parent_burned;
+      let child_burned = model.child_burned;
+
+      model.apply(op);
+
+      // Balances are u64 so underflow would already have panicked, totals must be conserved
+      prop_assert_eq!(model.parent_total(), parent_total);
+      prop_assert_eq!(model.child_total(), child_total);
+      // Refunds take the parent tokens they return back out of the record
+      let refunded = model.parent_refunded - parent_refunded;
+      if refunded > 0 {
+        prop_assert_eq!(model.record.parent_to_child_amount, parent_to_child - refunded);
+      } else {
+        prop_assert!(model.record.parent_to_child_amount >= parent_to_child);
+      }
+      prop_assert!(model.record.child_to_parent_amount >= child_to_parent);
+      prop_assert!(model.parent_burned >= parent_burned);
+      prop_assert!(model.child_burned >= child_burned);
+    }
+  }
+
+  #[test]
+  fn swap_round_trip_never_profits(
+    config in config_strategy(),
+    amount in 1u64..1_000_000_000_000,
+  ) {
+    let mut model = Model::new(config);
+    let before = model.user_parent;
+
+    if let Some(child_amount) = model.swap(SwapDirection::ParentToChild, amount) {
+      model.swap(SwapDirection::ChildToParent, child_amount);
+    }
+
+    prop_assert!(model.user_parent <= before);
+  }
+
+  #[test]
+  fn refund_round_trip_never_profits(
+    config in config_strategy(),
+    amounts in prop::collection::vec(1u64..1_000_000_000_000, 1..10),
+    refund_parts in 1u64..10,
+  ) {
+    let mut model = Model::new(config);
+    let parent_before = model.user_parent;
+    let child_before = model.user_child;
+
+    for amount in amounts {
+      model.swap(SwapDirection::ParentToChild, amount);
+    }
+    // Refund in several pieces so rounding is exercised on every partial refund
+    while model.record.refundable_child_amount > 0 {
+      let refundable = model.record.refundable_child_amount;
+      let part = (refundable / refund_parts).max(1);
+      prop_assert!(model.refund(part).is_some());
+    }
+
+    prop_assert!(model.user_parent <= parent_before);
+    prop_assert_eq!(model.user_child, child_before);
+  }
+
+  #[test]
+  fn refunds_only_pay_back_what_storage_kept(
+    config in config_strategy(),
+    amounts in prop::collection::vec(1u64..1_000_000_000_000, 1..10),
+  ) {
+    let mut model = Model::new(config);
+    let storage_before = model.parent_storage;
+
+    for amount in amounts {
+      model.swap(SwapDirection::ParentToChild, amount);
+    }
+    let refundable = model.record.refundable_child_amount;
+    if refundable > 0 {
+      prop_assert!(model.refund(refundable).is_some());
+    }
+
+    // Input burns are never paid back out of tokens other swappers left in storage
+    prop_assert!(model.parent_storage >= storage_before);
+  }
+}
@@ -0,0 +1,120 @@
+//! Runs ExecuteQueuedSwapV0 against the program in solana-program-test, checking that queued swaps
+//! settle before anything else swaps through the child, and that tickets the child can no longer
+//! swap are refunded rather than blocking the queue.
+
+use fungible_entangler::state::{
+  DrawdownLimitV0, FungibleChildEntanglerV1, QueuedSwapV0, SwapQueueV0,
+};
+use fungible_entangler_test_utils::{
+  add_queued_swap, add_swap_queue, get_program_account, instructions, mint_to, pda,
+  process_instructions, refresh_blockhash, swap_fixture::SOURCE_AMOUNT, SwapFixture,
+};
+
+const QUEUED_AMOUNT: u64 = 10_000;
+
+/// A live child with a single parent to child ticket of QUEUED_AMOUNT from the payer, not yet
+/// executed
+async fn queued_fixture(
+  configure: impl FnOnce(&mut FungibleChildEntanglerV1),
+) -> (SwapFixture, QueuedSwapV0) {
+  let mut fixture = SwapFixture::new(|child| {
+    child.queued_swap_count = 1;
+    configure(child);
+  })
+  .await;
+  let (payer, keys) = (fixture.payer(), fixture.keys);
+  add_swap_queue(&mut fixture.context, &keys, 1);
+  let queued_swap = add_queued_swap(
+    &mut fixture.context,
+    &keys,
+    QueuedSwapV0 {
+      owner: payer,
+      ticket: 0,
+      parent_to_child: true,
+      amount: QUEUED_AMOUNT,
+      destination: fixture.child_account,
+      refund_account: fixture.parent_account,
+      payer,
+      ..Default::default()
+    },
+  );
+  mint_to(
+    &mut fixture.context,
+    &keys.parent_mint,
+    &queued_swap.escrow,
+    QUEUED_AMOUNT,
+  )
+  .await
+  .unwrap();
+
+  (fixture, queued_swap)
+}
+
+async fn execute(fixture: &mut SwapFixture, queued_swap: &QueuedSwapV0) {
+  let ix = instructions::execute_queued_swap(&fixture.keys, queued_swap);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_direct_swaps_wait_for_the_queue() {
+  let (mut fixture, queued_swap) = queued_fixture(|_| {}).await;
+
+  assert!(fixture.swap_parent_for_child(QUEUED_AMOUNT).await.is_err());
+  assert!(fixture.swap_child_for_parent(QUEUED_AMOUNT).await.is_err());
+
+  execute(&mut fixture, &queued_swap).await;
+  assert_eq!(fixture.child_entangler().await.queued_swap_count, 0);
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + QUEUED_AMOUNT
+  );
+
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  fixture.swap_parent_for_child(QUEUED_AMOUNT).await.unwrap();
+}
+
+/// Executes a ticket the child configured by configure can't take, checking that it is refunded
+/// and the queue moves past it
+async fn assert_refunded(configure: impl FnOnce(&mut FungibleChildEntanglerV1)) {
+  let (mut fixture, queued_swap) = queued_fixture(configure).await;
+
+  execute(&mut fixture, &queued_swap).await;
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT + QUEUED_AMOUNT
+  );
+  assert_eq!(fixture.balance(fixture.child_account).await, SOURCE_AMOUNT);
+  assert_eq!(fixture.child_entangler().await.queued_swap_count, 0);
+  let swap_queue: SwapQueueV0 = get_program_account(
+    &mut fixture.context,
+    &pda::swap_queue(&fixture.keys.child_entangler),
+  )
+  .await
+  .unwrap();
+  assert_eq!(swap_queue.next_execute, 1);
+}
+
+#[tokio::test]
+async fn test_tickets_of_a_frozen_child_are_refunded() {
+  assert_refunded(|child| child.frozen = true).await;
+}
+
+#[tokio::test]
+async fn test_tickets_of_an_expired_child_are_refunded() {
+  assert_refunded(|child| child.expiry_unix_time = Some(0)).await;
+}
+
+#[tokio::test]
+async fn test_tickets_over_the_drawdown_limit_are_refunded() {
+  assert_refunded(|child| {
+    child.drawdown_limit = Some(DrawdownLimitV0 {
+      window_seconds: 3_600,
+      max_child_amount: QUEUED_AMOUNT - 1,
+      max_parent_amount: u64::MAX,
+      ..Default::default()
+    });
+  })
+  .await;
+}
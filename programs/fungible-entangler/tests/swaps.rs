@@ -0,0 +1,250 @@
+//! Runs swaps, refunds and reverse swap rebates against the program in solana-program-test.
+
+use fungible_entangler::{
+  instructions::{RefundSwapV0Args, SwapV0Args},
+  state::{BurnConfigV0, BurnSideV0},
+};
+use fungible_entangler_test_utils::{
+  advance_clock, instructions, mint_to, process_instructions,
+  swap_fixture::{SOURCE_AMOUNT, STORAGE_AMOUNT},
+  SwapFixture,
+};
+
+#[tokio::test]
+async fn test_swaps_charge_fees_and_burn_output() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.refund_window_seconds = Some(3_600);
+    child.swap_fee_bps = 100;
+    child.burn = Some(BurnConfigV0 {
+      bps: 1_000,
+      side: BurnSideV0::Output,
+    });
+  })
+  .await;
+  let keys = fixture.keys;
+
+  // 1% of 10_000 stays in child storage as the fee, then 10% of the 9_900 left is burned
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - 10_000
+  );
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 8_910
+  );
+  assert_eq!(
+    fixture.balance(keys.parent_storage()).await,
+    STORAGE_AMOUNT + 10_000
+  );
+  assert_eq!(
+    fixture.balance(keys.child_storage()).await,
+    STORAGE_AMOUNT - 9_900
+  );
+  assert_eq!(
+    fixture.supply(keys.child_mint).await,
+    STORAGE_AMOUNT + SOURCE_AMOUNT - 990
+  );
+
+  fixture.swap_child_for_parent(5_000).await.unwrap();
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - 10_000 + 4_455
+  );
+  assert_eq!(
+    fixture.balance(keys.parent_storage()).await,
+    STORAGE_AMOUNT + 10_000 - 4_950
+  );
+  assert_eq!(
+    fixture.supply(keys.parent_mint).await,
+    STORAGE_AMOUNT + SOURCE_AMOUNT - 495
+  );
+
+  let child = fixture.child_entangler().await;
+  assert_eq!(child.accrued_child_fee_amount, 100);
+  assert_eq!(child.accrued_parent_fee_amount, 50);
+  assert_eq!(child.total_parent_to_child_amount, 10_000);
+  assert_eq!(child.total_child_to_parent_amount, 5_000);
+  assert_eq!(child.recorded_parent_to_child_amount, 10_000);
+  assert_eq!(child.recorded_child_to_parent_amount, 5_000);
+  assert_eq!(
+    fixture.parent_entangler().await.reserved_parent_fee_amount,
+    50
+  );
+}
+
+#[tokio::test]
+async fn test_input_burns_leave_storage_with_the_rest() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.burn = Some(BurnConfigV0 {
+      bps: 500,
+      side: BurnSideV0::Input,
+    });
+  })
+  .await;
+  let keys = fixture.keys;
+
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 10_000
+  );
+  assert_eq!(
+    fixture.balance(keys.parent_storage()).await,
+    STORAGE_AMOUNT + 9_500
+  );
+  assert_eq!(
+    fixture.supply(keys.parent_mint).await,
+    STORAGE_AMOUNT + SOURCE_AMOUNT - 500
+  );
+}
+
+#[tokio::test]
+async fn test_swap_fails_past_storage() {
+  let mut fixture = SwapFixture::new(|_| {}).await;
+  let keys = fixture.keys;
+  let parent_account = fixture.parent_account;
+  mint_to(
+    &mut fixture.context,
+    &keys.parent_mint,
+    &parent_account,
+    STORAGE_AMOUNT,
+  )
+  .await
+  .unwrap();
+
+  assert!(fixture
+    .swap_parent_for_child(STORAGE_AMOUNT + 1)
+    .await
+    .is_err());
+  fixture.swap_parent_for_child(STORAGE_AMOUNT).await.unwrap();
+  assert_eq!(fixture.balance(keys.child_storage()).await, 0);
+}
+
+#[tokio::test]
+async fn test_swaps_leave_reserved_fees_in_storage() {
+  // Fees owed back to swappers leave 5_000 of each storage to swap against
+  let mut fixture = SwapFixture::with_parent(|parent, child| {
+    parent.reserved_parent_fee_amount = STORAGE_AMOUNT - 5_000;
+    child.refund_window_seconds = Some(3_600);
+    child.accrued_child_fee_amount = STORAGE_AMOUNT - 5_000;
+  })
+  .await;
+  let keys = fixture.keys;
+  let payer = fixture.payer();
+
+  assert!(fixture.swap_parent_for_child(5_001).await.is_err());
+  assert!(fixture.swap_child_for_parent(5_001).await.is_err());
+
+  let ix = instructions::swap_parent_for_child(
+    &payer,
+    &keys,
+    &fixture.parent_account,
+    &payer,
+    &fixture.child_account,
+    &[],
+    SwapV0Args {
+      amount: Some(10_000),
+      allow_partial: true,
+      ..Default::default()
+    },
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(keys.child_storage()).await,
+    STORAGE_AMOUNT - 5_000
+  );
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - 5_000
+  );
+
+  fixture.swap_child_for_parent(5_000).await.unwrap();
+  assert_eq!(fixture.balance(keys.parent_storage()).await, STORAGE_AMOUNT);
+}
+
+#[tokio::test]
+async fn test_refund_returns_what_storage_kept() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.refund_window_seconds = Some(3_600);
+    child.burn = Some(BurnConfigV0 {
+      bps: 1_000,
+      side: BurnSideV0::Input,
+    });
+  })
+  .await;
+  let keys = fixture.keys;
+  let payer = fixture.payer();
+
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  let record = fixture.swap_record().await;
+  assert_eq!(record.refundable_parent_amount, 9_000);
+  assert_eq!(record.refundable_child_amount, 10_000);
+
+  // Half of the child tokens pay back half of what parent storage kept after the burn
+  let ix = instructions::refund_swap(
+    &keys,
+    &fixture.child_account,
+    &payer,
+    &fixture.parent_account,
+    RefundSwapV0Args { amount: 5_000 },
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - 10_000 + 4_500
+  );
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 5_000
+  );
+  assert_eq!(
+    fixture.balance(keys.parent_storage()).await,
+    STORAGE_AMOUNT + 9_000 - 4_500
+  );
+  let child = fixture.child_entangler().await;
+  assert_eq!(child.total_parent_to_child_amount, 5_500);
+  assert_eq!(child.recorded_parent_to_child_amount, 5_500);
+
+  // Nothing is refundable once the window closes
+  advance_clock(&mut fixture.context, 3_601).await.unwrap();
+  let ix = instructions::refund_swap(
+    &keys,
+    &fixture.child_account,
+    &payer,
+    &fixture.parent_account,
+    RefundSwapV0Args { amount: 1_000 },
+  );
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+async fn test_reverse_swap_rebates_fee() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.swap_fee_bps = 100;
+    child.reverse_rebate_seconds = Some(3_600);
+  })
+  .await;
+
+  fixture.swap_parent_for_child(10_000).await.unwrap();
+  assert_eq!(fixture.swap_record().await.rebatable_fee_amount, 100);
+
+  // The 100 child tokens of fees come back on top of the parent tokens for the swap
+  fixture.swap_child_for_parent(9_900).await.unwrap();
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + 100
+  );
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - 10_000 + 9_801
+  );
+  assert_eq!(fixture.swap_record().await.rebatable_fee_amount, 0);
+  assert_eq!(fixture.child_entangler().await.accrued_child_fee_amount, 0);
+}
@@ -0,0 +1,53 @@
+//! Runs the token-swap adapter against the program in solana-program-test.
+
+use fungible_entangler_test_utils::{
+  instructions, process_instructions, swap_fixture::SOURCE_AMOUNT, SwapFixture,
+};
+
+const SWAP_AMOUNT: u64 = 10_000;
+
+async fn token_swap(fixture: &mut SwapFixture) -> bool {
+  let ix = instructions::token_swap(
+    &fixture.payer(),
+    &fixture.keys,
+    &fixture.parent_account,
+    &fixture.child_account,
+    true,
+    SWAP_AMOUNT,
+    SWAP_AMOUNT,
+  );
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_ok()
+}
+
+#[tokio::test]
+async fn test_token_swap_swaps_parent_for_child() {
+  let mut fixture = SwapFixture::new(|_| {}).await;
+
+  assert!(token_swap(&mut fixture).await);
+  assert_eq!(
+    fixture.balance(fixture.parent_account).await,
+    SOURCE_AMOUNT - SWAP_AMOUNT
+  );
+  assert_eq!(
+    fixture.balance(fixture.child_account).await,
+    SOURCE_AMOUNT + SWAP_AMOUNT
+  );
+  assert_eq!(
+    fixture.child_entangler().await.total_parent_to_child_amount,
+    SWAP_AMOUNT
+  );
+}
+
+#[tokio::test]
+async fn test_token_swap_rejects_children_that_track_swaps() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.refund_window_seconds = Some(3_600);
+  })
+  .await;
+
+  // The swap would go unrecorded, out of reach of refunds and the fee distribution
+  assert!(!token_swap(&mut fixture).await);
+  assert_eq!(fixture.balance(fixture.parent_account).await, SOURCE_AMOUNT);
+}
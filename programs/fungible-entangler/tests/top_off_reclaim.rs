@@ -0,0 +1,138 @@
+//! Checks how much of child storage TopOff depositors may reclaim once the child expires, and that
+//! the authority can't withdraw it from under them.
+
+use anchor_lang::prelude::Pubkey;
+use fungible_entangler::state::FungibleChildEntanglerV1;
+use fungible_entangler_test_utils::{
+  add_top_off_record, create_token_account, instructions, process_instructions, refresh_blockhash,
+  swap_fixture::STORAGE_AMOUNT, SwapFixture,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[test]
+fn test_reclaim_is_capped_at_deposits() {
+  // The authority's own liquidity and swap inflows are not the depositors' to take
+  let child = FungibleChildEntanglerV1 {
+    total_top_off_amount: 1_000,
+    ..Default::default()
+  };
+  assert_eq!(child.reclaimable_top_off_amount(5_000), 1_000);
+  assert_eq!(child.unreclaimed_top_off_amount(5_000), 1_000);
+}
+
+#[test]
+fn test_shortfall_is_shared_pro_rata() {
+  // Two depositors of 750 and 250 split the 400 left in storage 3 to 1
+  let child = FungibleChildEntanglerV1 {
+    total_top_off_amount: 1_000,
+    ..Default::default()
+  };
+  assert_eq!(child.top_off_reclaim_amount(400, 750).unwrap(), 300);
+  assert_eq!(child.top_off_reclaim_amount(400, 250).unwrap(), 100);
+
+  // Without a shortfall each takes back what they deposited
+  assert_eq!(child.top_off_reclaim_amount(5_000, 750).unwrap(), 750);
+  assert_eq!(child.top_off_reclaim_amount(5_000, 250).unwrap(), 250);
+}
+
+/// An expired child whose STORAGE_AMOUNT of storage covers only half of the TopOff deposits,
+/// with a destination for each depositor
+async fn shortfall_fixture(depositors: &[(&Keypair, u64)]) -> (SwapFixture, Vec<Pubkey>) {
+  let total = depositors.iter().map(|(_, amount)| amount).sum();
+  assert_eq!(total, 2 * STORAGE_AMOUNT);
+  let mut fixture = SwapFixture::new(|child| {
+    child.expiry_unix_time = Some(0);
+    child.total_top_off_amount = total;
+  })
+  .await;
+  let keys = fixture.keys;
+
+  let mut destinations = vec![];
+  for (depositor, amount) in depositors {
+    add_top_off_record(&mut fixture.context, &keys, &depositor.pubkey(), *amount);
+    destinations.push(
+      create_token_account(&mut fixture.context, &keys.child_mint, &depositor.pubkey())
+        .await
+        .unwrap(),
+    );
+  }
+
+  (fixture, destinations)
+}
+
+#[tokio::test]
+async fn test_depositors_reclaim_their_share_of_a_shortfall() {
+  let first = Keypair::new();
+  let second = Keypair::new();
+  let (mut fixture, destinations) = shortfall_fixture(&[
+    (&first, STORAGE_AMOUNT * 3 / 2),
+    (&second, STORAGE_AMOUNT / 2),
+  ])
+  .await;
+  let keys = fixture.keys;
+
+  // Reclaiming first takes no more than the first depositor's share
+  let ix = instructions::reclaim_after_expiry(&keys, &first.pubkey(), &destinations[0]);
+  process_instructions(&mut fixture.context, &[ix], &[&first])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(destinations[0]).await,
+    STORAGE_AMOUNT * 3 / 4
+  );
+
+  let ix = instructions::reclaim_after_expiry(&keys, &second.pubkey(), &destinations[1]);
+  process_instructions(&mut fixture.context, &[ix], &[&second])
+    .await
+    .unwrap();
+  assert_eq!(fixture.balance(destinations[1]).await, STORAGE_AMOUNT / 4);
+  assert_eq!(fixture.balance(keys.child_storage()).await, 0);
+
+  // Each deposit is reclaimed once
+  refresh_blockhash(&mut fixture.context).await.unwrap();
+  let ix = instructions::reclaim_after_expiry(&keys, &second.pubkey(), &destinations[1]);
+  assert!(
+    process_instructions(&mut fixture.context, &[ix], &[&second])
+      .await
+      .is_err()
+  );
+}
+
+#[tokio::test]
+async fn test_authority_cannot_withdraw_deposits_before_expiry() {
+  let mut fixture = SwapFixture::new(|child| {
+    child.expiry_unix_time = Some(i64::MAX);
+    child.total_top_off_amount = STORAGE_AMOUNT / 4;
+  })
+  .await;
+  let keys = fixture.keys;
+  let payer = fixture.payer();
+  let destination = fixture.child_account;
+
+  let ix =
+    instructions::transfer_child_storage(&payer, &keys, &destination, STORAGE_AMOUNT * 3 / 4 + 1);
+  assert!(process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .is_err());
+
+  let ix =
+    instructions::transfer_child_storage(&payer, &keys, &destination, STORAGE_AMOUNT * 3 / 4);
+  process_instructions(&mut fixture.context, &[ix], &[])
+    .await
+    .unwrap();
+  assert_eq!(
+    fixture.balance(keys.child_storage()).await,
+    STORAGE_AMOUNT / 4
+  );
+}
+
+#[test]
+fn test_reclaims_release_storage_to_the_authority() {
+  let child = FungibleChildEntanglerV1 {
+    total_top_off_amount: 1_000,
+    reclaim_snapshot_amount: Some(5_000),
+    reclaimed_top_off_amount: 600,
+    ..Default::default()
+  };
+  assert_eq!(child.unreclaimed_top_off_amount(4_400), 400);
+}
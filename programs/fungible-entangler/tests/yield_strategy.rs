@@ -0,0 +1,168 @@
+//! Runs RebalanceStrategyV0 against a mock lending program in solana-program-test, checking that
+//! the child entangler's signature only reaches child storage and never the escrows it also holds.
+
+use anchor_lang::{
+  prelude::{AccountInfo, AccountMeta, Pubkey},
+  solana_program::{entrypoint::ProgramResult, program::invoke},
+};
+use fungible_entangler::{instructions::RebalanceStrategyV0Args, state::YieldStrategyV0};
+use fungible_entangler_test_utils::{
+  instructions, mint_to, process_instructions, program_test, set_token_account,
+  swap_fixture::STORAGE_AMOUNT, SwapFixture,
+};
+use solana_program_test::processor;
+use std::convert::TryInto;
+
+const DEPOSIT_AMOUNT: u64 = 100_000;
+const ESCROW_AMOUNT: u64 = 2 * DEPOSIT_AMOUNT;
+
+/// A lending program that moves the amount in its instruction data from every source into the
+/// vault. Accounts are the authority of the sources, the token program, the vault, then sources
+fn process_strategy(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+  let amount = u64::from_le_bytes(data[..8].try_into().unwrap());
+  let (authority, token_program, vault) = (&accounts[0], &accounts[1], &accounts[2]);
+  for source in &accounts[3..] {
+    invoke(
+      &spl_token::instruction::transfer(
+        token_program.key,
+        source.key,
+        vault.key,
+        authority.key,
+        &[],
+        amount,
+      )?,
+      &[
+        source.clone(),
+        vault.clone(),
+        authority.clone(),
+        token_program.clone(),
+      ],
+    )?;
+  }
+
+  Ok(())
+}
+
+struct StrategyFixture {
+  fixture: SwapFixture,
+  strategy_program: Pubkey,
+  vault: Pubkey,
+  escrow: Pubkey,
+}
+
+/// A child lending to the mock program, with a vault for the program and an escrow of
+/// ESCROW_AMOUNT held by the child entangler
+async fn strategy_fixture() -> StrategyFixture {
+  let strategy_program = Pubkey::new_unique();
+  let mut program_test = program_test();
+  program_test.add_builtin_program(
+    "mock_strategy",
+    strategy_program,
+    processor!(process_strategy).unwrap(),
+  );
+  let mut fixture = SwapFixture::start(program_test, |parent, child| {
+    child.yield_strategy = Some(YieldStrategyV0 {
+      program: strategy_program,
+      keeper: parent.authority.unwrap(),
+      ..Default::default()
+    });
+  })
+  .await;
+  let keys = fixture.keys;
+
+  let vault = Pubkey::new_unique();
+  set_token_account(
+    &mut fixture.context,
+    &vault,
+    &keys.child_mint,
+    &Pubkey::new_unique(),
+  );
+  let escrow = Pubkey::new_unique();
+  set_token_account(
+    &mut fixture.context,
+    &escrow,
+    &keys.child_mint,
+    &keys.child_entangler,
+  );
+  mint_to(
+    &mut fixture.context,
+    &keys.child_mint,
+    &escrow,
+    ESCROW_AMOUNT,
+  )
+  .await
+  .unwrap();
+
+  StrategyFixture {
+    fixture,
+    strategy_program,
+    vault,
+    escrow,
+  }
+}
+
+impl StrategyFixture {
+  async fn deposit(&mut self, sources: &[Pubkey]) -> Result<(), ()> {
+    let keys = self.fixture.keys;
+    let mut strategy_accounts = vec![
+      AccountMeta::new_readonly(keys.child_entangler, false),
+      AccountMeta::new_readonly(spl_token::id(), false),
+      AccountMeta::new(self.vault, false),
+    ];
+    strategy_accounts.extend(
+      sources
+        .iter()
+        .map(|source| AccountMeta::new(*source, false)),
+    );
+    let ix = instructions::rebalance_strategy(
+      &self.fixture.payer(),
+      &keys,
+      &self.strategy_program,
+      &strategy_accounts,
+      RebalanceStrategyV0Args {
+        deposit: true,
+        amount: DEPOSIT_AMOUNT,
+        data: DEPOSIT_AMOUNT.to_le_bytes().to_vec(),
+      },
+    );
+
+    process_instructions(&mut self.fixture.context, &[ix], &[])
+      .await
+      .map_err(|_| ())
+  }
+}
+
+#[tokio::test]
+async fn test_deposits_take_child_storage() {
+  let mut strategy = strategy_fixture().await;
+  let child_storage = strategy.fixture.keys.child_storage();
+
+  strategy.deposit(&[child_storage]).await.unwrap();
+  assert_eq!(
+    strategy.fixture.balance(child_storage).await,
+    STORAGE_AMOUNT - DEPOSIT_AMOUNT
+  );
+  assert_eq!(
+    strategy.fixture.balance(strategy.vault).await,
+    DEPOSIT_AMOUNT
+  );
+  let yield_strategy = strategy.fixture.child_entangler().await.yield_strategy;
+  assert_eq!(yield_strategy.unwrap().deposited_amount, DEPOSIT_AMOUNT);
+}
+
+#[tokio::test]
+async fn test_deposits_never_reach_the_child_escrows() {
+  let mut strategy = strategy_fixture().await;
+  let child_storage = strategy.fixture.keys.child_storage();
+
+  // Child storage moves by exactly the deposit, so only the escrow check stops this
+  assert!(strategy
+    .deposit(&[child_storage, strategy.escrow])
+    .await
+    .is_err());
+  assert_eq!(
+    strategy.fixture.balance(strategy.escrow).await,
+    ESCROW_AMOUNT
+  );
+  assert_eq!(strategy.fixture.balance(strategy.vault).await, 0);
+}
@@ -188,8 +188,8 @@ impl Curve for PrimitiveCurve {
       match *self {
         // b dS + (c dS^(1 + pow/frac))/(1 + pow/frac)
         PrimitiveCurve::ExponentialCurveV0 { pow, frac, b, c } => {
-          let pow_prec = PreciseNumber::new(u128::try_from(pow).ok()?)?;
-          let frac_prec = PreciseNumber::new(u128::try_from(frac).ok()?)?;
+          let pow_prec = PreciseNumber::new(u128::from(pow))?;
+          let frac_prec = PreciseNumber::new(u128::from(frac))?;
           let c_prec = to_prec(c);
           let b_prec = to_prec(b);
           if b == 0 && c != 0 {
@@ -225,8 +225,8 @@ impl Curve for PrimitiveCurve {
     } else {
       match *self {
         PrimitiveCurve::ExponentialCurveV0 { pow, frac, b, c } => {
-          let pow_prec = PreciseNumber::new(u128::try_from(pow).ok()?)?;
-          let frac_prec = PreciseNumber::new(u128::try_from(frac).ok()?)?;
+          let pow_prec = PreciseNumber::new(u128::from(pow))?;
+          let frac_prec = PreciseNumber::new(u128::from(frac))?;
 
           if b == 0 && c != 0 {
             expected_target_amount_exp(
@@ -276,8 +276,8 @@ impl Curve for PrimitiveCurve {
         PrimitiveCurve::ExponentialCurveV0 { pow, frac, c, b } => {
           let b_prec = to_prec(b);
           let c_prec = to_prec(c);
-          let pow_prec = PreciseNumber::new(u128::try_from(pow).ok()?)?;
-          let frac_prec = PreciseNumber::new(u128::try_from(frac).ok()?)?;
+          let pow_prec = PreciseNumber::new(u128::from(pow))?;
+          let frac_prec = PreciseNumber::new(u128::from(frac))?;
           b_prec.checked_mul(amount)?.checked_add(&price_exp_initial(
             &c_prec,
             &pow_prec.checked_div(&frac_prec)?,
@@ -300,8 +300,8 @@ impl Curve for PrimitiveCurve {
       match *self {
         PrimitiveCurve::ExponentialCurveV0 { pow, frac, c, b } => {
           if b == 0 && c != 0 {
-            let pow_prec = PreciseNumber::new(u128::try_from(pow).ok()?)?;
-            let frac_prec = PreciseNumber::new(u128::try_from(frac).ok()?)?;
+            let pow_prec = PreciseNumber::new(u128::from(pow))?;
+            let frac_prec = PreciseNumber::new(u128::from(frac))?;
             let k = pow_prec.checked_div(&frac_prec)?;
             price_exp(&k, amount, base_amount, target_supply, sell)
           } else if c == 0 {
@@ -344,7 +344,7 @@ fn transition_fees(
   if let Some(fees) = transition_fees_opt {
     let offset_in_current_curve =
       PreciseNumber::new(u128::try_from(time_offset.checked_sub(curve.offset)?).ok()?)?;
-    let interval = PreciseNumber::new(u128::try_from(fees.interval).ok()?)?;
+    let interval = PreciseNumber::new(u128::from(fees.interval))?;
     // Decaying percentage. Starts at 100%, works its way down to 0 over the interval. (interval - curr_offset) / interval.
     // When curr_offset is past interval, checked_sub fails and this is just none
     let percent_of_fees_opt = interval
@@ -140,16 +140,16 @@ pub fn buy_shared_logic(common: &mut BuyCommonV0, args: &BuyV0Args) -> Result<Bu
     }
   }
 
-  if token_bonding.mint_cap.is_some()
-    && target_mint.supply.checked_add(total_amount).unwrap() > token_bonding.mint_cap.unwrap()
-  {
-    msg!(
-      "Mint cap is {} {} {}",
-      token_bonding.mint_cap.unwrap(),
-      target_mint.supply,
-      total_amount
-    );
-    return Err(error!(ErrorCode::PassedMintCap));
+  if let Some(mint_cap) = token_bonding.mint_cap {
+    if target_mint.supply.checked_add(total_amount).unwrap() > mint_cap {
+      msg!(
+        "Mint cap is {} {} {}",
+        mint_cap,
+        target_mint.supply,
+        total_amount
+      );
+      return Err(error!(ErrorCode::PassedMintCap));
+    }
   }
 
   if token_bonding.purchase_cap.is_some() && total_amount > token_bonding.purchase_cap.unwrap() {
@@ -22,7 +22,7 @@ pub fn curve_is_valid(curve: &PiecewiseCurve) -> bool {
     {
       curves.iter().all(|c| primitive_curve_is_valid(&c.curve) && c.offset >= 0) &&
         // The first curve starts at time 0
-        curves.get(0).map(|c| c.offset).unwrap_or(1) == 0 &&
+        curves.first().map(|c| c.offset).unwrap_or(1) == 0 &&
         // The curves list is ordered by offset
         curves.windows(2).all(|c| c[0].offset <= c[1].offset)
     }
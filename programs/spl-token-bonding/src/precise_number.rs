@@ -47,7 +47,6 @@ pub const fn ln2hi() -> InnerUint {
 }
 pub const LN2HI: PreciseNumber = PreciseNumber { value: ln2hi() };
 #[inline]
-
 pub const fn ln2hi_scale() -> InnerUint {
   U192([7766279631452241920_u64, 5_u64, 0_u64])
 }
@@ -36,10 +36,8 @@ pub fn get_percent_prec(percent: u32) -> Result<PreciseNumber> {
 
 pub fn get_percent(value: u64, percent: u32) -> Result<u64> {
   u64::try_from(
-    u128::try_from(value)
-      .ok()
-      .or_arith_error()?
-      .checked_mul(u128::try_from(percent).ok().or_arith_error()?)
+    u128::from(value)
+      .checked_mul(u128::from(percent))
       .or_arith_error()?
       .checked_div(u32::MAX as u128)
       .or_arith_error()?,
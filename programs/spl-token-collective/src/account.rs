@@ -272,7 +272,7 @@ pub struct UpdateTokenBondingV0Wrapper<'info> {
   pub collective: Box<Account<'info, CollectiveV0>>,
   /// CHECK: Checked via constraints
   #[account(
-    address = collective.authority.unwrap_or(Pubkey::default()),
+    address = collective.authority.unwrap_or_default(),
     constraint = collective.config.is_open || authority.is_signer
   )]
   pub authority: AccountInfo<'info>,
@@ -649,7 +649,7 @@ pub struct UpdateCurveV0Wrapper<'info> {
   pub collective: Box<Account<'info, CollectiveV0>>,
   /// CHECK: Checked via constraints
   #[account(
-    constraint = (collective.config.is_open || authority.is_signer) && authority.key() == collective.authority.unwrap_or(Pubkey::default())
+    constraint = (collective.config.is_open || authority.is_signer) && authority.key() == collective.authority.unwrap_or_default()
   )]
   pub authority: AccountInfo<'info>,
   #[account(
@@ -217,13 +217,13 @@ pub mod spl_token_collective {
       config.and_then(|c| c.unclaimed_token_bonding_settings.as_ref());
     let token_metadata_settings_opt =
       config.and_then(|c| c.unclaimed_token_metadata_settings.as_ref());
-    if token_bonding_settings_opt.is_some() {
+    if let Some(token_bonding_settings) = token_bonding_settings_opt {
       verify_token_bonding_defaults(
-        token_bonding_settings_opt.unwrap(),
+        token_bonding_settings,
         &initialize_args.token_bonding,
       )?;
       verify_token_bonding_royalties(
-        token_bonding_settings_opt.unwrap(),
+        token_bonding_settings,
         &initialize_args.token_bonding,
         &ctx.accounts.mint_token_ref.key(),
         &initialize_args.buy_base_royalties,
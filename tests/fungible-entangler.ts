@@ -5,6 +5,7 @@ import ChaiAsPromised from "chai-as-promised";
 import { createMint, createAtaAndMint } from "@strata-foundation/spl-utils";
 import {
   FungibleEntangler,
+  hashSeed,
   IFungibleParentEntangler,
   IFungibleChildEntangler,
 } from "../packages/fungible-entangler/src";
@@ -24,6 +25,13 @@ describe("fungible-entangler", () => {
   const fungibleEntanglerProgram = new FungibleEntangler(provider, program);
   const me = fungibleEntanglerProgram.wallet.publicKey;
 
+  before(async () => {
+    // Entanglers can only be created under the global config, which the upgrade authority sets up
+    if (!(await fungibleEntanglerProgram.getGlobalConfig())) {
+      await fungibleEntanglerProgram.initializeGlobalConfig();
+    }
+  });
+
   it("allows creation of a parent entangler", async () => {
     const dynamicSeed = Keypair.generate().publicKey;
     const parentMint = await createMint(provider, me, 0);
@@ -42,6 +50,9 @@ describe("fungible-entangler", () => {
 
     expect(tB58(parentEntanglerAcct.parentMint)).to.eq(tB58(parentMint));
     expect(tB58(parentEntanglerAcct.authority)).to.eq(tB58(me));
+    expect(Buffer.from(parentEntanglerAcct.dynamicSeedHash)).to.deep.eq(
+      hashSeed(dynamicSeed.toBuffer())
+    );
 
     await tokenUtils.expectAtaBalance(me, parentEntanglerAcct.parentMint, 0);
     await tokenUtils.expectBalance(parentEntanglerAcct.parentStorage, 100);